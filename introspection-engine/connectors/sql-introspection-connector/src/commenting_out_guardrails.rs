@@ -67,7 +67,9 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
     // use unsupported types to drop @@id / @@unique /@@index
     for mf in &unsupported_types {
         let model = datamodel.find_model_mut(&mf.model);
-        model.indices.retain(|i| !i.fields.contains(&mf.field));
+        model
+            .indices
+            .retain(|i| !i.fields.contains(&mf.field) && !i.include.contains(&mf.field));
         if model.id_fields.contains(&mf.field) {
             model.id_fields = vec![]
         };
@@ -76,11 +78,14 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
     // models without uniques / ids
     for model in datamodel.models_mut() {
         if model.strict_unique_criterias().is_empty() {
-            model.is_commented_out = true;
-            model.documentation = Some(
+            let documentation = if model.is_view {
+                "The underlying view does not contain a valid unique identifier and can therefore currently not be handled. Mark a column with @id or @unique to query it."
+            } else {
                 "The underlying table does not contain a valid unique identifier and can therefore currently not be handled."
-                    .to_string(),
-            );
+            };
+
+            model.is_commented_out = true;
+            model.documentation = Some(documentation.to_string());
             models_without_identifiers.push(Model {
                 model: model.name.clone(),
             })
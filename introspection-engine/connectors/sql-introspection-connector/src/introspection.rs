@@ -1,7 +1,7 @@
 use crate::misc_helpers::{
-    calculate_backrelation_field, calculate_index, calculate_many_to_many_field, calculate_relation_field,
-    calculate_scalar_field, is_migration_table, is_prisma_1_point_0_join_table, is_prisma_1_point_1_or_2_join_table,
-    is_relay_table,
+    calculate_backrelation_field, calculate_check_constraint, calculate_index, calculate_many_to_many_field,
+    calculate_primary_key_constraint_name, calculate_relation_field, calculate_scalar_field, is_migration_table,
+    is_prisma_1_point_0_join_table, is_prisma_1_point_1_or_2_join_table, is_relay_table,
 };
 use crate::version_checker::VersionChecker;
 use crate::SqlError;
@@ -27,6 +27,7 @@ pub fn introspect(
     {
         debug!("Calculating model: {}", table.name);
         let mut model = Model::new(table.name.clone(), None);
+        model.schema = table.schema.clone();
 
         for column in &table.columns {
             version_check.check_column_for_type_and_default_value(&column);
@@ -48,7 +49,7 @@ pub fn introspect(
         }) {
             version_check.has_inline_relations(table);
             version_check.uses_on_delete(foreign_key, table);
-            let relation_field = calculate_relation_field(schema, table, foreign_key)?;
+            let relation_field = calculate_relation_field(schema, table, foreign_key, &sql_family)?;
             model.add_field(Field::RelationField(relation_field));
         }
 
@@ -60,8 +61,15 @@ pub fn introspect(
             model.add_index(calculate_index(index));
         }
 
+        for check_constraint in &table.check_constraints {
+            model
+                .check_constraints
+                .push(calculate_check_constraint(check_constraint));
+        }
+
         if table.primary_key_columns().len() > 1 {
             model.id_fields = table.primary_key_columns();
+            model.id_constraint_name = calculate_primary_key_constraint_name(table, &sql_family);
         }
 
         version_check.always_has_created_at_updated_at(table, &model);
@@ -70,6 +78,33 @@ pub fn introspect(
         data_model.add_model(model);
     }
 
+    for view in schema.views.iter() {
+        debug!("Calculating view: {}", view.name);
+        let mut model = Model::new(view.name.clone(), None);
+        model.is_view = true;
+        model.schema = view.schema.clone();
+
+        // Views have no keys or indexes of their own, their columns are calculated like those of
+        // a table without constraints.
+        let table = Table {
+            name: view.name.clone(),
+            schema: view.schema.clone(),
+            columns: view.columns.clone(),
+            indices: Vec::new(),
+            primary_key: None,
+            foreign_keys: Vec::new(),
+            check_constraints: Vec::new(),
+        };
+
+        for column in &view.columns {
+            version_check.check_column_for_type_and_default_value(&column);
+            let field = calculate_scalar_field(&table, &column, &sql_family, native_types);
+            model.add_field(Field::ScalarField(field));
+        }
+
+        data_model.add_model(model);
+    }
+
     for e in schema.enums.iter() {
         let values = e.values.iter().map(|v| dml::EnumValue::new(v)).collect();
         data_model.add_enum(dml::Enum::new(&e.name, values));
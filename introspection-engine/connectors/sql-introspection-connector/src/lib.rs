@@ -25,10 +25,17 @@ pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlError>;
 pub struct SqlIntrospectionConnector {
     connection_info: ConnectionInfo,
     describer: Box<dyn SqlSchemaDescriberBackend>,
+    /// The schemas from the datasource's `schemas` property. Empty if only the schema from the
+    /// connection string is introspected.
+    schemas: Vec<String>,
 }
 
 impl SqlIntrospectionConnector {
     pub async fn new(url: &str) -> ConnectorResult<SqlIntrospectionConnector> {
+        Self::new_with_schemas(url, Vec::new()).await
+    }
+
+    pub async fn new_with_schemas(url: &str, schemas: Vec<String>) -> ConnectorResult<SqlIntrospectionConnector> {
         let (describer, connection_info) = schema_describer_loading::load_describer(&url)
             .instrument(tracing::debug_span!("Loading describer"))
             .await
@@ -43,6 +50,7 @@ impl SqlIntrospectionConnector {
         Ok(SqlIntrospectionConnector {
             describer,
             connection_info,
+            schemas,
         })
     }
 
@@ -65,7 +73,11 @@ impl SqlIntrospectionConnector {
     }
 
     async fn describe(&self) -> SqlIntrospectionResult<SqlSchema> {
-        Ok(self.describer.describe(self.connection_info.schema_name()).await?)
+        if self.schemas.is_empty() {
+            Ok(self.describer.describe(self.connection_info.schema_name()).await?)
+        } else {
+            Ok(self.describer.describe_schemas(&self.schemas).await?)
+        }
     }
 
     async fn version(&self) -> SqlIntrospectionResult<String> {
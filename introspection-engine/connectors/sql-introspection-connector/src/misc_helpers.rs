@@ -1,13 +1,14 @@
 use crate::SqlError;
 use datamodel::{
     common::RelationNames, Datamodel, DefaultValue as DMLDef, FieldArity, FieldType, IndexDefinition, Model,
-    OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator as VG,
+    ReferentialAction, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator as VG,
 };
 use datamodel_connector::Connector;
 use quaint::connector::SqlFamily;
 use sql_datamodel_connector::SqlDatamodelConnectors;
 use sql_schema_describer::{
-    Column, ColumnArity, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, Index, IndexType, SqlSchema, Table,
+    CheckConstraint, Column, ColumnArity, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, ForeignKeyAction,
+    Index, IndexAlgorithm, IndexType, SQLSortOrder, SqlSchema, Table,
 };
 use tracing::debug;
 
@@ -95,7 +96,9 @@ pub fn calculate_many_to_many_field(
         fields: vec![],
         to: opposite_foreign_key.referenced_table.clone(),
         to_fields: opposite_foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
+        on_delete: None,
+        on_update: None,
+        constraint_name: None,
     };
 
     let basename = opposite_foreign_key.referenced_table.clone();
@@ -115,13 +118,104 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
         IndexType::Normal => datamodel::dml::IndexType::Normal,
     };
 
+    let field_options = index
+        .column_options
+        .iter()
+        .map(|(column, options)| {
+            let options = datamodel::dml::IndexFieldOptions {
+                sort_order: match options.sort_order {
+                    SQLSortOrder::Asc => datamodel::dml::SortOrder::Asc,
+                    SQLSortOrder::Desc => datamodel::dml::SortOrder::Desc,
+                },
+                length: options.length,
+            };
+
+            (column.clone(), options)
+        })
+        .collect();
+
+    let algorithm = index.algorithm.map(|algorithm| match algorithm {
+        IndexAlgorithm::Hash => datamodel::dml::IndexAlgorithm::Hash,
+        IndexAlgorithm::Gist => datamodel::dml::IndexAlgorithm::Gist,
+        IndexAlgorithm::Gin => datamodel::dml::IndexAlgorithm::Gin,
+        IndexAlgorithm::Brin => datamodel::dml::IndexAlgorithm::Brin,
+    });
+
     IndexDefinition {
         name: Some(index.name.clone()),
         fields: index.columns.clone(),
         tpe,
+        field_options,
+        algorithm,
+        predicate: index.predicate.clone(),
+        include: index.include.clone(),
+        database_name: None,
     }
 }
 
+/// Check constraint expressions refer to column names, so they are kept as the database reports them.
+pub(crate) fn calculate_check_constraint(check_constraint: &CheckConstraint) -> datamodel::dml::CheckConstraint {
+    debug!("Handling check constraint {:?}", check_constraint);
+
+    datamodel::dml::CheckConstraint {
+        name: check_constraint.name.clone(),
+        expression: check_constraint.expression.clone(),
+    }
+}
+
+/// Constraint names only end up in the datamodel when they differ from the name the database
+/// would pick on its own, so `map:` is reserved for names somebody chose deliberately.
+pub(crate) fn calculate_primary_key_constraint_name(table: &Table, family: &SqlFamily) -> Option<String> {
+    let primary_key = table.primary_key.as_ref()?;
+    let constraint_name = primary_key.constraint_name.as_ref()?;
+
+    let is_default = match family {
+        SqlFamily::Postgres => *constraint_name == format!("{}_pkey", table.name),
+        SqlFamily::Mssql => {
+            constraint_name.starts_with("PK__")
+                || *constraint_name == format!("PK_{}_{}", table.name, primary_key.columns.join("_"))
+        }
+        SqlFamily::Mysql | SqlFamily::Sqlite => true,
+    };
+
+    Some(constraint_name.clone()).filter(|_| !is_default)
+}
+
+pub(crate) fn calculate_unique_constraint_name(table: &Table, column: &Column, family: &SqlFamily) -> Option<String> {
+    let index = table
+        .indices
+        .iter()
+        .find(|index| index.is_unique() && index.columns.len() == 1 && index.columns[0] == column.name)?;
+
+    let is_default = index.name == format!("{}.{}_unique", table.name, column.name)
+        || index.name == format!("{}_{}_unique", table.name, column.name)
+        || match family {
+            SqlFamily::Postgres => index.name == format!("{}_{}_key", table.name, column.name),
+            SqlFamily::Mysql => index.name == column.name,
+            SqlFamily::Mssql => index.name.starts_with("UQ__"),
+            SqlFamily::Sqlite => index.name.starts_with("sqlite_autoindex_"),
+        };
+
+    Some(index.name.clone()).filter(|_| !is_default)
+}
+
+pub(crate) fn calculate_foreign_key_constraint_name(
+    table: &Table,
+    foreign_key: &ForeignKey,
+    family: &SqlFamily,
+) -> Option<String> {
+    let constraint_name = foreign_key.constraint_name.as_ref()?;
+
+    let is_default = match family {
+        SqlFamily::Postgres => *constraint_name == format!("{}_{}_fkey", table.name, foreign_key.columns.join("_")),
+        SqlFamily::Mysql => constraint_name.starts_with(&format!("{}_ibfk_", table.name)),
+        SqlFamily::Mssql => constraint_name.starts_with("FK__"),
+        SqlFamily::Sqlite => true,
+    };
+
+    Some(constraint_name.clone()).filter(|_| !is_default)
+}
+
 pub(crate) fn calculate_scalar_field(
     table: &Table,
     column: &Column,
@@ -151,6 +245,8 @@ pub(crate) fn calculate_scalar_field(
     let is_id = is_id(&column, &table);
     let default_value = calculate_default(table, &column, &arity);
     let is_unique = table.is_column_unique(&column.name) && !is_id;
+    let id_constraint_name = calculate_primary_key_constraint_name(table, family).filter(|_| is_id);
+    let unique_constraint_name = calculate_unique_constraint_name(table, column, family).filter(|_| is_unique);
 
     ScalarField {
         name: column.name.clone(),
@@ -163,7 +259,10 @@ pub(crate) fn calculate_scalar_field(
         documentation,
         is_generated: false,
         is_updated_at: false,
+        previous_database_name: None,
         is_commented_out,
+        id_constraint_name,
+        unique_constraint_name,
     }
 }
 
@@ -171,17 +270,10 @@ pub(crate) fn calculate_relation_field(
     schema: &SqlSchema,
     table: &Table,
     foreign_key: &ForeignKey,
+    family: &SqlFamily,
 ) -> Result<RelationField, SqlError> {
     debug!("Handling foreign key  {:?}", foreign_key);
 
-    let relation_info = RelationInfo {
-        name: calculate_relation_name(schema, foreign_key, table)?,
-        fields: foreign_key.columns.clone(),
-        to: foreign_key.referenced_table.clone(),
-        to_fields: foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
-    };
-
     let columns: Vec<&Column> = foreign_key
         .columns
         .iter()
@@ -193,9 +285,42 @@ pub(crate) fn calculate_relation_field(
         false => FieldArity::Required,
     };
 
+    let default_on_delete = match arity {
+        FieldArity::Optional => ReferentialAction::SetNull,
+        _ => ReferentialAction::Cascade,
+    };
+
+    let relation_info = RelationInfo {
+        name: calculate_relation_name(schema, foreign_key, table)?,
+        fields: foreign_key.columns.clone(),
+        to: foreign_key.referenced_table.clone(),
+        to_fields: foreign_key.referenced_columns.clone(),
+        on_delete: calculate_referential_action(&foreign_key.on_delete_action, default_on_delete),
+        on_update: calculate_referential_action(&foreign_key.on_update_action, ReferentialAction::Cascade),
+        constraint_name: calculate_foreign_key_constraint_name(table, foreign_key, family),
+    };
+
     Ok(RelationField::new(&foreign_key.referenced_table, arity, relation_info))
 }
 
+/// Only actions that differ from what the migration engine would render anyway end up in the
+/// schema. The engine never renders `NO ACTION` implicitly, so it is always kept.
+fn calculate_referential_action(action: &ForeignKeyAction, default: ReferentialAction) -> Option<ReferentialAction> {
+    let action = match action {
+        ForeignKeyAction::NoAction => ReferentialAction::NoAction,
+        ForeignKeyAction::Restrict => ReferentialAction::Restrict,
+        ForeignKeyAction::Cascade => ReferentialAction::Cascade,
+        ForeignKeyAction::SetNull => ReferentialAction::SetNull,
+        ForeignKeyAction::SetDefault => ReferentialAction::SetDefault,
+    };
+
+    if action == default {
+        None
+    } else {
+        Some(action)
+    }
+}
+
 pub(crate) fn calculate_backrelation_field(
     schema: &SqlSchema,
     model: &Model,
@@ -213,7 +338,9 @@ pub(crate) fn calculate_backrelation_field(
                 to: model.name.clone(),
                 fields: vec![],
                 to_fields: vec![],
-                on_delete: OnDeleteStrategy::None,
+                on_delete: None,
+                on_update: None,
+                constraint_name: None,
             };
 
             // unique or id
@@ -244,8 +371,12 @@ pub(crate) fn calculate_backrelation_field(
 pub(crate) fn calculate_default(table: &Table, column: &Column, arity: &FieldArity) -> Option<DMLDef> {
     match (&column.default, &column.tpe.family) {
         (_, _) if *arity == FieldArity::List => None,
-        (_, ColumnTypeFamily::Int) if column.auto_increment => Some(DMLDef::Expression(VG::new_autoincrement())),
-        (_, ColumnTypeFamily::Int) if is_sequence(column, table) => Some(DMLDef::Expression(VG::new_autoincrement())),
+        (_, ColumnTypeFamily::Int) | (_, ColumnTypeFamily::BigInt) if column.auto_increment => {
+            Some(DMLDef::Expression(VG::new_autoincrement()))
+        }
+        (_, ColumnTypeFamily::Int) | (_, ColumnTypeFamily::BigInt) if is_sequence(column, table) => {
+            Some(DMLDef::Expression(VG::new_autoincrement()))
+        }
         (Some(SQLDef::SEQUENCE(_)), _) => Some(DMLDef::Expression(VG::new_autoincrement())),
         (Some(SQLDef::NOW), ColumnTypeFamily::DateTime) => Some(DMLDef::Expression(VG::new_now())),
         (Some(SQLDef::DBGENERATED(_)), _) => Some(DMLDef::Expression(VG::new_dbgenerated())),
@@ -344,6 +475,7 @@ pub(crate) fn calculate_scalar_field_type(column: &Column, family: &SqlFamily) -
         _ if is_mysql_bit => FieldType::Base(ScalarType::Int, None),
         _ if is_postgres_interval => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int, None),
+        ColumnTypeFamily::BigInt => FieldType::Base(ScalarType::BigInt, None),
         ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float, None),
         ColumnTypeFamily::Decimal => FieldType::Base(ScalarType::Float, None),
         ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean, None),
@@ -353,7 +485,7 @@ pub(crate) fn calculate_scalar_field_type(column: &Column, family: &SqlFamily) -
         ColumnTypeFamily::Json => FieldType::Base(ScalarType::Json, None),
         ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::Enum(name) => FieldType::Enum(name.to_owned()),
-        ColumnTypeFamily::Binary => FieldType::Unsupported(fdt), //not explicit before
+        ColumnTypeFamily::Binary => FieldType::Base(ScalarType::Bytes, None),
         ColumnTypeFamily::Geometric => FieldType::Unsupported(fdt), // not explicit before
         ColumnTypeFamily::LogSequenceNumber => FieldType::Unsupported(fdt), // not explicit before
         ColumnTypeFamily::TextSearch => FieldType::Unsupported(fdt), // not explicit before
@@ -368,6 +500,7 @@ pub(crate) fn calculate_scalar_field_type_for_native_type(column: &Column) -> Fi
 
     match &column.tpe.family {
         ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int, None),
+        ColumnTypeFamily::BigInt => FieldType::Base(ScalarType::BigInt, None),
         ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float, None),
         ColumnTypeFamily::Decimal => FieldType::Base(ScalarType::Decimal, None),
         ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean, None),
@@ -76,6 +76,11 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel) -> Vec
             replace_field_names(&mut model.id_fields, &changed_field_name.0.field, &changed_field_name.1);
             for index in &mut model.indices {
                 replace_field_names(&mut index.fields, &changed_field_name.0.field, &changed_field_name.1);
+                replace_field_names(&mut index.include, &changed_field_name.0.field, &changed_field_name.1);
+
+                if let Some(options) = index.field_options.remove(&changed_field_name.0.field) {
+                    index.field_options.insert(changed_field_name.1.clone(), options);
+                }
             }
             for field in model.relation_fields_mut() {
                 replace_field_names(
@@ -96,6 +96,11 @@ fn sanitize_models(datamodel: &mut Datamodel, family: &SqlFamily) -> HashMap<Str
 
         for index in &mut model.indices {
             index.fields = sanitize_strings(&index.fields);
+            index.include = sanitize_strings(&index.include);
+            index.field_options = std::mem::take(&mut index.field_options)
+                .into_iter()
+                .map(|(field, options)| (sanitize_string(&field), options))
+                .collect();
         }
     }
 
@@ -21,7 +21,7 @@ async fn introspecting_a_table_without_uniques_should_comment_it_out_sqlite(api:
         })
         .await;
 
-    let dm = "model User {\n  id      Int    @id @default(autoincrement())\n  // Post Post[]\n}\n\n// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id])\n// }\n";
+    let dm = "model User {\n  id      Int    @id @default(autoincrement())\n  // Post Post[]\n}\n\n// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)\n// }\n";
 
     let result = dbg!(api.introspect().await);
     assert_eq!(&result, dm);
@@ -80,7 +80,7 @@ async fn introspecting_a_table_without_uniques_should_comment_it_out_mysql(api:
         })
         .await;
 
-    let dm = "// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id])\n\n  // @@index([user_id], name: \"user_id\")\n// }\n\nmodel User {\n  id      Int    @id @default(autoincrement())\n  // Post Post[]\n}\n";
+    let dm = "// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)\n\n  // @@index([user_id], name: \"user_id\")\n// }\n\nmodel User {\n  id      Int    @id @default(autoincrement())\n  // Post Post[]\n}\n";
 
     let result = dbg!(api.introspect().await);
     assert_eq!(&result, dm);
@@ -235,7 +235,7 @@ async fn introspecting_a_table_without_uniques_should_comment_it_out_mssql(api:
         })
         .await;
 
-    let dm = "// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id])\n// }\n\nmodel User {\n  id      Int    @id @default(autoincrement())\n  // Post Post[]\n}\n";
+    let dm = "// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)\n// }\n\nmodel User {\n  id      Int    @id @default(autoincrement())\n  // Post Post[]\n}\n";
 
     let result = dbg!(api.introspect().await);
     assert_eq!(&result, dm);
@@ -286,5 +286,5 @@ async fn introspecting_an_unsupported_type_should_comment_it_out_mssql(api: &Tes
 //     );
 //
 //     let result = dbg!(api.introspect().await);
-//     assert_eq!(&result, "model Post {\n  id     Int   @id @default(autoincrement())\n  user_1 Int?\n  User   User? @relation(fields: [user_1], references: [1])\n}\n\nmodel User {\n  id   Int    @id @default(autoincrement())\n  // This field was commented out because of an invalid name. Please provide a valid one that matches [a-zA-Z][a-zA-Z0-9_]*\n  // 1 Int    @map(\"1\") @unique\n  Post Post[]\n}\n");
+//     assert_eq!(&result, "model Post {\n  id     Int   @id @default(autoincrement())\n  user_1 Int?\n  User   User? @relation(fields: [user_1], references: [1], onDelete: NoAction, onUpdate: NoAction)\n}\n\nmodel User {\n  id   Int    @id @default(autoincrement())\n  // This field was commented out because of an invalid name. Please provide a valid one that matches [a-zA-Z][a-zA-Z0-9_]*\n  // 1 Int    @map(\"1\") @unique\n  Post Post[]\n}\n");
 // }
@@ -1,7 +1,7 @@
 use crate::test_harness::*;
 use datamodel::{
-    dml, Datamodel, DefaultValue as DMLDefault, Field, FieldArity, FieldType, IndexDefinition, Model, OnDeleteStrategy,
-    RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator,
+    dml, Datamodel, DefaultValue as DMLDefault, Field, FieldArity, FieldType, IndexDefinition, Model,
+    ReferentialAction, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator,
 };
 use native_types::{NativeType, PostgresType};
 use pretty_assertions::assert_eq;
@@ -15,6 +15,7 @@ use test_macros::test_each_connector;
 fn a_data_model_can_be_generated_from_a_schema() {
     let col_types = &[
         ColumnTypeFamily::Int,
+        ColumnTypeFamily::BigInt,
         ColumnTypeFamily::Float,
         ColumnTypeFamily::Boolean,
         ColumnTypeFamily::String,
@@ -49,6 +50,11 @@ fn a_data_model_can_be_generated_from_a_schema() {
             is_commented_out: true,
             indices: vec![],
             id_fields: vec![],
+            id_constraint_name: None,
+            check_constraints: vec![],
+            is_view: false,
+            schema: None,
+            previous_database_name: None,
             fields: col_types
                 .iter()
                 .map(|col_type| {
@@ -57,6 +63,8 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         ColumnTypeFamily::DateTime => (FieldType::Base(ScalarType::DateTime, None), false, None),
                         ColumnTypeFamily::Float => (FieldType::Base(ScalarType::Float, None), false, None),
                         ColumnTypeFamily::Int => (FieldType::Base(ScalarType::Int, None), false, None),
+                        ColumnTypeFamily::BigInt => (FieldType::Base(ScalarType::BigInt, None), false, None),
+                        ColumnTypeFamily::Binary => (FieldType::Base(ScalarType::Bytes, None), false, None),
                         ColumnTypeFamily::String => (FieldType::Base(ScalarType::String, None), false, None),
                         ColumnTypeFamily::Enum(name) => (FieldType::Enum(name.clone()), false, None),
                         ColumnTypeFamily::Uuid => (FieldType::Base(ScalarType::String, None), false, None),
@@ -74,17 +82,22 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         documentation,
                         is_generated: false,
                         is_updated_at: false,
+                        previous_database_name: None,
                         is_commented_out,
+                        id_constraint_name: None,
+                        unique_constraint_name: None,
                     })
                 })
                 .collect(),
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: col_types
                 .iter()
                 .map(|family| Column {
@@ -97,9 +110,11 @@ fn a_data_model_can_be_generated_from_a_schema() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result =
         calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -133,7 +148,10 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    previous_database_name: None,
                     is_commented_out: false,
+                    id_constraint_name: None,
+                    unique_constraint_name: None,
                 }),
                 Field::ScalarField(ScalarField::new(
                     "list",
@@ -144,13 +162,20 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             is_generated: false,
             indices: vec![],
             id_fields: vec![],
+            id_constraint_name: None,
+            check_constraints: vec![],
+            is_view: false,
+            schema: None,
+            previous_database_name: None,
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "optional".to_string(),
@@ -178,9 +203,11 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result =
         calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -214,7 +241,10 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    previous_database_name: None,
                     is_commented_out: false,
+                    id_constraint_name: None,
+                    unique_constraint_name: None,
                 }),
                 Field::ScalarField(ScalarField {
                     name: "bool_default".to_string(),
@@ -227,7 +257,10 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    previous_database_name: None,
                     is_commented_out: false,
+                    id_constraint_name: None,
+                    unique_constraint_name: None,
                 }),
                 Field::ScalarField(ScalarField {
                     name: "float_default".to_string(),
@@ -240,7 +273,10 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    previous_database_name: None,
                     is_commented_out: false,
+                    id_constraint_name: None,
+                    unique_constraint_name: None,
                 }),
                 Field::ScalarField(ScalarField {
                     name: "string_default".to_string(),
@@ -253,7 +289,10 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    previous_database_name: None,
                     is_commented_out: false,
+                    id_constraint_name: None,
+                    unique_constraint_name: None,
                 }),
             ],
             is_generated: false,
@@ -261,15 +300,27 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                 name: Some("unique".into()),
                 fields: vec!["no_default".into(), "int_default".into()],
                 tpe: dml::IndexType::Unique,
+                field_options: Default::default(),
+                algorithm: None,
+                predicate: None,
+                include: Vec::new(),
+                database_name: None,
             }],
             id_fields: vec![],
+            id_constraint_name: None,
+            check_constraints: vec![],
+            is_view: false,
+            schema: None,
+            previous_database_name: None,
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "no_default".to_string(),
@@ -306,12 +357,18 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "unique".to_string(),
                 columns: vec!["no_default".into(), "int_default".into()],
                 tpe: IndexType::Unique,
+                column_options: Default::default(),
+                algorithm: None,
+                predicate: None,
+                include: Vec::new(),
             }],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result =
         calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -341,11 +398,19 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    previous_database_name: None,
                     is_commented_out: false,
+                    id_constraint_name: None,
+                    unique_constraint_name: None,
                 })],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_constraint_name: None,
+                check_constraints: vec![],
+                is_view: false,
+                schema: None,
+                previous_database_name: None,
             },
             // Model with non-auto-incrementing primary key
             Model {
@@ -365,11 +430,19 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    previous_database_name: None,
                     is_commented_out: false,
+                    id_constraint_name: None,
+                    unique_constraint_name: None,
                 })],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_constraint_name: None,
+                check_constraints: vec![],
+                is_view: false,
+                schema: None,
+                previous_database_name: None,
             },
             // Model with primary key seeded by sequence
             Model {
@@ -389,20 +462,30 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    previous_database_name: None,
                     is_commented_out: false,
+                    id_constraint_name: None,
+                    unique_constraint_name: None,
                 })],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_constraint_name: None,
+                check_constraints: vec![],
+                is_view: false,
+                schema: None,
+                previous_database_name: None,
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![
             Table {
                 name: "Table1".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "primary".to_string(),
                     tpe: ColumnType {
@@ -423,9 +506,11 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             },
             Table {
                 name: "Table2".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "primary".to_string(),
                     tpe: ColumnType {
@@ -446,9 +531,11 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             },
             Table {
                 name: "Table3".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "primary".to_string(),
                     tpe: ColumnType {
@@ -474,10 +561,12 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result =
         calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -511,19 +600,29 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    previous_database_name: None,
                     is_commented_out: false,
+                    id_constraint_name: None,
+                    unique_constraint_name: Some("unique".to_string()),
                 }),
             ],
             is_generated: false,
             indices: vec![],
             id_fields: vec![],
+            id_constraint_name: None,
+            check_constraints: vec![],
+            is_view: false,
+            schema: None,
+            previous_database_name: None,
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "non_unique".to_string(),
@@ -542,12 +641,18 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "unique".to_string(),
                 columns: vec!["unique".to_string()],
                 tpe: IndexType::Unique,
+                column_options: Default::default(),
+                algorithm: None,
+                predicate: None,
+                include: Vec::new(),
             }],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result =
         calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -577,7 +682,10 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        previous_database_name: None,
                         is_commented_out: false,
+                        id_constraint_name: None,
+                        unique_constraint_name: None,
                     }),
                     Field::ScalarField(ScalarField::new(
                         "name",
@@ -592,13 +700,20 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             fields: vec![],
                             to_fields: vec![],
                             name: "CityToUser".to_string(),
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: None,
+                            on_update: None,
+                            constraint_name: None,
                         },
                     )),
                 ],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_constraint_name: None,
+                check_constraints: vec![],
+                is_view: false,
+                schema: None,
+                previous_database_name: None,
             },
             Model {
                 database_name: None,
@@ -618,7 +733,10 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        previous_database_name: None,
                         is_commented_out: false,
+                        id_constraint_name: None,
+                        unique_constraint_name: None,
                     }),
                     Field::ScalarField(ScalarField {
                         name: "city_id".to_string(),
@@ -631,7 +749,10 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        previous_database_name: None,
                         is_commented_out: false,
+                        id_constraint_name: None,
+                        unique_constraint_name: None,
                     }),
                     Field::ScalarField(ScalarField {
                         name: "city_name".to_string(),
@@ -644,7 +765,10 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        previous_database_name: None,
                         is_commented_out: false,
+                        id_constraint_name: None,
+                        unique_constraint_name: None,
                     }),
                     Field::RelationField(RelationField::new(
                         "City",
@@ -654,22 +778,31 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             to: "City".to_string(),
                             fields: vec!["city_id".to_string(), "city_name".to_string()],
                             to_fields: vec!["id".to_string(), "name".to_string()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: Some(ReferentialAction::NoAction),
+                            on_update: Some(ReferentialAction::NoAction),
+                            constraint_name: None,
                         },
                     )),
                 ],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_constraint_name: None,
+                check_constraints: vec![],
+                is_view: false,
+                schema: None,
+                previous_database_name: None,
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![
             Table {
                 name: "City".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
@@ -707,9 +840,11 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             },
             Table {
                 name: "User".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
@@ -765,14 +900,17 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     constraint_name: None,
                     columns: vec!["city-id".to_string(), "city-name".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                     on_update_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string(), "name".to_string()],
                 }],
+                check_constraints: vec![],
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result =
         calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -801,7 +939,10 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    previous_database_name: None,
                     is_commented_out: false,
+                    id_constraint_name: None,
+                    unique_constraint_name: None,
                 }),
                 Field::ScalarField(ScalarField::new(
                     "name",
@@ -819,15 +960,27 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 name: Some("name_last_name_unique".to_string()),
                 fields: vec!["name".to_string(), "lastname".to_string()],
                 tpe: datamodel::dml::IndexType::Unique,
+                field_options: Default::default(),
+                algorithm: None,
+                predicate: None,
+                include: Vec::new(),
+                database_name: None,
             }],
             id_fields: vec![],
+            id_constraint_name: None,
+            check_constraints: vec![],
+            is_view: false,
+            schema: None,
+            previous_database_name: None,
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "id".to_string(),
@@ -876,6 +1029,10 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 name: "name_last_name_unique".to_string(),
                 columns: vec!["name".to_string(), "lastname".to_string()],
                 tpe: IndexType::Unique,
+                column_options: Default::default(),
+                algorithm: None,
+                predicate: None,
+                include: Vec::new(),
             }],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
@@ -883,9 +1040,11 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result =
         calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -915,7 +1074,10 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        previous_database_name: None,
                         is_commented_out: false,
+                        id_constraint_name: None,
+                        unique_constraint_name: None,
                     }),
                     Field::ScalarField(ScalarField::new(
                         "name",
@@ -930,13 +1092,20 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             fields: vec![],
                             to_fields: vec![],
                             name: "CityToUser".to_string(),
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: None,
+                            on_update: None,
+                            constraint_name: None,
                         },
                     )),
                 ],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_constraint_name: None,
+                check_constraints: vec![],
+                is_view: false,
+                schema: None,
+                previous_database_name: None,
             },
             Model {
                 database_name: None,
@@ -956,7 +1125,10 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        previous_database_name: None,
                         is_commented_out: false,
+                        id_constraint_name: None,
+                        unique_constraint_name: None,
                     }),
                     Field::ScalarField(ScalarField::new(
                         "city_id",
@@ -971,22 +1143,31 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             to: "City".to_string(),
                             fields: vec!["city_id".to_string()],
                             to_fields: vec!["id".to_string()],
-                            on_delete: OnDeleteStrategy::None,
+                            on_delete: Some(ReferentialAction::NoAction),
+                            on_update: Some(ReferentialAction::NoAction),
+                            constraint_name: None,
                         },
                     )),
                 ],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_constraint_name: None,
+                check_constraints: vec![],
+                is_view: false,
+                schema: None,
+                previous_database_name: None,
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![
             Table {
                 name: "City".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
@@ -1024,9 +1205,11 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             },
             Table {
                 name: "User".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
@@ -1067,14 +1250,17 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     constraint_name: None,
                     columns: vec!["city_id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                     on_update_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string()],
                 }],
+                check_constraints: vec![],
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result =
         calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -1106,6 +1292,7 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
                 },
             ],
         }],
+        composite_types: vec![],
     };
 
     let enum_values = vec!["a".to_string(), "b".to_string()];
@@ -1113,9 +1300,11 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
         tables: vec![],
         enums: vec![Enum {
             name: "Enum".to_string(),
+            schema: None,
             values: enum_values,
         }],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result =
         calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -1187,7 +1376,7 @@ async fn one_to_many_relation_field_names_do_not_conflict_with_many_to_many_rela
         model Event {
             id                           Int    @id @default(autoincrement())
             hostId                       Int
-            User_EventToUser             User   @relation(fields: [hostId], references: [id])
+            User_EventToUser             User   @relation(fields: [hostId], references: [id], onDelete: NoAction, onUpdate: NoAction)
             User_EventToUserManyToMany   User[] @relation("EventToUserManyToMany")
 
             @@index([hostId], name: "hostId")
@@ -1204,7 +1393,7 @@ async fn one_to_many_relation_field_names_do_not_conflict_with_many_to_many_rela
         model Event {
             id                           Int    @id @default(autoincrement())
             hostId                       Int
-            User_EventToUser             User   @relation(fields: [hostId], references: [id])
+            User_EventToUser             User   @relation(fields: [hostId], references: [id], onDelete: NoAction, onUpdate: NoAction)
             User_EventToUserManyToMany   User[] @relation("EventToUserManyToMany")
         }
         "#
@@ -32,7 +32,7 @@ async fn compound_foreign_keys_should_work_for_one_to_one_relations(api: &TestAp
                 id       Int   @id @default(autoincrement())
                 user_id  Int?
                 user_age Int?
-                User     User? @relation(fields: [user_id, user_age], references: [id, age])
+                User     User? @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                     
                 @@unique([user_id, user_age], name: "post_user_unique")
             }
@@ -78,7 +78,7 @@ async fn compound_foreign_keys_should_work_for_required_one_to_one_relations(api
                 id       Int  @id @default(autoincrement())
                 user_id  Int
                 user_age Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age])
+                User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@unique([user_id, user_age], name: "post_user_unique")
             }
@@ -124,7 +124,7 @@ async fn compound_foreign_keys_should_work_for_one_to_many_relations(api: &TestA
                 id       Int   @id @default(autoincrement())
                 user_id  Int?
                 user_age Int?
-                User     User? @relation(fields: [user_id, user_age], references: [id, age])
+                User     User? @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
             }
                       
             model User {
@@ -168,7 +168,7 @@ async fn compound_foreign_keys_should_work_for_required_one_to_many_relations(ap
                 id       Int  @id @default(autoincrement())
                 user_id  Int
                 user_age Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age])
+                User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
             }
             
             model User {
@@ -209,7 +209,7 @@ async fn compound_foreign_keys_should_work_for_required_self_relations(api: &Tes
                 age          Int
                 partner_id   Int
                 partner_age  Int
-                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
                         
                 @@unique([id, age], name: "person_unique")
@@ -246,7 +246,7 @@ async fn compound_foreign_keys_should_work_for_self_relations(api: &TestApi) {
                 age          Int
                 partner_id   Int?
                 partner_age  Int?
-                Person       Person?  @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+                Person       Person?  @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
                 
                 @@unique([id, age], name: "person_unique")
@@ -282,7 +282,7 @@ async fn compound_foreign_keys_should_work_with_defaults(api: &TestApi) {
                 age          Int
                 partner_id   Int      @default(0)
                 partner_age  Int      @default(0)
-                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
                 
                 @@unique([id, age], name: "person_unique")
@@ -320,7 +320,7 @@ async fn compound_foreign_keys_should_work_for_one_to_many_relations_with_non_un
                 id       Int  @id @default(autoincrement())
                 user_id  Int
                 user_age Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age])
+                User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
             }
                       
             model User {
@@ -70,7 +70,7 @@ async fn introspecting_native_type_columns_feature_on(api: &TestApi) -> TestResu
   smallint                       Int      @mysql.SmallInt
   tinyint                        Int      @mysql.TinyInt
   mediumint                      Int      @mysql.MediumInt
-  bigint                         Int      @mysql.BigInt
+  bigint                         BigInt   @mysql.BigInt
   decimal                        Decimal  @mysql.Decimal(5, 3)
   numeric                        Decimal  @mysql.Decimal(4, 1)
   float                          Float    @mysql.Float
@@ -137,7 +137,7 @@ async fn introspecting_native_type_columns_feature_off(api: &TestApi) -> TestRes
   smallint                       Int
   tinyint                        Int
   mediumint                      Int
-  bigint                         Int
+  bigint                         BigInt
   decimal                        Float
   numeric                        Float
   float                          Float
@@ -145,18 +145,12 @@ async fn introspecting_native_type_columns_feature_off(api: &TestApi) -> TestRes
   bits                           Int
   chars                          String
   varchars                       String
-  // This type is currently not supported.
-  // binary                      binary(230)
-  // This type is currently not supported.
-  // varbinary                   varbinary(150)
-  // This type is currently not supported.
-  // tinyBlob                    tinyblob
-  // This type is currently not supported.
-  // blob                        blob
-  // This type is currently not supported.
-  // mediumBlob                  mediumblob
-  // This type is currently not supported.
-  // longBlob                    longblob
+  binary                         Bytes
+  varbinary                      Bytes
+  tinyBlob                       Bytes
+  blob                           Bytes
+  mediumBlob                     Bytes
+  longBlob                       Bytes
   tinytext                       String
   text                           String
   mediumText                     String
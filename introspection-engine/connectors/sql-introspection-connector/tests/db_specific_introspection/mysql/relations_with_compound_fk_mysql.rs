@@ -28,7 +28,7 @@ async fn compound_foreign_keys_should_work_for_one_to_one_relations(api: &TestAp
                 id       Int   @id @default(autoincrement())
                 user_id  Int?
                 user_age Int?
-                User     User? @relation(fields: [user_id, user_age], references: [id, age])
+                User     User? @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                     
                 @@unique([user_id, user_age], name: "post_user_unique")
             }
@@ -71,7 +71,7 @@ async fn compound_foreign_keys_should_work_for_required_one_to_one_relations(api
                 id       Int  @id @default(autoincrement())
                 user_id  Int
                 user_age Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age])
+                User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@unique([user_id, user_age], name: "post_user_unique")
             }
@@ -114,7 +114,7 @@ async fn compound_foreign_keys_should_work_for_one_to_many_relations(api: &TestA
                 id       Int   @id @default(autoincrement())
                 user_id  Int?
                 user_age Int?
-                User     User? @relation(fields: [user_id, user_age], references: [id, age])
+                User     User? @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                     
                 @@index([user_id, user_age], name: "user_id")
             }
@@ -157,7 +157,7 @@ async fn compound_foreign_keys_should_work_for_required_one_to_many_relations(ap
                 id       Int  @id @default(autoincrement())
                 user_id  Int
                 user_age Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age])
+                User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@index([user_id, user_age], name: "user_id")
             }
@@ -197,7 +197,7 @@ async fn compound_foreign_keys_should_work_for_required_self_relations(api: &Tes
                 age          Int
                 partner_id   Int
                 partner_age  Int
-                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
                         
                 @@index([partner_id, partner_age], name: "partner_id")
@@ -231,7 +231,7 @@ async fn compound_foreign_keys_should_work_for_self_relations(api: &TestApi) {
                 age          Int
                 partner_id   Int?
                 partner_age  Int?
-                Person       Person?  @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+                Person       Person?  @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
                 
                 @@index([partner_id, partner_age], name: "partner_id")
@@ -265,7 +265,7 @@ async fn compound_foreign_keys_should_work_with_defaults(api: &TestApi) {
                 age          Int
                 partner_id   Int      @default(0)
                 partner_age  Int      @default(0)
-                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+                Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                 other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
                 
                 @@index([partner_id, partner_age], name: "partner_id")
@@ -307,7 +307,7 @@ async fn compound_foreign_keys_should_work_with_defaults(api: &TestApi) {
 //       id       Int  @id @default(autoincrement())
 //       user_id  Int  @unique
 //       user_age Int  @unique
-//       User     User @relation(fields: [user_id, user_age], references: [id, age])
+//       User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
 //
 //       @@index([user_id, user_age], name: \"user_id_2\")
 //     }
@@ -350,7 +350,7 @@ async fn compound_foreign_keys_should_work_for_one_to_many_relations_with_non_un
                 id       Int  @id @default(autoincrement())
                 user_id  Int
                 user_age Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age])
+                User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                     
                 @@index([user_id, user_age], name: "user_id")
             }
@@ -91,8 +91,8 @@ async fn remapping_models_in_relations_should_work(api: &TestApi) {
     let dm = r#"
             model Post {
                 id              Int             @id @default(autoincrement())
-                user_id         Int             @unique
-                User_with_Space User_with_Space @relation(fields: [user_id], references: [id])
+                user_id         Int             @unique(map: "post_user_unique")
+                User_with_Space User_with_Space @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
                   
             model User_with_Space {
@@ -128,8 +128,8 @@ async fn remapping_models_in_relations_should_not_map_virtual_fields(api: &TestA
     let dm = r#"
             model Post_With_Space {
                 id      Int  @id @default(autoincrement())
-                user_id Int  @unique
-                User    User @relation(fields: [user_id], references: [id])
+                user_id Int  @unique(map: "post_user_unique")
+                User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 
                 @@map("Post With Space")
             }
@@ -170,7 +170,7 @@ async fn remapping_models_in_compound_relations_should_work(api: &TestApi) {
                 id              Int             @id @default(autoincrement())
                 user_id         Int
                 user_age        Int
-                User_with_Space User_with_Space @relation(fields: [user_id, user_age], references: [id, age])
+                User_with_Space User_with_Space @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
                     
                 @@unique([user_id, user_age], name: "post_user_unique")
             }
@@ -214,7 +214,7 @@ async fn remapping_fields_in_compound_relations_should_work(api: &TestApi) {
                 id       Int  @id @default(autoincrement())
                 user_id  Int
                 user_age Int
-                User     User @relation(fields: [user_id, user_age], references: [id, age_that_is_invalid])
+                User     User @relation(fields: [user_id, user_age], references: [id, age_that_is_invalid], onDelete: NoAction, onUpdate: NoAction)
                     
                 @@unique([user_id, user_age], name: "post_user_unique")
             }
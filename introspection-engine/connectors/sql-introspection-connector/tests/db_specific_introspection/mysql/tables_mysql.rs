@@ -145,7 +145,7 @@ async fn introspecting_a_table_with_unique_index_must_work(api: &TestApi) {
     let dm = r#"
             model Blog {
                 id      Int @id @default(autoincrement())
-                authorId String @unique
+                authorId String @unique(map: "test")
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -63,14 +63,14 @@ async fn introspecting_native_type_columns_feature_on(api: &TestApi) -> TestResu
   id              Int      @id @postgres.Integer
   smallint        Int      @postgres.SmallInt
   int             Int      @postgres.Integer
-  bigint          Int      @postgres.BigInt
+  bigint          BigInt   @postgres.BigInt
   decimal         Decimal  @postgres.Numeric(4, 2)
   numeric         Decimal  @postgres.Numeric(4, 2)
   real            Float    @postgres.Real
   doublePrecision Float    @postgres.DoublePrecision
   smallSerial     Int      @default(autoincrement()) @postgres.SmallInt
   serial          Int      @default(autoincrement()) @postgres.Integer
-  bigSerial       Int      @default(autoincrement()) @postgres.BigInt
+  bigSerial       BigInt   @default(autoincrement()) @postgres.BigInt
   varChar         String   @postgres.VarChar(200)
   char            String   @postgres.Char(200)
   text            String   @postgres.Text
@@ -133,19 +133,18 @@ async fn introspecting_native_type_columns_feature_off(api: &TestApi) -> TestRes
   id              Int      @id
   smallint        Int
   int             Int
-  bigint          Int
+  bigint          BigInt
   decimal         Float
   numeric         Float
   real            Float
   doublePrecision Float
   smallSerial     Int      @default(autoincrement())
   serial          Int      @default(autoincrement())
-  bigSerial       Int      @default(autoincrement())
+  bigSerial       BigInt   @default(autoincrement())
   varChar         String
   char            String
   text            String
-  // This type is currently not supported.
-  // bytea        bytea
+  bytea           Bytes
   ts              DateTime
   tstz            DateTime
   date            DateTime
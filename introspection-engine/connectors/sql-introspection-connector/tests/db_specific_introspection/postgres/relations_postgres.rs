@@ -22,7 +22,7 @@ async fn introspecting_a_one_to_one_req_relation_should_work(api: &TestApi) {
              model Post {
                id       Int @id @default(autoincrement())
                user_id  Int  @unique
-               User     User @relation(fields: [user_id], references: [id])
+               User     User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -54,7 +54,7 @@ async fn introspecting_a_one_to_one_relation_on_a_singular_primary_key_should_wo
     let dm = r#"
              model Post {
                id   Int  @id
-               User User @relation(fields: [id], references: [id])
+               User User @relation(fields: [id], references: [id], onDelete: NoAction, onUpdate: NoAction)
              }
                  
              model User {
@@ -94,7 +94,7 @@ async fn introspecting_a_one_to_one_relation_on_a_compound_primary_key_should_wo
              model Post {
                id_1 Int
                id_2 Int
-               User User @relation(fields: [id_1, id_2], references: [id_1, id_2])
+               User User @relation(fields: [id_1, id_2], references: [id_1, id_2], onDelete: NoAction, onUpdate: NoAction)
                    
                @@id([id_1, id_2])
              }
@@ -133,14 +133,14 @@ async fn introspecting_two_one_to_one_relations_between_the_same_models_should_w
             model Post {
             id                      Int   @id @default(autoincrement())
             user_id                 Int   @unique
-            User_Post_user_idToUser User  @relation("Post_user_idToUser", fields: [user_id], references: [id])
+            User_Post_user_idToUser User  @relation("Post_user_idToUser", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             User_PostToUser_post_id User? @relation("PostToUser_post_id")
         }
 
         model User {
             id                      Int   @id @default(autoincrement())
             post_id                 Int   @unique
-            Post_PostToUser_post_id Post  @relation("PostToUser_post_id", fields: [post_id], references: [id])
+            Post_PostToUser_post_id Post  @relation("PostToUser_post_id", fields: [post_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             Post_Post_user_idToUser Post? @relation("Post_user_idToUser")
         }
         "#;
@@ -166,7 +166,7 @@ async fn introspecting_a_one_to_one_relation_should_work(api: &TestApi) {
                model Post {
                 id      Int  @id @default(autoincrement())
                 user_id Int?  @unique
-                User    User? @relation(fields: [user_id], references: [id])
+                User    User? @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -197,7 +197,7 @@ async fn introspecting_a_one_to_one_relation_referencing_non_id_should_work(api:
             model Post {
                 id         Int     @id @default(autoincrement())
                 user_email String? @unique
-                User       User?   @relation(fields: [user_email], references: [email])
+                User       User?   @relation(fields: [user_email], references: [email], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -228,7 +228,7 @@ async fn introspecting_a_one_to_many_relation_should_work(api: &TestApi) {
              model Post {
                 id      Int   @id @default(autoincrement())
                 user_id Int?
-                User    User? @relation(fields: [user_id], references: [id])
+                User    User? @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -270,7 +270,7 @@ async fn introspecting_duplicate_fks_should_ignore_one_of_them(api: &TestApi) {
              model Post {
                  id      Int   @id @default(autoincrement())
                  user_id Int?
-                 User    User? @relation("Post_user_idToUser", fields: [user_id], references: [id])
+                 User    User? @relation("Post_user_idToUser", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
              }
 
              model User {
@@ -300,7 +300,7 @@ async fn introspecting_a_one_req_to_many_relation_should_work(api: &TestApi) {
             model Post {
                 id      Int  @id @default(autoincrement())
                 user_id Int
-                User    User @relation(fields: [user_id], references: [id])
+                User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -392,7 +392,7 @@ model User {
 //     let dm = r#"
 //            model Post {
 //               id      Int @id @default(autoincrement())
-//               postsToUserses PostsToUsers[] @relation(references: [post_id])
+//               postsToUserses PostsToUsers[] @relation(references: [post_id], onDelete: NoAction, onUpdate: NoAction)
 //            }
 //
 //            model PostsToUsers {
@@ -433,7 +433,7 @@ model User {
 //    let dm = r#"
 //            model Post {
 //               id      Int @id @default(autoincrement())
-//               postsToUserses PostsToUsers[] @relation(references: [post_id])
+//               postsToUserses PostsToUsers[] @relation(references: [post_id], onDelete: NoAction, onUpdate: NoAction)
 //            }
 //
 //            model PostsToUsers {
@@ -482,8 +482,8 @@ async fn introspecting_a_many_to_many_relation_with_an_id_should_work(api: &Test
                 id      Int  @id
                 user_id Int
                 post_id Int
-                Post    Post @relation(fields: [post_id], references: [id])
-                User    User @relation(fields: [user_id], references: [id])
+                Post    Post @relation(fields: [post_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+                User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -513,8 +513,8 @@ async fn introspecting_a_self_relation_should_work(api: &TestApi) {
                 id                                  Int    @id @default(autoincrement())
                 recruited_by                        Int?
                 direct_report                       Int?
-                User_UserToUser_direct_report       User?  @relation("UserToUser_direct_report", fields: [direct_report], references: [id])
-                User_UserToUser_recruited_by        User?  @relation("UserToUser_recruited_by", fields: [recruited_by], references: [id])
+                User_UserToUser_direct_report       User?  @relation("UserToUser_direct_report", fields: [direct_report], references: [id], onDelete: NoAction, onUpdate: NoAction)
+                User_UserToUser_recruited_by        User?  @relation("UserToUser_recruited_by", fields: [recruited_by], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 other_User_UserToUser_direct_report User[] @relation("UserToUser_direct_report")
                 other_User_UserToUser_recruited_by  User[] @relation("UserToUser_recruited_by")
             }
@@ -575,7 +575,7 @@ async fn introspecting_default_values_on_relations_should_work(api: &TestApi) {
             model Post {
                 id      Int   @id @default(autoincrement())
                 user_id Int?  @default(0)
-                User    User? @relation(fields: [user_id], references: [id])
+                User    User? @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -663,7 +663,7 @@ async fn introspecting_relations_should_avoid_name_clashes(api: &TestApi) {
             model x {
                 id String @id
                 y  String
-                y_xToy  y      @relation(fields: [y], references: [id])
+                y_xToy  y      @relation(fields: [y], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model y {
@@ -732,7 +732,7 @@ async fn introspecting_relations_should_avoid_name_clashes_2(api: &TestApi) {
             model x {
                 id                   String @id
                 y                    String
-                y_x_yToy             y      @relation("x_yToy", fields: [y], references: [id])
+                y_x_yToy             y      @relation("x_yToy", fields: [y], references: [id], map: "fk_y")
                 y_xToy_fk_x_1_fk_x_2 y[]    @relation("xToy_fk_x_1_fk_x_2")
 
                 @@unique([id, y], name: "unique_y_id")
@@ -743,7 +743,7 @@ async fn introspecting_relations_should_avoid_name_clashes_2(api: &TestApi) {
                x                    String
                fk_x_1               String
                fk_x_2               String
-               x_xToy_fk_x_1_fk_x_2 x      @relation("xToy_fk_x_1_fk_x_2", fields: [fk_x_1, fk_x_2], references: [y, id])
+               x_xToy_fk_x_1_fk_x_2 x      @relation("xToy_fk_x_1_fk_x_2", fields: [fk_x_1, fk_x_2], references: [y, id], map: "fk_y_x")
                x_x_yToy             x[]    @relation("x_yToy")
             }
         "#;
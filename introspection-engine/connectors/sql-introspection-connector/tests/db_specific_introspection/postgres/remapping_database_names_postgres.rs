@@ -97,7 +97,7 @@ async fn remapping_fk_columns_with_invalid_characters_should_work(api: &TestApi)
             model User {
                 id      Int  @id @default(autoincrement())
                 post_id Int  @map("post id")
-                Post    Post @relation(fields: [post_id], references: [id])
+                Post    Post @relation(fields: [post_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -125,8 +125,8 @@ async fn remapping_models_in_relations_should_work(api: &TestApi) {
     let dm = r#"
             model Post {
                 id              Int             @id @default(autoincrement())
-                user_id         Int             @unique
-                User_with_Space User_with_Space @relation(fields: [user_id], references: [id])
+                user_id         Int             @unique(map: "post_user_unique")
+                User_with_Space User_with_Space @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User_with_Space {
@@ -170,7 +170,7 @@ async fn remapping_models_in_compound_relations_should_work(api: &TestApi) {
                 id              Int             @id @default(autoincrement())
                 user_id         Int
                 user_name       String
-                User_with_Space User_with_Space @relation(fields: [user_id, user_name], references: [id, name])
+                User_with_Space User_with_Space @relation(fields: [user_id, user_name], references: [id, name], onDelete: NoAction, onUpdate: NoAction)
 
                 @@unique([user_id, user_name], name: "post_user_unique")
             }
@@ -217,7 +217,7 @@ async fn remapping_fields_in_compound_relations_should_work(api: &TestApi) {
                 id        Int    @id @default(autoincrement())
                 user_id   Int
                 user_name String
-                User      User   @relation(fields: [user_id, user_name], references: [id, name_that_is_invalid])
+                User      User   @relation(fields: [user_id, user_name], references: [id, name_that_is_invalid], onDelete: NoAction, onUpdate: NoAction)
 
                 @@unique([user_id, user_name], name: "post_user_unique")
             }
@@ -125,7 +125,7 @@ async fn introspecting_a_table_with_unique_index_must_work(api: &TestApi) {
     let dm = r#"
             model Blog {
                 id      Int @id @default(autoincrement())
-                authorId String @unique
+                authorId String @unique(map: "test")
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -301,7 +301,7 @@ async fn introspecting_a_table_without_uniques_should_comment_it_out(api: &TestA
         })
         .await;
 
-    let dm = "// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id])\n// }\n\nmodel User {\n  id      Int    @id @default(autoincrement())\n  // Post Post[]\n}\n";
+    let dm = "// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)\n// }\n\nmodel User {\n  id      Int    @id @default(autoincrement())\n  // Post Post[]\n}\n";
 
     let result = dbg!(api.introspect().await);
     assert_eq!(&result, dm);
@@ -368,7 +368,7 @@ async fn introspecting_default_values_should_work(api: &TestApi) {
                 id                  Int         @id @default(autoincrement())
                 numeric_int2        Int?            @default(2)
                 numeric_int4        Int?            @default(4)
-                numeric_int8        Int?            @default(8)
+                numeric_int8        BigInt?         @default(8)
                 numeric_decimal     Float?          @default(1234.1234)
                 numeric_float4      Float?          @default(123.1234)
                 numeric_float8      Float?          @default(123.1234)
@@ -568,9 +568,32 @@ async fn introspecting_a_table_with_partial_indexes_should_ignore_them(api: &Tes
               id       Int     @id
               staticid Int
               islatest Boolean
-              other    Int     @unique
+              other    Int     @unique(map: "idx_pages_unique_staticId_non_partial")
             }
         "#;
     let result = dbg!(api.introspect().await);
     custom_assert(&result, dm);
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_a_view_must_work(api: &TestApi) {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+        })
+        .await;
+
+    let sql = format!(
+        r#"CREATE VIEW "{schema}"."UserView" AS SELECT id, name FROM "{schema}"."User""#,
+        schema = api.schema_name()
+    );
+    api.database().execute_raw(&sql, &[]).await.unwrap();
+
+    let dm = "model User {\n  id   Int    @id @default(autoincrement())\n  name String\n}\n\n// The underlying view does not contain a valid unique identifier and can therefore currently not be handled. Mark a column with @id or @unique to query it.\n// view UserView {\n  // id   Int?\n  // name String?\n// }\n";
+
+    let result = dbg!(api.introspect().await);
+    assert_eq!(&result, dm);
+}
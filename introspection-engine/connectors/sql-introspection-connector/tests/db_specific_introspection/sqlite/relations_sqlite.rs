@@ -30,7 +30,7 @@ async fn introspecting_a_one_to_one_req_relation_should_work(api: &TestApi) {
             model Post {
                id       Int @id @default(autoincrement())
                user_id  Int  @unique
-               User     User @relation(fields: [user_id], references: [id])
+               User     User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -64,7 +64,7 @@ async fn introspecting_a_one_to_one_req_relation_using_shortened_syntax_should_w
             model Post {
                id       Int @id @default(autoincrement())
                user_id  Int  @unique
-               User     User @relation(fields: [user_id], references: [id])
+               User     User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -97,14 +97,14 @@ async fn introspecting_two_one_to_one_relations_between_the_same_models_should_w
             model User {
                 id                      Int   @id @default(autoincrement())
                 post_id                 Int   @unique
-                Post_PostToUser_post_id Post  @relation("PostToUser_post_id", fields: [post_id], references: [id])
+                Post_PostToUser_post_id Post  @relation("PostToUser_post_id", fields: [post_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 Post_Post_user_idToUser Post? @relation("Post_user_idToUser")
             }
 
             model Post {
                 id                      Int   @id @default(autoincrement())
                 user_id                 Int   @unique
-                User_Post_user_idToUser User  @relation("Post_user_idToUser", fields: [user_id], references: [id])
+                User_Post_user_idToUser User  @relation("Post_user_idToUser", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 User_PostToUser_post_id User? @relation("PostToUser_post_id")
             }
         "#;
@@ -139,7 +139,7 @@ async fn introspecting_a_one_to_one_relation_should_work(api: &TestApi) {
             model Post {
                 id      Int   @id @default(autoincrement())
                 user_id Int?  @unique
-                User    User? @relation(fields: [user_id], references: [id])
+                User    User? @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -174,7 +174,7 @@ async fn introspecting_a_one_to_one_relation_referencing_non_id_should_work(api:
             model Post {
                 id         Int     @id @default(autoincrement())
                 user_email String? @unique
-                User       User?   @relation(fields: [user_email], references: [email])
+                User       User?   @relation(fields: [user_email], references: [email], onDelete: NoAction, onUpdate: NoAction)
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -208,7 +208,7 @@ async fn introspecting_a_one_to_many_relation_should_work(api: &TestApi) {
             model Post {
                 id      Int   @id @default(autoincrement())
                 user_id Int?
-                User    User? @relation(fields: [user_id], references: [id])
+                User    User? @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -242,7 +242,7 @@ async fn introspecting_a_one_req_to_many_relation_should_work(api: &TestApi) {
             model Post {
                 id      Int  @id @default(autoincrement())
                 user_id Int
-                User    User @relation(fields: [user_id], references: [id])
+                User    User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -340,7 +340,7 @@ model Post {
 //
 //            model Post {
 //               id Int @id @default(autoincrement())
-//               postsToUserses PostsToUsers[] @relation(references: [post_id])
+//               postsToUserses PostsToUsers[] @relation(references: [post_id], onDelete: NoAction, onUpdate: NoAction)
 //            }
 //
 //            model PostsToUsers {
@@ -383,7 +383,7 @@ model Post {
 //
 //            model Post {
 //               id Int @id @default(autoincrement())
-//               postsToUserses PostsToUsers[] @relation(references: [post_id])
+//               postsToUserses PostsToUsers[] @relation(references: [post_id], onDelete: NoAction, onUpdate: NoAction)
 //            }
 //
 //            model PostsToUsers {
@@ -434,8 +434,8 @@ async fn introspecting_a_many_to_many_relation_with_an_id_should_work(api: &Test
                 id      Int    @id @default(autoincrement())
                 user_id Int
                 post_id Int
-                Post    Post   @relation(fields: [post_id], references: [id])
-                User    User   @relation(fields: [user_id], references: [id])
+                Post    Post   @relation(fields: [post_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+                User    User   @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
         "#;
@@ -465,8 +465,8 @@ async fn introspecting_a_self_relation_should_work(api: &TestApi) {
                 id                                  Int    @id @default(autoincrement())
                 recruited_by                        Int?
                 direct_report                       Int?
-                User_UserToUser_direct_report       User?  @relation("UserToUser_direct_report", fields: [direct_report], references: [id])
-                User_UserToUser_recruited_by        User?  @relation("UserToUser_recruited_by", fields: [recruited_by], references: [id])
+                User_UserToUser_direct_report       User?  @relation("UserToUser_direct_report", fields: [direct_report], references: [id], onDelete: NoAction, onUpdate: NoAction)
+                User_UserToUser_recruited_by        User?  @relation("UserToUser_recruited_by", fields: [recruited_by], references: [id], onDelete: NoAction, onUpdate: NoAction)
                 other_User_UserToUser_direct_report User[] @relation("UserToUser_direct_report")
                 other_User_UserToUser_recruited_by  User[] @relation("UserToUser_recruited_by")
             }
@@ -536,7 +536,7 @@ async fn introspecting_id_fields_with_foreign_key_should_work(api: &TestApi) {
             model Post {
                 test    String
                 user_id Int    @id @default(autoincrement())
-                User    User   @relation(fields: [user_id], references: [id])
+                User    User   @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
         "#;
     let result = dbg!(api.introspect().await);
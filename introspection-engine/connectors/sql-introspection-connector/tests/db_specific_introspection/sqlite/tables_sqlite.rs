@@ -75,7 +75,7 @@ async fn introspecting_a_table_with_unique_index_must_work(api: &TestApi) {
     let dm = r#"
             model Blog {
                 id Int @id @default(autoincrement())
-                authorId String @unique
+                authorId String @unique(map: "test")
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -335,7 +335,7 @@ async fn introspecting_a_table_with_partial_indexes_should_ignore_them(api: &Tes
               id       Int     @id @default(autoincrement())
               staticid Int
               islatest Boolean
-              other    Int     @unique
+              other    Int     @unique(map: "idx_pages_unique_staticId_non_partial")
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -109,7 +109,7 @@ async fn re_introspecting_mapped_model_and_field_name(api: &TestApi) {
             model Post {
                id               Int         @id @default(autoincrement())
                c_user_id        Int         @map("user_id")
-               Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id])
+               Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model Custom_User {
@@ -124,7 +124,7 @@ async fn re_introspecting_mapped_model_and_field_name(api: &TestApi) {
             model Post {
                id               Int         @id @default(autoincrement())
                c_user_id        Int         @map("user_id")
-               Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id])
+               Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model Custom_User {
@@ -169,7 +169,7 @@ async fn re_introspecting_manually_mapped_model_and_field_name(api: &TestApi) {
             model Post {
                id               Int         @id @default(autoincrement())
                c_user_id        Int         @map("user_id")
-               Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id])
+               Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model Custom_User {
@@ -184,7 +184,7 @@ async fn re_introspecting_manually_mapped_model_and_field_name(api: &TestApi) {
             model Post {
                id               Int         @id @default(autoincrement())
                c_user_id        Int         @map("user_id")
-               Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id])
+               Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model Custom_User {
@@ -571,8 +571,8 @@ async fn re_introspecting_multiple_changed_relation_names(api: &TestApi) {
                   id                                            Int         @id @default(autoincrement())
                   morningEmployeeId                             Int
                   eveningEmployeeId                             Int
-                  Employee_EmployeeToSchedule_eveningEmployeeId Employee    @relation("EmployeeToSchedule_eveningEmployeeId", fields: [eveningEmployeeId], references: [id])
-                  Employee_EmployeeToSchedule_morningEmployeeId Employee    @relation("EmployeeToSchedule_morningEmployeeId", fields: [morningEmployeeId], references: [id])
+                  Employee_EmployeeToSchedule_eveningEmployeeId Employee    @relation("EmployeeToSchedule_eveningEmployeeId", fields: [eveningEmployeeId], references: [id], onDelete: NoAction, onUpdate: NoAction)
+                  Employee_EmployeeToSchedule_morningEmployeeId Employee    @relation("EmployeeToSchedule_morningEmployeeId", fields: [morningEmployeeId], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
         "#;
 
@@ -587,8 +587,8 @@ async fn re_introspecting_multiple_changed_relation_names(api: &TestApi) {
                   id                                            Int         @id @default(autoincrement())
                   morningEmployeeId                             Int
                   eveningEmployeeId                             Int
-                  Employee_EmployeeToSchedule_eveningEmployeeId Employee    @relation("EmployeeToSchedule_eveningEmployeeId", fields: [eveningEmployeeId], references: [id])
-                  Employee_EmployeeToSchedule_morningEmployeeId Employee    @relation("EmployeeToSchedule_morningEmployeeId", fields: [morningEmployeeId], references: [id])
+                  Employee_EmployeeToSchedule_eveningEmployeeId Employee    @relation("EmployeeToSchedule_eveningEmployeeId", fields: [eveningEmployeeId], references: [id], onDelete: NoAction, onUpdate: NoAction)
+                  Employee_EmployeeToSchedule_morningEmployeeId Employee    @relation("EmployeeToSchedule_morningEmployeeId", fields: [morningEmployeeId], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model Unrelated {
@@ -621,7 +621,7 @@ async fn re_introspecting_custom_virtual_relation_field_names(api: &TestApi) {
              model Post {
                id               Int @id @default(autoincrement())
                user_id          Int  @unique
-               custom_User      User @relation(fields: [user_id], references: [id])
+               custom_User      User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -634,7 +634,7 @@ async fn re_introspecting_custom_virtual_relation_field_names(api: &TestApi) {
              model Post {
                id               Int @id @default(autoincrement())
                user_id          Int  @unique
-               custom_User      User @relation(fields: [user_id], references: [id])
+               custom_User      User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model User {
@@ -853,8 +853,8 @@ async fn re_introspecting_multiple_changed_relation_names_due_to_mapped_models(a
                id               Int @id @default(autoincrement())
                user_id          Int  @unique
                user_id2         Int  @unique
-               custom_User      Custom_User @relation("CustomRelationName", fields: [user_id], references: [id])
-               custom_User2     Custom_User @relation("AnotherCustomRelationName", fields: [user_id2], references: [id])
+               custom_User      Custom_User @relation("CustomRelationName", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+               custom_User2     Custom_User @relation("AnotherCustomRelationName", fields: [user_id2], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model Custom_User {
@@ -871,8 +871,8 @@ async fn re_introspecting_multiple_changed_relation_names_due_to_mapped_models(a
                id               Int @id @default(autoincrement())
                user_id          Int  @unique
                user_id2         Int  @unique
-               custom_User      Custom_User @relation("CustomRelationName", fields: [user_id], references: [id])
-               custom_User2     Custom_User @relation("AnotherCustomRelationName", fields: [user_id2], references: [id])
+               custom_User      Custom_User @relation("CustomRelationName", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+               custom_User2     Custom_User @relation("AnotherCustomRelationName", fields: [user_id2], references: [id], onDelete: NoAction, onUpdate: NoAction)
             }
 
             model Custom_User {
@@ -71,19 +71,14 @@ impl RpcImpl {
     ) -> Result<(Configuration, String, Box<dyn IntrospectionConnector>), Error> {
         let config = datamodel::parse_configuration(&schema)?;
 
-        let url = config
+        let source = config
             .datasources
             .first()
-            .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?
-            .url()
-            .to_owned()
-            .value;
-
-        Ok((
-            config,
-            url.clone(),
-            Box::new(SqlIntrospectionConnector::new(&url).await?),
-        ))
+            .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?;
+        let url = source.url().to_owned().value;
+        let connector = SqlIntrospectionConnector::new_with_schemas(&url, source.schemas.clone()).await?;
+
+        Ok((config, url, Box::new(connector)))
     }
 
     pub async fn catch<O>(fut: impl std::future::Future<Output = ConnectorResult<O>>) -> RpcResult<O> {
@@ -4,6 +4,7 @@ use crate::{ConnectorCapability, NativeTypeConstructor, NativeTypeInstance};
 
 pub struct CombinedConnector {
     capabilities: Vec<ConnectorCapability>,
+    max_identifier_length: Option<usize>,
 }
 
 impl CombinedConnector {
@@ -21,8 +22,15 @@ impl CombinedConnector {
             }
         }
 
+        // the strictest limit of all connectors applies.
+        let max_identifier_length = connectors
+            .iter()
+            .filter_map(|connector| connector.max_identifier_length())
+            .min();
+
         Box::new(CombinedConnector {
             capabilities: combined_capabilities,
+            max_identifier_length,
         })
     }
 }
@@ -32,6 +40,10 @@ impl Connector for CombinedConnector {
         &self.capabilities
     }
 
+    fn max_identifier_length(&self) -> Option<usize> {
+        self.max_identifier_length
+    }
+
     fn available_native_type_constructors(&self) -> &Vec<NativeTypeConstructor> {
         unimplemented!("A combined connector must not be used for native types")
     }
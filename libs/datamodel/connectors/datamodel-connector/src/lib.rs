@@ -66,6 +66,40 @@ pub trait Connector: Send + Sync {
     fn supports_non_indexed_auto_increment(&self) -> bool {
         self.has_capability(ConnectorCapability::AutoIncrementNonIndexedAllowed)
     }
+
+    fn supports_multiple_schemas(&self) -> bool {
+        self.has_capability(ConnectorCapability::MultipleSchemas)
+    }
+
+    fn supports_index_prefix_length(&self) -> bool {
+        self.has_capability(ConnectorCapability::IndexPrefixLength)
+    }
+
+    fn supports_index_algorithms(&self) -> bool {
+        self.has_capability(ConnectorCapability::IndexAlgorithms)
+    }
+
+    fn supports_partial_indexes(&self) -> bool {
+        self.has_capability(ConnectorCapability::PartialIndexes)
+    }
+
+    fn supports_index_include_columns(&self) -> bool {
+        self.has_capability(ConnectorCapability::IndexIncludeColumns)
+    }
+
+    fn supports_named_primary_keys(&self) -> bool {
+        self.has_capability(ConnectorCapability::NamedPrimaryKeys)
+    }
+
+    fn supports_named_foreign_keys(&self) -> bool {
+        self.has_capability(ConnectorCapability::NamedForeignKeys)
+    }
+
+    /// The maximum length of identifiers, such as constraint names, in the database. `None` if
+    /// the database does not limit it.
+    fn max_identifier_length(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// Not all Databases are created equal. Hence connectors for our datasources support different capabilities.
@@ -81,8 +115,18 @@ pub enum ConnectorCapability {
     AutoIncrementAllowedOnNonId,
     AutoIncrementMultipleAllowed,
     AutoIncrementNonIndexedAllowed,
+    MultipleSchemas,
+    IndexPrefixLength,
+    IndexAlgorithms,
+    PartialIndexes,
+    IndexIncludeColumns,
+    NamedPrimaryKeys,
+    NamedForeignKeys,
     // start of Query Engine Capabilities
     InsensitiveFilters,
+    JsonFilteringArrayPath,
+    JsonFilteringJsonPath,
+    JsonFilteringArrayContains,
 }
 
 /// Contains all capabilities that the connector is able to serve.
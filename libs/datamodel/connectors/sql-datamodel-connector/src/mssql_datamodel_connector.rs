@@ -12,6 +12,11 @@ impl MsSqlDatamodelConnector {
             ConnectorCapability::AutoIncrementAllowedOnNonId,
             ConnectorCapability::AutoIncrementMultipleAllowed,
             ConnectorCapability::AutoIncrementNonIndexedAllowed,
+            ConnectorCapability::MultipleSchemas,
+            ConnectorCapability::PartialIndexes,
+            ConnectorCapability::IndexIncludeColumns,
+            ConnectorCapability::NamedPrimaryKeys,
+            ConnectorCapability::NamedForeignKeys,
         ];
 
         let constructors: Vec<NativeTypeConstructor> = vec![];
@@ -28,6 +33,10 @@ impl Connector for MsSqlDatamodelConnector {
         &self.capabilities
     }
 
+    fn max_identifier_length(&self) -> Option<usize> {
+        Some(128)
+    }
+
     fn available_native_type_constructors(&self) -> &Vec<NativeTypeConstructor> {
         &self.constructors
     }
@@ -50,6 +50,10 @@ impl MySqlDatamodelConnector {
             ConnectorCapability::Json,
             ConnectorCapability::MultipleIndexesWithSameName,
             ConnectorCapability::AutoIncrementAllowedOnNonId,
+            ConnectorCapability::IndexPrefixLength,
+            ConnectorCapability::NamedForeignKeys,
+            ConnectorCapability::JsonFilteringJsonPath,
+            ConnectorCapability::JsonFilteringArrayContains,
         ];
 
         let int = NativeTypeConstructor::without_args(INT_TYPE_NAME, ScalarType::Int);
@@ -60,8 +64,8 @@ impl MySqlDatamodelConnector {
         let unsigned_tiny_int = NativeTypeConstructor::without_args(UNSIGNED_TINY_INT_TYPE_NAME, ScalarType::Int);
         let medium_int = NativeTypeConstructor::without_args(MEDIUM_INT_TYPE_NAME, ScalarType::Int);
         let unsigned_medium_int = NativeTypeConstructor::without_args(UNSIGNED_MEDIUM_INT_TYPE_NAME, ScalarType::Int);
-        let big_int = NativeTypeConstructor::without_args(BIG_INT_TYPE_NAME, ScalarType::Int);
-        let unsigned_big_int = NativeTypeConstructor::without_args(UNSIGNED_BIG_INT_TYPE_NAME, ScalarType::Int);
+        let big_int = NativeTypeConstructor::without_args(BIG_INT_TYPE_NAME, ScalarType::BigInt);
+        let unsigned_big_int = NativeTypeConstructor::without_args(UNSIGNED_BIG_INT_TYPE_NAME, ScalarType::BigInt);
         let decimal = NativeTypeConstructor::with_args(DECIMAL_TYPE_NAME, 2, ScalarType::Decimal);
         let numeric = NativeTypeConstructor::with_args(NUMERIC_TYPE_NAME, 2, ScalarType::Decimal);
         let float = NativeTypeConstructor::without_args(FLOAT_TYPE_NAME, ScalarType::Float);
@@ -134,6 +138,10 @@ impl Connector for MySqlDatamodelConnector {
         &self.capabilities
     }
 
+    fn max_identifier_length(&self) -> Option<usize> {
+        Some(64)
+    }
+
     fn available_native_type_constructors(&self) -> &Vec<NativeTypeConstructor> {
         &self.constructors
     }
@@ -45,19 +45,27 @@ impl PostgresDatamodelConnector {
             ConnectorCapability::AutoIncrementMultipleAllowed,
             ConnectorCapability::AutoIncrementAllowedOnNonId,
             ConnectorCapability::AutoIncrementNonIndexedAllowed,
+            ConnectorCapability::MultipleSchemas,
+            ConnectorCapability::IndexAlgorithms,
+            ConnectorCapability::PartialIndexes,
+            ConnectorCapability::IndexIncludeColumns,
+            ConnectorCapability::NamedPrimaryKeys,
+            ConnectorCapability::NamedForeignKeys,
             ConnectorCapability::InsensitiveFilters,
+            ConnectorCapability::JsonFilteringArrayPath,
+            ConnectorCapability::JsonFilteringArrayContains,
         ];
 
         let small_int = NativeTypeConstructor::without_args(SMALL_INT_TYPE_NAME, ScalarType::Int);
         let integer = NativeTypeConstructor::without_args(INTEGER_TYPE_NAME, ScalarType::Int);
-        let big_int = NativeTypeConstructor::without_args(BIG_INT_TYPE_NAME, ScalarType::Int);
+        let big_int = NativeTypeConstructor::without_args(BIG_INT_TYPE_NAME, ScalarType::BigInt);
         let decimal = NativeTypeConstructor::with_args(DECIMAL_TYPE_NAME, 2, ScalarType::Decimal);
         let numeric = NativeTypeConstructor::with_args(NUMERIC_TYPE_NAME, 2, ScalarType::Decimal);
         let real = NativeTypeConstructor::without_args(REAL_TYPE_NAME, ScalarType::Float);
         let double_precision = NativeTypeConstructor::without_args(DOUBLE_PRECISION_TYPE_NAME, ScalarType::Float);
         let small_serial = NativeTypeConstructor::without_args(SMALL_SERIAL_TYPE_NAME, ScalarType::Int);
         let serial = NativeTypeConstructor::without_args(SERIAL_TYPE_NAME, ScalarType::Int);
-        let big_serial = NativeTypeConstructor::without_args(BIG_SERIAL_TYPE_NAME, ScalarType::Int);
+        let big_serial = NativeTypeConstructor::without_args(BIG_SERIAL_TYPE_NAME, ScalarType::BigInt);
         let varchar = NativeTypeConstructor::with_args(VARCHAR_TYPE_NAME, 1, ScalarType::String);
         let char = NativeTypeConstructor::with_args(CHAR_TYPE_NAME, 1, ScalarType::String);
         let text = NativeTypeConstructor::without_args(TEXT_TYPE_NAME, ScalarType::String);
@@ -120,6 +128,10 @@ impl Connector for PostgresDatamodelConnector {
         &self.capabilities
     }
 
+    fn max_identifier_length(&self) -> Option<usize> {
+        Some(63)
+    }
+
     fn available_native_type_constructors(&self) -> &Vec<NativeTypeConstructor> {
         &self.constructors
     }
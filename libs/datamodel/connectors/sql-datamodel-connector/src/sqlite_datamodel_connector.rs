@@ -8,7 +8,11 @@ pub struct SqliteDatamodelConnector {
 
 impl SqliteDatamodelConnector {
     pub fn new() -> SqliteDatamodelConnector {
-        let capabilities = vec![];
+        let capabilities = vec![
+            ConnectorCapability::PartialIndexes,
+            ConnectorCapability::Json,
+            ConnectorCapability::JsonFilteringJsonPath,
+        ];
         let constructors: Vec<NativeTypeConstructor> = vec![];
 
         SqliteDatamodelConnector {
@@ -3,7 +3,7 @@ pub fn get_sort_index_of_attribute(is_field_attribute: bool, attribute_name: &st
     let correct_order = if is_field_attribute {
         vec!["id", "unique", "default", "updatedAt", "map", "relation"]
     } else {
-        vec!["id", "unique", "index", "map"]
+        vec!["id", "unique", "index", "check", "map"]
     };
     if let Some(sort_index) = correct_order
         .iter()
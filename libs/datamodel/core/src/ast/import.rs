@@ -0,0 +1,16 @@
+use super::*;
+
+/// An `import "./other.prisma"` declaration, pulling the definitions of another file into the
+/// schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Import {
+    /// The imported path, relative to the directory of the importing file.
+    pub path: String,
+    pub span: Span,
+}
+
+impl WithSpan for Import {
+    fn span(&self) -> &Span {
+        &self.span
+    }
+}
@@ -6,11 +6,13 @@
 mod argument;
 mod attribute;
 mod comment;
+mod composite_type;
 mod r#enum;
 mod expression;
 mod field;
 mod generator_config;
 mod identifier;
+mod import;
 mod model;
 mod source_config;
 mod span;
@@ -25,10 +27,12 @@ pub mod renderer;
 pub use argument::Argument;
 pub use attribute::Attribute;
 pub use comment::Comment;
+pub use composite_type::CompositeType;
 pub use expression::Expression;
 pub use field::{Field, FieldArity};
 pub use generator_config::GeneratorConfig;
 pub use identifier::Identifier;
+pub use import::Import;
 pub use model::Model;
 pub use r#enum::{Enum, EnumValue};
 pub use source_config::SourceConfig;
@@ -48,13 +52,18 @@ pub use traits::{ArgumentContainer, WithAttributes, WithDocumentation, WithIdent
 /// Schema = Datamodel + Generators + Datasources
 #[derive(Debug, Clone, PartialEq)]
 pub struct SchemaAst {
-    /// All models, enums, datasources, generators or type aliases
+    /// All models, composite types, enums, datasources, generators or type aliases
     pub tops: Vec<Top>,
+    /// The `import` declarations of all the files the schema was parsed from
+    pub imports: Vec<Import>,
 }
 
 impl SchemaAst {
     pub fn empty() -> Self {
-        SchemaAst { tops: Vec::new() }
+        SchemaAst {
+            tops: Vec::new(),
+            imports: Vec::new(),
+        }
     }
 
     pub fn find_source(&self, source: &str) -> Option<&SourceConfig> {
@@ -90,6 +99,10 @@ impl SchemaAst {
         })
     }
 
+    pub fn find_composite_type(&self, type_name: &str) -> Option<&CompositeType> {
+        self.composite_types().into_iter().find(|t| t.name.name == type_name)
+    }
+
     pub fn find_enum(&self, enum_name: &str) -> Option<&Enum> {
         self.enums().into_iter().find(|e| e.name.name == enum_name)
     }
@@ -124,6 +137,16 @@ impl SchemaAst {
             .collect()
     }
 
+    pub fn composite_types(&self) -> Vec<&CompositeType> {
+        self.tops
+            .iter()
+            .filter_map(|top| match top {
+                Top::CompositeType(x) => Some(x),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn enums(&self) -> Vec<&Enum> {
         self.tops
             .iter()
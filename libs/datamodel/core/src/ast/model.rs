@@ -15,6 +15,8 @@ pub struct Model {
     pub span: Span,
     /// Should this be commented out.
     pub commented_out: bool,
+    /// Whether this was declared with the `view` keyword.
+    pub is_view: bool,
 }
 
 impl Model {
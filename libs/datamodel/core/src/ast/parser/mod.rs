@@ -1,9 +1,12 @@
 mod helpers;
+mod move_to_file;
 mod parse_attribute;
 mod parse_comments;
+mod parse_composite_type;
 mod parse_enum;
 mod parse_expression;
 mod parse_field;
+mod parse_import;
 mod parse_model;
 mod parse_schema;
 mod parse_source_and_generator;
@@ -11,7 +14,7 @@ mod parse_types;
 
 // TODO: why does this need to be public?
 pub use parse_expression::parse_expression;
-pub use parse_schema::parse_schema;
+pub use parse_schema::{parse_schema, parse_schema_files};
 
 // The derive is placed here because it generates the `Rule` enum which is used in all parsing functions.
 // It is more convenient if this enum is directly available here.
@@ -0,0 +1,153 @@
+use crate::ast::*;
+use crate::error::{DatamodelError, ErrorCollection};
+
+/// Files are parsed one at a time, with all their spans in the default file. This moves the
+/// spans of a parsed file to its id in the multi-file schema.
+pub trait MoveToFile {
+    fn move_to_file(&mut self, file_id: FileId);
+}
+
+impl<T: MoveToFile> MoveToFile for Vec<T> {
+    fn move_to_file(&mut self, file_id: FileId) {
+        for item in self.iter_mut() {
+            item.move_to_file(file_id);
+        }
+    }
+}
+
+impl MoveToFile for Span {
+    fn move_to_file(&mut self, file_id: FileId) {
+        *self = self.in_file(file_id);
+    }
+}
+
+impl MoveToFile for SchemaAst {
+    fn move_to_file(&mut self, file_id: FileId) {
+        self.tops.move_to_file(file_id);
+        self.imports.move_to_file(file_id);
+    }
+}
+
+impl MoveToFile for Import {
+    fn move_to_file(&mut self, file_id: FileId) {
+        self.span.move_to_file(file_id);
+    }
+}
+
+impl MoveToFile for Top {
+    fn move_to_file(&mut self, file_id: FileId) {
+        match self {
+            Top::Enum(r#enum) => r#enum.move_to_file(file_id),
+            Top::Model(model) => model.move_to_file(file_id),
+            Top::CompositeType(composite_type) => composite_type.move_to_file(file_id),
+            Top::Source(source) => {
+                source.name.move_to_file(file_id);
+                source.properties.move_to_file(file_id);
+                source.span.move_to_file(file_id);
+            }
+            Top::Generator(generator) => {
+                generator.name.move_to_file(file_id);
+                generator.properties.move_to_file(file_id);
+                generator.span.move_to_file(file_id);
+            }
+            Top::Type(custom_type) => custom_type.move_to_file(file_id),
+        }
+    }
+}
+
+impl MoveToFile for Model {
+    fn move_to_file(&mut self, file_id: FileId) {
+        self.name.move_to_file(file_id);
+        self.fields.move_to_file(file_id);
+        self.attributes.move_to_file(file_id);
+        self.span.move_to_file(file_id);
+    }
+}
+
+impl MoveToFile for CompositeType {
+    fn move_to_file(&mut self, file_id: FileId) {
+        self.name.move_to_file(file_id);
+        self.fields.move_to_file(file_id);
+        self.span.move_to_file(file_id);
+    }
+}
+
+impl MoveToFile for Field {
+    fn move_to_file(&mut self, file_id: FileId) {
+        self.field_type.move_to_file(file_id);
+        self.name.move_to_file(file_id);
+        self.attributes.move_to_file(file_id);
+        self.span.move_to_file(file_id);
+    }
+}
+
+impl MoveToFile for Enum {
+    fn move_to_file(&mut self, file_id: FileId) {
+        self.name.move_to_file(file_id);
+        self.values.move_to_file(file_id);
+        self.attributes.move_to_file(file_id);
+        self.span.move_to_file(file_id);
+    }
+}
+
+impl MoveToFile for EnumValue {
+    fn move_to_file(&mut self, file_id: FileId) {
+        self.name.move_to_file(file_id);
+        self.attributes.move_to_file(file_id);
+        self.span.move_to_file(file_id);
+    }
+}
+
+impl MoveToFile for Attribute {
+    fn move_to_file(&mut self, file_id: FileId) {
+        self.name.move_to_file(file_id);
+        self.arguments.move_to_file(file_id);
+        self.span.move_to_file(file_id);
+    }
+}
+
+impl MoveToFile for Argument {
+    fn move_to_file(&mut self, file_id: FileId) {
+        self.name.move_to_file(file_id);
+        self.value.move_to_file(file_id);
+        self.span.move_to_file(file_id);
+    }
+}
+
+impl MoveToFile for Identifier {
+    fn move_to_file(&mut self, file_id: FileId) {
+        self.span.move_to_file(file_id);
+    }
+}
+
+impl MoveToFile for Expression {
+    fn move_to_file(&mut self, file_id: FileId) {
+        match self {
+            Expression::NumericValue(_, span)
+            | Expression::BooleanValue(_, span)
+            | Expression::StringValue(_, span)
+            | Expression::Any(_, span)
+            | Expression::ConstantValue(_, span) => span.move_to_file(file_id),
+            Expression::Function(_, arguments, span) => {
+                arguments.move_to_file(file_id);
+                span.move_to_file(file_id);
+            }
+            Expression::Array(elements, span) => {
+                elements.move_to_file(file_id);
+                span.move_to_file(file_id);
+            }
+        }
+    }
+}
+
+impl MoveToFile for DatamodelError {
+    fn move_to_file(&mut self, file_id: FileId) {
+        self.span_mut().move_to_file(file_id);
+    }
+}
+
+impl MoveToFile for ErrorCollection {
+    fn move_to_file(&mut self, file_id: FileId) {
+        self.errors.move_to_file(file_id);
+    }
+}
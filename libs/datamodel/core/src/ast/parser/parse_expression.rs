@@ -3,6 +3,7 @@ use regex::Regex;
 use std::borrow::Cow;
 
 use super::helpers::{parsing_catch_all, Token, TokenExtensions};
+use super::parse_attribute::parse_attribute_arg;
 use super::Rule;
 use crate::ast::*;
 
@@ -25,12 +26,17 @@ pub fn parse_expression(token: &Token) -> Expression {
 
 fn parse_function(token: &Token) -> Expression {
     let mut name: Option<String> = None;
-    let mut arguments: Vec<Expression> = vec![];
+    let mut arguments: Vec<Argument> = vec![];
 
     for current in token.relevant_children() {
         match current.as_rule() {
             Rule::non_empty_identifier => name = Some(current.as_str().to_string()),
-            Rule::expression => arguments.push(parse_expression(&current)),
+            Rule::argument => arguments.push(parse_attribute_arg(&current)),
+            Rule::expression => arguments.push(Argument {
+                name: Identifier::new(""),
+                value: parse_expression(&current),
+                span: Span::from_pest(current.as_span()),
+            }),
             _ => parsing_catch_all(&current, "function"),
         }
     }
@@ -62,7 +68,7 @@ pub fn parse_arg_value(token: &Token) -> Expression {
     }
 }
 
-fn parse_string_literal(token: &Token) -> String {
+pub fn parse_string_literal(token: &Token) -> String {
     let current = token.first_relevant_child();
     match current.as_rule() {
         Rule::string_content => unescape_string_literal(current.as_str()).into_owned(),
@@ -0,0 +1,25 @@
+use super::{
+    helpers::{parsing_catch_all, Token, TokenExtensions},
+    parse_expression::parse_string_literal,
+    Rule,
+};
+use crate::ast::*;
+
+pub fn parse_import(token: &Token) -> Import {
+    let mut path: Option<String> = None;
+
+    for current in token.relevant_children() {
+        match current.as_rule() {
+            Rule::string_literal => path = Some(parse_string_literal(&current)),
+            _ => parsing_catch_all(&current, "import"),
+        }
+    }
+
+    match path {
+        Some(path) => Import {
+            path,
+            span: Span::from_pest(token.as_span()),
+        },
+        _ => panic!("Encountered impossible import during parsing: {:?}", token.as_str()),
+    }
+}
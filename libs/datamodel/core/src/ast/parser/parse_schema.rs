@@ -2,7 +2,10 @@ use pest::Parser;
 
 use super::{
     helpers::{parsing_catch_all, TokenExtensions},
+    move_to_file::MoveToFile,
+    parse_composite_type::parse_composite_type,
     parse_enum::parse_enum,
+    parse_import::parse_import,
     parse_model::parse_model,
     parse_source_and_generator::{parse_generator, parse_source},
     parse_types::parse_type_alias,
@@ -10,6 +13,7 @@ use super::{
 };
 use crate::ast::*;
 use crate::error::{DatamodelError, ErrorCollection};
+use crate::SourceFile;
 
 /// Parses a Prisma V2 datamodel document into an internal AST representation.
 pub fn parse_schema(datamodel_string: &str) -> Result<SchemaAst, ErrorCollection> {
@@ -20,12 +24,18 @@ pub fn parse_schema(datamodel_string: &str) -> Result<SchemaAst, ErrorCollection
         Ok(mut datamodel_wrapped) => {
             let datamodel = datamodel_wrapped.next().unwrap();
             let mut top_level_definitions: Vec<Top> = vec![];
+            let mut imports: Vec<Import> = vec![];
             for current in datamodel.relevant_children() {
                 match current.as_rule() {
+                    Rule::import_declaration => imports.push(parse_import(&current)),
                     Rule::model_declaration => match parse_model(&current) {
                         Ok(model) => top_level_definitions.push(Top::Model(model)),
                         Err(mut err) => errors.append(&mut err),
                     },
+                    Rule::composite_type_declaration => match parse_composite_type(&current) {
+                        Ok(composite_type) => top_level_definitions.push(Top::CompositeType(composite_type)),
+                        Err(mut err) => errors.append(&mut err),
+                    },
                     Rule::enum_declaration => match parse_enum(&current) {
                         Ok(enm) => top_level_definitions.push(Top::Enum(enm)),
                         Err(mut err) => errors.append(&mut err),
@@ -57,6 +67,7 @@ pub fn parse_schema(datamodel_string: &str) -> Result<SchemaAst, ErrorCollection
 
             Ok(SchemaAst {
                 tops: top_level_definitions,
+                imports,
             })
         }
         Err(err) => {
@@ -76,6 +87,40 @@ pub fn parse_schema(datamodel_string: &str) -> Result<SchemaAst, ErrorCollection
     }
 }
 
+/// Parses the files of a multi-file schema into one AST. The spans point into the file they
+/// come from, identified by its index in `files`.
+pub fn parse_schema_files(files: &[SourceFile]) -> Result<SchemaAst, ErrorCollection> {
+    let mut errors = ErrorCollection::new();
+    let mut schema = SchemaAst::empty();
+
+    for (index, file) in files.iter().enumerate() {
+        let file_id = FileId(index);
+
+        match parse_schema(&file.content) {
+            Ok(mut ast) => {
+                ast.move_to_file(file_id);
+
+                for import in &ast.imports {
+                    if !files.iter().any(|other| other.is_imported_by(file, &import.path)) {
+                        errors.push(DatamodelError::new_import_not_found_error(&import.path, import.span));
+                    }
+                }
+
+                schema.tops.append(&mut ast.tops);
+                schema.imports.append(&mut ast.imports);
+            }
+            Err(mut err) => {
+                err.move_to_file(file_id);
+                errors.append(&mut err);
+            }
+        }
+    }
+
+    errors.ok()?;
+
+    Ok(schema)
+}
+
 fn get_expected_from_error(positives: &[Rule]) -> Vec<&'static str> {
     positives
         .iter()
@@ -86,7 +131,9 @@ fn get_expected_from_error(positives: &[Rule]) -> Vec<&'static str> {
 
 fn rule_to_string(rule: Rule) -> &'static str {
     match rule {
+        Rule::import_declaration => "import declaration",
         Rule::model_declaration => "model declaration",
+        Rule::composite_type_declaration => "composite type declaration",
         Rule::enum_declaration => "enum declaration",
         Rule::source_block => "source definition",
         Rule::generator_block => "generator definition",
@@ -130,9 +177,11 @@ fn rule_to_string(rule: Rule) -> &'static str {
         Rule::BLOCK_CLOSE => "End of block (\"}\")",
         Rule::MODEL_KEYWORD => "\"model\" keyword",
         Rule::TYPE_KEYWORD => "\"type\" keyword",
+        Rule::VIEW_KEYWORD => "\"view\" keyword",
         Rule::ENUM_KEYWORD => "\"enum\" keyword",
         Rule::GENERATOR_KEYWORD => "\"generator\" keyword",
         Rule::DATASOURCE_KEYWORD => "\"datasource\" keyword",
+        Rule::IMPORT_KEYWORD => "\"import\" keyword",
         Rule::INTERPOLATION_START => "string interpolation start",
         Rule::INTERPOLATION_END => "string interpolation end",
         Rule::CATCH_ALL => "CATCH ALL",
@@ -6,6 +6,7 @@ use pest::Parser;
 use super::helpers::*;
 use crate::ast::helper::get_sort_index_of_attribute;
 use crate::common::WritableString;
+use crate::{ast::FileId, SourceFile};
 use pest::iterators::Pair;
 
 pub struct Reformatter<'a> {
@@ -16,8 +17,9 @@ pub struct Reformatter<'a> {
 
 impl<'a> Reformatter<'a> {
     pub fn new(input: &'a str) -> Self {
-        let missing_fields = Self::find_all_missing_fields(&input);
-        let missing_field_attributes = Self::find_all_missing_attributes(&input);
+        let files = [SourceFile::new("schema.prisma", input)];
+        let missing_fields = Self::find_all_missing_fields(&files);
+        let missing_field_attributes = Self::find_all_missing_attributes(&files);
         Reformatter {
             input,
             missing_fields,
@@ -25,10 +27,31 @@ impl<'a> Reformatter<'a> {
         }
     }
 
+    /// Reformats one file of a multi-file schema. The missing fields and attributes are looked
+    /// for in the whole schema, since relations can point to models in other files.
+    pub fn for_file(files: &'a [SourceFile], file_id: FileId) -> Self {
+        Reformatter {
+            input: &files[file_id.0].content,
+            missing_fields: Self::find_all_missing_fields(files),
+            missing_field_attributes: Self::find_all_missing_attributes(files),
+        }
+    }
+
+    /// Reformats every file of a multi-file schema, keeping their paths.
+    pub fn reformat_files(files: &[SourceFile], ident_width: usize) -> Vec<SourceFile> {
+        (0..files.len())
+            .map(|index| {
+                let formatted = Reformatter::for_file(files, FileId(index)).reformat_internal(ident_width);
+
+                SourceFile::new(files[index].path.clone(), formatted)
+            })
+            .collect()
+    }
+
     // this finds all auto generated fields, that are added during auto generation AND are missing from the original input.
-    fn find_all_missing_fields(schema_string: &str) -> Result<Vec<MissingField>, crate::error::ErrorCollection> {
-        let schema_ast = crate::parse_schema_ast(&schema_string)?;
-        let datamodel = crate::parse_datamodel_and_ignore_datasource_urls(&schema_string)?;
+    fn find_all_missing_fields(files: &[SourceFile]) -> Result<Vec<MissingField>, crate::error::ErrorCollection> {
+        let schema_ast = crate::parse_schema_ast_from_files(files)?;
+        let datamodel = crate::parse_datamodel_from_files_and_ignore_datasource_urls(files)?;
         let lowerer = crate::transform::dml_to_ast::LowerDmlToAst::new(None);
         let mut result = Vec::new();
 
@@ -51,10 +74,10 @@ impl<'a> Reformatter<'a> {
     }
 
     fn find_all_missing_attributes(
-        schema_string: &str,
+        files: &[SourceFile],
     ) -> Result<Vec<MissingFieldAttribute>, crate::error::ErrorCollection> {
-        let schema_ast = crate::parse_schema_ast(&schema_string)?;
-        let datamodel = crate::parse_datamodel_and_ignore_datasource_urls(&schema_string)?;
+        let schema_ast = crate::parse_schema_ast_from_files(files)?;
+        let datamodel = crate::parse_datamodel_from_files_and_ignore_datasource_urls(files)?;
         let lowerer = crate::transform::dml_to_ast::LowerDmlToAst::new(None);
         let mut missing_field_attributes = Vec::new();
         for model in datamodel.models() {
@@ -153,7 +176,12 @@ impl<'a> Reformatter<'a> {
                         comment(target, current.as_str());
                     }
                 }
+                Rule::import_declaration => {
+                    Self::reformat_import(target, &current);
+                    seen_at_least_one_top_level_element = true;
+                }
                 Rule::model_declaration => self.reformat_model(target, &current),
+                Rule::composite_type_declaration => self.reformat_composite_type(target, &current),
                 Rule::enum_declaration => self.reformat_enum(target, &current),
                 Rule::source_block => self.reformat_datasource(target, &current),
                 Rule::generator_block => self.reformat_generator(target, &current),
@@ -178,6 +206,20 @@ impl<'a> Reformatter<'a> {
         target.write("");
     }
 
+    // Imports are rendered one per line, without blank lines between them.
+    fn reformat_import(target: &mut Renderer, token: &Token) {
+        for current in token.clone().into_inner() {
+            match current.as_rule() {
+                Rule::string_literal => {
+                    target.write("import ");
+                    target.write(current.as_str());
+                    target.end_line();
+                }
+                _ => Self::reformat_generic_token(target, &current),
+            }
+        }
+    }
+
     fn reformat_datasource(&self, target: &mut Renderer, token: &Token) {
         self.reformat_block_element(
             "datasource",
@@ -224,8 +266,13 @@ impl<'a> Reformatter<'a> {
     }
 
     fn reformat_model(&self, target: &mut Renderer, token: &Token) {
+        let is_view = token
+            .clone()
+            .into_inner()
+            .any(|current| current.as_rule() == Rule::VIEW_KEYWORD);
+
         self.reformat_block_element_internal(
-            "model",
+            if is_view { "view" } else { "model" },
             target,
             &token,
             Box::new(|table, renderer, token, model_name| {
@@ -236,6 +283,7 @@ impl<'a> Reformatter<'a> {
                         Self::reformat_attribute(renderer, &token, "@@");
                     }
                     Rule::field_declaration => self.reformat_field(table, &token, model_name),
+                    Rule::VIEW_KEYWORD => {}
                     _ => Self::reformat_generic_token(table, &token),
                 }
             }),
@@ -252,6 +300,19 @@ impl<'a> Reformatter<'a> {
         );
     }
 
+    fn reformat_composite_type(&self, target: &mut Renderer, token: &Token) {
+        self.reformat_block_element(
+            "type",
+            target,
+            token,
+            Box::new(|table, _, token, type_name| match token.as_rule() {
+                Rule::field_declaration => self.reformat_field(table, &token, type_name),
+                Rule::TYPE_KEYWORD => {}
+                _ => Self::reformat_generic_token(table, &token),
+            }),
+        );
+    }
+
     fn reformat_block_element(
         &self,
         block_type: &'static str,
@@ -673,6 +734,13 @@ impl<'a> Reformatter<'a> {
                     Self::reformat_expression(target, &current);
                     has_seen_one_argument = true;
                 }
+                Rule::argument => {
+                    if has_seen_one_argument {
+                        target.write(", ");
+                    }
+                    Self::reformat_attribute_arg(target, &current);
+                    has_seen_one_argument = true;
+                }
                 Rule::doc_comment | Rule::doc_comment_and_new_line => {
                     panic!("Comments inside expressions not supported yet.")
                 }
@@ -67,6 +67,7 @@ impl<'a> Renderer<'a> {
 
                     match other {
                         ast::Top::Model(model) => self.render_model(model),
+                        ast::Top::CompositeType(composite_type) => self.render_composite_type(composite_type),
                         ast::Top::Enum(enm) => self.render_enum(enm),
                         ast::Top::Source(source) => self.render_source_block(source),
                         ast::Top::Generator(generator) => self.render_generator_block(generator),
@@ -176,7 +177,9 @@ impl<'a> Renderer<'a> {
 
         Self::render_documentation(self, model);
 
-        self.write(format!("{}model ", comment_out).as_ref());
+        let keyword = if model.is_view { "view" } else { "model" };
+
+        self.write(format!("{}{} ", comment_out, keyword).as_ref());
         self.write(&model.name.name);
         self.write(" {");
         self.end_line();
@@ -204,6 +207,28 @@ impl<'a> Renderer<'a> {
         self.end_line();
     }
 
+    fn render_composite_type(&mut self, composite_type: &ast::CompositeType) {
+        Self::render_documentation(self, composite_type);
+
+        self.write("type ");
+        self.write(&composite_type.name.name);
+        self.write(" {");
+        self.end_line();
+        self.indent_up();
+
+        let mut field_formatter = TableFormat::new();
+
+        for field in &composite_type.fields {
+            Self::render_field(&mut field_formatter, &field, false);
+        }
+
+        field_formatter.render(self);
+
+        self.indent_down();
+        self.write("}");
+        self.end_line();
+    }
+
     fn sort_attributes(mut attributes: Vec<Attribute>, is_field_attribute: bool) -> Vec<Attribute> {
         // sort attributes
         attributes.sort_by(|a, b| {
@@ -369,15 +394,15 @@ impl<'a> Renderer<'a> {
         };
     }
 
-    fn render_func(target: &mut dyn LineWriteable, name: &str, vals: &[ast::Expression]) {
+    fn render_func(target: &mut dyn LineWriteable, name: &str, args: &[ast::Argument]) {
         target.write(name);
         target.write("(");
-        for (idx, val) in vals.iter().enumerate() {
+        for (idx, arg) in args.iter().enumerate() {
             if idx > 0 {
                 target.write(", ");
             }
 
-            Self::render_value(target, val);
+            Self::render_argument(target, arg);
         }
         target.write(")");
     }
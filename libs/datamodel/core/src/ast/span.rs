@@ -1,25 +1,32 @@
+/// Identifies the file of a multi-file schema a span points into. It is the index of the file in
+/// the list of files the schema was parsed from, so single-file schemas always use the default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FileId(pub usize);
+
 /// Represents a location in a datamodel's text representation.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Span {
     pub start: usize,
     pub end: usize,
+    pub file_id: FileId,
 }
 
 impl Span {
     pub fn new(start: usize, end: usize) -> Span {
-        Span { start, end }
+        Span {
+            start,
+            end,
+            file_id: FileId::default(),
+        }
     }
 
     // Creates a new empty span.
     pub fn empty() -> Span {
-        Span { start: 0, end: 0 }
+        Span::new(0, 0)
     }
     /// Creates a new ast::Span from a pest::Span.
     pub fn from_pest(s: pest::Span) -> Span {
-        Span {
-            start: s.start(),
-            end: s.end(),
-        }
+        Span::new(s.start(), s.end())
     }
 
     /// Adds an offset to a span.
@@ -27,8 +34,14 @@ impl Span {
         Span {
             start: offset + self.start,
             end: offset + self.end,
+            file_id: self.file_id,
         }
     }
+
+    /// Moves the span to another file of the schema.
+    pub fn in_file(&self, file_id: FileId) -> Span {
+        Span { file_id, ..*self }
+    }
 }
 
 impl std::fmt::Display for Span {
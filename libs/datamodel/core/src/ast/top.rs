@@ -5,6 +5,7 @@ use super::*;
 pub enum Top {
     Enum(Enum),
     Model(Model),
+    CompositeType(CompositeType),
     Source(SourceConfig),
     Generator(GeneratorConfig),
     Type(Field),
@@ -15,6 +16,7 @@ impl WithIdentifier for Top {
         match self {
             Top::Enum(x) => x.identifier(),
             Top::Model(x) => x.identifier(),
+            Top::CompositeType(x) => x.identifier(),
             Top::Source(x) => x.identifier(),
             Top::Generator(x) => x.identifier(),
             Top::Type(x) => x.identifier(),
@@ -27,6 +29,7 @@ impl WithSpan for Top {
         match self {
             Top::Enum(x) => x.span(),
             Top::Model(x) => x.span(),
+            Top::CompositeType(x) => x.span(),
             Top::Source(x) => x.span(),
             Top::Generator(x) => x.span(),
             Top::Type(x) => x.span(),
@@ -38,7 +41,9 @@ impl Top {
     pub fn get_type(&self) -> &str {
         match self {
             Top::Enum(_) => "enum",
+            Top::Model(model) if model.is_view => "view",
             Top::Model(_) => "model",
+            Top::CompositeType(_) => "type",
             Top::Source(_) => "source",
             Top::Generator(_) => "generator",
             Top::Type(_) => "type",
@@ -49,6 +54,7 @@ impl Top {
         match self {
             Top::Enum(x) => &x.name.name,
             Top::Model(x) => &x.name.name,
+            Top::CompositeType(x) => &x.name.name,
             Top::Source(x) => &x.name.name,
             Top::Generator(x) => &x.name.name,
             Top::Type(x) => &x.name.name,
@@ -62,6 +68,13 @@ impl Top {
         }
     }
 
+    pub fn as_composite_type(&self) -> Option<&CompositeType> {
+        match self {
+            Top::CompositeType(composite_type) => Some(composite_type),
+            _ => None,
+        }
+    }
+
     pub fn as_enum(&self) -> Option<&Enum> {
         match self {
             Top::Enum(r#enum) => Some(r#enum),
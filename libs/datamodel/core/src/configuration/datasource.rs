@@ -10,12 +10,16 @@ pub struct Datasource {
     /// the provider that was selected as active from all specified providers
     pub active_provider: String,
     pub url: StringFromEnvVar,
+    /// the database the migration engine resets and uses to replay migrations, instead of creating a temporary one
+    pub shadow_database_url: Option<StringFromEnvVar>,
     pub documentation: Option<String>,
     /// a connector representing the intersection of all providers specified
     pub combined_connector: Box<dyn Connector>,
     /// the connector of the active provider
     pub active_connector: Box<dyn Connector>,
     pub preview_features: Vec<String>,
+    /// the database schemas the datasource spans. Empty if only the schema from the url is used.
+    pub schemas: Vec<String>,
 }
 
 impl Datasource {
@@ -23,6 +27,10 @@ impl Datasource {
         &self.url
     }
 
+    pub fn has_schema(&self, name: &str) -> bool {
+        self.schemas.iter().any(|schema| schema == name)
+    }
+
     pub fn capabilities(&self) -> ConnectorCapabilities {
         let capabilities = self.active_connector.capabilities().clone();
         ConnectorCapabilities::new(capabilities)
@@ -10,6 +10,7 @@ use super::*;
 pub struct Datamodel {
     pub enums: Vec<Enum>,
     pub models: Vec<Model>,
+    pub composite_types: Vec<CompositeType>,
 }
 
 impl Datamodel {
@@ -17,6 +18,7 @@ impl Datamodel {
         Datamodel {
             enums: Vec::new(),
             models: Vec::new(),
+            composite_types: Vec::new(),
         }
     }
 
@@ -45,6 +47,11 @@ impl Datamodel {
         self.models.push(model);
     }
 
+    /// Adds a composite type to this datamodel.
+    pub fn add_composite_type(&mut self, composite_type: CompositeType) {
+        self.composite_types.push(composite_type);
+    }
+
     /// Gets an iterator over all models.
     pub fn models(&self) -> std::slice::Iter<Model> {
         self.models.iter()
@@ -55,6 +62,11 @@ impl Datamodel {
         self.enums.iter()
     }
 
+    /// Gets an iterator over all composite types.
+    pub fn composite_types(&self) -> std::slice::Iter<CompositeType> {
+        self.composite_types.iter()
+    }
+
     /// Gets a mutable iterator over all models.
     pub fn models_mut(&mut self) -> std::slice::IterMut<Model> {
         self.models.iter_mut()
@@ -103,6 +115,11 @@ impl Datamodel {
         self.enums().find(|m| m.name == *name)
     }
 
+    /// Finds a composite type by name.
+    pub fn find_composite_type(&self, name: &str) -> Option<&CompositeType> {
+        self.composite_types().find(|t| t.name == *name)
+    }
+
     /// Finds an enum by database name.
     pub fn find_enum_db_name(&self, db_name: &str) -> Option<&Enum> {
         self.enums().find(|e| e.database_name == Some(db_name.to_owned()))
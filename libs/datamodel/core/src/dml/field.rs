@@ -32,6 +32,8 @@ pub enum FieldType {
     Enum(String),
     /// This is a relation field.
     Relation(RelationInfo),
+    /// This is a field holding a value of the composite type with the given name, stored inline.
+    CompositeType(String),
     /// native field type.
     NativeType(ScalarType, NativeTypeInstance),
     /// This is a field with an unsupported datatype - used by introspection only.
@@ -48,6 +50,10 @@ impl FieldType {
         }
     }
 
+    pub fn is_composite_type(&self) -> bool {
+        matches!(self, FieldType::CompositeType(_))
+    }
+
     pub fn scalar_type(&self) -> Option<ScalarType> {
         match self {
             FieldType::NativeType(st, _) => Some(*st),
@@ -259,6 +265,9 @@ pub struct ScalarField {
     /// The database internal name.
     pub database_name: Option<String>,
 
+    /// The database name this field had before being renamed, set via `@map(previous: ...)`.
+    pub previous_database_name: Option<String>,
+
     /// The default value.
     pub default_value: Option<DefaultValue>,
 
@@ -268,6 +277,12 @@ pub struct ScalarField {
     /// true if this field marked with @id.
     pub is_id: bool,
 
+    /// The database name of the primary key constraint, set via `@id(map: ...)`.
+    pub id_constraint_name: Option<String>,
+
+    /// The database name of the unique constraint, set via `@unique(map: ...)`.
+    pub unique_constraint_name: Option<String>,
+
     /// Comments associated with this field.
     pub documentation: Option<String>,
 
@@ -290,9 +305,12 @@ impl ScalarField {
             arity,
             field_type,
             database_name: None,
+            previous_database_name: None,
             default_value: None,
             is_unique: false,
             is_id: false,
+            id_constraint_name: None,
+            unique_constraint_name: None,
             documentation: None,
             is_generated: false,
             is_updated_at: false,
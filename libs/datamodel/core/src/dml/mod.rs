@@ -1,5 +1,6 @@
 //! This module contains the models representing the Datamodel part of a Prisma schema.
 //! It contains the main data structures that the engines can build upon.
+mod composite_type;
 mod datamodel;
 mod default_value;
 mod r#enum;
@@ -9,6 +10,7 @@ mod relation_info;
 mod traits;
 
 pub use self::datamodel::*;
+pub use composite_type::*;
 pub use default_value::*;
 pub use field::*;
 pub use model::*;
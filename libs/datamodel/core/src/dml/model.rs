@@ -1,5 +1,6 @@
 use super::*;
 use crate::Field;
+use std::collections::BTreeMap;
 
 /// Represents a model in a prisma schema.
 #[derive(Debug, PartialEq, Clone)]
@@ -12,24 +13,44 @@ pub struct Model {
     pub documentation: Option<String>,
     /// The database internal name of this model.
     pub database_name: Option<String>,
+    /// The database name this model had before being renamed, set via `@@map(previous: ...)`.
+    pub previous_database_name: Option<String>,
     /// Indicates if this model is embedded or not.
     pub is_embedded: bool,
     /// Describes Composite Indexes
     pub indices: Vec<IndexDefinition>,
     /// Describes Composite Primary Keys
     pub id_fields: Vec<String>,
+    /// The database name of the primary key constraint, set via `@@id(map: ...)`.
+    pub id_constraint_name: Option<String>,
+    /// Describes check constraints defined via `@@check`.
+    pub check_constraints: Vec<CheckConstraint>,
     /// Indicates if this model is generated.
     pub is_generated: bool,
     /// Indicates if this model has to be commented out.
     pub is_commented_out: bool,
+    /// Indicates if this model is backed by a database view. Views are read-only.
+    pub is_view: bool,
+    /// The database schema this model lives in, set via `@@schema`.
+    pub schema: Option<String>,
 }
 
 /// Represents an index defined via `@@index` or `@@unique`.
 #[derive(Debug, PartialEq, Clone)]
 pub struct IndexDefinition {
     pub name: Option<String>,
+    /// The database name of the index, set via `map:`. Takes precedence over `name`.
+    pub database_name: Option<String>,
     pub fields: Vec<String>,
     pub tpe: IndexType,
+    /// Sort order and prefix length of the fields that do not use the defaults, keyed by field name.
+    pub field_options: BTreeMap<String, IndexFieldOptions>,
+    /// The index method set via `type:`.
+    pub algorithm: Option<IndexAlgorithm>,
+    /// The predicate of a partial index, set via `where:`.
+    pub predicate: Option<String>,
+    /// Fields stored in the index without being part of the key, set via `include:`.
+    pub include: Vec<String>,
 }
 
 impl IndexDefinition {
@@ -39,6 +60,18 @@ impl IndexDefinition {
             _ => false,
         }
     }
+
+    /// The name of the index in the database, if it is not left to the migration engine.
+    pub fn db_name(&self) -> Option<&str> {
+        self.database_name.as_deref().or_else(|| self.name.as_deref())
+    }
+}
+
+/// Represents a check constraint defined via `@@check`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckConstraint {
+    pub name: String,
+    pub expression: String,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -47,6 +80,73 @@ pub enum IndexType {
     Normal,
 }
 
+/// Options of a single field in an index, e.g. `title(length: 10, sort: Desc)`.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct IndexFieldOptions {
+    pub sort_order: SortOrder,
+    /// The number of leading characters that are indexed. Only supported on MySQL.
+    pub length: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        SortOrder::Asc
+    }
+}
+
+impl SortOrder {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "Asc" => Some(SortOrder::Asc),
+            "Desc" => Some(SortOrder::Desc),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "Asc",
+            SortOrder::Desc => "Desc",
+        }
+    }
+}
+
+/// The index methods that can be chosen with `type:`. Only supported on Postgres.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IndexAlgorithm {
+    Hash,
+    Gist,
+    Gin,
+    Brin,
+}
+
+impl IndexAlgorithm {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "Hash" => Some(IndexAlgorithm::Hash),
+            "Gist" => Some(IndexAlgorithm::Gist),
+            "Gin" => Some(IndexAlgorithm::Gin),
+            "Brin" => Some(IndexAlgorithm::Brin),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IndexAlgorithm::Hash => "Hash",
+            IndexAlgorithm::Gist => "Gist",
+            IndexAlgorithm::Gin => "Gin",
+            IndexAlgorithm::Brin => "Brin",
+        }
+    }
+}
+
 /// A unique criteria is a set of fields through which a record can be uniquely identified.
 #[derive(Debug)]
 pub struct UniqueCriteria<'a> {
@@ -67,11 +167,16 @@ impl Model {
             fields: vec![],
             indices: vec![],
             id_fields: vec![],
+            id_constraint_name: None,
+            check_constraints: vec![],
             documentation: None,
             database_name,
+            previous_database_name: None,
             is_embedded: false,
             is_generated: false,
             is_commented_out: false,
+            is_view: false,
+            schema: None,
         }
     }
 
@@ -166,6 +271,13 @@ impl Model {
             .expect("We assume an internally valid datamodel before mutating.")
     }
 
+    /// The database name of the primary key constraint, set on either `@id` or `@@id`.
+    pub fn primary_key_constraint_name(&self) -> Option<&str> {
+        self.id_constraint_name
+            .as_deref()
+            .or_else(|| self.singular_id_fields().find_map(|f| f.id_constraint_name.as_deref()))
+    }
+
     /// Finds the name of all id fields
     pub fn id_field_names(&self) -> Vec<String> {
         let singular_id_field = self.singular_id_fields().next();
@@ -1,3 +1,5 @@
+use std::{fmt, str::FromStr};
+
 /// Holds information about a relation field.
 #[derive(Debug, Clone)]
 pub struct RelationInfo {
@@ -9,18 +11,24 @@ pub struct RelationInfo {
     pub to_fields: Vec<String>,
     /// The name of the relation. Internally, an empty string signals no name.
     pub name: String,
-    /// A strategy indicating what happens when
-    /// a related node is deleted.
-    pub on_delete: OnDeleteStrategy,
+    /// The action taken on this side of the relation when the
+    /// referenced record is deleted. `None` if not explicitly set.
+    pub on_delete: Option<ReferentialAction>,
+    /// The action taken on this side of the relation when the
+    /// referenced fields are updated. `None` if not explicitly set.
+    pub on_update: Option<ReferentialAction>,
+    /// The database name of the foreign key constraint, set via `map:`.
+    pub constraint_name: Option<String>,
 }
 
 impl PartialEq for RelationInfo {
-    //ignores the relation name for reintrospection
+    //ignores the relation and constraint names for reintrospection
     fn eq(&self, other: &Self) -> bool {
         self.to == other.to
             && self.fields == other.fields
             && self.to_fields == other.to_fields
             && self.on_delete == other.on_delete
+            && self.on_update == other.on_update
     }
 }
 
@@ -33,23 +41,67 @@ impl RelationInfo {
             fields: Vec::new(),
             to_fields: Vec::new(),
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: None,
+            on_update: None,
+            constraint_name: None,
         }
     }
 }
 
-/// Describes what happens when related nodes are deleted.
+/// Describes what happens to the referencing records when the referenced
+/// record is deleted or its referenced fields are updated.
 #[derive(Debug, Copy, PartialEq, Clone)]
-pub enum OnDeleteStrategy {
+pub enum ReferentialAction {
+    /// Deletes the referencing records, or updates their referencing fields.
     Cascade,
-    None,
+    /// Prevents the deletion or update while referencing records exist.
+    Restrict,
+    /// Like `Restrict`, but the check may be deferred by the database.
+    NoAction,
+    /// Sets the referencing fields to null.
+    SetNull,
+    /// Sets the referencing fields to their default values.
+    SetDefault,
+}
+
+impl ReferentialAction {
+    /// All referential actions, in the order they are suggested in the schema language.
+    pub const ALL: &'static [ReferentialAction] = &[
+        ReferentialAction::Cascade,
+        ReferentialAction::Restrict,
+        ReferentialAction::NoAction,
+        ReferentialAction::SetNull,
+        ReferentialAction::SetDefault,
+    ];
 }
 
-impl ToString for OnDeleteStrategy {
-    fn to_string(&self) -> String {
+impl fmt::Display for ReferentialAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            OnDeleteStrategy::Cascade => String::from("CASCADE"),
-            OnDeleteStrategy::None => String::from("NONE"),
+            ReferentialAction::Cascade => write!(f, "Cascade"),
+            ReferentialAction::Restrict => write!(f, "Restrict"),
+            ReferentialAction::NoAction => write!(f, "NoAction"),
+            ReferentialAction::SetNull => write!(f, "SetNull"),
+            ReferentialAction::SetDefault => write!(f, "SetDefault"),
         }
     }
 }
+
+impl FromStr for ReferentialAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ReferentialAction::ALL
+            .iter()
+            .find(|action| action.to_string() == s)
+            .copied()
+            .ok_or_else(|| {
+                let allowed: Vec<String> = ReferentialAction::ALL.iter().map(|a| format!("`{}`", a)).collect();
+                format!(
+                    "Invalid referential action: `{}`. Allowed values: ({})",
+                    s,
+                    allowed.join(", ")
+                )
+            })
+    }
+}
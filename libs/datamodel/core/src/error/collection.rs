@@ -1,4 +1,5 @@
 use super::DatamodelError;
+use crate::SourceFile;
 
 /// Represents a list of validation or parser errors.
 ///
@@ -64,6 +65,18 @@ impl ErrorCollection {
 
         String::from_utf8_lossy(&message).into_owned()
     }
+
+    /// Pretty prints the errors of a schema parsed from several files, each in its own file.
+    pub fn to_pretty_string_for_files(&self, files: &[SourceFile]) -> String {
+        let mut message: Vec<u8> = Vec::new();
+
+        for err in self.to_iter() {
+            err.pretty_print_in_files(&mut message, files)
+                .expect("printing datamodel error");
+        }
+
+        String::from_utf8_lossy(&message).into_owned()
+    }
 }
 
 impl std::fmt::Display for ErrorCollection {
@@ -1,7 +1,7 @@
 mod collection;
 pub use collection::*;
 
-use crate::ast::Span;
+use crate::{ast::Span, SourceFile};
 use colored::Colorize;
 use thiserror::Error;
 
@@ -121,6 +121,9 @@ pub enum DatamodelError {
 
     #[error("Error validating: {}", message)]
     ValidationError { message: String, span: Span },
+
+    #[error("The imported file \"{}\" is not part of the schema files.", import_path)]
+    ImportNotFoundError { import_path: String, span: Span },
 }
 
 #[rustfmt::skip]
@@ -346,6 +349,10 @@ impl DatamodelError {
         DatamodelError::PreviewFeatureNotKnownError { preview_feature: String::from(preview_feature), expected_preview_features:  expected_preview_features.join(", "), span}
     }
 
+    pub fn new_import_not_found_error(import_path: &str, span: Span) -> DatamodelError {
+        DatamodelError::ImportNotFoundError { import_path: String::from(import_path), span }
+    }
+
     pub fn new_value_parser_error(expected_type: &str, parser_error: &str, raw: &str, span: Span) -> DatamodelError {
         DatamodelError::ValueParserError {
             expected_type: String::from(expected_type),
@@ -402,6 +409,49 @@ impl DatamodelError {
             DatamodelError::EnumValidationError {span, ..} => *span,
             DatamodelError::ConnectorError { span, .. } => *span,
             DatamodelError::PreviewFeatureNotKnownError {span, ..} => *span,
+            DatamodelError::ImportNotFoundError { span, .. } => *span,
+        }
+    }
+
+    pub(crate) fn span_mut(&mut self) -> &mut Span {
+        match self {
+            DatamodelError::ArgumentNotFound { span, .. } => span,
+            DatamodelError::AttributeArgumentNotFound { span, .. } => span,
+            DatamodelError::ArgumentCountMissmatch { span, .. } => span,
+            DatamodelError::SourceArgumentNotFound { span, .. } => span,
+            DatamodelError::GeneratorArgumentNotFound { span, .. } => span,
+            DatamodelError::AttributeValidationError { span, .. } => span,
+            DatamodelError::AttributeNotKnownError { span, .. } => span,
+            DatamodelError::ReservedScalarTypeError { span, .. } => span,
+            DatamodelError::FunctionNotKnownError { span, .. } => span,
+            DatamodelError::DatasourceProviderNotKnownError { span, .. } => span,
+            DatamodelError::LiteralParseError { span, .. } => span,
+            DatamodelError::TypeNotFoundError { span, .. } => span,
+            DatamodelError::ScalarTypeNotFoundError { span, .. } => span,
+            DatamodelError::ParserError { span, .. } => span,
+            DatamodelError::FunctionalEvaluationError { span, .. } => span,
+            DatamodelError::EnvironmentFunctionalEvaluationError { span, .. } => span,
+            DatamodelError::TypeMismatchError { span, .. } => span,
+            DatamodelError::ValueParserError { span, .. } => span,
+            DatamodelError::ValidationError { span, .. } => span,
+            DatamodelError::LegacyParserError { span, .. } => span,
+            DatamodelError::ModelValidationError { span, .. } => span,
+            DatamodelError::DuplicateAttributeError { span, .. } => span,
+            DatamodelError::DuplicateConfigKeyError { span, .. } => span,
+            DatamodelError::DuplicateTopError { span, .. } => span,
+            DatamodelError::DuplicateFieldError { span, .. } => span,
+            DatamodelError::DuplicateEnumValueError { span, .. } => span,
+            DatamodelError::DuplicateArgumentError { span, .. } => span,
+            DatamodelError::DuplicateDefaultArgumentError { span, .. } => span,
+            DatamodelError::UnusedArgumentError { span, .. } => span,
+            DatamodelError::ScalarListFieldsAreNotSupported {span, ..} => span,
+            DatamodelError::MultipleIndexesWithSameNameAreNotSupported {span, ..} => span,
+            DatamodelError::FieldValidationError {span , ..} => span,
+            DatamodelError::SourceValidationError {span, ..} => span,
+            DatamodelError::EnumValidationError {span, ..} => span,
+            DatamodelError::ConnectorError { span, .. } => span,
+            DatamodelError::PreviewFeatureNotKnownError {span, ..} => span,
+            DatamodelError::ImportNotFoundError { span, .. } => span,
         }
     }
     pub fn description(&self) -> String {
@@ -411,6 +461,14 @@ impl DatamodelError {
     pub fn pretty_print(&self, f: &mut dyn std::io::Write, file_name: &str, text: &str) -> std::io::Result<()> {
         pretty_print_error(f, file_name, text, self)
     }
+
+    /// Pretty prints the error in the file of a multi-file schema its span points into.
+    pub fn pretty_print_in_files(&self, f: &mut dyn std::io::Write, files: &[SourceFile]) -> std::io::Result<()> {
+        match files.get(self.span().file_id.0) {
+            Some(file) => pretty_print_error(f, &file.path, &file.content, self),
+            None => writeln!(f, "{}: {}", "error".bright_red().bold(), self.description().bold()),
+        }
+    }
 }
 
 /// Given the datamodel text representation, pretty prints an error, including
@@ -1,4 +1,4 @@
-use super::{Datamodel, Enum, EnumValue, Field, Function, Model, UniqueIndex};
+use super::{CompositeType, Datamodel, Enum, EnumValue, Field, Function, Model, UniqueIndex};
 use crate::{dml, IndexType, ScalarType};
 use prisma_value::PrismaValue;
 use rust_decimal::prelude::ToPrimitive;
@@ -17,6 +17,7 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
     let mut datamodel = Datamodel {
         models: vec![],
         enums: vec![],
+        types: vec![],
     };
 
     for enum_model in schema.enums() {
@@ -27,6 +28,10 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
         datamodel.models.push(model_to_dmmf(&model));
     }
 
+    for composite_type in schema.composite_types() {
+        datamodel.types.push(composite_type_to_dmmf(&composite_type));
+    }
+
     datamodel
 }
 
@@ -89,18 +94,33 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
     }
 }
 
+fn composite_type_to_dmmf(composite_type: &dml::CompositeType) -> CompositeType {
+    CompositeType {
+        name: composite_type.name.clone(),
+        fields: composite_type
+            .fields()
+            .map(|f| field_to_dmmf_internal(&dml::Field::ScalarField(f.clone()), false))
+            .collect(),
+        documentation: composite_type.documentation.clone(),
+    }
+}
+
 fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
     let a_relation_field_is_based_on_this_field: bool = model
         .relation_fields()
         .any(|f| f.relation_info.fields.iter().any(|f| f == field.name()));
 
+    field_to_dmmf_internal(field, a_relation_field_is_based_on_this_field)
+}
+
+fn field_to_dmmf_internal(field: &dml::Field, is_read_only: bool) -> Field {
     Field {
         name: field.name().to_string(),
         kind: get_field_kind(field),
         is_required: *field.arity() == dml::FieldArity::Required,
         is_list: *field.arity() == dml::FieldArity::List,
         is_id: field.is_id(),
-        is_read_only: a_relation_field_is_based_on_this_field,
+        is_read_only,
         has_default_value: field.default_value().is_some(),
         default: default_value_to_serde(&field.default_value().cloned()),
         is_unique: field.is_unique(),
@@ -108,6 +128,7 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
         relation_from_fields: get_relation_from_fields(field),
         relation_to_fields: get_relation_to_fields(field),
         relation_on_delete: get_relation_delete_strategy(field),
+        relation_on_update: get_relation_update_strategy(field),
         field_type: get_field_type(field),
         is_generated: Some(field.is_generated()),
         is_updated_at: Some(field.is_updated_at()),
@@ -118,6 +139,7 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
 fn get_field_kind(field: &dml::Field) -> String {
     match field.field_type() {
         dml::FieldType::Relation(_) => String::from("object"),
+        dml::FieldType::CompositeType(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
         dml::FieldType::Base(_, _) => String::from("scalar"),
         tpe => unimplemented!("DMMF does not support field type {:?}", tpe),
@@ -144,6 +166,8 @@ fn prisma_value_to_serde(value: &PrismaValue) -> serde_json::Value {
         PrismaValue::Null => serde_json::Value::Null,
         PrismaValue::Uuid(val) => serde_json::Value::String(val.to_string()),
         PrismaValue::Json(val) => serde_json::Value::String(val.to_string()),
+        PrismaValue::BigInt(val) => serde_json::Value::String(val.to_string()),
+        PrismaValue::Bytes(val) => serde_json::Value::String(prisma_value::encode_bytes(val)),
         PrismaValue::List(value_vec) => {
             serde_json::Value::Array(value_vec.iter().map(|pv| prisma_value_to_serde(pv)).collect())
         }
@@ -163,6 +187,7 @@ fn get_field_type(field: &dml::Field) -> String {
     match &field.field_type() {
         dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
         dml::FieldType::Enum(t) => t.clone(),
+        dml::FieldType::CompositeType(t) => t.clone(),
         dml::FieldType::Unsupported(t) => t.clone(),
         dml::FieldType::Base(t, _) => type_to_string(t),
         dml::FieldType::NativeType(t, _) => type_to_string(t),
@@ -196,7 +221,19 @@ fn get_relation_to_fields(field: &dml::Field) -> Option<Vec<String>> {
 
 fn get_relation_delete_strategy(field: &dml::Field) -> Option<String> {
     match &field {
-        dml::Field::RelationField(rf) => Some(rf.relation_info.on_delete.to_string()),
+        dml::Field::RelationField(rf) => Some(
+            rf.relation_info
+                .on_delete
+                .map(|action| action.to_string())
+                .unwrap_or_else(|| String::from("NONE")),
+        ),
+        _ => None,
+    }
+}
+
+fn get_relation_update_strategy(field: &dml::Field) -> Option<String> {
+    match &field {
+        dml::Field::RelationField(rf) => rf.relation_info.on_update.map(|action| action.to_string()),
         _ => None,
     }
 }
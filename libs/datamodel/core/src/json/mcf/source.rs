@@ -8,7 +8,11 @@ pub struct SourceConfig {
     pub active_provider: String,
     pub url: StringFromEnvVar,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow_database_url: Option<StringFromEnvVar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub schemas: Vec<String>,
 }
 
 pub fn render_sources_to_json_value(sources: &[configuration::Datasource]) -> serde_json::Value {
@@ -37,6 +41,8 @@ fn source_to_json_struct(source: &configuration::Datasource) -> SourceConfig {
         provider: source.provider.clone(),
         active_provider: source.active_provider.to_string(),
         url: source.url().clone(),
+        shadow_database_url: source.shadow_database_url.clone(),
         documentation: source.documentation.clone(),
+        schemas: source.schemas.clone(),
     }
 }
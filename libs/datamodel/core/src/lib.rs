@@ -16,6 +16,7 @@
 //! * `configuration`: contains the models representing the Datasources and Generators of a Prisma schema
 //! * `transform`: contains the logic to turn an AST into models and vice versa
 //! * `json`: contains the logic to turn models into their JSON/DMMF representation
+//! * `source_file`: contains the files of multi-file schemas and the logic to load them from disk
 //!
 //! The flow between the layers is depicted in the following diagram.
 //!<pre>
@@ -82,11 +83,13 @@ pub mod configuration;
 pub mod dml;
 pub mod error;
 pub mod json;
+pub mod source_file;
 pub mod transform;
 pub mod walkers;
 
 pub use configuration::*;
 pub use dml::*;
+pub use source_file::{load_schema_files, SourceFile};
 
 use crate::ast::SchemaAst;
 use std::io::Write;
@@ -97,17 +100,28 @@ use transform::{
 
 /// Parses and validates a datamodel string, using core attributes only.
 pub fn parse_datamodel(datamodel_string: &str) -> Result<Datamodel, error::ErrorCollection> {
-    parse_datamodel_internal(datamodel_string, false)
+    parse_datamodel_from_files(&single_file(datamodel_string))
 }
 
 pub fn parse_datamodel_and_ignore_datasource_urls(datamodel_string: &str) -> Result<Datamodel, error::ErrorCollection> {
-    parse_datamodel_internal(datamodel_string, true)
+    parse_datamodel_from_files_and_ignore_datasource_urls(&single_file(datamodel_string))
+}
+
+/// Parses and validates a datamodel split across several files, using core attributes only.
+pub fn parse_datamodel_from_files(files: &[SourceFile]) -> Result<Datamodel, error::ErrorCollection> {
+    parse_datamodel_internal(files, false)
+}
+
+pub fn parse_datamodel_from_files_and_ignore_datasource_urls(
+    files: &[SourceFile],
+) -> Result<Datamodel, error::ErrorCollection> {
+    parse_datamodel_internal(files, true)
 }
 
 /// Parses and validates a datamodel string, using core attributes only.
 /// In case of an error, a pretty, colorful string is returned.
 pub fn parse_datamodel_or_pretty_error(datamodel_string: &str, file_name: &str) -> Result<Datamodel, String> {
-    match parse_datamodel_internal(datamodel_string, false) {
+    match parse_datamodel(datamodel_string) {
         Ok(dml) => Ok(dml),
         Err(errs) => {
             let mut buffer = std::io::Cursor::new(Vec::<u8>::new());
@@ -125,10 +139,10 @@ pub fn parse_datamodel_or_pretty_error(datamodel_string: &str, file_name: &str)
 }
 
 fn parse_datamodel_internal(
-    datamodel_string: &str,
+    files: &[SourceFile],
     ignore_datasource_urls: bool,
 ) -> Result<Datamodel, error::ErrorCollection> {
-    let ast = ast::parser::parse_schema(datamodel_string)?;
+    let ast = ast::parser::parse_schema_files(files)?;
     let sources = load_sources(&ast, ignore_datasource_urls, vec![])?;
     let validator = ValidationPipeline::new(&sources);
 
@@ -156,16 +170,20 @@ pub fn parse_schema_ast(datamodel_string: &str) -> Result<SchemaAst, error::Erro
     ast::parser::parse_schema(datamodel_string)
 }
 
+/// Parses the files of a multi-file schema into one AST, checking that every import refers to
+/// one of the files.
+pub fn parse_schema_ast_from_files(files: &[SourceFile]) -> Result<SchemaAst, error::ErrorCollection> {
+    ast::parser::parse_schema_files(files)
+}
+
 /// Loads all configuration blocks from a datamodel using the built-in source definitions.
 pub fn parse_configuration(datamodel_string: &str) -> Result<Configuration, error::ErrorCollection> {
-    let ast = ast::parser::parse_schema(datamodel_string)?;
-    let datasources = load_sources(&ast, false, vec![])?;
-    let generators = GeneratorLoader::load_generators_from_ast(&ast)?;
+    parse_configuration_from_files(&single_file(datamodel_string))
+}
 
-    Ok(Configuration {
-        datasources,
-        generators,
-    })
+/// Loads all configuration blocks from a datamodel split across several files.
+pub fn parse_configuration_from_files(files: &[SourceFile]) -> Result<Configuration, error::ErrorCollection> {
+    parse_configuration_internal(files, false, vec![])
 }
 
 /// - `datasource_url_overrides`: the tuples consist of datasource name and url
@@ -173,21 +191,36 @@ pub fn parse_configuration_with_url_overrides(
     schema: &str,
     datasource_url_overrides: Vec<(String, String)>,
 ) -> Result<Configuration, error::ErrorCollection> {
-    let ast = ast::parser::parse_schema(schema)?;
-    let datasources = load_sources(&ast, false, datasource_url_overrides)?;
-    let generators = GeneratorLoader::load_generators_from_ast(&ast)?;
+    parse_configuration_from_files_with_url_overrides(&single_file(schema), datasource_url_overrides)
+}
 
-    Ok(Configuration {
-        datasources,
-        generators,
-    })
+/// - `datasource_url_overrides`: the tuples consist of datasource name and url
+pub fn parse_configuration_from_files_with_url_overrides(
+    files: &[SourceFile],
+    datasource_url_overrides: Vec<(String, String)>,
+) -> Result<Configuration, error::ErrorCollection> {
+    parse_configuration_internal(files, false, datasource_url_overrides)
 }
 
 pub fn parse_configuration_and_ignore_datasource_urls(
     datamodel_string: &str,
 ) -> Result<Configuration, error::ErrorCollection> {
-    let ast = ast::parser::parse_schema(datamodel_string)?;
-    let datasources = load_sources(&ast, true, vec![])?;
+    parse_configuration_from_files_and_ignore_datasource_urls(&single_file(datamodel_string))
+}
+
+pub fn parse_configuration_from_files_and_ignore_datasource_urls(
+    files: &[SourceFile],
+) -> Result<Configuration, error::ErrorCollection> {
+    parse_configuration_internal(files, true, vec![])
+}
+
+fn parse_configuration_internal(
+    files: &[SourceFile],
+    ignore_datasource_urls: bool,
+    datasource_url_overrides: Vec<(String, String)>,
+) -> Result<Configuration, error::ErrorCollection> {
+    let ast = ast::parser::parse_schema_files(files)?;
+    let datasources = load_sources(&ast, ignore_datasource_urls, datasource_url_overrides)?;
     let generators = GeneratorLoader::load_generators_from_ast(&ast)?;
 
     Ok(Configuration {
@@ -196,6 +229,11 @@ pub fn parse_configuration_and_ignore_datasource_urls(
     })
 }
 
+/// The single file of a schema passed as a string.
+fn single_file(datamodel_string: &str) -> [SourceFile; 1] {
+    [SourceFile::new("schema.prisma", datamodel_string)]
+}
+
 fn load_sources(
     schema_ast: &SchemaAst,
     ignore_datasource_urls: bool,
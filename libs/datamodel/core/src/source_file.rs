@@ -0,0 +1,105 @@
+//! Prisma schemas can be split across several files. The files are either passed explicitly, or
+//! loaded from disk by following the `import "./other.prisma"` declarations of a root file.
+use crate::ast::parser::parse_schema;
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+
+/// One file of a Prisma schema.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceFile {
+    /// The path of the file. Imports in the file are resolved relative to its directory.
+    pub path: String,
+    pub content: String,
+}
+
+impl SourceFile {
+    pub fn new(path: impl Into<String>, content: impl Into<String>) -> Self {
+        SourceFile {
+            path: path.into(),
+            content: content.into(),
+        }
+    }
+
+    /// Whether the `import` declaration with the given path, in the `importing` file, refers to
+    /// this file.
+    pub fn is_imported_by(&self, importing: &SourceFile, import_path: &str) -> bool {
+        normalize_path(Path::new(&self.path)) == resolve_import(&importing.path, import_path)
+    }
+}
+
+/// Reads the schema file at `path` and, transitively, the files it imports. The root file comes
+/// first. Imports that cannot be read are left out, they are reported with their location when
+/// the files are parsed.
+pub fn load_schema_files(path: impl AsRef<Path>) -> std::io::Result<Vec<SourceFile>> {
+    let path = path.as_ref();
+    let root = SourceFile::new(path.to_string_lossy(), std::fs::read_to_string(path)?);
+    let mut files = vec![root];
+    let mut next_file = 0;
+
+    while next_file < files.len() {
+        // Files that fail to parse are reported when the schema is parsed, their imports are not
+        // followed.
+        let imports = parse_schema(&files[next_file].content)
+            .map(|ast| ast.imports)
+            .unwrap_or_default();
+
+        for import in imports {
+            let imported_path = resolve_import(&files[next_file].path, &import.path);
+
+            if files
+                .iter()
+                .any(|file| normalize_path(Path::new(&file.path)) == imported_path)
+            {
+                continue;
+            }
+
+            if let Ok(content) = std::fs::read_to_string(&imported_path) {
+                files.push(SourceFile::new(imported_path.to_string_lossy(), content));
+            }
+        }
+
+        next_file += 1;
+    }
+
+    Ok(files)
+}
+
+fn resolve_import(importing_path: &str, import_path: &str) -> PathBuf {
+    let directory = Path::new(importing_path).parent().unwrap_or_else(|| Path::new(""));
+
+    normalize_path(&directory.join(import_path))
+}
+
+/// Resolves `.` and `..` without touching the file system, so paths can be compared.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir if matches!(normalized.components().last(), Some(Component::Normal(_))) => {
+                normalized.pop();
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn imports_are_resolved_relative_to_the_importing_file() {
+        let importing = SourceFile::new("prisma/schema.prisma", "");
+        let billing = SourceFile::new("prisma/billing/invoices.prisma", "");
+        let users = SourceFile::new("./users.prisma", "");
+
+        assert!(billing.is_imported_by(&importing, "./billing/invoices.prisma"));
+        assert!(billing.is_imported_by(&importing, "billing/../billing/invoices.prisma"));
+        assert!(!billing.is_imported_by(&importing, "./invoices.prisma"));
+        assert!(users.is_imported_by(&importing, "../users.prisma"));
+    }
+}
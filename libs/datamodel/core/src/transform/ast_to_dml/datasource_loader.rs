@@ -13,6 +13,8 @@ use crate::{ast, Datasource};
 use datamodel_connector::{CombinedConnector, Connector};
 
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
+const SCHEMAS_KEY: &str = "schemas";
+const SHADOW_DATABASE_URL_KEY: &str = "shadowDatabaseUrl";
 
 /// Is responsible for loading and validating Datasources defined in an AST.
 pub struct DatasourceLoader {
@@ -149,6 +151,30 @@ impl DatasourceLoader {
             }
         }
 
+        let schemas_arg = args.arg(SCHEMAS_KEY);
+        let (schemas, schemas_span) = match schemas_arg.ok() {
+            Some(x) => (x.as_array().to_str_vec()?, x.span()),
+            None => (Vec::new(), Span::empty()),
+        };
+
+        let shadow_database_url_arg = args.arg(SHADOW_DATABASE_URL_KEY).ok();
+        let shadow_database_url = match &shadow_database_url_arg {
+            // Keep the env var name or the literal, so the property survives reformatting.
+            Some(arg) if ignore_datasource_urls && arg.is_from_env() => Some(StringFromEnvVar {
+                from_env_var: Some(arg.as_env_function()?.var_name().to_owned()),
+                value: String::new(),
+            }),
+            Some(arg) => {
+                let (from_env_var, value) = arg.as_str_from_env()?;
+
+                Some(StringFromEnvVar {
+                    from_env_var,
+                    value: value.trim().to_owned(),
+                })
+            }
+            None => None,
+        };
+
         let documentation = ast_source.documentation.clone().map(|comment| comment.text);
         let url = StringFromEnvVar {
             from_env_var: env_var_for_url,
@@ -187,15 +213,42 @@ impl DatasourceLoader {
         let (successes, errors): (Vec<_>, Vec<_>) = validated_providers.into_iter().partition(|result| result.is_ok());
         if !successes.is_empty() {
             let first_successful_provider = successes.into_iter().next().unwrap()?;
+
+            if !schemas.is_empty() && !first_successful_provider.connector().supports_multiple_schemas() {
+                return Err(DatamodelError::new_source_validation_error(
+                    &format!(
+                        "The `{}` property is not supported by the provider `{}`.",
+                        SCHEMAS_KEY,
+                        first_successful_provider.canonical_name()
+                    ),
+                    source_name,
+                    schemas_span,
+                ));
+            }
+
+            if let (Some(shadow_database_url), Some(arg)) = (&shadow_database_url, &shadow_database_url_arg) {
+                if !ignore_datasource_urls {
+                    validate_shadow_database_url(
+                        shadow_database_url,
+                        &url,
+                        first_successful_provider,
+                        source_name,
+                        arg.span(),
+                    )?;
+                }
+            }
+
             Ok(Datasource {
                 name: source_name.to_string(),
                 provider: providers,
                 active_provider: first_successful_provider.canonical_name().to_string(),
                 url,
+                shadow_database_url,
                 documentation: documentation.clone(),
                 combined_connector,
                 active_connector: first_successful_provider.connector(),
                 preview_features,
+                schemas,
             })
         } else {
             Err(errors.into_iter().next().unwrap().err().unwrap())
@@ -207,6 +260,42 @@ impl DatasourceLoader {
     }
 }
 
+fn validate_shadow_database_url(
+    shadow_database_url: &StringFromEnvVar,
+    url: &StringFromEnvVar,
+    provider: &Box<dyn DatasourceProvider>,
+    source_name: &str,
+    span: Span,
+) -> Result<(), DatamodelError> {
+    if shadow_database_url.value.is_empty() {
+        return Err(DatamodelError::new_source_validation_error(
+            &format!(
+                "The `{}` property of the datasource `{}` must not be empty.",
+                SHADOW_DATABASE_URL_KEY, source_name
+            ),
+            source_name,
+            span,
+        ));
+    }
+
+    provider
+        .can_handle_url(source_name, shadow_database_url)
+        .map_err(|err_msg| DatamodelError::new_source_validation_error(&err_msg, source_name, span))?;
+
+    if shadow_database_url.value == url.value {
+        return Err(DatamodelError::new_source_validation_error(
+            &format!(
+                "The `{}` and `url` properties of the datasource `{}` must point to different databases. The shadow database is reset by the migration engine.",
+                SHADOW_DATABASE_URL_KEY, source_name
+            ),
+            source_name,
+            span,
+        ));
+    }
+
+    Ok(())
+}
+
 fn get_builtin_datasource_providers() -> Vec<Box<dyn DatasourceProvider>> {
     vec![
         Box::new(MySqlDatasourceProvider::new()),
@@ -45,6 +45,10 @@ impl<'a> LiftAstToDml<'a> {
                     Ok(md) => schema.add_model(md),
                     Err(mut err) => errors.append(&mut err),
                 },
+                ast::Top::CompositeType(ct) => match self.lift_composite_type(&ct, ast_schema) {
+                    Ok(ct) => schema.add_composite_type(ct),
+                    Err(mut err) => errors.append(&mut err),
+                },
                 ast::Top::Source(_) => { /* Source blocks are explicitly ignored by the validator */ }
                 ast::Top::Generator(_) => { /* Generator blocks are explicitly ignored by the validator */ }
                 // TODO: For now, type blocks are never checked on their own.
@@ -63,6 +67,7 @@ impl<'a> LiftAstToDml<'a> {
     fn lift_model(&self, ast_model: &ast::Model, ast_schema: &ast::SchemaAst) -> Result<dml::Model, ErrorCollection> {
         let mut model = dml::Model::new(ast_model.name.name.clone(), None);
         model.documentation = ast_model.documentation.clone().map(|comment| comment.text);
+        model.is_view = ast_model.is_view;
 
         let mut errors = ErrorCollection::new();
 
@@ -84,6 +89,87 @@ impl<'a> LiftAstToDml<'a> {
         Ok(model)
     }
 
+    /// Internal: Validates a composite type AST node and lifts it to a DML composite type.
+    fn lift_composite_type(
+        &self,
+        ast_composite_type: &ast::CompositeType,
+        ast_schema: &ast::SchemaAst,
+    ) -> Result<dml::CompositeType, ErrorCollection> {
+        let mut composite_type = dml::CompositeType::new(&ast_composite_type.name.name);
+        composite_type.documentation = ast_composite_type.documentation.clone().map(|comment| comment.text);
+
+        let mut errors = ErrorCollection::new();
+
+        for ast_field in &ast_composite_type.fields {
+            match self.lift_field(ast_field, ast_schema) {
+                Ok(Field::ScalarField(field)) if field.is_id || field.is_unique => {
+                    errors.push(DatamodelError::new_field_validation_error(
+                        "Fields of composite types cannot be marked as id or unique.",
+                        &ast_composite_type.name.name,
+                        &ast_field.name.name,
+                        ast_field.span,
+                    ))
+                }
+                Ok(Field::ScalarField(field)) => composite_type.add_field(field),
+                Ok(Field::RelationField(_)) => errors.push(DatamodelError::new_field_validation_error(
+                    "Composite types cannot contain relation fields. Only scalar, enum and composite types are allowed.",
+                    &ast_composite_type.name.name,
+                    &ast_field.name.name,
+                    ast_field.field_type.span,
+                )),
+                Err(mut err) => errors.append(&mut err),
+            }
+        }
+
+        if let Err(err) = self.check_composite_type_recursion(ast_composite_type, ast_schema, &mut Vec::new()) {
+            errors.push(err);
+        }
+
+        if errors.has_errors() {
+            return Err(errors);
+        }
+
+        Ok(composite_type)
+    }
+
+    /// Internal: Composite types are stored inline, so they cannot contain themselves.
+    fn check_composite_type_recursion(
+        &self,
+        ast_composite_type: &ast::CompositeType,
+        ast_schema: &ast::SchemaAst,
+        checked_types: &mut Vec<String>,
+    ) -> Result<(), DatamodelError> {
+        checked_types.push(ast_composite_type.name.name.clone());
+
+        for ast_field in &ast_composite_type.fields {
+            let type_name = &ast_field.field_type.name;
+
+            if type_name == &checked_types[0] {
+                return Err(DatamodelError::new_validation_error(
+                    &format!(
+                        "Recursive composite types are not allowed. Recursive path was: {} -> {}.",
+                        checked_types.join(" -> "),
+                        type_name
+                    ),
+                    ast_field.field_type.span,
+                ));
+            }
+
+            if checked_types.iter().any(|x| x == type_name) {
+                // A cycle not involving the type we started from, reported on its own types.
+                continue;
+            }
+
+            if let Some(nested) = ast_schema.find_composite_type(type_name) {
+                self.check_composite_type_recursion(nested, ast_schema, checked_types)?;
+            }
+        }
+
+        checked_types.pop();
+
+        Ok(())
+    }
+
     /// Internal: Validates an enum AST node.
     fn lift_enum(&self, ast_enum: &ast::Enum) -> Result<dml::Enum, ErrorCollection> {
         let mut errors = ErrorCollection::new();
@@ -336,6 +422,8 @@ impl<'a> LiftAstToDml<'a> {
             Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
         } else if ast_schema.find_enum(type_name).is_some() {
             Ok((dml::FieldType::Enum(type_name.clone()), vec![]))
+        } else if ast_schema.find_composite_type(type_name).is_some() {
+            Ok((dml::FieldType::CompositeType(type_name.clone()), vec![]))
         } else {
             self.resolve_custom_type(ast_field, ast_schema, checked_types)
         }
@@ -5,7 +5,7 @@ use crate::{
     common::{NameNormalizer, RelationNames},
     dml,
     error::ErrorCollection,
-    Field, OnDeleteStrategy, ScalarField, UniqueCriteria,
+    Field, ScalarField, UniqueCriteria,
 };
 
 /// Helper for standardsing a datamodel.
@@ -179,7 +179,9 @@ impl Standardiser {
                         fields: vec![],
                         to_fields: vec![],
                         name: rel_info.name.clone(),
-                        on_delete: OnDeleteStrategy::None,
+                        on_delete: None,
+                        on_update: None,
+                        constraint_name: None,
                     };
                     let mut back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
                     back_relation_field.arity = dml::FieldArity::List;
@@ -250,7 +252,9 @@ impl Standardiser {
                         fields: underlying_field_names,
                         to_fields: unique_criteria_field_names,
                         name: rel_info.name.clone(),
-                        on_delete: OnDeleteStrategy::None,
+                        on_delete: None,
+                        on_update: None,
+                        constraint_name: None,
                     };
 
                     let back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
@@ -52,6 +52,11 @@ impl<'a> Validator<'a> {
                 errors_for_model.push(err);
             }
 
+            if let Err(err) = self.validate_model_schema(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push(err);
+            }
+
             if let Err(err) = self.validate_relations_not_ambiguous(ast_schema, model) {
                 errors_for_model.push(err);
             }
@@ -60,6 +65,10 @@ impl<'a> Validator<'a> {
                 errors_for_model.push(err);
             }
 
+            if let Err(err) = self.validate_views_have_no_relations(ast_schema, schema, model) {
+                errors_for_model.push(err);
+            }
+
             if let Err(ref mut the_errors) =
                 self.validate_field_arities(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
             {
@@ -84,6 +93,18 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_index_options(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_constraint_names(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
             if let Err(ref mut the_errors) = self.validate_base_fields_for_relation(
                 schema,
                 ast_schema.find_model(&model.name).expect(STATE_ERROR),
@@ -176,6 +197,15 @@ impl<'a> Validator<'a> {
             }
         }
 
+        for composite_type in ast_schema.composite_types() {
+            errors.push_opt(composite_type.name.validate("Composite type").err());
+
+            for field in composite_type.fields.iter() {
+                errors.push_opt(field.name.validate("Field").err());
+                errors.append(&mut field.validate_attributes());
+            }
+        }
+
         for enum_decl in ast_schema.enums() {
             errors.push_opt(enum_decl.name.validate("Enum").err());
             errors.append(&mut enum_decl.validate_attributes());
@@ -205,7 +235,7 @@ impl<'a> Validator<'a> {
         for model in schema.models() {
             if let Some(ast_model) = ast_schema.find_model(&model.name) {
                 for index in model.indices.iter() {
-                    if let Some(index_name) = &index.name {
+                    if let Some(index_name) = index.db_name() {
                         if index_names.contains(index_name) && !multiple_indexes_with_same_name_are_supported {
                             let ast_index = ast_model
                                 .attributes
@@ -236,7 +266,8 @@ impl<'a> Validator<'a> {
         };
 
         for field in model.scalar_fields() {
-            if field.is_list() && !scalar_lists_are_supported {
+            // Lists of composite types are stored inline in a single Json value.
+            if field.is_list() && !scalar_lists_are_supported && !field.field_type.is_composite_type() {
                 errors.push(DatamodelError::new_scalar_list_fields_are_not_supported(
                     &model.name,
                     &field.name,
@@ -271,6 +302,21 @@ impl<'a> Validator<'a> {
                     ));
                 }
             }
+
+            if let dml::FieldType::CompositeType(type_name) = &field.field_type {
+                let supports_json_type = match self.source {
+                    Some(source) => source.combined_connector.supports_json(),
+                    None => false,
+                };
+                if !supports_json_type {
+                    errors.push(DatamodelError::new_field_validation_error(
+                        &format!("Field `{}` in model `{}` can't be of the composite type `{}`. Composite types are stored as Json and the current connector does not support the Json type.", &field.name, &model.name, type_name),
+                        &model.name,
+                        &field.name,
+                        ast_model.find_field(&field.name).span,
+                    ));
+                }
+            }
         }
 
         if errors.has_errors() {
@@ -449,6 +495,194 @@ impl<'a> Validator<'a> {
         }
     }
 
+    fn validate_model_schema(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let schemas: &[String] = self.source.map(|source| source.schemas.as_slice()).unwrap_or(&[]);
+
+        match &model.schema {
+            Some(schema) if !schemas.contains(schema) => {
+                let span = ast_model
+                    .attributes
+                    .iter()
+                    .find(|attribute| attribute.name.name == "schema")
+                    .map(|attribute| attribute.span)
+                    .unwrap_or(ast_model.span);
+
+                Err(DatamodelError::new_attribute_validation_error(
+                    &format!(
+                        "The schema `{}` is not listed in the `schemas` property of the datasource.",
+                        schema
+                    ),
+                    "schema",
+                    span,
+                ))
+            }
+            None if !schemas.is_empty() => Err(DatamodelError::new_model_validation_error(
+                "This model is missing an `@@schema` attribute. Every model must be assigned to one of the datasource's schemas when `schemas` is set.",
+                &model.name,
+                ast_model.span,
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Index options are only available on the databases that support them.
+    fn validate_index_options(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        let source = match self.source {
+            Some(source) => source,
+            None => return Ok(()),
+        };
+        let connector = &source.combined_connector;
+
+        for (idx, index) in model.indices.iter().enumerate() {
+            let attribute_name = if index.is_unique() { "unique" } else { "index" };
+            let span = index_attribute_span(ast_model, model, idx);
+
+            let mut unsupported = |argument: &str| {
+                errors.push(DatamodelError::new_attribute_validation_error(
+                    &format!(
+                        "The `{}` argument is not supported by the `{}` provider.",
+                        argument, source.active_provider
+                    ),
+                    attribute_name,
+                    span,
+                ))
+            };
+
+            if index.field_options.values().any(|options| options.length.is_some())
+                && !connector.supports_index_prefix_length()
+            {
+                unsupported("length");
+            }
+
+            if index.algorithm.is_some() && !connector.supports_index_algorithms() {
+                unsupported("type");
+            }
+
+            if index.predicate.is_some() && !connector.supports_partial_indexes() {
+                unsupported("where");
+            }
+
+            if !index.include.is_empty() && !connector.supports_index_include_columns() {
+                unsupported("include");
+            }
+        }
+
+        errors.ok()
+    }
+
+    /// Constraint names set via `map:` must be supported by the database and fit into its identifiers.
+    fn validate_constraint_names(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        let source = match self.source {
+            Some(source) => source,
+            None => return Ok(()),
+        };
+        let connector = &source.combined_connector;
+
+        let field_attribute_span = |field_name: &str, attribute_name: &str| {
+            let ast_field = ast_model.find_field(field_name);
+
+            ast_field
+                .attributes
+                .iter()
+                .find(|attribute| attribute.name.name == attribute_name)
+                .map(|attribute| attribute.span)
+                .unwrap_or(ast_field.span)
+        };
+
+        // The constraint names with the attribute they are set on, and its span.
+        let mut constraint_names: Vec<(&str, &str, ast::Span)> = Vec::new();
+
+        if let Some(constraint_name) = &model.id_constraint_name {
+            let span = ast_model
+                .attributes
+                .iter()
+                .find(|attribute| attribute.name.name == "id")
+                .map(|attribute| attribute.span)
+                .unwrap_or(ast_model.span);
+
+            constraint_names.push((constraint_name, "id", span));
+        }
+
+        for field in model.scalar_fields() {
+            if let Some(constraint_name) = &field.id_constraint_name {
+                constraint_names.push((constraint_name, "id", field_attribute_span(&field.name, "id")));
+            }
+
+            if let Some(constraint_name) = &field.unique_constraint_name {
+                constraint_names.push((constraint_name, "unique", field_attribute_span(&field.name, "unique")));
+            }
+        }
+
+        for (idx, index) in model.indices.iter().enumerate() {
+            if let Some(index_name) = index.db_name() {
+                let attribute_name = if index.is_unique() { "unique" } else { "index" };
+                constraint_names.push((index_name, attribute_name, index_attribute_span(ast_model, model, idx)));
+            }
+        }
+
+        for field in model.relation_fields() {
+            if let Some(constraint_name) = &field.relation_info.constraint_name {
+                let span = field_attribute_span(&field.name, "relation");
+
+                if field.relation_info.fields.is_empty() {
+                    errors.push(DatamodelError::new_attribute_validation_error(
+                        "The `map` argument can only be set on the side of the relation that defines `fields`.",
+                        "relation",
+                        span,
+                    ));
+                } else if !connector.supports_named_foreign_keys() {
+                    errors.push(DatamodelError::new_attribute_validation_error(
+                        &format!(
+                            "Naming foreign keys with `map` is not supported by the `{}` provider.",
+                            source.active_provider
+                        ),
+                        "relation",
+                        span,
+                    ));
+                }
+
+                constraint_names.push((constraint_name, "relation", span));
+            }
+        }
+
+        if !connector.supports_named_primary_keys() {
+            for (_, _, span) in constraint_names.iter().filter(|(_, attribute, _)| *attribute == "id") {
+                errors.push(DatamodelError::new_attribute_validation_error(
+                    &format!(
+                        "Naming primary keys with `map` is not supported by the `{}` provider.",
+                        source.active_provider
+                    ),
+                    "id",
+                    *span,
+                ));
+            }
+        }
+
+        if let Some(max_length) = connector.max_identifier_length() {
+            for (constraint_name, attribute_name, span) in constraint_names {
+                if constraint_name.len() > max_length {
+                    errors.push(DatamodelError::new_attribute_validation_error(
+                        &format!(
+                            "The constraint name `{}` is {} characters long, but the `{}` provider only allows names of up to {} characters.",
+                            constraint_name,
+                            constraint_name.len(),
+                            source.active_provider,
+                            max_length
+                        ),
+                        attribute_name,
+                        span,
+                    ));
+                }
+            }
+        }
+
+        errors.ok()
+    }
+
     fn validate_enum_name(&self, ast_enum: &ast::Enum, dml_enum: &dml::Enum) -> Result<(), DatamodelError> {
         let validator = super::reserved_model_names::TypeNameValidator::new();
 
@@ -496,6 +730,31 @@ impl<'a> Validator<'a> {
         Ok(())
     }
 
+    fn validate_views_have_no_relations(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        for field in model.relation_fields() {
+            let points_to_view = datamodel
+                .find_model(&field.relation_info.to)
+                .map(|related_model| related_model.is_view)
+                .unwrap_or(false);
+
+            if model.is_view || points_to_view {
+                return Err(DatamodelError::new_field_validation_error(
+                    "Views are read-only and cannot be part of a relation.",
+                    &model.name,
+                    &field.name,
+                    ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     fn validate_base_fields_for_relation(
         &self,
         _datamodel: &dml::Datamodel,
@@ -522,6 +781,16 @@ impl<'a> Validator<'a> {
                 .cloned()
                 .collect();
 
+            let referenced_composite_type_fields: Vec<String> = rel_info
+                .fields
+                .iter()
+                .filter(|base_field| match model.find_scalar_field(&base_field) {
+                    Some(f) => f.field_type.is_composite_type(),
+                    None => false,
+                })
+                .cloned()
+                .collect();
+
             let at_least_one_underlying_field_is_required = rel_info
                 .fields
                 .iter()
@@ -552,6 +821,13 @@ impl<'a> Validator<'a> {
                     );
             }
 
+            if !referenced_composite_type_fields.is_empty() {
+                errors.push(DatamodelError::new_validation_error(
+                        &format!("The argument fields must not refer to fields of composite types. Composite types are stored inline and cannot be used in relations. But it is referencing the following composite type fields: {}", referenced_composite_type_fields.join(", ")),
+                        ast_field.span)
+                    );
+            }
+
             if at_least_one_underlying_field_is_required && !field.is_required() {
                 errors.push(DatamodelError::new_validation_error(
                         &format!(
@@ -573,6 +849,32 @@ impl<'a> Validator<'a> {
                         ast_field.span)
                     );
             }
+
+            let has_referential_actions = rel_info.on_delete.is_some() || rel_info.on_update.is_some();
+
+            if has_referential_actions && rel_info.fields.is_empty() {
+                errors.push(DatamodelError::new_validation_error(
+                        &format!(
+                            "The relation field `{}` defines referential actions, but does not define `fields`. Referential actions can only be set on the side of the relation that holds the foreign key.",
+                            &field.name,
+                        ),
+                        ast_field.span)
+                    );
+            }
+
+            let uses_set_null = rel_info.on_delete == Some(dml::ReferentialAction::SetNull)
+                || rel_info.on_update == Some(dml::ReferentialAction::SetNull);
+
+            if uses_set_null && at_least_one_underlying_field_is_required {
+                errors.push(DatamodelError::new_validation_error(
+                        &format!(
+                            "The relation field `{}` uses the referential action `SetNull`, but at least one of its scalar fields {} is required. Make the fields optional or choose a different action.",
+                            &field.name,
+                            rel_info.fields.join(", ")
+                        ),
+                        ast_field.span)
+                    );
+            }
         }
 
         if errors.has_errors() {
@@ -609,6 +911,18 @@ impl<'a> Validator<'a> {
                 .cloned()
                 .collect();
 
+            let referenced_composite_type_fields: Vec<String> = rel_info
+                .to_fields
+                .iter()
+                .filter(
+                    |referenced_field| match related_model.find_scalar_field(&referenced_field) {
+                        Some(f) => f.field_type.is_composite_type(),
+                        None => false,
+                    },
+                )
+                .cloned()
+                .collect();
+
             let fields_with_wrong_type: Vec<DatamodelError> = rel_info.fields.iter().zip(rel_info.to_fields.iter())
                     .filter_map(|(base_field, referenced_field)| {
                         let base_field = model.find_field(&base_field)?;
@@ -650,6 +964,15 @@ impl<'a> Validator<'a> {
                     );
             }
 
+            if !referenced_composite_type_fields.is_empty() {
+                errors.push(DatamodelError::new_validation_error(
+                        &format!("The argument `references` must not refer to fields of composite types in the related model `{}`. Composite types are stored inline and cannot be used in relations. But it is referencing the following composite type fields: {}",
+                                 &related_model.name,
+                                 referenced_composite_type_fields.join(", ")),
+                        ast_field.span)
+                    );
+            }
+
             if !rel_info.to_fields.is_empty() && !errors.has_errors() {
                 // when we have other errors already don't push this error additionally
                 let references_unique_criteria = related_model.loose_unique_criterias().iter().any(|criteria| {
@@ -1013,3 +1336,20 @@ impl<'a> Validator<'a> {
         Ok(())
     }
 }
+
+/// The span of the `@@unique` or `@@index` attribute that defines the index at `idx` in the model.
+fn index_attribute_span(ast_model: &ast::Model, model: &dml::Model, idx: usize) -> ast::Span {
+    let index = &model.indices[idx];
+    let attribute_name = if index.is_unique() { "unique" } else { "index" };
+
+    // The indexes of a model are in the order of their attributes, per index type.
+    let position = model.indices[..idx].iter().filter(|i| i.tpe == index.tpe).count();
+
+    ast_model
+        .attributes
+        .iter()
+        .filter(|attribute| attribute.name.name == attribute_name)
+        .nth(position)
+        .map(|attribute| attribute.span)
+        .unwrap_or(ast_model.span)
+}
@@ -0,0 +1,59 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::error::DatamodelError;
+use crate::{ast, dml, CheckConstraint};
+
+/// Prismas builtin `@@check` attribute.
+pub struct ModelLevelCheckAttributeValidator {}
+
+impl AttributeValidator<dml::Model> for ModelLevelCheckAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        "check"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let name = args.arg("name")?.as_str()?;
+        let expression = args.arg("expression")?.as_str()?;
+
+        if expression.trim().is_empty() {
+            return self.new_attribute_validation_error("The check expression must not be empty.", args.span());
+        }
+
+        if obj.check_constraints.iter().any(|check| check.name == name) {
+            return Err(DatamodelError::new_model_validation_error(
+                &format!("The check constraint name `{}` is used multiple times.", name),
+                &obj.name,
+                args.span(),
+            ));
+        }
+
+        obj.check_constraints.push(CheckConstraint { name, expression });
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Attribute>, DatamodelError> {
+        let attributes = model
+            .check_constraints
+            .iter()
+            .map(|check| {
+                ast::Attribute::new(
+                    self.attribute_name(),
+                    vec![
+                        ast::Argument::new_string("name", &check.name),
+                        ast::Argument::new_string("expression", &check.expression),
+                    ],
+                )
+            })
+            .collect();
+
+        Ok(attributes)
+    }
+}
@@ -20,6 +20,13 @@ impl AttributeValidator<dml::Field> for DefaultAttributeValidator {
                 return self.new_attribute_validation_error("Cannot set a default value on list field.", args.span());
             }
 
+            if let dml::FieldType::CompositeType(_) = sf.field_type {
+                return self.new_attribute_validation_error(
+                    "Cannot set a default value on a composite type field.",
+                    args.span(),
+                );
+            }
+
             if let dml::FieldType::Base(scalar_type, _) = sf.field_type {
                 let dv = args
                     .default_arg("value")?
@@ -73,8 +80,12 @@ pub fn lower_default_value(dv: dml::DefaultValue) -> ast::Expression {
     match dv {
         dml::DefaultValue::Single(v) => lower_prisma_value(&v),
         dml::DefaultValue::Expression(e) => {
-            let exprs = e.args.iter().map(lower_prisma_value).collect();
-            ast::Expression::Function(e.name, exprs, ast::Span::empty())
+            let args = e
+                .args
+                .iter()
+                .map(|arg| ast::Argument::new("", lower_prisma_value(arg)))
+                .collect();
+            ast::Expression::Function(e.name, args, ast::Span::empty())
         }
     }
 }
@@ -91,6 +102,10 @@ pub fn lower_prisma_value(pv: &PrismaValue) -> ast::Expression {
         PrismaValue::Null => ast::Expression::ConstantValue("null".to_string(), ast::Span::empty()),
         PrismaValue::Uuid(val) => ast::Expression::StringValue(val.to_string(), ast::Span::empty()),
         PrismaValue::Json(val) => ast::Expression::StringValue(val.to_string(), ast::Span::empty()),
+        PrismaValue::BigInt(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
+        PrismaValue::Bytes(value) => {
+            ast::Expression::StringValue(prisma_value::encode_bytes(value), ast::Span::empty())
+        }
         PrismaValue::List(vec) => ast::Expression::Array(
             vec.iter().map(|pv| lower_prisma_value(pv)).collect(),
             ast::Span::empty(),
@@ -12,8 +12,23 @@ impl AttributeValidator<dml::Field> for IdAttributeValidator {
 
     fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
         if let dml::Field::ScalarField(sf) = obj {
-            if sf.arity == dml::FieldArity::Required {
+            if let dml::FieldType::CompositeType(type_name) = &sf.field_type {
+                self.new_attribute_validation_error(
+                    &format!(
+                        "The field `{}` is of the composite type `{}` and cannot be marked with `@{}`. Composite types are stored inline and cannot be used as id.",
+                        &sf.name,
+                        type_name,
+                        self.attribute_name()
+                    ),
+                    args.span(),
+                )
+            } else if sf.arity == dml::FieldArity::Required {
                 sf.is_id = true;
+
+                if let Some(map) = args.optional_arg("map") {
+                    sf.id_constraint_name = Some(map.as_str()?);
+                }
+
                 Ok(())
             } else {
                 self.new_attribute_validation_error("Fields that are marked as id must be required.", args.span())
@@ -37,7 +52,13 @@ impl AttributeValidator<dml::Field> for IdAttributeValidator {
     ) -> Result<Vec<ast::Attribute>, DatamodelError> {
         if let dml::Field::ScalarField(sf) = field {
             if sf.is_id {
-                return Ok(vec![ast::Attribute::new(self.attribute_name(), Vec::new())]);
+                let mut args = Vec::new();
+
+                if let Some(constraint_name) = &sf.id_constraint_name {
+                    args.push(ast::Argument::new_string("map", constraint_name));
+                }
+
+                return Ok(vec![ast::Attribute::new(self.attribute_name(), args)]);
             }
         }
         Ok(vec![])
@@ -60,6 +81,10 @@ impl AttributeValidator<dml::Model> for ModelLevelIdAttributeValidator {
             .collect();
         obj.id_fields = fields;
 
+        if let Some(map) = args.optional_arg("map") {
+            obj.id_constraint_name = Some(map.as_str()?);
+        }
+
         let undefined_fields: Vec<String> = obj
             .id_fields
             .iter()
@@ -82,6 +107,16 @@ impl AttributeValidator<dml::Model> for ModelLevelIdAttributeValidator {
             .map(|f| f.to_owned())
             .collect();
 
+        let referenced_composite_type_fields: Vec<String> = obj
+            .id_fields
+            .iter()
+            .filter(|field| match obj.find_field(&field) {
+                Some(field) => field.field_type().is_composite_type(),
+                None => false,
+            })
+            .map(|f| f.to_owned())
+            .collect();
+
         if !undefined_fields.is_empty() {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
@@ -104,6 +139,17 @@ impl AttributeValidator<dml::Model> for ModelLevelIdAttributeValidator {
             ));
         }
 
+        if !referenced_composite_type_fields.is_empty() {
+            return Err(DatamodelError::new_model_validation_error(
+                &format!(
+                    "The id definition refers to the composite type fields {}. Composite types are stored inline and cannot be used in id definitions.",
+                    referenced_composite_type_fields.join(", ")
+                ),
+                &obj.name,
+                args.span(),
+            ));
+        }
+
         // the unwrap is safe because we error on undefined fields before
         let fields_that_are_not_required: Vec<_> = obj
             .id_fields
@@ -143,6 +189,10 @@ impl AttributeValidator<dml::Model> for ModelLevelIdAttributeValidator {
                     .collect(),
             ));
 
+            if let Some(constraint_name) = &model.id_constraint_name {
+                args.push(ast::Argument::new_string("map", constraint_name));
+            }
+
             return Ok(vec![ast::Attribute::new(self.attribute_name(), args)]);
         }
 
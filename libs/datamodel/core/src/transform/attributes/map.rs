@@ -14,11 +14,13 @@ impl AttributeValidator<dml::Model> for MapAttributeValidator {
     }
 
     fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
-        internal_validate_and_apply(args, obj)
+        internal_validate_and_apply(args, obj)?;
+        obj.previous_database_name = previous_name(args)?;
+        Ok(())
     }
 
     fn serialize(&self, obj: &dml::Model, _datamodel: &Datamodel) -> Result<Vec<Attribute>, DatamodelError> {
-        internal_serialize(obj)
+        internal_serialize_with_previous_name(obj, obj.previous_database_name.as_deref())
     }
 }
 
@@ -38,11 +40,22 @@ impl AttributeValidator<dml::Field> for MapAttributeValidatorForField {
                 args.span(),
             );
         }
-        internal_validate_and_apply(args, obj)
+        internal_validate_and_apply(args, obj)?;
+
+        if let dml::Field::ScalarField(sf) = obj {
+            sf.previous_database_name = previous_name(args)?;
+        }
+
+        Ok(())
     }
 
     fn serialize(&self, obj: &dml::Field, _datamodel: &Datamodel) -> Result<Vec<Attribute>, DatamodelError> {
-        internal_serialize(obj)
+        match obj {
+            dml::Field::ScalarField(sf) => {
+                internal_serialize_with_previous_name(obj, sf.previous_database_name.as_deref())
+            }
+            dml::Field::RelationField(_) => internal_serialize(obj),
+        }
     }
 }
 
@@ -94,3 +107,30 @@ fn internal_serialize(obj: &dyn WithDatabaseName) -> Result<Vec<ast::Attribute>,
         None => Ok(vec![]),
     }
 }
+
+/// The optional `previous` argument, naming the database object before a rename.
+fn previous_name(args: &mut Arguments) -> Result<Option<String>, DatamodelError> {
+    args.optional_arg("previous")
+        .map(|arg| {
+            arg.as_str().map_err(|err| {
+                DatamodelError::new_attribute_validation_error(&format!("{}", err), ATTRIBUTE_NAME, err.span())
+            })
+        })
+        .transpose()
+}
+
+fn internal_serialize_with_previous_name(
+    obj: &dyn WithDatabaseName,
+    previous_name: Option<&str>,
+) -> Result<Vec<ast::Attribute>, DatamodelError> {
+    let mut attributes = internal_serialize(obj)?;
+
+    if let (Some(attribute), Some(previous_name)) = (attributes.first_mut(), previous_name) {
+        attribute.arguments.push(ast::Argument::new(
+            "previous",
+            ast::Expression::StringValue(String::from(previous_name), Span::empty()),
+        ));
+    }
+
+    Ok(attributes)
+}
@@ -1,9 +1,11 @@
 mod attribute_list_validator;
 mod attribute_validator;
+mod check;
 mod default;
 mod id;
 mod map;
 mod relation;
+mod schema;
 mod unique_and_index;
 mod updated_at;
 
@@ -51,7 +53,9 @@ fn new_builtin_model_attributes() -> AttributeListValidator<dml::Model> {
     validator.add(Box::new(id::ModelLevelIdAttributeValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelUniqueAttributeValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexAttributeValidator {}));
+    validator.add(Box::new(check::ModelLevelCheckAttributeValidator {}));
     validator.add(Box::new(map::MapAttributeValidator {}));
+    validator.add(Box::new(schema::SchemaAttributeValidator {}));
 
     validator
 }
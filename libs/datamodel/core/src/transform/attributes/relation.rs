@@ -31,12 +31,32 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 rf.relation_info.fields = base_fields.as_array().to_literal_vec()?;
             }
 
-            // TODO: bring `onDelete` back once `prisma migrate` is a thing
-            //            if let Ok(on_delete) = args.arg("onDelete") {
-            //                relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
-            //            }
+            if let Ok(on_delete) = args.arg("onDelete") {
+                rf.relation_info.on_delete = Some(self.parse_referential_action(&on_delete)?);
+            }
+
+            if let Ok(on_update) = args.arg("onUpdate") {
+                rf.relation_info.on_update = Some(self.parse_referential_action(&on_update)?);
+            }
+
+            if let Ok(map) = args.arg("map") {
+                rf.relation_info.constraint_name = Some(map.as_str()?);
+            }
 
             Ok(())
+        } else if let dml::Field::ScalarField(dml::ScalarField {
+            field_type: dml::FieldType::CompositeType(type_name),
+            ..
+        }) = &*field
+        {
+            self.new_attribute_validation_error(
+                &format!(
+                    "The field `{}` is of the composite type `{}`. Composite types are stored inline and cannot be used in relations.",
+                    field.name(),
+                    type_name
+                ),
+                args.span(),
+            )
         } else {
             self.new_attribute_validation_error("Invalid field type, not a relation.", args.span())
         }
@@ -99,11 +119,16 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 }
             }
 
-            if relation_info.on_delete != dml::OnDeleteStrategy::None {
-                args.push(ast::Argument::new_constant(
-                    "onDelete",
-                    &relation_info.on_delete.to_string(),
-                ));
+            if let Some(on_delete) = relation_info.on_delete {
+                args.push(ast::Argument::new_constant("onDelete", &on_delete.to_string()));
+            }
+
+            if let Some(on_update) = relation_info.on_update {
+                args.push(ast::Argument::new_constant("onUpdate", &on_update.to_string()));
+            }
+
+            if let Some(constraint_name) = &relation_info.constraint_name {
+                args.push(ast::Argument::new_string("map", constraint_name));
             }
 
             if !args.is_empty() {
@@ -113,3 +138,11 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
         Ok(vec![])
     }
 }
+
+impl RelationAttributeValidator {
+    fn parse_referential_action(&self, value: &ValueValidator) -> Result<dml::ReferentialAction, DatamodelError> {
+        value.as_constant_literal()?.parse().map_err(|message: String| {
+            DatamodelError::new_attribute_validation_error(&message, self.attribute_name(), value.span())
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::ast::{Attribute, Span};
+use crate::error::DatamodelError;
+use crate::{ast, dml, Datamodel};
+
+/// Prismas builtin `@@schema` attribute.
+pub struct SchemaAttributeValidator {}
+
+const ATTRIBUTE_NAME: &str = "schema";
+
+impl AttributeValidator<dml::Model> for SchemaAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        ATTRIBUTE_NAME
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let schema = args.default_arg("name")?.as_str().map_err(|err| {
+            DatamodelError::new_attribute_validation_error(&format!("{}", err), ATTRIBUTE_NAME, err.span())
+        })?;
+        obj.schema = Some(schema);
+        Ok(())
+    }
+
+    fn serialize(&self, obj: &dml::Model, _datamodel: &Datamodel) -> Result<Vec<Attribute>, DatamodelError> {
+        match &obj.schema {
+            Some(schema) => Ok(vec![ast::Attribute::new(
+                ATTRIBUTE_NAME,
+                vec![ast::Argument::new_unnamed(ast::Expression::StringValue(
+                    schema.clone(),
+                    Span::empty(),
+                ))],
+            )]),
+            None => Ok(vec![]),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::{super::helpers::*, AttributeValidator};
 use crate::error::DatamodelError;
-use crate::{ast, dml, IndexDefinition, IndexType};
+use crate::{ast, dml, IndexAlgorithm, IndexDefinition, IndexFieldOptions, IndexType, SortOrder};
 use std::collections::HashMap;
 
 /// Prismas builtin `@unique` attribute.
@@ -45,6 +45,10 @@ impl AttributeValidator<dml::Field> for FieldLevelUniqueAttributeValidator {
                 );
             } else {
                 sf.is_unique = true;
+
+                if let Some(map) = args.optional_arg("map") {
+                    sf.unique_constraint_name = Some(map.as_str()?);
+                }
             }
         }
         Ok(())
@@ -57,7 +61,13 @@ impl AttributeValidator<dml::Field> for FieldLevelUniqueAttributeValidator {
     ) -> Result<Vec<ast::Attribute>, DatamodelError> {
         if let dml::Field::ScalarField(sf) = field {
             if sf.is_unique {
-                return Ok(vec![ast::Attribute::new(self.attribute_name(), vec![])]);
+                let mut args = Vec::new();
+
+                if let Some(constraint_name) = &sf.unique_constraint_name {
+                    args.push(ast::Argument::new_string("map", constraint_name));
+                }
+
+                return Ok(vec![ast::Attribute::new(self.attribute_name(), args)]);
             }
         }
         Ok(vec![])
@@ -134,6 +144,11 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             name: None,
             fields: vec![],
             tpe: index_type,
+            field_options: Default::default(),
+            algorithm: None,
+            predicate: None,
+            include: Vec::new(),
+            database_name: None,
         };
         let name = match args.optional_arg("name") {
             Some(name_arg) => Some(name_arg.as_str()?),
@@ -141,13 +156,49 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
         };
         index_def.name = name;
 
-        let fields = args
-            .default_arg("fields")?
-            .as_array()
-            .iter()
-            .map(|f| f.as_constant_literal().unwrap())
-            .collect();
-        index_def.fields = fields;
+        if let Some(map) = args.optional_arg("map") {
+            index_def.database_name = Some(map.as_str()?);
+        }
+
+        for field in args.default_arg("fields")?.as_array() {
+            let (field_name, field_args) = field.as_constant_literal_with_args()?;
+            let options = parse_index_field_options(&field_args)?;
+
+            if options != IndexFieldOptions::default() {
+                index_def.field_options.insert(field_name.clone(), options);
+            }
+
+            index_def.fields.push(field_name);
+        }
+
+        if let Some(algorithm) = args.optional_arg("type") {
+            let algorithm_name = algorithm.as_constant_literal()?;
+
+            index_def.algorithm = match IndexAlgorithm::from_str(&algorithm_name) {
+                Some(algorithm) => Some(algorithm),
+                None => {
+                    return self.new_attribute_validation_error(
+                        &format!(
+                            "Unknown index type `{}`. Valid types are `Hash`, `Gist`, `Gin` and `Brin`.",
+                            algorithm_name
+                        ),
+                        algorithm.span(),
+                    )
+                }
+            };
+        }
+
+        if let Some(predicate) = args.optional_arg("where") {
+            index_def.predicate = Some(predicate.as_str()?);
+        }
+
+        if let Some(include) = args.optional_arg("include") {
+            index_def.include = include
+                .as_array()
+                .iter()
+                .map(|field| field.as_constant_literal())
+                .collect::<Result<_, _>>()?;
+        }
 
         let duplicated_fields = find_duplicates(&index_def.fields);
         if !duplicated_fields.is_empty() {
@@ -165,6 +216,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
         let undefined_fields: Vec<String> = index_def
             .fields
             .iter()
+            .chain(index_def.include.iter())
             .filter_map(|field| {
                 if obj.find_field(&field).is_none() {
                     Some(field.to_string())
@@ -177,6 +229,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
         let referenced_relation_fields: Vec<String> = index_def
             .fields
             .iter()
+            .chain(index_def.include.iter())
             .filter(|field| obj.find_relation_field(&field).is_some())
             .map(|f| f.to_owned())
             .collect();
@@ -252,12 +305,31 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
                     index_def
                         .fields
                         .iter()
-                        .map(|f| ast::Expression::ConstantValue(f.to_string(), ast::Span::empty()))
+                        .map(|f| render_index_field(f, index_def.field_options.get(f)))
                         .collect(),
                 ));
                 if let Some(name) = &index_def.name {
                     args.push(ast::Argument::new_string("name", &name));
                 }
+                if let Some(database_name) = &index_def.database_name {
+                    args.push(ast::Argument::new_string("map", &database_name));
+                }
+                if let Some(algorithm) = &index_def.algorithm {
+                    args.push(ast::Argument::new_constant("type", algorithm.as_str()));
+                }
+                if let Some(predicate) = &index_def.predicate {
+                    args.push(ast::Argument::new_string("where", &predicate));
+                }
+                if !index_def.include.is_empty() {
+                    args.push(ast::Argument::new_array(
+                        "include",
+                        index_def
+                            .include
+                            .iter()
+                            .map(|f| ast::Expression::ConstantValue(f.to_string(), ast::Span::empty()))
+                            .collect(),
+                    ));
+                }
 
                 ast::Attribute::new(self.attribute_name(), args)
             })
@@ -267,6 +339,70 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
     }
 }
 
+/// Parses the arguments of a field in an index definition, as in `title(length: 10, sort: Desc)`.
+fn parse_index_field_options(args: &[ast::Argument]) -> Result<IndexFieldOptions, DatamodelError> {
+    let mut options = IndexFieldOptions::default();
+
+    for arg in args {
+        let value = ValueValidator::new(&arg.value);
+
+        match arg.name.name.as_str() {
+            "sort" => {
+                let sort_order = value.as_constant_literal()?;
+
+                options.sort_order = SortOrder::from_str(&sort_order).ok_or_else(|| {
+                    DatamodelError::new_validation_error(
+                        &format!(
+                            "Unknown sort order `{}`. Valid sort orders are `Asc` and `Desc`.",
+                            sort_order
+                        ),
+                        value.span(),
+                    )
+                })?;
+            }
+            "length" => {
+                let length = value.as_int()?;
+
+                if length <= 0 {
+                    return Err(DatamodelError::new_validation_error(
+                        "The index length must be a positive number.",
+                        value.span(),
+                    ));
+                }
+
+                options.length = Some(length as u32);
+            }
+            name => return Err(DatamodelError::new_unused_argument_error(name, arg.span)),
+        }
+    }
+
+    Ok(options)
+}
+
+/// Renders a field in an index definition, with its arguments if it has options.
+fn render_index_field(field_name: &str, options: Option<&IndexFieldOptions>) -> ast::Expression {
+    let mut args = Vec::new();
+
+    if let Some(options) = options {
+        if let Some(length) = options.length {
+            args.push(ast::Argument::new(
+                "length",
+                ast::Expression::NumericValue(length.to_string(), ast::Span::empty()),
+            ));
+        }
+
+        if options.sort_order != SortOrder::Asc {
+            args.push(ast::Argument::new_constant("sort", options.sort_order.as_str()));
+        }
+    }
+
+    if args.is_empty() {
+        ast::Expression::ConstantValue(field_name.to_string(), ast::Span::empty())
+    } else {
+        ast::Expression::Function(field_name.to_string(), args, ast::Span::empty())
+    }
+}
+
 fn attribute_name(index_type: dml::IndexType) -> &'static str {
     if index_type == dml::IndexType::Unique {
         "unique"
@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::configuration::Datasource;
+use crate::configuration::{Datasource, StringFromEnvVar};
 
 pub struct DatasourceSerializer {}
 
@@ -31,6 +31,23 @@ impl DatasourceSerializer {
             }
         }
 
+        match &source.shadow_database_url {
+            Some(StringFromEnvVar {
+                from_env_var: Some(env_var),
+                ..
+            }) => {
+                let values = vec![ast::Expression::StringValue(env_var.to_string(), ast::Span::empty())];
+                arguments.push(ast::Argument::new_function("shadowDatabaseUrl", "env", values));
+            }
+            Some(shadow_database_url) => {
+                arguments.push(ast::Argument::new_string(
+                    "shadowDatabaseUrl",
+                    &shadow_database_url.value,
+                ));
+            }
+            None => (),
+        }
+
         if !&source.preview_features.is_empty() {
             let features: Vec<ast::Expression> = source
                 .preview_features
@@ -41,6 +58,16 @@ impl DatasourceSerializer {
             arguments.push(ast::Argument::new_array("previewFeatures", features));
         }
 
+        if !source.schemas.is_empty() {
+            let schemas: Vec<ast::Expression> = source
+                .schemas
+                .iter()
+                .map(|s| ast::Expression::StringValue(s.to_owned(), ast::Span::empty()))
+                .collect();
+
+            arguments.push(ast::Argument::new_array("schemas", schemas));
+        }
+
         ast::SourceConfig {
             name: ast::Identifier::new(&source.name),
             properties: arguments,
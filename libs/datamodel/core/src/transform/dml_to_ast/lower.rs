@@ -32,6 +32,13 @@ impl<'a> LowerDmlToAst<'a> {
             }
         }
 
+        for composite_type in datamodel.composite_types() {
+            match self.lower_composite_type(composite_type, datamodel) {
+                Ok(res) => tops.push(ast::Top::CompositeType(res)),
+                Err(mut err) => errors.append(&mut err),
+            }
+        }
+
         for enm in datamodel.enums() {
             match self.lower_enum(enm, datamodel) {
                 Ok(res) => tops.push(ast::Top::Enum(res)),
@@ -39,7 +46,7 @@ impl<'a> LowerDmlToAst<'a> {
             }
         }
 
-        Ok(ast::SchemaAst { tops })
+        Ok(ast::SchemaAst { tops, imports: vec![] })
     }
 
     pub fn lower_model(&self, model: &dml::Model, datamodel: &dml::Datamodel) -> Result<ast::Model, ErrorCollection> {
@@ -64,6 +71,34 @@ impl<'a> LowerDmlToAst<'a> {
             documentation: model.documentation.clone().map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
             commented_out: model.is_commented_out,
+            is_view: model.is_view,
+        })
+    }
+
+    fn lower_composite_type(
+        &self,
+        composite_type: &dml::CompositeType,
+        datamodel: &dml::Datamodel,
+    ) -> Result<ast::CompositeType, ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+        let mut fields: Vec<ast::Field> = Vec::new();
+
+        for field in composite_type.fields() {
+            match self.lower_field(&dml::Field::ScalarField(field.clone()), datamodel) {
+                Ok(ast_field) => fields.push(ast_field),
+                Err(mut err) => errors.append(&mut err),
+            };
+        }
+
+        if errors.has_errors() {
+            return Err(errors);
+        }
+
+        Ok(ast::CompositeType {
+            name: ast::Identifier::new(&composite_type.name),
+            fields,
+            documentation: composite_type.documentation.clone().map(|text| ast::Comment { text }),
+            span: ast::Span::empty(),
         })
     }
 
@@ -134,6 +169,7 @@ impl<'a> LowerDmlToAst<'a> {
                 ast::Identifier::new(&custom_type_name.as_ref().unwrap_or(&tpe.to_string()))
             }
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe.to_string()),
+            dml::FieldType::CompositeType(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Unsupported(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Relation(rel) => ast::Identifier::new(&rel.to),
             dml::FieldType::NativeType(prisma_tpe, _native_tpe) => ast::Identifier::new(&prisma_tpe.to_string()),
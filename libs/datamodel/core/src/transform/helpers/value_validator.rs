@@ -60,6 +60,8 @@ impl ValueValidator {
             ScalarType::String => self.as_str().map(PrismaValue::String),
             ScalarType::Json => self.as_str().map(PrismaValue::String),
             ScalarType::Decimal => self.as_float().map(PrismaValue::Float),
+            ScalarType::BigInt => self.as_int().map(PrismaValue::BigInt),
+            ScalarType::Bytes => self.as_bytes().map(PrismaValue::Bytes),
             _ => todo!(),
         }
     }
@@ -121,6 +123,16 @@ impl ValueValidator {
         }
     }
 
+    /// Tries to convert the wrapped value to Prisma Bytes. Bytes are given as base64 encoded strings.
+    pub fn as_bytes(&self) -> Result<Vec<u8>, DatamodelError> {
+        match &self.value {
+            ast::Expression::StringValue(value, _) => {
+                self.wrap_error_from_result(prisma_value::decode_bytes(value), "base64 encoded bytes")
+            }
+            _ => Err(self.construct_type_mismatch_error("String")),
+        }
+    }
+
     /// Tries to convert the wrapped value to a Prisma Boolean.
     pub fn as_bool(&self) -> Result<bool, DatamodelError> {
         match &self.value {
@@ -153,6 +165,15 @@ impl ValueValidator {
         }
     }
 
+    /// Unwraps the wrapped value as a constant literal that may carry arguments, like
+    /// `title(length: 10)`. A plain `title` has no arguments.
+    pub fn as_constant_literal_with_args(&self) -> Result<(String, Vec<ast::Argument>), DatamodelError> {
+        match &self.value {
+            ast::Expression::Function(name, args, _) => Ok((name.to_string(), args.clone())),
+            _ => self.as_constant_literal().map(|name| (name, Vec::new())),
+        }
+    }
+
     /// Unwraps the wrapped value as a constant literal..
     pub fn as_array(&self) -> Vec<ValueValidator> {
         match &self.value {
@@ -2,8 +2,8 @@
 //! The most prominent functionality is the pain free navigation of relations.
 use crate::{
     dml::{
-        Datamodel, DefaultValue, Enum, FieldArity, FieldType, IndexDefinition, Model, ScalarField, ScalarType,
-        WithDatabaseName,
+        CheckConstraint, CompositeType, Datamodel, DefaultValue, Enum, FieldArity, FieldType, IndexDefinition, Model,
+        ReferentialAction, ScalarField, ScalarType, WithDatabaseName,
     },
     RelationField,
 };
@@ -69,6 +69,19 @@ impl<'a> ModelWalker<'a> {
         self.model.final_database_name()
     }
 
+    /// The database name of the model before it was renamed, if any.
+    pub fn previous_db_name(&self) -> Option<&'a str> {
+        self.model.previous_database_name.as_deref()
+    }
+
+    pub fn is_view(&self) -> bool {
+        self.model.is_view
+    }
+
+    pub fn schema(&self) -> Option<&'a str> {
+        self.model.schema.as_deref()
+    }
+
     pub fn into_relation_fields(self) -> impl Iterator<Item = RelationFieldWalker<'a>> + 'a {
         self.model.relation_fields().map(move |field| RelationFieldWalker {
             datamodel: self.datamodel,
@@ -105,6 +118,15 @@ impl<'a> ModelWalker<'a> {
         self.model.indices.iter()
     }
 
+    pub fn check_constraints<'b>(&'b self) -> impl Iterator<Item = &'a CheckConstraint> + 'b {
+        self.model.check_constraints.iter()
+    }
+
+    /// The database name of the primary key constraint, if set via `map:` on `@id` or `@@id`.
+    pub fn primary_key_constraint_name(&self) -> Option<&'a str> {
+        self.model.primary_key_constraint_name()
+    }
+
     pub fn name(&self) -> &'a str {
         &self.model.name
     }
@@ -157,6 +179,11 @@ impl<'a> ScalarFieldWalker<'a> {
         self.field.final_database_name()
     }
 
+    /// The database name of the field before it was renamed, if any.
+    pub fn previous_db_name(&self) -> Option<&'a str> {
+        self.field.previous_database_name.as_deref()
+    }
+
     pub fn default_value(&self) -> Option<&'a DefaultValue> {
         self.field.default_value.as_ref()
     }
@@ -169,6 +196,9 @@ impl<'a> ScalarFieldWalker<'a> {
             }),
             FieldType::Base(scalar_type, _) => TypeWalker::Base(*scalar_type),
             FieldType::NativeType(scalar_type, native_type) => TypeWalker::NativeType(*scalar_type, native_type),
+            FieldType::CompositeType(name) => {
+                TypeWalker::CompositeType(self.datamodel.find_composite_type(name).unwrap())
+            }
             _ => TypeWalker::Other,
         }
     }
@@ -185,6 +215,11 @@ impl<'a> ScalarFieldWalker<'a> {
         self.field.is_unique
     }
 
+    /// The database name of the unique constraint, if set via `@unique(map: ...)`.
+    pub fn unique_constraint_name(&self) -> Option<&'a str> {
+        self.field.unique_constraint_name.as_deref()
+    }
+
     pub fn model(&self) -> ModelWalker<'a> {
         ModelWalker {
             model: self.model,
@@ -202,6 +237,7 @@ pub enum TypeWalker<'a> {
     Enum(EnumWalker<'a>),
     Base(ScalarType),
     NativeType(ScalarType, &'a NativeTypeInstance),
+    CompositeType(&'a CompositeType),
     Other,
 }
 
@@ -290,10 +326,30 @@ impl<'a> RelationFieldWalker<'a> {
         self.field.relation_info.name.as_ref()
     }
 
+    /// The explicit `onDelete` referential action of the relation field, if any.
+    pub fn on_delete_action(&self) -> Option<ReferentialAction> {
+        self.field.relation_info.on_delete
+    }
+
+    /// The explicit `onUpdate` referential action of the relation field, if any.
+    pub fn on_update_action(&self) -> Option<ReferentialAction> {
+        self.field.relation_info.on_update
+    }
+
+    /// The database name of the foreign key constraint, if set via `@relation(map: ...)`.
+    pub fn foreign_key_constraint_name(&self) -> Option<&'a str> {
+        self.field.relation_info.constraint_name.as_deref()
+    }
+
     pub fn referenced_table_name(&self) -> &'a str {
         self.referenced_model().final_database_name()
     }
 
+    /// The schema of the referenced model, if it is assigned to one with `@@schema`.
+    pub fn referenced_schema(&self) -> Option<&'a str> {
+        self.referenced_model().schema.as_deref()
+    }
+
     fn referenced_model(&self) -> &'a Model {
         self.datamodel
             .find_model(&self.field.relation_info.to)
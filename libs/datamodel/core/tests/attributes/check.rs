@@ -0,0 +1,103 @@
+use datamodel::{render_datamodel_to_string, CheckConstraint};
+
+use crate::common::*;
+
+#[test]
+fn basic_check_constraint_must_work() {
+    let dml = r#"
+    model Product {
+        id    Int @id
+        price Int
+
+        @@check(name: "price_positive", expression: "price > 0")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let product_model = schema.assert_has_model("Product");
+
+    assert_eq!(
+        product_model.check_constraints,
+        vec![CheckConstraint {
+            name: "price_positive".to_string(),
+            expression: "price > 0".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn multiple_check_constraints_must_work() {
+    let dml = r#"
+    model Product {
+        id       Int @id
+        price    Int
+        discount Int
+
+        @@check(name: "price_positive", expression: "price > 0")
+        @@check(name: "discount_below_price", expression: "discount < price")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let product_model = schema.assert_has_model("Product");
+    let names: Vec<&str> = product_model
+        .check_constraints
+        .iter()
+        .map(|check| check.name.as_str())
+        .collect();
+
+    assert_eq!(names, vec!["price_positive", "discount_below_price"]);
+}
+
+#[test]
+fn check_constraint_names_must_be_unique_per_model() {
+    let dml = r#"
+    model Product {
+        id    Int @id
+        price Int
+
+        @@check(name: "price_positive", expression: "price > 0")
+        @@check(name: "price_positive", expression: "price >= 1")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    assert!(errors
+        .to_iter()
+        .any(|err| err.description() == "The check constraint name `price_positive` is used multiple times."));
+}
+
+#[test]
+fn check_constraints_must_have_an_expression() {
+    let dml = r#"
+    model Product {
+        id Int @id
+
+        @@check(name: "price_positive", expression: " ")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    assert!(errors
+        .to_iter()
+        .any(|err| err.description().contains("The check expression must not be empty.")));
+}
+
+#[test]
+fn check_attributes_must_serialize_to_valid_dml() {
+    let dml = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check(name: "price_positive", expression: "price > 0")
+        }
+    "#;
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    assert!(rendered.contains(r#"@@check(name: "price_positive", expression: "price > 0")"#));
+    assert_eq!(parse(&rendered), schema);
+}
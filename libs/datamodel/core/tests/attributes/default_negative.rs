@@ -77,6 +77,24 @@ fn must_error_if_default_value_parser_error() {
     ));
 }
 
+#[test]
+fn must_error_if_bytes_default_value_is_not_base64() {
+    let dml = r#"
+    model Model {
+        id Int @id
+        bytes Bytes @default("not base64!")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Expected a base64 encoded bytes value, but failed while parsing \"not base64!\": Could not convert from `base64 encoded bytes` to `PrismaValue::Bytes`.",
+        "default",
+        Span::new(67, 80),
+    ));
+}
+
 #[test]
 fn must_error_if_unknown_function_is_used() {
     let dml = r#"
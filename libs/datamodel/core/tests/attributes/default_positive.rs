@@ -15,6 +15,8 @@ fn should_set_default_for_all_scalar_types() {
         string String @default("String")
         boolean Boolean @default(false)
         dateTime DateTime @default("2019-06-17T14:20:57Z")
+        bigInt BigInt @default(9007199254740993)
+        bytes Bytes @default("aGVsbG8=")
     }
     "#;
 
@@ -45,6 +47,14 @@ fn should_set_default_for_all_scalar_types() {
         .assert_default_value(DefaultValue::Single(PrismaValue::DateTime(
             "2019-06-17T14:20:57Z".parse::<DateTime<Utc>>().unwrap(),
         )));
+    user_model
+        .assert_has_scalar_field("bigInt")
+        .assert_base_type(&ScalarType::BigInt)
+        .assert_default_value(DefaultValue::Single(PrismaValue::BigInt(9007199254740993)));
+    user_model
+        .assert_has_scalar_field("bytes")
+        .assert_base_type(&ScalarType::Bytes)
+        .assert_default_value(DefaultValue::Single(PrismaValue::Bytes(b"hello".to_vec())));
 }
 
 #[test]
@@ -1,4 +1,8 @@
-use datamodel::{ast::Span, error::*, render_datamodel_to_string, IndexDefinition, IndexType};
+use datamodel::{
+    ast::Span, error::*, render_datamodel_to_string, IndexAlgorithm, IndexDefinition, IndexFieldOptions, IndexType,
+    SortOrder,
+};
+use std::collections::BTreeMap;
 
 use crate::common::*;
 
@@ -20,6 +24,11 @@ fn basic_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        field_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
     });
 }
 
@@ -45,6 +54,11 @@ fn indexes_on_enum_fields_must_work() {
         name: None,
         fields: vec!["role".to_string()],
         tpe: IndexType::Normal,
+        field_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
     });
 }
 
@@ -91,6 +105,11 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        field_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
     });
 }
 
@@ -126,12 +145,22 @@ fn multiple_indexes_with_same_name_are_supported_by_mysql() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["id".to_string()],
         tpe: IndexType::Normal,
+        field_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
     });
 
     post_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["id".to_string()],
         tpe: IndexType::Normal,
+        field_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
     });
 }
 
@@ -222,12 +251,22 @@ fn multiple_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        field_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        field_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
     });
 }
 
@@ -265,3 +304,146 @@ fn index_attributes_must_serialize_to_valid_dml() {
 
     assert!(datamodel::parse_datamodel(&render_datamodel_to_string(&schema).unwrap()).is_ok());
 }
+
+#[test]
+fn index_field_options_must_work() {
+    let dml = r#"
+    model Post {
+        id        Int      @id
+        title     String
+        createdAt DateTime
+
+        @@index([title(length: 10), createdAt(sort: Desc)])
+    }
+    "#;
+
+    let schema = parse(&format!("{}\n{}", MYSQL_SOURCE, dml));
+    let post_model = schema.assert_has_model("Post");
+
+    let mut field_options = BTreeMap::new();
+    field_options.insert(
+        "title".to_string(),
+        IndexFieldOptions {
+            sort_order: SortOrder::Asc,
+            length: Some(10),
+        },
+    );
+    field_options.insert(
+        "createdAt".to_string(),
+        IndexFieldOptions {
+            sort_order: SortOrder::Desc,
+            length: None,
+        },
+    );
+
+    post_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["title".to_string(), "createdAt".to_string()],
+        tpe: IndexType::Normal,
+        field_options,
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
+    });
+}
+
+#[test]
+fn postgres_index_type_predicate_and_include_must_work() {
+    let dml = r#"
+    model Post {
+        id        Int       @id
+        tags      String[]
+        title     String
+        deletedAt DateTime?
+
+        @@index([tags], type: Gin)
+        @@index([title], where: "deleted_at IS NULL", include: [id])
+    }
+    "#;
+
+    let schema = parse(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    let post_model = schema.assert_has_model("Post");
+
+    post_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["tags".to_string()],
+        tpe: IndexType::Normal,
+        field_options: Default::default(),
+        algorithm: Some(IndexAlgorithm::Gin),
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
+    });
+    post_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["title".to_string()],
+        tpe: IndexType::Normal,
+        field_options: Default::default(),
+        algorithm: None,
+        predicate: Some("deleted_at IS NULL".to_string()),
+        include: vec!["id".to_string()],
+        database_name: None,
+    });
+}
+
+#[test]
+fn unknown_sort_orders_must_error() {
+    let dml = r#"
+    model Post {
+        id    Int    @id
+        title String
+
+        @@index([title(sort: Sideways)])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    assert!(errors.to_iter().any(|err| err
+        .description()
+        .contains("Unknown sort order `Sideways`. Valid sort orders are `Asc` and `Desc`.")));
+}
+
+#[test]
+fn index_options_must_be_supported_by_the_provider() {
+    let dml = r#"
+    model Post {
+        id    Int    @id
+        title String
+
+        @@index([title(length: 10)], type: Hash)
+    }
+    "#;
+
+    let errors = parse_error(&format!("{}\n{}", SQLITE_SOURCE, dml));
+
+    assert!(errors.to_iter().any(|err| err
+        .description()
+        .contains("The `length` argument is not supported by the `sqlite` provider.")));
+    assert!(errors.to_iter().any(|err| err
+        .description()
+        .contains("The `type` argument is not supported by the `sqlite` provider.")));
+}
+
+#[test]
+fn index_options_must_serialize_to_valid_dml() {
+    let dml = r#"
+        model Post {
+            id        Int      @id
+            title     String
+            createdAt DateTime
+
+            @@index([title, createdAt(sort: Desc)], type: Brin, where: "id > 0", include: [id])
+        }
+    "#;
+    let schema = parse(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    assert!(
+        rendered.contains(r#"@@index([title, createdAt(sort: Desc)], type: Brin, where: "id > 0", include: [id])"#),
+        "{}",
+        rendered
+    );
+    assert_eq!(parse(&format!("{}\n{}", POSTGRES_SOURCE, rendered)), schema);
+}
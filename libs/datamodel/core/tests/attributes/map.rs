@@ -32,6 +32,46 @@ fn map_attribute() {
         .assert_with_db_name("post_text");
 }
 
+#[test]
+fn map_attribute_with_a_previous_name() {
+    let dml = r#"
+    model User {
+        id Int @id
+        firstName String @map("first_name", previous: "firstname")
+
+        @@map("user", previous: "users")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User").assert_with_db_name("user");
+    assert_eq!(user_model.previous_database_name.as_deref(), Some("users"));
+
+    let field = user_model
+        .assert_has_scalar_field("firstName")
+        .assert_with_db_name("first_name");
+    assert_eq!(field.previous_database_name.as_deref(), Some("firstname"));
+
+    let rendered = datamodel::render_datamodel_to_string(&schema).unwrap();
+    assert!(rendered.contains(r#"@map("first_name", previous: "firstname")"#));
+    assert!(rendered.contains(r#"@@map("user", previous: "users")"#));
+}
+
+#[test]
+fn map_must_error_for_a_previous_name_on_enums() {
+    let dml = r#"
+    enum Color {
+        Red
+        Green
+
+        @@map("colors", previous: "colours")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is(DatamodelError::new_unused_argument_error("previous", Span::new(69, 88)));
+}
+
 #[test]
 fn map_must_error_for_relation_fields() {
     let dml = r#"
@@ -53,3 +93,114 @@ fn map_must_error_for_relation_fields() {
         Span::new(128, 146),
     ));
 }
+
+#[test]
+fn map_arguments_on_constraints_must_work() {
+    let dml = r#"
+    model User {
+        id    Int    @id(map: "users_primary")
+        email String @unique(map: "users_email_key")
+        posts Post[]
+    }
+
+    model Post {
+        authorId Int
+        slug     String
+        author   User   @relation(fields: [authorId], references: [id], map: "posts_author_fk")
+
+        @@id([authorId, slug], map: "posts_primary")
+        @@index([slug], map: "posts_slug_idx")
+    }
+    "#;
+
+    let schema = parse(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    let user_model = schema.assert_has_model("User");
+    let post_model = schema.assert_has_model("Post");
+
+    let id = user_model.assert_has_scalar_field("id");
+    assert_eq!(id.id_constraint_name.as_deref(), Some("users_primary"));
+    assert_eq!(user_model.primary_key_constraint_name(), Some("users_primary"));
+
+    let email = user_model.assert_has_scalar_field("email");
+    assert_eq!(email.unique_constraint_name.as_deref(), Some("users_email_key"));
+
+    let author = post_model.assert_has_relation_field("author");
+    assert_eq!(author.relation_info.constraint_name.as_deref(), Some("posts_author_fk"));
+
+    assert_eq!(post_model.primary_key_constraint_name(), Some("posts_primary"));
+    assert_eq!(post_model.indices[0].db_name(), Some("posts_slug_idx"));
+
+    let rendered = datamodel::render_datamodel_to_string(&schema).unwrap();
+    assert!(rendered.contains(r#"@id(map: "users_primary")"#), "{}", rendered);
+    assert!(rendered.contains(r#"@unique(map: "users_email_key")"#), "{}", rendered);
+    assert!(rendered.contains(r#"map: "posts_author_fk")"#), "{}", rendered);
+    assert!(
+        rendered.contains(r#"@@id([authorId, slug], map: "posts_primary")"#),
+        "{}",
+        rendered
+    );
+    assert!(
+        rendered.contains(r#"@@index([slug], map: "posts_slug_idx")"#),
+        "{}",
+        rendered
+    );
+    assert_eq!(parse(&format!("{}\n{}", POSTGRES_SOURCE, rendered)), schema);
+}
+
+#[test]
+fn map_arguments_on_constraints_must_respect_the_identifier_length_limit() {
+    let name = "a".repeat(64);
+    let dml = format!(
+        r#"
+    model User {{
+        id    Int    @id
+        email String @unique(map: "{}")
+    }}
+    "#,
+        name
+    );
+
+    let errors = parse_error(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+
+    assert!(errors.to_iter().any(|err| err.description().contains(&format!(
+        "The constraint name `{}` is 64 characters long, but the `postgres` provider only allows names of up to 63 characters.",
+        name
+    ))));
+}
+
+#[test]
+fn map_arguments_on_primary_keys_must_be_supported_by_the_provider() {
+    let dml = r#"
+    model User {
+        id Int @id(map: "users_primary")
+    }
+    "#;
+
+    let errors = parse_error(&format!("{}\n{}", MYSQL_SOURCE, dml));
+
+    assert!(errors.to_iter().any(|err| err
+        .description()
+        .contains("Naming primary keys with `map` is not supported by the `mysql` provider.")));
+}
+
+#[test]
+fn map_arguments_on_relations_must_be_on_the_side_defining_fields() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(map: "posts_author_fk")
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+
+    assert!(errors.to_iter().any(|err| err
+        .description()
+        .contains("The `map` argument can only be set on the side of the relation that defines `fields`.")));
+}
@@ -1,4 +1,5 @@
 pub mod builtin_attributes;
+pub mod check;
 pub mod default_negative;
 pub mod default_positive;
 pub mod id_negative;
@@ -11,6 +12,8 @@ pub mod relations_legacy;
 pub mod relations_negative;
 pub mod relations_new;
 pub mod relations_positive;
+pub mod relations_referential_actions;
+pub mod schema;
 pub mod unique;
 pub mod unique_criteria;
 pub mod updated_at_negative;
@@ -85,7 +85,7 @@ fn settings_must_be_deteced() {
         .assert_relation_to_fields(&["id"])
         .assert_arity(&dml::FieldArity::Optional);
     // TODO: bring `onDelete` back once `prisma migrate` is a thing
-    //        .assert_relation_delete_strategy(dml::OnDeleteStrategy::Cascade);
+    //        .assert_relation_delete_strategy(dml::ReferentialAction::Cascade);
 }
 
 #[test]
@@ -0,0 +1,132 @@
+use crate::common::*;
+use datamodel::dml;
+
+#[test]
+fn referential_actions_are_parsed() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id], onDelete: Restrict, onUpdate: NoAction)
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema
+        .assert_has_model("Post")
+        .assert_has_relation_field("user")
+        .assert_relation_delete_strategy(dml::ReferentialAction::Restrict)
+        .assert_relation_update_strategy(dml::ReferentialAction::NoAction);
+}
+
+#[test]
+fn referential_actions_are_not_set_by_default() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let field = schema.assert_has_model("Post").assert_has_relation_field("user");
+
+    assert_eq!(field.relation_info.on_delete, None);
+    assert_eq!(field.relation_info.on_update, None);
+}
+
+#[test]
+fn set_null_is_allowed_on_optional_relations() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int   @id
+        userId Int?
+        user   User? @relation(fields: [userId], references: [id], onDelete: SetNull, onUpdate: Cascade)
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema
+        .assert_has_model("Post")
+        .assert_has_relation_field("user")
+        .assert_relation_delete_strategy(dml::ReferentialAction::SetNull)
+        .assert_relation_update_strategy(dml::ReferentialAction::Cascade);
+}
+
+#[test]
+fn invalid_referential_actions_must_error() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id], onDelete: Explode)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": Invalid referential action: `Explode`. Allowed values: (`Cascade`, `Restrict`, `NoAction`, `SetNull`, `SetDefault`)",
+    );
+}
+
+#[test]
+fn set_null_on_required_fields_must_error() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id], onDelete: SetNull)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error validating: The relation field `user` uses the referential action `SetNull`, but at least one of its scalar fields userId is required. Make the fields optional or choose a different action.",
+    );
+}
+
+#[test]
+fn referential_actions_on_the_side_without_fields_must_error() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(onDelete: Cascade)
+    }
+
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error validating: The relation field `posts` defines referential actions, but does not define `fields`. Referential actions can only be set on the side of the relation that holds the foreign key.",
+    );
+}
@@ -0,0 +1,131 @@
+use crate::common::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn schema_attribute_assigns_models_to_schemas() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost"
+        schemas = ["auth", "billing"]
+    }
+
+    model User {
+        id Int @id
+
+        @@schema("auth")
+    }
+
+    model Invoice {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+
+        @@schema(name: "billing")
+    }
+    "#;
+
+    let config = datamodel::parse_configuration(dml).unwrap();
+    assert_eq!(config.datasources[0].schemas, vec!["auth", "billing"]);
+
+    let schema = parse(dml);
+    assert_eq!(schema.assert_has_model("User").schema.as_deref(), Some("auth"));
+    assert_eq!(schema.assert_has_model("Invoice").schema.as_deref(), Some("billing"));
+}
+
+#[test]
+fn schema_attribute_must_reference_a_schema_of_the_datasource() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost"
+        schemas = ["auth"]
+    }
+
+    model User {
+        id Int @id
+
+        @@schema("billing")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@schema\": The schema `billing` is not listed in the `schemas` property of the datasource.",
+    );
+}
+
+#[test]
+fn schema_attribute_must_not_be_used_without_schemas_on_the_datasource() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost"
+    }
+
+    model User {
+        id Int @id
+
+        @@schema("auth")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@schema\": The schema `auth` is not listed in the `schemas` property of the datasource.",
+    );
+}
+
+#[test]
+fn models_must_have_a_schema_attribute_when_the_datasource_defines_schemas() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost"
+        schemas = ["auth"]
+    }
+
+    model User {
+        id Int @id
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error validating model \"User\": This model is missing an `@@schema` attribute. Every model must be assigned to one of the datasource's schemas when `schemas` is set.",
+    );
+}
+
+#[test]
+fn schemas_are_not_supported_on_mysql() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost"
+        schemas = ["auth"]
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error validating datasource `db`: The `schemas` property is not supported by the provider `mysql`.",
+    );
+}
+
+#[test]
+fn schemas_and_schema_attributes_roundtrip() {
+    let input = r#"datasource db {
+  provider = "postgresql"
+  url      = "postgresql://localhost"
+  schemas  = ["auth", "billing"]
+}
+
+model User {
+  id Int @id
+
+  @@schema("auth")
+}
+"#;
+
+    let config = datamodel::parse_configuration(input).unwrap();
+    let dml = parse(input);
+    let rendered = datamodel::render_datamodel_and_config_to_string(&dml, &config).unwrap();
+
+    assert_eq!(rendered, input);
+}
@@ -22,6 +22,11 @@ fn basic_unique_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        field_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
     });
 }
 
@@ -163,6 +168,11 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        field_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
     });
 }
 
@@ -186,12 +196,22 @@ fn multiple_unique_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        field_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        field_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
     });
 }
 
@@ -217,6 +237,11 @@ fn multi_field_unique_indexes_on_enum_fields_must_work() {
         name: None,
         fields: vec!["role".to_string()],
         tpe: IndexType::Unique,
+        field_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
+        database_name: None,
     });
 }
 
@@ -12,6 +12,8 @@ fn parse_scalar_types() {
         age          Int
         isPro        Boolean
         averageGrade Float
+        followers    BigInt
+        avatar       Bytes
     }
     "#;
 
@@ -29,6 +31,12 @@ fn parse_scalar_types() {
     user_model
         .assert_has_scalar_field("averageGrade")
         .assert_base_type(&ScalarType::Float);
+    user_model
+        .assert_has_scalar_field("followers")
+        .assert_base_type(&ScalarType::BigInt);
+    user_model
+        .assert_has_scalar_field("avatar")
+        .assert_base_type(&ScalarType::Bytes);
 }
 
 #[test]
@@ -169,13 +177,11 @@ fn json_type_must_work_for_some_connectors() {
         Span::new(50, 60),
     ));
 
-    // SQLite does not support it
-    parse_error(&format!("{}\n{}", SQLITE_SOURCE, dml)).assert_is(DatamodelError::new_field_validation_error(
-        "Field `json` in model `User` can\'t be of type Json. The current connector does not support the Json type.",
-        "User",
-        "json",
-        Span::new(139, 149),
-    ));
+    // SQLite does support it
+    parse(&format!("{}\n{}", SQLITE_SOURCE, dml))
+        .assert_has_model("User")
+        .assert_has_scalar_field("json")
+        .assert_base_type(&ScalarType::Json);
 
     // Postgres does support it
     parse(&format!("{}\n{}", POSTGRES_SOURCE, dml))
@@ -0,0 +1,250 @@
+use crate::common::*;
+use datamodel::{dml, error::ErrorCollection, render_datamodel_to_string, ScalarType};
+
+fn assert_error_contains(errors: &ErrorCollection, message: &str) {
+    assert!(
+        errors.to_iter().any(|err| err.description().contains(message)),
+        "Expected an error containing `{}`. Errors are: {:?}",
+        message,
+        errors
+    );
+}
+
+#[test]
+fn parse_basic_composite_type() {
+    let dml = r#"
+    type Address {
+        street  String
+        zipCode String?
+        tags    String[]
+    }
+
+    model User {
+        id Int @id
+    }
+    "#;
+
+    let schema = parse(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    let address = schema.find_composite_type("Address").unwrap();
+
+    assert_eq!(address.fields().count(), 3);
+    address
+        .find_field("street")
+        .unwrap()
+        .assert_base_type(&ScalarType::String)
+        .assert_arity(&dml::FieldArity::Required);
+    address
+        .find_field("zipCode")
+        .unwrap()
+        .assert_arity(&dml::FieldArity::Optional);
+    address.find_field("tags").unwrap().assert_arity(&dml::FieldArity::List);
+}
+
+#[test]
+fn composite_types_can_be_used_as_field_types() {
+    let dml = r#"
+    type Address {
+        street String
+    }
+
+    model User {
+        id        Int       @id
+        address   Address
+        addresses Address[]
+    }
+    "#;
+
+    let schema = parse(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    let user = schema.assert_has_model("User");
+
+    let address = user.assert_has_scalar_field("address");
+    assert_eq!(address.field_type, dml::FieldType::CompositeType("Address".to_owned()));
+    address.assert_arity(&dml::FieldArity::Required);
+
+    let addresses = user.assert_has_scalar_field("addresses");
+    assert_eq!(
+        addresses.field_type,
+        dml::FieldType::CompositeType("Address".to_owned())
+    );
+    addresses.assert_arity(&dml::FieldArity::List);
+}
+
+#[test]
+fn composite_types_can_be_nested() {
+    let dml = r#"
+    enum Country {
+        DE
+        US
+    }
+
+    type City {
+        name    String
+        country Country
+    }
+
+    type Address {
+        street String
+        city   City
+    }
+
+    model User {
+        id      Int     @id
+        address Address
+    }
+    "#;
+
+    let schema = parse(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    let address = schema.find_composite_type("Address").unwrap();
+
+    assert_eq!(
+        address.find_field("city").unwrap().field_type,
+        dml::FieldType::CompositeType("City".to_owned())
+    );
+}
+
+#[test]
+fn composite_types_are_rendered_with_the_type_keyword() {
+    let dml = r#"
+    type Address {
+        street String
+    }
+
+    model User {
+        id      Int     @id
+        address Address
+    }
+    "#;
+
+    let schema = parse(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    assert!(rendered.contains("type Address {"), "{}", rendered);
+    assert!(rendered.contains("address Address"), "{}", rendered);
+}
+
+#[test]
+fn composite_types_require_json_support() {
+    let dml = r#"
+    type Address {
+        street String
+    }
+
+    model User {
+        id      Int     @id
+        address Address
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    assert_error_contains(&errors, "Address");
+}
+
+#[test]
+fn composite_type_fields_cannot_be_ids() {
+    let dml = r#"
+    type Address {
+        street String
+    }
+
+    model User {
+        address Address @id
+    }
+    "#;
+
+    let errors = parse_error(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    assert_error_contains(
+        &errors,
+        "The field `address` is of the composite type `Address` and cannot be marked with `@id`.",
+    );
+}
+
+#[test]
+fn composite_type_fields_cannot_be_part_of_compound_ids() {
+    let dml = r#"
+    type Address {
+        street String
+    }
+
+    model User {
+        name    String
+        address Address
+
+        @@id([name, address])
+    }
+    "#;
+
+    let errors = parse_error(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    assert_error_contains(
+        &errors,
+        "The id definition refers to the composite type fields address.",
+    );
+}
+
+#[test]
+fn composite_type_fields_cannot_be_relations() {
+    let dml = r#"
+    type Address {
+        street String
+    }
+
+    model User {
+        id      Int     @id
+        address Address @relation(fields: [id], references: [id])
+    }
+    "#;
+
+    let errors = parse_error(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    assert_error_contains(
+        &errors,
+        "The field `address` is of the composite type `Address`. Composite types are stored inline and cannot be used in relations.",
+    );
+}
+
+#[test]
+fn composite_types_cannot_contain_relation_fields() {
+    let dml = r#"
+    type Address {
+        owner User
+    }
+
+    model User {
+        id Int @id
+    }
+    "#;
+
+    let errors = parse_error(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    assert_error_contains(&errors, "Composite types cannot contain relation fields.");
+}
+
+#[test]
+fn composite_types_cannot_be_recursive() {
+    let dml = r#"
+    type Address {
+        street String
+        next   Address?
+    }
+
+    model User {
+        id Int @id
+    }
+    "#;
+
+    let errors = parse_error(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    assert_error_contains(
+        &errors,
+        "Recursive composite types are not allowed. Recursive path was: Address -> Address.",
+    );
+}
+
+#[test]
+fn composite_type_fields_cannot_have_duplicate_names() {
+    let dml = r#"
+    type Address {
+        street String
+        street String
+    }
+    "#;
+
+    let errors = parse_error(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    assert_error_contains(&errors, "street");
+}
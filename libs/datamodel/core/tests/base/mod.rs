@@ -2,5 +2,7 @@ pub mod array_sugar;
 pub mod base_types;
 pub mod basic;
 pub mod comments;
+pub mod composite_types;
 pub mod duplicates;
 pub mod preview_features;
+pub mod views;
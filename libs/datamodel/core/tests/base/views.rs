@@ -0,0 +1,88 @@
+use crate::common::*;
+use datamodel::ScalarType;
+
+#[test]
+fn parse_basic_view() {
+    let dml = r#"
+    view UserInfo {
+        id    Int    @id
+        email String @unique
+    }
+    "#;
+
+    let schema = parse(dml);
+    let view = schema.assert_has_model("UserInfo");
+
+    assert!(view.is_view);
+    view.assert_has_scalar_field("email")
+        .assert_base_type(&ScalarType::String);
+}
+
+#[test]
+fn models_are_not_views() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    assert!(!schema.assert_has_model("User").is_view);
+}
+
+#[test]
+fn views_are_rendered_with_the_view_keyword() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    view UserInfo {
+        id Int @id
+    }
+    "#;
+
+    let expected = "model User {\n  id Int @id\n}\n\nview UserInfo {\n  id Int @id\n}\n";
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml)).unwrap();
+
+    assert_eq!(rendered, expected);
+}
+
+#[test]
+fn views_cannot_have_relation_fields() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    view UserInfo {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error validating field `user` in model `UserInfo`: Views are read-only and cannot be part of a relation.",
+    );
+}
+
+#[test]
+fn models_cannot_have_relations_to_views() {
+    let dml = r#"
+    model Post {
+        id     Int      @id
+        infoId Int
+        info   UserInfo @relation(fields: [infoId], references: [id])
+    }
+
+    view UserInfo {
+        id Int @id
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error validating field `info` in model `Post`: Views are read-only and cannot be part of a relation.",
+    );
+}
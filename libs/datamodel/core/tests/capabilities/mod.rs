@@ -108,21 +108,21 @@ fn validate_uniqueness_of_names_of_indexes(providers: &[&str], must_error: bool)
 
 #[test]
 fn json_must_only_be_supported_if_all_specified_providers_support_them() {
-    // Only Postgres and MySQL support JSON.
-    test_json_support(&["postgres", "sqlite", "mysql"], true);
-    test_json_support(&["postgres", "sqlite"], true);
+    // Postgres, MySQL and SQLite all support JSON.
+    test_json_support(&["postgres", "sqlite", "mysql"], false);
+    test_json_support(&["postgres", "sqlite"], false);
     test_json_support(&["postgres", "mysql"], false);
     test_json_support(&["postgres"], false);
 
-    test_json_support(&["mysql", "sqlite", "postgres"], true);
-    test_json_support(&["mysql", "sqlite"], true);
+    test_json_support(&["mysql", "sqlite", "postgres"], false);
+    test_json_support(&["mysql", "sqlite"], false);
     test_json_support(&["mysql", "postgres"], false);
     test_json_support(&["mysql"], false);
 
-    test_json_support(&["sqlite", "mysql", "postgres"], true);
-    test_json_support(&["sqlite", "mysql"], true);
-    test_json_support(&["sqlite", "postgres"], true);
-    test_json_support(&["sqlite"], true);
+    test_json_support(&["sqlite", "mysql", "postgres"], false);
+    test_json_support(&["sqlite", "mysql"], false);
+    test_json_support(&["sqlite", "postgres"], false);
+    test_json_support(&["sqlite"], false);
 }
 
 fn test_json_support(providers: &[&str], must_error: bool) {
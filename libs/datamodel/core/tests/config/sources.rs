@@ -209,6 +209,86 @@ fn new_lines_in_source_must_work() {
     assert_eq_json(&rendered, expected);
 }
 
+#[test]
+fn schemas_must_be_rendered_to_json() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost"
+          schemas = ["auth", "public"]
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema).unwrap();
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+        {
+          "name": "ds",
+          "provider": ["postgresql"],
+          "activeProvider": "postgresql",
+          "url": {
+              "fromEnvVar": null,
+              "value": "postgresql://localhost"
+          },
+          "schemas": ["auth", "public"]
+        }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+}
+
+#[test]
+fn shadow_database_url_must_be_rendered_to_json() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost/prisma"
+          shadowDatabaseUrl = "postgresql://localhost/prisma_shadow"
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema).unwrap();
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+        {
+          "name": "ds",
+          "provider": ["postgresql"],
+          "activeProvider": "postgresql",
+          "url": {
+              "fromEnvVar": null,
+              "value": "postgresql://localhost/prisma"
+          },
+          "shadowDatabaseUrl": {
+              "fromEnvVar": null,
+              "value": "postgresql://localhost/prisma_shadow"
+          }
+        }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+}
+
+#[test]
+fn shadow_database_url_must_differ_from_url() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost/prisma"
+          shadowDatabaseUrl = "postgresql://localhost/prisma"
+        }
+    "#;
+
+    let errors = datamodel::parse_configuration(schema).err().unwrap();
+
+    errors.assert_is(DatamodelError::new_source_validation_error(
+        "The `shadowDatabaseUrl` and `url` properties of the datasource `ds` must point to different databases. The shadow database is reset by the migration engine.",
+        "ds",
+        Span::new(137, 168),
+    ));
+}
+
 #[test]
 #[serial]
 fn must_error_if_env_var_is_missing() {
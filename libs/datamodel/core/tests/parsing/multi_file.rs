@@ -0,0 +1,73 @@
+use datamodel::{ast::FileId, error::DatamodelError, SourceFile};
+
+const SCHEMA: &str = r#"
+import "./billing/invoices.prisma"
+
+model User {
+    id       Int       @id
+    invoices Invoice[]
+}
+"#;
+
+#[test]
+fn models_can_be_split_across_imported_files() {
+    let files = vec![
+        SourceFile::new("prisma/schema.prisma", SCHEMA),
+        SourceFile::new(
+            "prisma/billing/invoices.prisma",
+            r#"
+            model Invoice {
+                id     Int  @id
+                userId Int
+                user   User @relation(fields: [userId], references: [id])
+            }
+            "#,
+        ),
+    ];
+
+    let datamodel = datamodel::parse_datamodel_from_files(&files).unwrap();
+
+    assert!(datamodel.find_model("User").is_some());
+    assert!(datamodel.find_model("Invoice").is_some());
+}
+
+#[test]
+fn errors_point_into_the_file_they_come_from() {
+    let files = vec![
+        SourceFile::new("prisma/schema.prisma", SCHEMA),
+        SourceFile::new(
+            "prisma/billing/invoices.prisma",
+            r#"
+            model Invoice {
+                id   Int  @id
+                user Usr
+            }
+            "#,
+        ),
+    ];
+
+    let errors = datamodel::parse_datamodel_from_files(&files).unwrap_err();
+
+    assert_eq!(errors.errors.len(), 1);
+    assert_eq!(
+        errors.errors[0].description(),
+        "Type \"Usr\" is neither a built-in type, nor refers to another model, custom type, or enum."
+    );
+    assert_eq!(errors.errors[0].span().file_id, FileId(1));
+    assert!(errors
+        .to_pretty_string_for_files(&files)
+        .contains("prisma/billing/invoices.prisma:4"));
+}
+
+#[test]
+fn imports_of_files_that_are_not_part_of_the_schema_are_an_error() {
+    let files = vec![SourceFile::new("prisma/schema.prisma", SCHEMA)];
+
+    let errors = datamodel::parse_datamodel_from_files(&files).unwrap_err();
+
+    assert_eq!(errors.errors.len(), 1);
+    assert_eq!(
+        errors.errors[0],
+        DatamodelError::new_import_not_found_error("./billing/invoices.prisma", datamodel::ast::Span::new(1, 35))
+    );
+}
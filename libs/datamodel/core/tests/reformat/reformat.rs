@@ -532,7 +532,7 @@ fn reformatting_a_model_with_native_type_definitions_must_work() {
 
 model Blog {
   id     Int    @id
-  bigInt Int    @pg.BigInt
+  bigInt BigInt @pg.BigInt
   foobar String @pg.VarChar(12)
 }
 "#;
@@ -824,3 +824,72 @@ fn assert_reformat(schema: &str, expected_result: &str) {
     println!("result: {}", result);
     assert_eq!(result, expected_result);
 }
+
+#[test]
+fn test_reformat_view_simple() {
+    let input = r#"
+        view UserInfo { 
+            id               Int                   @id 
+            email String
+        }
+    "#;
+
+    let expected = r#"view UserInfo {
+  id    Int    @id
+  email String
+}
+"#;
+
+    assert_reformat(input, expected);
+}
+
+#[test]
+fn test_reformat_files_keeps_each_file_separate() {
+    let files = vec![
+        datamodel::SourceFile::new(
+            "schema.prisma",
+            r#"
+        import    "./posts.prisma"
+        model User {
+            id Int @id
+          posts Post[]
+        }
+    "#,
+        ),
+        datamodel::SourceFile::new(
+            "posts.prisma",
+            r#"
+        model Post {
+            id Int @id
+            userId Int
+              user User @relation(fields: [userId], references: [id])
+        }
+    "#,
+        ),
+    ];
+
+    let expected_schema = r#"import "./posts.prisma"
+
+model User {
+  id    Int    @id
+  posts Post[]
+}
+"#;
+
+    let expected_posts = r#"model Post {
+  id     Int  @id
+  userId Int
+  user   User @relation(fields: [userId], references: [id])
+}
+"#;
+
+    let result = datamodel::ast::reformat::Reformatter::reformat_files(&files, 2);
+
+    assert_eq!(
+        result,
+        vec![
+            datamodel::SourceFile::new("schema.prisma", expected_schema),
+            datamodel::SourceFile::new("posts.prisma", expected_posts),
+        ]
+    );
+}
@@ -123,7 +123,7 @@ fn test_parser_renderer_native_types_via_ast() {
 
 model Blog {
   id     Int    @id
-  bigInt Int    @pg.BigInt
+  bigInt BigInt @pg.BigInt
   foobar String @pg.VarChar(12)
 }
 "#;
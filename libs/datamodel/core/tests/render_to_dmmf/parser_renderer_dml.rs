@@ -255,7 +255,7 @@ fn test_parser_renderer_native_types_via_dml() {
 
 model Blog {
   id     Int    @id
-  bigInt Int    @pg.BigInt
+  bigInt BigInt @pg.BigInt
   foobar String @pg.VarChar(12)
 }
 "#;
@@ -120,7 +120,7 @@ fn should_fail_on_native_type_with_invalid_datasource_name() {
 
         model Blog {
             id     Int    @id
-            bigInt Int    @pg.BigInt
+            bigInt BigInt @pg.BigInt
         }
     "#;
 
@@ -143,7 +143,7 @@ fn should_fail_on_native_type_with_invalid_number_of_arguments() {
 
         model Blog {
             id     Int    @id
-            bigInt Int    @pg.BigInt
+            bigInt BigInt @pg.BigInt
             foobar String @pg.VarChar()
         }
     "#;
@@ -215,7 +215,7 @@ fn should_fail_on_native_type_with_incompatible_type() {
 
         model Blog {
             id     Int    @id
-            bigInt Int    @pg.BigInt
+            bigInt BigInt @pg.BigInt
             foobar Boolean @pg.VarChar(5)
         }
     "#;
@@ -239,7 +239,7 @@ fn should_fail_on_native_type_with_invalid_arguments() {
 
         model Blog {
             id     Int    @id
-            bigInt Int    @pg.BigInt
+            bigInt BigInt @pg.BigInt
             foobar String @pg.VarChar(a)
         }
     "#;
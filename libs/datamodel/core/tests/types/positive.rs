@@ -178,7 +178,7 @@ fn should_handle_type_specifications_on_postgres() {
 
         model Blog {
             id     Int    @id
-            bigInt Int    @pg.BigInt
+            bigInt BigInt @pg.BigInt
             foobar String @pg.VarChar(26)
         }
     "#;
@@ -56,7 +56,14 @@ macro_rules! flags {
 // `connectOrCreate`: `connectOrCreate` nested query in the QE.
 // `atomicNumberOperations`: New and expanded number operations for updates.
 // `microsoftSqlServer`: Support for Microsoft SQL Server databases
-flags!(transaction, connectOrCreate, atomicNumberOperations, microsoftSqlServer);
+// `interactiveTransactions`: Long-lived transactions spanning multiple requests in the QE.
+flags!(
+    transaction,
+    connectOrCreate,
+    atomicNumberOperations,
+    microsoftSqlServer,
+    interactiveTransactions
+);
 
 /// Initializes the feature flags with given flags.
 /// Noop if already initialized.
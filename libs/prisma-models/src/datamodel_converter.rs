@@ -29,6 +29,7 @@ impl<'a> DatamodelConverter<'a> {
             models: self.convert_models(),
             relations: self.convert_relations(),
             enums: self.convert_enums(),
+            composite_types: self.convert_composite_types(),
             version: Some("v2".to_string()),
         }
     }
@@ -43,6 +44,13 @@ impl<'a> DatamodelConverter<'a> {
             .collect()
     }
 
+    fn convert_composite_types(&self) -> Vec<InternalCompositeType> {
+        self.datamodel
+            .composite_types()
+            .map(|ct| convert_composite_type(ct, self.datamodel))
+            .collect()
+    }
+
     fn convert_enum_values(&self, enm: &dml::Enum) -> Vec<InternalEnumValue> {
         enm.values()
             .map(|enum_value| InternalEnumValue {
@@ -58,6 +66,7 @@ impl<'a> DatamodelConverter<'a> {
             .map(|model| ModelTemplate {
                 name: model.name.clone(),
                 is_embedded: model.is_embedded,
+                is_view: model.is_view,
                 fields: self.convert_fields(&model),
                 manifestation: model.database_name().map(|s| s.to_owned()),
                 id_field_names: model.id_fields.clone(),
@@ -100,6 +109,7 @@ impl<'a> DatamodelConverter<'a> {
                     is_autoincrement: sf.is_auto_increment(),
                     behaviour: sf.behaviour(),
                     internal_enum: sf.internal_enum(self.datamodel),
+                    composite_type: sf.internal_composite_type(self.datamodel),
                     db_name: sf.database_name.clone(),
                     arity: sf.arity,
                     default_value: sf.default_value.clone(),
@@ -336,6 +346,7 @@ trait DatamodelFieldExtensions {
     fn behaviour(&self) -> Option<FieldBehaviour>;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
     fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue;
+    fn internal_composite_type(&self, datamodel: &dml::Datamodel) -> Option<InternalCompositeType>;
     // fn default_value(&self) -> Option<dml::DefaultValue>; todo this is not applicable anymore
 }
 
@@ -344,6 +355,7 @@ impl DatamodelFieldExtensions for dml::ScalarField {
         match &self.field_type {
             dml::FieldType::Enum(x) => TypeIdentifier::Enum(x.clone()),
             dml::FieldType::Relation(_) => TypeIdentifier::String, // Todo: Unused
+            dml::FieldType::CompositeType(_) => TypeIdentifier::Json,
             dml::FieldType::Base(scalar, _) => match scalar {
                 dml::ScalarType::Boolean => TypeIdentifier::Boolean,
                 dml::ScalarType::DateTime => TypeIdentifier::DateTime,
@@ -351,6 +363,8 @@ impl DatamodelFieldExtensions for dml::ScalarField {
                 dml::ScalarType::Int => TypeIdentifier::Int,
                 dml::ScalarType::String => TypeIdentifier::String,
                 dml::ScalarType::Json => TypeIdentifier::Json,
+                dml::ScalarType::BigInt => TypeIdentifier::BigInt,
+                dml::ScalarType::Bytes => TypeIdentifier::Bytes,
                 _ => todo!(),
             },
             dml::FieldType::Unsupported(_) => panic!("These should always be commented out"),
@@ -380,7 +394,7 @@ impl DatamodelFieldExtensions for dml::ScalarField {
             _ => false,
         };
 
-        let is_an_int = self.type_identifier() == TypeIdentifier::Int;
+        let is_an_int = matches!(self.type_identifier(), TypeIdentifier::Int | TypeIdentifier::BigInt);
 
         is_autogenerated_id && is_an_int
     }
@@ -414,4 +428,29 @@ impl DatamodelFieldExtensions for dml::ScalarField {
             database_name: enum_value.database_name.clone(),
         }
     }
+
+    fn internal_composite_type(&self, datamodel: &dml::Datamodel) -> Option<InternalCompositeType> {
+        match self.field_type {
+            dml::FieldType::CompositeType(ref name) => datamodel
+                .find_composite_type(name)
+                .map(|ct| convert_composite_type(ct, datamodel)),
+            _ => None,
+        }
+    }
+}
+
+fn convert_composite_type(composite_type: &dml::CompositeType, datamodel: &dml::Datamodel) -> InternalCompositeType {
+    InternalCompositeType {
+        name: composite_type.name.clone(),
+        fields: composite_type
+            .fields()
+            .map(|f| InternalCompositeTypeField {
+                name: f.name.clone(),
+                type_identifier: f.type_identifier(),
+                arity: f.arity,
+                internal_enum: f.internal_enum(datamodel),
+                composite_type: f.internal_composite_type(datamodel),
+            })
+            .collect(),
+    }
 }
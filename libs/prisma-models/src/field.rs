@@ -66,6 +66,8 @@ pub enum TypeIdentifier {
     DateTime,
     UUID,
     Int,
+    BigInt,
+    Bytes,
 }
 
 impl Field {
@@ -171,6 +173,8 @@ impl From<ScalarType> for TypeIdentifier {
             ScalarType::DateTime => Self::DateTime,
             ScalarType::Json => Self::Json,
             ScalarType::Decimal => Self::Float,
+            ScalarType::BigInt => Self::BigInt,
+            ScalarType::Bytes => Self::Bytes,
             _ => todo!(),
         }
     }
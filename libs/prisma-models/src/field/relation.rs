@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use datamodel::{FieldArity, RelationInfo};
+use datamodel::{FieldArity, ReferentialAction, RelationInfo};
 use once_cell::sync::OnceCell;
 use std::{
     fmt::Debug,
@@ -158,6 +158,15 @@ impl RelationField {
         !self.is_required
     }
 
+    /// True if an explicit `onDelete` action on this field makes the database take care of the
+    /// referencing records when the referenced record is deleted.
+    pub fn resolves_deletions_in_database(&self) -> bool {
+        matches!(
+            self.relation_info.on_delete,
+            Some(ReferentialAction::Cascade) | Some(ReferentialAction::SetNull) | Some(ReferentialAction::SetDefault)
+        )
+    }
+
     pub fn model(&self) -> ModelRef {
         self.model
             .upgrade()
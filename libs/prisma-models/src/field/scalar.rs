@@ -27,6 +27,7 @@ pub struct ScalarFieldTemplate {
     pub is_autoincrement: bool,
     pub behaviour: Option<FieldBehaviour>,
     pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<InternalCompositeType>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
@@ -41,6 +42,7 @@ pub struct ScalarField {
     pub is_auto_generated_int_id: bool,
     pub is_autoincrement: bool,
     pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<InternalCompositeType>,
     pub behaviour: Option<FieldBehaviour>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
@@ -62,6 +64,7 @@ impl Debug for ScalarField {
             .field("is_auto_generated_int_id", &self.is_auto_generated_int_id)
             .field("is_autoincrement", &self.is_autoincrement)
             .field("internal_enum", &self.internal_enum)
+            .field("composite_type", &self.composite_type)
             .field("behaviour", &self.behaviour)
             .field("arity", &self.arity)
             .field("db_name", &self.db_name)
@@ -84,6 +87,7 @@ impl Hash for ScalarField {
         self.is_id.hash(state);
         self.is_auto_generated_int_id.hash(state);
         self.internal_enum.hash(state);
+        self.composite_type.hash(state);
         self.behaviour.hash(state);
         self.is_unique.hash(state);
         self.model().hash(state);
@@ -101,6 +105,7 @@ impl PartialEq for ScalarField {
             && self.is_id == other.is_id
             && self.is_auto_generated_int_id == other.is_auto_generated_int_id
             && self.internal_enum == other.internal_enum
+            && self.composite_type == other.composite_type
             && self.behaviour == other.behaviour
             && self.default_value == other.default_value
             && self.is_unique == other.is_unique
@@ -136,6 +141,7 @@ impl ScalarFieldTemplate {
             read_only: OnceCell::new(),
             is_unique: self.is_unique,
             internal_enum: self.internal_enum,
+            composite_type: self.composite_type,
             behaviour: self.behaviour,
             arity: self.arity,
             db_name: self.db_name,
@@ -198,8 +204,16 @@ impl ScalarField {
         &self.db_name.as_ref().unwrap_or(&self.name)
     }
 
+    /// Composite type fields are stored inline as a single Json value, lists of them as a Json array.
+    pub fn is_composite(&self) -> bool {
+        self.composite_type.is_some()
+    }
+
     pub fn type_identifier_with_arity(&self) -> (TypeIdentifier, FieldArity) {
-        (self.type_identifier.clone(), self.arity)
+        match self.arity {
+            FieldArity::List if self.is_composite() => (self.type_identifier.clone(), FieldArity::Optional),
+            arity => (self.type_identifier.clone(), arity),
+        }
     }
 
     pub fn is_read_only(&self) -> bool {
@@ -103,7 +103,10 @@ impl Fields {
     }
 
     pub fn scalar_list(&self) -> Vec<ScalarFieldRef> {
-        self.scalar().into_iter().filter(|sf| sf.is_list).collect()
+        self.scalar()
+            .into_iter()
+            .filter(|sf| sf.is_list && !sf.is_composite())
+            .collect()
     }
 
     fn scalar_weak(&self) -> &[ScalarFieldWeak] {
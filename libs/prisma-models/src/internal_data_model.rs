@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use datamodel::FieldArity;
 use once_cell::sync::OnceCell;
 use std::sync::{Arc, Weak};
 
@@ -10,12 +11,14 @@ pub struct InternalDataModelTemplate {
     pub models: Vec<ModelTemplate>,
     pub relations: Vec<RelationTemplate>,
     pub enums: Vec<InternalEnum>,
+    pub composite_types: Vec<InternalCompositeType>,
     pub version: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct InternalDataModel {
     pub enums: Vec<InternalEnum>,
+    pub composite_types: Vec<InternalCompositeType>,
     version: Option<String>,
 
     /// Todo clarify / rename.
@@ -90,12 +93,39 @@ impl InternalEnumValue {
     }
 }
 
+/// A composite type. Values of it are stored inline as Json in the fields using it.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InternalCompositeType {
+    pub name: String,
+    pub fields: Vec<InternalCompositeTypeField>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InternalCompositeTypeField {
+    pub name: String,
+    pub type_identifier: TypeIdentifier,
+    pub arity: FieldArity,
+    pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<InternalCompositeType>,
+}
+
+impl InternalCompositeTypeField {
+    pub fn is_required(&self) -> bool {
+        self.arity.is_required()
+    }
+
+    pub fn is_list(&self) -> bool {
+        self.arity.is_list()
+    }
+}
+
 impl InternalDataModelTemplate {
     pub fn build(self, db_name: String) -> InternalDataModelRef {
         let internal_data_model = Arc::new(InternalDataModel {
             models: OnceCell::new(),
             relations: OnceCell::new(),
             enums: self.enums,
+            composite_types: self.composite_types,
             version: self.version,
             db_name,
             relation_fields: OnceCell::new(),
@@ -142,6 +172,10 @@ impl InternalDataModel {
             .ok_or_else(|| DomainError::ModelNotFound { name: name.to_string() })
     }
 
+    pub fn find_composite_type(&self, name: &str) -> Option<&InternalCompositeType> {
+        self.composite_types.iter().find(|ct| ct.name == name)
+    }
+
     pub fn find_relation(&self, name: &str) -> crate::Result<RelationWeakRef> {
         self.relations
             .get()
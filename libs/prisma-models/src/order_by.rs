@@ -1,15 +1,63 @@
-use crate::{ModelRef, ScalarFieldRef};
+use crate::{ModelRef, RelationFieldRef, ScalarFieldRef};
 use std::string::ToString;
 
+/// An ordering of records, either by a scalar field of the model, by a scalar
+/// field of a model related via to-one relations (`path`), or by an aggregation
+/// of the records of a to-many relation at the end of the `path`.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct OrderBy {
-    pub field: ScalarFieldRef,
+    /// The field to order by. `None` if ordering by an aggregation of related records.
+    pub field: Option<ScalarFieldRef>,
+
+    /// The relation fields to traverse from the ordered model to the field or the aggregated relation.
+    /// Empty if ordering by a field of the ordered model itself.
+    pub path: Vec<RelationFieldRef>,
+
     pub sort_order: SortOrder,
+    pub sort_aggregation: Option<SortAggregation>,
 }
 
 impl OrderBy {
     pub fn new(field: ScalarFieldRef, sort_order: SortOrder) -> Self {
-        Self { field, sort_order }
+        Self::with_path(field, vec![], sort_order)
+    }
+
+    /// Ordering by a field of a related model, reached through the to-one relations of the path.
+    pub fn with_path(field: ScalarFieldRef, path: Vec<RelationFieldRef>, sort_order: SortOrder) -> Self {
+        Self {
+            field: Some(field),
+            path,
+            sort_order,
+            sort_aggregation: None,
+        }
+    }
+
+    /// Ordering by the number of related records of the to-many relation at the end of the path.
+    pub fn to_many_count(path: Vec<RelationFieldRef>, sort_order: SortOrder) -> Self {
+        Self {
+            field: None,
+            path,
+            sort_order,
+            sort_aggregation: Some(SortAggregation::Count),
+        }
+    }
+
+    /// Returns the field if the ordering is on a scalar field of the ordered model itself.
+    pub fn model_field(&self) -> Option<&ScalarFieldRef> {
+        if self.path.is_empty() {
+            self.field.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Ordering values can be null if the field is optional or reached through an optional relation.
+    /// Counts are never null.
+    pub fn is_nullable(&self) -> bool {
+        match self.field {
+            Some(ref field) => !field.is_required || self.path.iter().any(|rf| !rf.is_required),
+            None => false,
+        }
     }
 }
 
@@ -23,6 +71,12 @@ pub enum SortOrder {
     Descending,
 }
 
+/// Aggregations of related records that can be ordered by.
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum SortAggregation {
+    Count,
+}
+
 impl ToString for SortOrder {
     fn to_string(&self) -> String {
         match self {
@@ -34,9 +88,6 @@ impl ToString for SortOrder {
 
 impl From<ScalarFieldRef> for OrderBy {
     fn from(field: ScalarFieldRef) -> Self {
-        Self {
-            field,
-            sort_order: SortOrder::Ascending,
-        }
+        Self::new(field, SortOrder::Ascending)
     }
 }
@@ -21,6 +21,8 @@ impl PrismaValueExtensions for PrismaValue {
             (val @ PrismaValue::DateTime(_), TypeIdentifier::DateTime) => val,
             (val @ PrismaValue::Enum(_), TypeIdentifier::Enum(_)) => val,
             (val @ PrismaValue::Uuid(_), TypeIdentifier::UUID) => val,
+            (val @ PrismaValue::BigInt(_), TypeIdentifier::BigInt) => val,
+            (val @ PrismaValue::Bytes(_), TypeIdentifier::Bytes) => val,
 
             // Valid String coercions
             (PrismaValue::Int(i), TypeIdentifier::String) => PrismaValue::String(format!("{}", i)),
@@ -43,6 +45,19 @@ impl PrismaValueExtensions for PrismaValue {
                 }
             },
             (PrismaValue::Float(f), TypeIdentifier::Int) => PrismaValue::Int(f.trunc().to_i64().unwrap()),
+            (PrismaValue::BigInt(i), TypeIdentifier::Int) => PrismaValue::Int(i),
+
+            // Valid BigInt coercions
+            (PrismaValue::Int(i), TypeIdentifier::BigInt) => PrismaValue::BigInt(i),
+            (PrismaValue::String(s), TypeIdentifier::BigInt) => match s.parse() {
+                Ok(i) => PrismaValue::BigInt(i),
+                Err(_) => {
+                    return Err(DomainError::ConversionFailure(
+                        format!("{:?}", s),
+                        format!("{:?}", to_type),
+                    ))
+                }
+            },
 
             // Todo other coercions here
 
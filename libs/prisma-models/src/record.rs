@@ -74,7 +74,11 @@ impl ManyRecords {
 
         self.records.sort_by(|a, b| {
             let mut orderings = order_bys.into_iter().map(|o| {
-                let index = field_indices[o.field.db_name()];
+                let field = o
+                    .model_field()
+                    .expect("In-memory ordering is only possible on scalar fields of the model.");
+
+                let index = field_indices[field.db_name()];
                 match o.sort_order {
                     SortOrder::Ascending => a.values[index].cmp(&b.values[index]),
                     SortOrder::Descending => b.values[index].cmp(&a.values[index]),
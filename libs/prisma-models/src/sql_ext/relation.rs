@@ -64,7 +64,7 @@ impl AsTable for Relation {
             // table, so MSSQL can convert the `INSERT .. ON CONFLICT IGNORE` into
             // a `MERGE` statement.
             RelationLinkManifestation::RelationTable(ref m) => {
-                let db = self.model_a().internal_data_model().db_name.clone();
+                let db = self.model_a().db_schema_name();
                 let table: Table = (db, m.table.clone()).into();
 
                 table.add_unique_index(vec![Column::from("A"), Column::from("B")])
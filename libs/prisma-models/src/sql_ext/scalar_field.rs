@@ -19,8 +19,10 @@ impl ScalarFieldExt for ScalarField {
             (PrismaValue::Uuid(u), _) => u.to_string().into(),
             (PrismaValue::List(l), _) => Value::Array(Some(l.into_iter().map(|x| self.value(x)).collect())),
             (PrismaValue::Json(s), _) => Value::Json(serde_json::from_str(&s).unwrap()),
+            (PrismaValue::BigInt(i), _) => i.into(),
+            (PrismaValue::Bytes(b), _) => Value::Bytes(Some(b.into())),
             (PrismaValue::Null, ident) => match ident {
-                _ if self.is_list => Value::Array(None),
+                _ if self.is_list && !self.is_composite() => Value::Array(None),
                 TypeIdentifier::String => Value::Text(None),
                 TypeIdentifier::Float => Value::Real(None),
                 TypeIdentifier::Boolean => Value::Boolean(None),
@@ -29,6 +31,8 @@ impl ScalarFieldExt for ScalarField {
                 TypeIdentifier::DateTime => Value::DateTime(None),
                 TypeIdentifier::UUID => Value::Uuid(None),
                 TypeIdentifier::Int => Value::Integer(None),
+                TypeIdentifier::BigInt => Value::Integer(None),
+                TypeIdentifier::Bytes => Value::Bytes(None),
             },
         }
     }
@@ -47,6 +51,8 @@ pub fn convert_lossy<'a>(pv: PrismaValue) -> Value<'a> {
         PrismaValue::Uuid(u) => u.to_string().into(),
         PrismaValue::List(l) => Value::Array(Some(l.into_iter().map(|x| convert_lossy(x)).collect())),
         PrismaValue::Json(s) => Value::Json(serde_json::from_str(&s).unwrap()),
+        PrismaValue::BigInt(i) => i.into(),
+        PrismaValue::Bytes(b) => Value::Bytes(Some(b.into())),
         PrismaValue::Null => Value::Integer(None), // Can't tell which type the null is supposed to be.
     }
 }
@@ -1,3 +1,5 @@
+use std::fmt;
+
 #[derive(Debug)]
 pub struct ConversionFailure {
     pub from: &'static str,
@@ -9,3 +11,11 @@ impl ConversionFailure {
         ConversionFailure { from, to }
     }
 }
+
+impl fmt::Display for ConversionFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Could not convert from `{}` to `{}`", self.from, self.to)
+    }
+}
+
+impl std::error::Error for ConversionFailure {}
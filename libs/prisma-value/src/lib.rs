@@ -37,12 +37,26 @@ pub enum PrismaValue {
 
     #[serde(serialize_with = "serialize_decimal")]
     Float(Decimal),
+
+    #[serde(serialize_with = "serialize_bigint")]
+    BigInt(i64),
+
+    #[serde(serialize_with = "serialize_bytes")]
+    Bytes(Vec<u8>),
 }
 
 pub fn stringify_date(date: &DateTime<Utc>) -> String {
     format!("{}", date.format("%Y-%m-%dT%H:%M:%S%.3fZ"))
 }
 
+pub fn encode_bytes(bytes: &[u8]) -> String {
+    base64::encode(bytes)
+}
+
+pub fn decode_bytes(s: &str) -> PrismaValueResult<Vec<u8>> {
+    base64::decode(s).map_err(|_| ConversionFailure::new("base64 encoded bytes", "PrismaValue::Bytes"))
+}
+
 impl TryFrom<serde_json::Value> for PrismaValue {
     type Error = crate::error::ConversionFailure;
 
@@ -100,6 +114,21 @@ where
     Option::<u8>::None.serialize(serializer)
 }
 
+fn serialize_bigint<S>(int: &i64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    int.to_string().serialize(serializer)
+}
+
+fn serialize_bytes<S, T>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: AsRef<[u8]>,
+{
+    encode_bytes(bytes.as_ref()).serialize(serializer)
+}
+
 fn serialize_decimal<S>(decimal: &Decimal, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -150,6 +179,8 @@ impl fmt::Display for PrismaValue {
             PrismaValue::Null => "null".fmt(f),
             PrismaValue::Uuid(x) => x.fmt(f),
             PrismaValue::Json(x) => x.fmt(f),
+            PrismaValue::BigInt(x) => x.fmt(f),
+            PrismaValue::Bytes(x) => encode_bytes(x).fmt(f),
             PrismaValue::List(x) => {
                 let as_string = format!("{:?}", x);
                 as_string.fmt(f)
@@ -224,6 +255,7 @@ impl TryFrom<PrismaValue> for i64 {
     fn try_from(value: PrismaValue) -> PrismaValueResult<i64> {
         match value {
             PrismaValue::Int(i) => Ok(i),
+            PrismaValue::BigInt(i) => Ok(i),
             _ => Err(ConversionFailure::new("PrismaValue", "i64")),
         }
     }
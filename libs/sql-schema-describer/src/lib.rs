@@ -10,6 +10,7 @@ use regex::Regex;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     error::Error,
     fmt::{self, Debug},
     str::FromStr,
@@ -49,6 +50,48 @@ pub trait SqlSchemaDescriberBackend: Send + Sync + 'static {
     async fn get_metadata(&self, schema: &str) -> SqlSchemaDescriberResult<SQLMetadata>;
     /// Describe a database schema.
     async fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema>;
+    /// Describe several database schemas as one. Tables are tagged with the schema they live in.
+    async fn describe_schemas(&self, schemas: &[String]) -> SqlSchemaDescriberResult<SqlSchema> {
+        let mut result = SqlSchema::empty();
+
+        for schema in schemas {
+            let described = self.describe(schema).await?;
+
+            for mut table in described.tables {
+                table.schema = Some(schema.clone());
+
+                for fk in table.foreign_keys.iter_mut() {
+                    if fk.referenced_schema.is_none() {
+                        fk.referenced_schema = Some(schema.clone());
+                    }
+                }
+
+                result.tables.push(table);
+            }
+
+            for mut enm in described.enums {
+                enm.schema = Some(schema.clone());
+
+                // Enums with the same name can live in different schemas.
+                if !result
+                    .enums
+                    .iter()
+                    .any(|existing| existing.name == enm.name && existing.schema == enm.schema)
+                {
+                    result.enums.push(enm);
+                }
+            }
+
+            for mut view in described.views {
+                view.schema = Some(schema.clone());
+                result.views.push(view);
+            }
+
+            result.sequences.extend(described.sequences);
+        }
+
+        Ok(result)
+    }
     /// Get the database version.
     async fn version(&self, schema: &str) -> SqlSchemaDescriberResult<Option<String>>;
 }
@@ -69,6 +112,9 @@ pub struct SqlSchema {
     pub enums: Vec<Enum>,
     /// The schema's sequences, unique to Postgres.
     pub sequences: Vec<Sequence>,
+    /// The schema's views.
+    #[serde(default)]
+    pub views: Vec<View>,
 }
 
 impl SqlSchema {
@@ -86,6 +132,13 @@ impl SqlSchema {
         self.enums.iter().find(|x| x.name == name)
     }
 
+    /// Get a table by name, within the given schema if there is one.
+    pub fn get_table_in_schema(&self, schema: Option<&str>, name: &str) -> Option<&Table> {
+        self.tables
+            .iter()
+            .find(|t| t.name == name && (schema.is_none() || t.schema.as_deref() == schema))
+    }
+
     pub fn table(&self, name: &str) -> core::result::Result<&Table, String> {
         match self.tables.iter().find(|t| t.name == name) {
             Some(t) => Ok(t),
@@ -102,11 +155,17 @@ impl SqlSchema {
         self.sequences.iter().find(|x| x.name == name)
     }
 
+    /// Get a view.
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|x| x.name == name)
+    }
+
     pub fn empty() -> SqlSchema {
         SqlSchema {
             tables: Vec::new(),
             enums: Vec::new(),
             sequences: Vec::new(),
+            views: Vec::new(),
         }
     }
 }
@@ -117,6 +176,9 @@ impl SqlSchema {
 pub struct Table {
     /// The table's name.
     pub name: String,
+    /// The schema the table lives in, when the database was described across several schemas.
+    #[serde(default)]
+    pub schema: Option<String>,
     /// The table's columns.
     pub columns: Vec<Column>,
     /// The table's indices.
@@ -125,6 +187,30 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The table's check constraints.
+    #[serde(default)]
+    pub check_constraints: Vec<CheckConstraint>,
+}
+
+/// A view found in a schema.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    /// The view's name.
+    pub name: String,
+    /// The schema the view lives in, when the database was described across several schemas.
+    #[serde(default)]
+    pub schema: Option<String>,
+    /// The SQL definition of the view, if the database exposes it.
+    pub definition: Option<String>,
+    /// The view's columns.
+    pub columns: Vec<Column>,
+}
+
+impl View {
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|c| c.name == name)
+    }
 }
 
 impl Table {
@@ -209,12 +295,95 @@ pub struct Index {
     pub columns: Vec<String>,
     /// Type of index.
     pub tpe: IndexType,
+    /// Sort order and prefix length of the columns that do not use the defaults, keyed by column name.
+    #[serde(default)]
+    pub column_options: BTreeMap<String, IndexColumnOptions>,
+    /// The index method, when it is not the default B-tree.
+    #[serde(default)]
+    pub algorithm: Option<IndexAlgorithm>,
+    /// The `WHERE` predicate of a partial index.
+    #[serde(default)]
+    pub predicate: Option<String>,
+    /// Non-key columns stored in the index with `INCLUDE`.
+    #[serde(default)]
+    pub include: Vec<String>,
 }
 
 impl Index {
     pub fn is_unique(&self) -> bool {
         self.tpe == IndexType::Unique
     }
+
+    /// The options of an index column, defaulting to an ascending column without prefix length.
+    pub fn column_options(&self, column_name: &str) -> IndexColumnOptions {
+        self.column_options.get(column_name).cloned().unwrap_or_default()
+    }
+}
+
+/// Options of a single index column.
+#[derive(PartialEq, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexColumnOptions {
+    /// The sort order of the column in the index.
+    pub sort_order: SQLSortOrder,
+    /// The number of leading characters that are indexed (MySQL only).
+    pub length: Option<u32>,
+}
+
+/// The sort order of an index column.
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SQLSortOrder {
+    Asc,
+    Desc,
+}
+
+impl Default for SQLSortOrder {
+    fn default() -> Self {
+        SQLSortOrder::Asc
+    }
+}
+
+/// The index methods that can be chosen on Postgres besides the default B-tree.
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IndexAlgorithm {
+    Hash,
+    Gist,
+    Gin,
+    Brin,
+}
+
+impl IndexAlgorithm {
+    /// Parses the access method name Postgres reports, returning `None` for B-tree and unknown methods.
+    pub fn from_access_method(name: &str) -> Option<Self> {
+        match name {
+            "hash" => Some(IndexAlgorithm::Hash),
+            "gist" => Some(IndexAlgorithm::Gist),
+            "gin" => Some(IndexAlgorithm::Gin),
+            "brin" => Some(IndexAlgorithm::Brin),
+            _ => None,
+        }
+    }
+
+    pub fn as_access_method(&self) -> &'static str {
+        match self {
+            IndexAlgorithm::Hash => "hash",
+            IndexAlgorithm::Gist => "gist",
+            IndexAlgorithm::Gin => "gin",
+            IndexAlgorithm::Brin => "brin",
+        }
+    }
+}
+
+/// A check constraint of a table.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckConstraint {
+    /// The constraint name.
+    pub name: String,
+    /// The SQL expression the rows of the table have to satisfy.
+    pub expression: String,
 }
 
 /// The primary key of a table.
@@ -304,6 +473,8 @@ impl ColumnType {
 pub enum ColumnTypeFamily {
     /// Integer types.
     Int,
+    /// 64-bit integer types.
+    BigInt,
     /// Floating point types.
     Float,
     /// Decimal Types.
@@ -357,6 +528,7 @@ impl fmt::Display for ColumnTypeFamily {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let str = match self {
             Self::Int => "int".to_string(),
+            Self::BigInt => "bigInt".to_string(),
             Self::Float => "float".to_string(),
             Self::Decimal => "decimal".to_string(),
             Self::Boolean => "boolean".to_string(),
@@ -431,6 +603,9 @@ pub struct ForeignKey {
     pub columns: Vec<String>,
     /// Referenced table.
     pub referenced_table: String,
+    /// Schema of the referenced table, if known. `None` means the schema of the referencing table.
+    #[serde(default)]
+    pub referenced_schema: Option<String>,
     /// Referenced columns.
     pub referenced_columns: Vec<String>,
     /// Action on deletion.
@@ -443,6 +618,7 @@ impl PartialEq for ForeignKey {
     fn eq(&self, other: &Self) -> bool {
         self.columns == other.columns
             && self.referenced_table == other.referenced_table
+            && self.referenced_schema == other.referenced_schema
             && self.referenced_columns == other.referenced_columns
     }
 }
@@ -453,6 +629,9 @@ impl PartialEq for ForeignKey {
 pub struct Enum {
     /// Enum name.
     pub name: String,
+    /// The schema the enum lives in, when the database was described across several schemas.
+    #[serde(default)]
+    pub schema: Option<String>,
     /// Possible enum values.
     pub values: Vec<String>,
 }
@@ -495,13 +674,17 @@ static RE_NUM: Lazy<Regex> = Lazy::new(|| Regex::new(r"^'?(\d+)'?$").expect("com
 static RE_FLOAT: Lazy<Regex> = Lazy::new(|| Regex::new(r"^'?([^']+)'?$").expect("compile regex"));
 
 pub fn parse_int(value: &str) -> Option<PrismaValue> {
+    parse_i64(value).map(PrismaValue::Int)
+}
+
+pub fn parse_big_int(value: &str) -> Option<PrismaValue> {
+    parse_i64(value).map(PrismaValue::BigInt)
+}
+
+fn parse_i64(value: &str) -> Option<i64> {
     let captures = RE_NUM.captures(value)?;
     let num_str = captures.get(1).expect("get capture").as_str();
-    let num_rslt = num_str.parse::<i64>();
-    match num_rslt {
-        Ok(num) => Some(PrismaValue::Int(num)),
-        Err(_) => None,
-    }
+    num_str.parse::<i64>().ok()
 }
 
 pub fn parse_bool(value: &str) -> Option<PrismaValue> {
@@ -533,6 +716,34 @@ pub fn unquote_string(val: &str) -> String {
         .into()
 }
 
+/// Removes the parentheses databases wrap around check constraint expressions, e.g.
+/// `((price > 0))` becomes `price > 0`. Parentheses that do not enclose the whole expression, as in
+/// `(a > 0) AND (b > 0)`, are kept.
+pub fn unwrap_check_expression(expression: &str) -> &str {
+    let mut expression = expression.trim();
+
+    while expression.starts_with('(') && expression.ends_with(')') {
+        let mut depth = 0;
+        let closes_at_end = expression.char_indices().all(|(idx, c)| {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => (),
+            }
+
+            depth > 0 || idx == expression.len() - 1
+        });
+
+        if !closes_at_end {
+            break;
+        }
+
+        expression = expression[1..expression.len() - 1].trim();
+    }
+
+    expression
+}
+
 #[derive(Debug)]
 struct Precision {
     character_maximum_length: Option<u32>,
@@ -581,4 +792,12 @@ mod tests {
 
         assert_eq!(unquote_string("heh ".into()), "heh ");
     }
+
+    #[test]
+    fn unwrapping_check_expressions_works() {
+        assert_eq!(unwrap_check_expression("((price > 0))"), "price > 0");
+        assert_eq!(unwrap_check_expression("price > 0"), "price > 0");
+        assert_eq!(unwrap_check_expression("(a > 0) AND (b > 0)"), "(a > 0) AND (b > 0)");
+        assert_eq!(unwrap_check_expression("([price]>(0))"), "[price]>(0)");
+    }
 }
@@ -37,19 +37,29 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = self.get_all_columns(schema).await;
         let mut indexes = self.get_all_indices(schema).await;
         let mut foreign_keys = self.get_foreign_keys(schema).await;
+        let mut check_constraints = self.get_check_constraints(schema).await;
 
         let table_names = self.get_table_names(schema).await;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in table_names {
-            let table = self.get_table(&table_name, &mut columns, &mut indexes, &mut foreign_keys);
+            let table = self.get_table(
+                &table_name,
+                &mut columns,
+                &mut indexes,
+                &mut foreign_keys,
+                &mut check_constraints,
+            );
             tables.push(table);
         }
 
+        let views = self.get_views(schema, &mut columns).await;
+
         Ok(SqlSchema {
             tables,
             enums: vec![],
             sequences: vec![],
+            views,
         })
     }
 
@@ -119,6 +129,42 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(&self, schema: &str, columns: &mut HashMap<String, Vec<Column>>) -> Vec<View> {
+        debug!("Getting views");
+
+        let select = r#"
+            SELECT
+                v.name AS view_name,
+                OBJECT_DEFINITION(v.object_id) AS view_definition
+            FROM sys.views v
+            WHERE SCHEMA_NAME(v.schema_id) = @P1
+            AND v.is_ms_shipped = 0
+            ORDER BY v.name ASC
+        "#;
+
+        let rows = self.conn.query_raw(select, &[schema.into()]).await.expect("get views");
+
+        let views: Vec<View> = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+                let definition = row.get("view_definition").and_then(|x| x.to_string());
+                let columns = columns.remove(&name).unwrap_or_default();
+
+                View {
+                    name,
+                    schema: None,
+                    definition,
+                    columns,
+                }
+            })
+            .collect();
+
+        debug!("Found views: {:?}", views.iter().map(|v| &v.name).collect::<Vec<_>>());
+
+        views
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         debug!("Getting db size");
 
@@ -155,19 +201,66 @@ impl SqlSchemaDescriber {
         columns: &mut HashMap<String, Vec<Column>>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
     ) -> Table {
         let columns = columns.remove(name).expect("table columns not found");
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
+        let check_constraints = check_constraints.remove(name).unwrap_or_default();
 
         Table {
             name: name.to_string(),
+            schema: None,
             columns,
             foreign_keys,
             indices: indices.into_iter().map(|(_k, v)| v).collect(),
             primary_key,
+            check_constraints,
+        }
+    }
+
+    async fn get_check_constraints(&self, schema: &str) -> HashMap<String, Vec<CheckConstraint>> {
+        let sql = r#"
+            SELECT
+                t.name AS table_name,
+                cc.name AS constraint_name,
+                cc.definition AS definition
+            FROM sys.check_constraints cc
+            INNER JOIN sys.tables t ON t.object_id = cc.parent_object_id
+            WHERE SCHEMA_NAME(t.schema_id) = @P1
+            ORDER BY t.name, cc.name
+        "#;
+
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into()])
+            .await
+            .expect("querying for check constraints");
+
+        let mut map: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        for row in rows {
+            let table_name = row
+                .get("table_name")
+                .and_then(|x| x.to_string())
+                .expect("get table name");
+            let name = row
+                .get("constraint_name")
+                .and_then(|x| x.to_string())
+                .expect("get constraint name");
+            let definition = row
+                .get("definition")
+                .and_then(|x| x.to_string())
+                .expect("get definition");
+
+            map.entry(table_name).or_default().push(CheckConstraint {
+                name,
+                expression: unwrap_check_expression(&definition).to_owned(),
+            });
         }
+
+        map
     }
 
     async fn get_all_columns(&self, schema: &str) -> HashMap<String, Vec<Column>> {
@@ -181,9 +274,10 @@ impl SqlSchemaDescriber {
                 columnproperty(object_id(@P1 + '.' + table_name), column_name, 'IsIdentity') is_identity,
                 table_name
             FROM information_schema.columns c
-            INNER JOIN sys.tables t
+            INNER JOIN sys.objects t
             ON c.TABLE_NAME = t.name AND SCHEMA_ID(c.TABLE_SCHEMA) = t.schema_id
             WHERE table_schema = @P1
+            AND t.type IN ('U', 'V')
             AND t.is_ms_shipped = 'false'
             ORDER BY ordinal_position
         "#;
@@ -261,6 +355,10 @@ impl SqlSchemaDescriber {
                                 Some(int_value) => DefaultValue::VALUE(int_value),
                                 None => DefaultValue::DBGENERATED(default_string),
                             },
+                            ColumnTypeFamily::BigInt => match parse_big_int(&default_string) {
+                                Some(int_value) => DefaultValue::VALUE(int_value),
+                                None => DefaultValue::DBGENERATED(default_string),
+                            },
                             ColumnTypeFamily::Float => match parse_float(&default_string) {
                                 Some(float_value) => DefaultValue::VALUE(float_value),
                                 None => DefaultValue::DBGENERATED(default_string),
@@ -317,6 +415,9 @@ impl SqlSchemaDescriber {
                 ind.is_primary_key AS is_primary_key,
                 col.name AS column_name,
                 ic.index_column_id AS seq_in_index,
+                ic.is_descending_key AS is_descending,
+                ic.is_included_column AS is_included,
+                ind.filter_definition AS filter_definition,
                 t.name AS table_name
             FROM
                 sys.indexes ind
@@ -382,26 +483,45 @@ impl SqlSchemaDescriber {
                                 primary_key.replace(PrimaryKey {
                                     columns: vec![column_name],
                                     sequence: None,
-                                    constraint_name: None,
+                                    constraint_name: Some(index_name),
                                 });
                             }
                         };
-                    } else if indexes_map.contains_key(&index_name) {
-                        if let Some(index) = indexes_map.get_mut(&index_name) {
-                            index.columns.push(column_name);
-                        }
                     } else {
-                        indexes_map.insert(
-                            index_name.clone(),
-                            Index {
-                                name: index_name,
-                                columns: vec![column_name],
-                                tpe: match is_unique {
-                                    true => IndexType::Unique,
-                                    false => IndexType::Normal,
-                                },
+                        let index = indexes_map.entry(index_name.clone()).or_insert_with(|| Index {
+                            name: index_name,
+                            columns: Vec::new(),
+                            tpe: match is_unique {
+                                true => IndexType::Unique,
+                                false => IndexType::Normal,
                             },
-                        );
+                            column_options: Default::default(),
+                            algorithm: None,
+                            predicate: row
+                                .get("filter_definition")
+                                .and_then(|x| x.to_string())
+                                .map(|filter| unwrap_check_expression(&filter).to_owned()),
+                            include: Vec::new(),
+                        });
+
+                        let is_included = row.get("is_included").and_then(|x| x.as_bool()).unwrap_or(false);
+                        let is_descending = row.get("is_descending").and_then(|x| x.as_bool()).unwrap_or(false);
+
+                        if is_included {
+                            index.include.push(column_name);
+                        } else {
+                            if is_descending {
+                                index.column_options.insert(
+                                    column_name.clone(),
+                                    IndexColumnOptions {
+                                        sort_order: SQLSortOrder::Desc,
+                                        length: None,
+                                    },
+                                );
+                            }
+
+                            index.columns.push(column_name);
+                        }
                     }
                 }
                 None => {
@@ -416,6 +536,22 @@ impl SqlSchemaDescriber {
                     index_map.remove(index_name);
                 }
             }
+
+            // Unique indexes over nullable columns are created with a filter excluding NULL values,
+            // so that several rows can leave the columns empty. That filter is an implementation
+            // detail and not a user-defined partial index.
+            for index in index_map.values_mut().filter(|index| index.is_unique()) {
+                let null_filter = index
+                    .columns
+                    .iter()
+                    .map(|column| format!("[{}] IS NOT NULL", column))
+                    .collect::<Vec<_>>()
+                    .join(" AND ");
+
+                if index.predicate.as_deref() == Some(null_filter.as_str()) {
+                    index.predicate = None;
+                }
+            }
         }
 
         map
@@ -431,6 +567,7 @@ impl SqlSchemaDescriber {
                 OBJECT_NAME(fk.constraint_object_id) AS constraint_name,
                 parent_table.name AS table_name,
                 referenced_table.name AS referenced_table_name,
+                SCHEMA_NAME(referenced_table.schema_id) AS referenced_schema_name,
                 parent_column.name AS column_name,
                 referenced_column.name AS referenced_column_name,
                 rc.delete_rule AS delete_rule,
@@ -492,6 +629,11 @@ impl SqlSchemaDescriber {
                 .and_then(|x| x.to_string())
                 .expect("get referenced_table_name");
 
+            let referenced_schema = row
+                .get("referenced_schema_name")
+                .and_then(|x| x.to_string())
+                .filter(|referenced_schema| referenced_schema != schema);
+
             let referenced_column = row
                 .get("referenced_column_name")
                 .and_then(|x| x.to_string())
@@ -555,6 +697,7 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name.clone()),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema,
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
@@ -588,7 +731,8 @@ impl SqlSchemaDescriber {
             "date" | "time" | "datetime" | "datetime2" | "smalldatetime" | "datetimeoffset" => DateTime,
             "numeric" | "decimal" | "float" | "real" | "smallmoney" | "money" => Float,
             "char" | "nchar" | "varchar" | "nvarchar" | "text" | "ntext" => String,
-            "tinyint" | "smallint" | "int" | "bigint" => Int,
+            "tinyint" | "smallint" | "int" => Int,
+            "bigint" => BigInt,
             "binary" | "varbinary" | "image" => Binary,
             "uniqueidentifier" => Uuid,
             "bit" => Boolean,
@@ -58,18 +58,24 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = get_all_columns(&self.conn, schema, &flavour).await;
         let mut indexes = get_all_indexes(&self.conn, schema).await;
         let mut fks = get_foreign_keys(&self.conn, schema).await;
+        let mut check_constraints = get_check_constraints(&self.conn, schema, &flavour).await;
 
         let mut enums = vec![];
         for table_name in &table_names {
-            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks);
+            let (table, enms) =
+                self.get_table(table_name, &mut columns, &mut indexes, &mut fks, &mut check_constraints);
             tables.push(table);
             enums.extend(enms.iter().cloned());
         }
 
+        let (views, view_enums) = self.get_views(schema, &mut columns).await;
+        enums.extend(view_enums);
+
         Ok(SqlSchema {
             tables,
             enums,
             sequences: vec![],
+            views,
         })
     }
 
@@ -106,7 +112,7 @@ impl SqlSchemaDescriber {
         debug!("Getting table names");
         let sql = "SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = ?
-            -- Views are not supported yet
+            -- Views are described separately
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self
@@ -127,6 +133,39 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(
+        &self,
+        schema: &str,
+        columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
+    ) -> (Vec<View>, Vec<Enum>) {
+        debug!("Getting views");
+        let sql = "SELECT table_name as view_name, view_definition as view_definition
+            FROM information_schema.views
+            WHERE table_schema = ?
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+
+        let mut views = Vec::new();
+        let mut enums = Vec::new();
+
+        for row in rows {
+            let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+            let definition = row.get("view_definition").and_then(|x| x.to_string());
+            let (columns, view_enums) = columns.remove(&name).unwrap_or_default();
+
+            enums.extend(view_enums);
+            views.push(View {
+                name,
+                schema: None,
+                definition,
+                columns,
+            });
+        }
+
+        debug!("Found views: {:?}", views.iter().map(|v| &v.name).collect::<Vec<_>>());
+        (views, enums)
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         use rust_decimal::prelude::*;
 
@@ -157,19 +196,23 @@ impl SqlSchemaDescriber {
         columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
     ) -> (Table, Vec<Enum>) {
         debug!("Getting table '{}'", name);
         let (columns, enums) = columns.remove(name).expect("table columns not found");
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
+        let check_constraints = check_constraints.remove(name).unwrap_or_default();
         (
             Table {
                 name: name.to_string(),
+                schema: None,
                 columns,
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
                 primary_key,
+                check_constraints,
             },
             enums,
         )
@@ -296,6 +339,10 @@ async fn get_all_columns(
                             Some(int_value) => DefaultValue::VALUE(int_value),
                             None => DefaultValue::DBGENERATED(default_string),
                         },
+                        ColumnTypeFamily::BigInt => match parse_big_int(&default_string) {
+                            Some(int_value) => DefaultValue::VALUE(int_value),
+                            None => DefaultValue::DBGENERATED(default_string),
+                        },
                         ColumnTypeFamily::Float => match parse_float(&default_string) {
                             Some(float_value) => DefaultValue::VALUE(float_value),
                             None => DefaultValue::DBGENERATED(default_string),
@@ -363,7 +410,9 @@ async fn get_all_indexes(
                 non_unique AS non_unique,
                 column_name AS column_name,
                 seq_in_index AS seq_in_index,
-                table_name AS table_name
+                table_name AS table_name,
+                sub_part AS sub_part,
+                collation AS column_order
             FROM INFORMATION_SCHEMA.STATISTICS
             WHERE table_schema = ?
             ORDER BY index_name, seq_in_index
@@ -413,22 +462,34 @@ async fn get_all_indexes(
                             });
                         }
                     };
-                } else if indexes_map.contains_key(&index_name) {
-                    if let Some(index) = indexes_map.get_mut(&index_name) {
-                        index.columns.push(column_name);
-                    }
                 } else {
-                    indexes_map.insert(
-                        index_name.clone(),
-                        Index {
-                            name: index_name,
-                            columns: vec![column_name],
-                            tpe: match is_unique {
-                                true => IndexType::Unique,
-                                false => IndexType::Normal,
-                            },
+                    let index = indexes_map.entry(index_name.clone()).or_insert_with(|| Index {
+                        name: index_name,
+                        columns: Vec::new(),
+                        tpe: match is_unique {
+                            true => IndexType::Unique,
+                            false => IndexType::Normal,
                         },
-                    );
+                        column_options: Default::default(),
+                        algorithm: None,
+                        predicate: None,
+                        include: Vec::new(),
+                    });
+
+                    let options = IndexColumnOptions {
+                        // MySQL reports `A` for ascending and `D` for descending columns.
+                        sort_order: match row.get("column_order").and_then(|x| x.to_string()).as_deref() {
+                            Some("D") => SQLSortOrder::Desc,
+                            _ => SQLSortOrder::Asc,
+                        },
+                        length: row.get("sub_part").and_then(|x| x.as_i64()).map(|length| length as u32),
+                    };
+
+                    if options != IndexColumnOptions::default() {
+                        index.column_options.insert(column_name.clone(), options);
+                    }
+
+                    index.columns.push(column_name);
                 }
             }
             None => {
@@ -448,6 +509,82 @@ async fn get_all_indexes(
     map
 }
 
+async fn get_check_constraints(
+    conn: &dyn Queryable,
+    schema_name: &str,
+    flavour: &Flavour,
+) -> HashMap<String, Vec<CheckConstraint>> {
+    // MariaDB scopes check constraint names to their table, MySQL to the schema.
+    let sql = match flavour {
+        Flavour::MariaDb => {
+            "
+            SELECT
+                cc.table_name table_name,
+                cc.constraint_name constraint_name,
+                cc.check_clause check_clause
+            FROM information_schema.check_constraints cc
+            WHERE cc.constraint_schema = ?
+            ORDER BY cc.table_name, cc.constraint_name
+            "
+        }
+        Flavour::Mysql => {
+            "
+            SELECT
+                tc.table_name table_name,
+                cc.constraint_name constraint_name,
+                cc.check_clause check_clause
+            FROM information_schema.check_constraints cc
+            INNER JOIN information_schema.table_constraints tc ON
+                tc.constraint_schema = cc.constraint_schema
+                AND tc.constraint_name = cc.constraint_name
+            WHERE cc.constraint_schema = ? AND tc.constraint_type = 'CHECK'
+            ORDER BY tc.table_name, cc.constraint_name
+            "
+        }
+    };
+
+    let mut map: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+    // The check_constraints table only exists from MySQL 8.0.16 and MariaDB 10.2 on. Earlier
+    // versions parse, but do not enforce check constraints.
+    let rows = match conn.query_raw(sql, &[schema_name.into()]).await {
+        Ok(rows) => rows,
+        Err(err) => {
+            debug!("Could not query check constraints: {:?}", err);
+            return map;
+        }
+    };
+
+    for row in rows.into_iter() {
+        let table_name = row
+            .get("table_name")
+            .and_then(|x| x.to_string())
+            .expect("get table name");
+        let name = row
+            .get("constraint_name")
+            .and_then(|x| x.to_string())
+            .expect("get constraint name");
+        let check_clause = row
+            .get("check_clause")
+            .and_then(|x| x.to_string())
+            .expect("get check clause");
+
+        // MariaDB implements JSON columns as text columns with a `json_valid()` check named after the column.
+        if let Flavour::MariaDb = flavour {
+            if check_clause == format!("json_valid(`{}`)", name) {
+                continue;
+            }
+        }
+
+        map.entry(table_name).or_default().push(CheckConstraint {
+            name,
+            expression: unwrap_check_expression(&check_clause).to_owned(),
+        });
+    }
+
+    map
+}
+
 async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<String, Vec<ForeignKey>> {
     // Foreign keys covering multiple columns will return multiple rows, which we need to
     // merge.
@@ -560,6 +697,7 @@ async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<St
                     constraint_name: Some(constraint_name.clone()),
                     columns: vec![column],
                     referenced_table,
+                    referenced_schema: None,
                     referenced_columns: vec![referenced_column],
                     on_delete_action,
                     on_update_action,
@@ -611,7 +749,7 @@ fn get_column_type_and_enum(
         "tinyint" if is_tinyint1() && !invalid_bool_default() => (ColumnTypeFamily::Boolean, Some(MySqlType::TinyInt)),
         "tinyint" => (ColumnTypeFamily::Int, Some(MySqlType::TinyInt)),
         "mediumint" => (ColumnTypeFamily::Int, Some(MySqlType::MediumInt)),
-        "bigint" => (ColumnTypeFamily::Int, Some(MySqlType::BigInt)),
+        "bigint" => (ColumnTypeFamily::BigInt, Some(MySqlType::BigInt)),
         "decimal" => (
             ColumnTypeFamily::Decimal,
             Some(MySqlType::Decimal(
@@ -703,6 +841,7 @@ fn get_column_type_and_enum(
             tpe,
             Some(Enum {
                 name: name.clone(),
+                schema: None,
                 values: extract_enum_values(&full_data_type),
             }),
         ),
@@ -33,18 +33,28 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = self.get_columns(schema, &enums).await;
         let mut foreign_keys = self.get_foreign_keys(schema).await;
         let mut indexes = self.get_indices(schema, &sequences).await;
+        let mut check_constraints = self.get_check_constraints(schema).await;
 
         let table_names = self.get_table_names(schema).await;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in &table_names {
-            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
+            tables.push(self.get_table(
+                &table_name,
+                &mut columns,
+                &mut foreign_keys,
+                &mut indexes,
+                &mut check_constraints,
+            ));
         }
 
+        let views = self.get_views(schema, &mut columns).await;
+
         Ok(SqlSchema {
             enums,
             sequences,
             tables,
+            views,
         })
     }
 
@@ -81,7 +91,7 @@ impl SqlSchemaDescriber {
         debug!("Getting table names");
         let sql = "SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = $1
-            -- Views are not supported yet
+            -- Views are described separately
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self
@@ -102,6 +112,34 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(&self, schema: &str, columns: &mut HashMap<String, Vec<Column>>) -> Vec<View> {
+        debug!("Getting views");
+        let sql = "SELECT table_name as view_name, view_definition as view_definition
+            FROM information_schema.views
+            WHERE table_schema = $1
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+
+        let views: Vec<View> = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+                let definition = row.get("view_definition").and_then(|x| x.to_string());
+                let columns = columns.remove(&name).unwrap_or_default();
+
+                View {
+                    name,
+                    schema: None,
+                    definition,
+                    columns,
+                }
+            })
+            .collect();
+
+        debug!("Found views: {:?}", views.iter().map(|v| &v.name).collect::<Vec<_>>());
+        views
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         debug!("Getting db size");
         let sql =
@@ -124,18 +162,64 @@ impl SqlSchemaDescriber {
         columns: &mut HashMap<String, Vec<Column>>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
         indices: &mut HashMap<String, (Vec<Index>, Option<PrimaryKey>)>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
     ) -> Table {
         debug!("Getting table '{}'", name);
         let (indices, primary_key) = indices.remove(name).unwrap_or_else(|| (Vec::new(), None));
         let foreign_keys = foreign_keys.remove(name).unwrap_or_else(Vec::new);
+        let check_constraints = check_constraints.remove(name).unwrap_or_else(Vec::new);
         let columns = columns.remove(name).expect("could not get columns");
         Table {
             name: name.to_string(),
+            schema: None,
             columns,
             foreign_keys,
             indices,
             primary_key,
+            check_constraints,
+        }
+    }
+
+    async fn get_check_constraints(&self, schema: &str) -> HashMap<String, Vec<CheckConstraint>> {
+        debug!("Getting check constraints");
+        let sql = "SELECT rel.relname AS table_name,
+                con.conname AS constraint_name,
+                pg_get_expr(con.conbin, con.conrelid) AS expression
+            FROM pg_constraint con
+            JOIN pg_class rel ON rel.oid = con.conrelid
+            JOIN pg_namespace ns ON ns.oid = rel.relnamespace
+            WHERE con.contype = 'c' AND ns.nspname = $1
+            ORDER BY rel.relname, con.conname";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into()])
+            .await
+            .expect("querying for check constraints");
+
+        let mut check_constraints: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        for row in rows {
+            let table_name = row
+                .get("table_name")
+                .and_then(|x| x.to_string())
+                .expect("get table name");
+            let name = row
+                .get("constraint_name")
+                .and_then(|x| x.to_string())
+                .expect("get constraint name");
+            let expression = row
+                .get("expression")
+                .and_then(|x| x.to_string())
+                .expect("get expression");
+
+            check_constraints.entry(table_name).or_default().push(CheckConstraint {
+                name,
+                expression: unwrap_check_expression(&expression).to_owned(),
+            });
         }
+
+        debug!("Found check constraints: {:?}", check_constraints);
+        check_constraints
     }
 
     async fn get_columns(&self, schema: &str, enums: &[Enum]) -> HashMap<String, Vec<Column>> {
@@ -244,6 +328,13 @@ impl SqlSchemaDescriber {
                                     false => DefaultValue::DBGENERATED(default_string),
                                 },
                             },
+                            ColumnTypeFamily::BigInt => match parse_big_int(&default_string) {
+                                Some(int_value) => DefaultValue::VALUE(int_value),
+                                None => match is_autoincrement(&default_string, schema, &table_name, &col_name) {
+                                    true => DefaultValue::SEQUENCE(default_string),
+                                    false => DefaultValue::DBGENERATED(default_string),
+                                },
+                            },
                             ColumnTypeFamily::Float => match parse_float(&default_string) {
                                 Some(float_value) => DefaultValue::VALUE(float_value),
                                 None => DefaultValue::DBGENERATED(default_string),
@@ -328,6 +419,7 @@ impl SqlSchemaDescriber {
                 con.oid as "con_id",
                 att2.attname as "child_column",
                 cl.relname as "parent_table",
+                parent_ns.nspname as "parent_schema",
                 att.attname as "parent_column",
                 con.confdeltype,
                 con.confupdtype,
@@ -360,6 +452,8 @@ impl SqlSchemaDescriber {
                 att.attrelid = con.confrelid and att.attnum = con.child
             JOIN pg_class cl on
                 cl.oid = con.confrelid
+            JOIN pg_namespace parent_ns on
+                parent_ns.oid = cl.relnamespace
             JOIN pg_attribute att2 on
                 att2.attrelid = con.conrelid and att2.attnum = con.parent
             ORDER BY con_id, con.colidx"#;
@@ -384,6 +478,10 @@ impl SqlSchemaDescriber {
                 .get("parent_table")
                 .and_then(|x| x.to_string())
                 .expect("get parent_table");
+            let referenced_schema = row
+                .get("parent_schema")
+                .and_then(|x| x.to_string())
+                .filter(|referenced_schema| referenced_schema != schema);
             let referenced_column = row
                 .get("parent_column")
                 .and_then(|x| x.to_string())
@@ -430,6 +528,7 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema,
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
@@ -475,6 +574,10 @@ impl SqlSchemaDescriber {
             rawIndex.indisprimary AS is_primary_key,
             tableInfos.relname AS table_name,
             rawIndex.indkeyidx,
+            -- indoption has no entries for INCLUDE columns, bit 0 marks descending key columns
+            COALESCE(rawIndex.indoption[rawIndex.indkeyidx] & 1 = 1, false) AS is_descending,
+            rawIndex.predicate,
+            (SELECT amname FROM pg_am WHERE pg_am.oid = indexInfos.relam) AS access_method,
             pg_get_serial_sequence('"' || $1 || '"."' || tableInfos.relname || '"', columnInfos.attname) AS sequence_name
         FROM
             -- pg_class stores infos about tables, indices etc: https://www.postgresql.org/docs/current/catalog-pg-class.html
@@ -487,12 +590,12 @@ impl SqlSchemaDescriber {
                     indexrelid,
                     indisunique,
                     indisprimary,
+                    pg_get_expr(indpred, indrelid) AS predicate,
                     pg_index.indkey AS indkey,
+                    pg_index.indoption AS indoption,
                     generate_subscripts(pg_index.indkey, 1) AS indkeyidx
                 FROM pg_index
-                -- ignores partial indexes
-                Where indpred is Null
-                GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey
+                GROUP BY indrelid, indexrelid, indisunique, indisprimary, predicate, indkeyidx, indkey, indoption
                 ORDER BY indrelid, indexrelid, indkeyidx
             ) rawIndex,
             -- pg_attribute stores infos about columns: https://www.postgresql.org/docs/current/catalog-pg-attribute.html
@@ -512,7 +615,7 @@ impl SqlSchemaDescriber {
             -- we only consider stuff out of one specific schema
             AND tableInfos.relnamespace = schemaInfo.oid
             AND schemaInfo.nspname = $1
-        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx
+        GROUP BY tableInfos.relname, indexInfos.relname, indexInfos.relam, rawIndex.indisunique, rawIndex.indisprimary, rawIndex.predicate, rawIndex.indoption, columnInfos.attname, rawIndex.indkeyidx
         ORDER BY rawIndex.indkeyidx
         "#;
         let rows = self
@@ -521,12 +624,17 @@ impl SqlSchemaDescriber {
             .await
             .expect("querying for indices");
 
+        let key_column_counts = self.get_index_key_column_counts(schema).await;
+
         for index in rows {
             debug!("Got index: {:?}", index);
             let IndexRow {
                 column_name,
                 is_primary_key,
                 is_unique,
+                is_descending,
+                predicate,
+                access_method,
                 name,
                 sequence_name,
                 table_name,
@@ -560,17 +668,46 @@ impl SqlSchemaDescriber {
             } else {
                 let entry: &mut (Vec<Index>, _) = indexes_map.entry(table_name).or_insert_with(|| (Vec::new(), None));
 
-                if let Some(existing_index) = entry.0.iter_mut().find(|idx| idx.name == name) {
-                    existing_index.columns.push(column_name);
+                let index = match entry.0.iter().position(|idx| idx.name == name) {
+                    Some(position) => &mut entry.0[position],
+                    None => {
+                        entry.0.push(Index {
+                            name: name.clone(),
+                            columns: Vec::new(),
+                            tpe: match is_unique {
+                                true => IndexType::Unique,
+                                false => IndexType::Normal,
+                            },
+                            column_options: Default::default(),
+                            algorithm: access_method.as_deref().and_then(IndexAlgorithm::from_access_method),
+                            predicate: predicate.as_deref().map(|p| unwrap_check_expression(p).to_owned()),
+                            include: Vec::new(),
+                        });
+
+                        entry.0.last_mut().unwrap()
+                    }
+                };
+
+                // Columns after the key columns are the ones listed in `INCLUDE (...)`.
+                let is_included = key_column_counts
+                    .get(&name)
+                    .map(|count| index.columns.len() >= *count)
+                    .unwrap_or(false);
+
+                if is_included {
+                    index.include.push(column_name);
                 } else {
-                    entry.0.push(Index {
-                        name,
-                        columns: vec![column_name],
-                        tpe: match is_unique {
-                            true => IndexType::Unique,
-                            false => IndexType::Normal,
-                        },
-                    })
+                    if is_descending {
+                        index.column_options.insert(
+                            column_name.clone(),
+                            IndexColumnOptions {
+                                sort_order: SQLSortOrder::Desc,
+                                length: None,
+                            },
+                        );
+                    }
+
+                    index.columns.push(column_name);
                 }
             }
         }
@@ -578,6 +715,36 @@ impl SqlSchemaDescriber {
         indexes_map
     }
 
+    /// Returns a map from index name to the number of key columns, for the indexes that have
+    /// `INCLUDE` columns. `indnkeyatts` only exists since Postgres 11, older versions have no
+    /// included columns.
+    async fn get_index_key_column_counts(&self, schema: &str) -> HashMap<String, usize> {
+        let sql = r#"
+            SELECT indexInfos.relname AS name, pg_index.indnkeyatts AS key_column_count
+            FROM pg_index
+            INNER JOIN pg_class indexInfos ON indexInfos.oid = pg_index.indexrelid
+            INNER JOIN pg_namespace schemaInfo ON schemaInfo.oid = indexInfos.relnamespace
+            WHERE schemaInfo.nspname = $1 AND pg_index.indnkeyatts < pg_index.indnatts
+        "#;
+
+        let rows = match self.conn.query_raw(sql, &[schema.into()]).await {
+            Ok(rows) => rows,
+            Err(_) => return HashMap::new(),
+        };
+
+        rows.into_iter()
+            .map(|row| {
+                let name = row.get("name").and_then(|x| x.to_string()).expect("get index name");
+                let count = row
+                    .get("key_column_count")
+                    .and_then(|x| x.as_i64())
+                    .expect("get key column count");
+
+                (name, count as usize)
+            })
+            .collect()
+    }
+
     async fn get_sequences(&self, schema: &str) -> SqlSchemaDescriberResult<Vec<Sequence>> {
         debug!("Getting sequences");
         let sql = "SELECT start_value, sequence_name
@@ -635,7 +802,11 @@ impl SqlSchemaDescriber {
 
         let mut enums: Vec<Enum> = enum_values
             .into_iter()
-            .map(|(k, v)| Enum { name: k, values: v })
+            .map(|(k, v)| Enum {
+                name: k,
+                schema: None,
+                values: v,
+            })
             .collect();
 
         enums.sort_by(|a, b| Ord::cmp(&a.name, &b.name));
@@ -651,6 +822,9 @@ struct IndexRow {
     column_name: String,
     is_unique: bool,
     is_primary_key: bool,
+    is_descending: bool,
+    predicate: Option<String>,
+    access_method: Option<String>,
     table_name: String,
     sequence_name: Option<String>,
 }
@@ -671,7 +845,7 @@ fn get_column_type<'a>(
         x if data_type == "ARRAY" && x.starts_with('_') && enum_exists(trim(x)) => (Enum(trim(x).to_owned()), None),
         "int2" | "_int2" => (Int, Some(PostgresType::SmallInt)),
         "int4" | "_int4" => (Int, Some(PostgresType::Integer)),
-        "int8" | "_int8" => (Int, Some(PostgresType::BigInt)),
+        "int8" | "_int8" => (BigInt, Some(PostgresType::BigInt)),
         "oid" | "_oid" => (Int, None),
         "float4" | "_float4" => (Float, Some(PostgresType::Real)),
         "float8" | "_float8" => (Float, Some(PostgresType::DoublePrecision)),
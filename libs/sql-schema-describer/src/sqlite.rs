@@ -50,12 +50,15 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables[table_index].foreign_keys[fk_index].referenced_columns = columns
         }
 
+        let views = self.get_views(schema).await;
+
         Ok(SqlSchema {
             // There's no enum type in SQLite.
             enums: vec![],
             // There are no sequences in SQLite.
             sequences: vec![],
             tables,
+            views,
         })
     }
     async fn version(&self, schema: &str) -> crate::SqlSchemaDescriberResult<Option<String>> {
@@ -101,6 +104,29 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(&self, schema: &str) -> Vec<View> {
+        let sql = r#"SELECT name, sql FROM sqlite_master WHERE type='view'"#;
+        debug!("describing views with query: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, &[]).await.expect("get views");
+        let mut views = Vec::new();
+
+        for row in result_set.into_iter() {
+            let name = row.get("name").and_then(|x| x.to_string()).expect("view name");
+            let definition = row.get("sql").and_then(|x| x.to_string());
+            let (columns, _) = self.get_columns(schema, &name).await;
+
+            views.push(View {
+                name,
+                schema: None,
+                definition,
+                columns,
+            });
+        }
+
+        debug!("Found views: {:?}", views.iter().map(|v| &v.name).collect::<Vec<_>>());
+        views
+    }
+
     async fn get_size(&self, _schema: &str) -> usize {
         debug!("Getting db size");
         let sql = r#"SELECT page_count * page_size as size FROM pragma_page_count(), pragma_page_size();"#;
@@ -118,15 +144,37 @@ impl SqlSchemaDescriber {
         let (columns, primary_key) = self.get_columns(schema, name).await;
         let foreign_keys = self.get_foreign_keys(schema, name).await;
         let indices = self.get_indices(schema, name).await;
+        let check_constraints = self.get_check_constraints(name).await;
         Table {
             name: name.to_string(),
+            schema: None,
             columns,
             indices,
             primary_key,
             foreign_keys,
+            check_constraints,
         }
     }
 
+    /// SQLite does not expose check constraints through a pragma, so they are parsed from the
+    /// `CREATE TABLE` statement stored in `sqlite_master`.
+    async fn get_check_constraints(&self, table: &str) -> Vec<CheckConstraint> {
+        let sql = r#"SELECT sql FROM sqlite_master WHERE type='table' AND name = ?"#;
+        debug!("describing check constraints with query: '{}'", sql);
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[table.into()])
+            .await
+            .expect("get table definition");
+
+        result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
+            .map(|definition| parse_check_constraints(&definition))
+            .unwrap_or_default()
+    }
+
     async fn get_columns(&self, _schema: &str, table: &str) -> (Vec<Column>, Option<PrimaryKey>) {
         let sql = format!(r#"PRAGMA table_info ("{}")"#, table);
         debug!("describing table columns, query: '{}'", sql);
@@ -159,6 +207,10 @@ impl SqlSchemaDescriber {
                                     Some(int_value) => DefaultValue::VALUE(int_value),
                                     None => DefaultValue::DBGENERATED(default_string),
                                 },
+                                ColumnTypeFamily::BigInt => match parse_big_int(&default_string) {
+                                    Some(int_value) => DefaultValue::VALUE(int_value),
+                                    None => DefaultValue::DBGENERATED(default_string),
+                                },
                                 ColumnTypeFamily::Float => match parse_float(&default_string) {
                                     Some(float_value) => DefaultValue::VALUE(float_value),
                                     None => DefaultValue::DBGENERATED(default_string),
@@ -361,6 +413,7 @@ impl SqlSchemaDescriber {
                 let fk = ForeignKey {
                     columns,
                     referenced_table: intermediate_fk.referenced_table.to_owned(),
+                    referenced_schema: None,
                     referenced_columns,
                     on_delete_action: intermediate_fk.on_delete_action.to_owned(),
                     on_update_action: intermediate_fk.on_update_action.to_owned(),
@@ -388,12 +441,11 @@ impl SqlSchemaDescriber {
         let filtered_rows = result_set
             .into_iter()
             // Exclude primary keys, they are inferred separately.
-            .filter(|row| row.get("origin").and_then(|origin| origin.as_str()).unwrap() != "pk")
-            // Exclude partial indices
-            .filter(|row| row.get("partial").and_then(|partial| partial.as_bool()).unwrap() != true);
+            .filter(|row| row.get("origin").and_then(|origin| origin.as_str()).unwrap() != "pk");
 
         for row in filtered_rows {
             let is_unique = row.get("unique").and_then(|x| x.as_bool()).expect("get unique");
+            let is_partial = row.get("partial").and_then(|x| x.as_bool()).unwrap_or(false);
             let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
             let mut index = Index {
                 name: name.clone(),
@@ -402,14 +454,40 @@ impl SqlSchemaDescriber {
                     false => IndexType::Normal,
                 },
                 columns: vec![],
+                column_options: Default::default(),
+                algorithm: None,
+                predicate: None,
+                include: Vec::new(),
             };
 
-            let sql = format!(r#"PRAGMA index_info("{}");"#, name);
+            if is_partial {
+                index.predicate = self.get_index_predicate(&name).await;
+            }
+
+            // Unlike `index_info`, `index_xinfo` also reports the sort order of the columns.
+            let sql = format!(r#"PRAGMA index_xinfo("{}");"#, name);
             let result_set = self.conn.query_raw(&sql, &[]).await.expect("querying for index info");
             debug!("Got index description results: {:?}", result_set);
             for row in result_set.into_iter() {
+                // Auxiliary columns, like the rowid, are not part of the index definition.
+                if !row.get("key").and_then(|x| x.as_bool()).unwrap_or(false) {
+                    continue;
+                }
+
                 let pos = row.get("seqno").and_then(|x| x.as_i64()).expect("get seqno") as usize;
                 let col_name = row.get("name").and_then(|x| x.to_string()).expect("get name");
+                let is_descending = row.get("desc").and_then(|x| x.as_bool()).unwrap_or(false);
+
+                if is_descending {
+                    index.column_options.insert(
+                        col_name.clone(),
+                        IndexColumnOptions {
+                            sort_order: SQLSortOrder::Desc,
+                            length: None,
+                        },
+                    );
+                }
+
                 if index.columns.len() <= pos {
                     index.columns.resize(pos + 1, "".to_string());
                 }
@@ -421,6 +499,23 @@ impl SqlSchemaDescriber {
 
         indices
     }
+
+    /// SQLite does not expose the `WHERE` clause of partial indexes, so we read it from the
+    /// `CREATE INDEX` statement.
+    async fn get_index_predicate(&self, index_name: &str) -> Option<String> {
+        let sql = r#"SELECT sql FROM sqlite_master WHERE type='index' AND name = ?"#;
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[index_name.into()])
+            .await
+            .expect("get index definition");
+
+        result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
+            .and_then(|definition| parse_index_predicate(&definition))
+    }
 }
 
 fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
@@ -434,6 +529,8 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         "real" => ColumnTypeFamily::Decimal,
         "float" => ColumnTypeFamily::Decimal,
         "serial" => ColumnTypeFamily::Int,
+        "bigint" => ColumnTypeFamily::BigInt,
+        "blob" => ColumnTypeFamily::Binary,
         "boolean" => ColumnTypeFamily::Boolean,
         "text" => ColumnTypeFamily::String,
         s if s.contains("char") => ColumnTypeFamily::String,
@@ -442,6 +539,7 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         "date" => ColumnTypeFamily::DateTime,
         "datetime" => ColumnTypeFamily::DateTime,
         "timestamp" => ColumnTypeFamily::DateTime,
+        "json" => ColumnTypeFamily::Json,
         "binary" => ColumnTypeFamily::Binary,
         "double" => ColumnTypeFamily::Float,
         "binary[]" => ColumnTypeFamily::Binary,
@@ -481,6 +579,57 @@ fn unquote_sqlite_string_default(s: &str) -> Cow<'_, str> {
     }
 }
 
+/// Extracts the named check constraints from a `CREATE TABLE` statement. Unnamed check
+/// constraints cannot be addressed in migrations, so they are skipped.
+fn parse_check_constraints(definition: &str) -> Vec<CheckConstraint> {
+    static CHECK_CONSTRAINT_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"(?i)\bCONSTRAINT\s+("[^"]+"|`[^`]+`|\[[^\]]+\]|\w+)\s+CHECK\s*\("#).unwrap());
+
+    let mut check_constraints = Vec::new();
+
+    for captures in CHECK_CONSTRAINT_RE.captures_iter(definition) {
+        let name = captures.get(1).unwrap().as_str();
+        let name = name.trim_matches(|c| c == '"' || c == '`' || c == '[' || c == ']');
+        let expression_start = captures.get(0).unwrap().end();
+
+        let mut depth = 1;
+        let mut in_string = false;
+        let mut expression_end = None;
+
+        for (idx, c) in definition[expression_start..].char_indices() {
+            match c {
+                '\'' => in_string = !in_string,
+                '(' if !in_string => depth += 1,
+                ')' if !in_string => depth -= 1,
+                _ => (),
+            }
+
+            if depth == 0 {
+                expression_end = Some(expression_start + idx);
+                break;
+            }
+        }
+
+        if let Some(expression_end) = expression_end {
+            check_constraints.push(CheckConstraint {
+                name: name.to_owned(),
+                expression: unwrap_check_expression(&definition[expression_start..expression_end]).to_owned(),
+            });
+        }
+    }
+
+    check_constraints
+}
+
+/// Extracts the predicate of a partial index from its `CREATE INDEX` statement.
+fn parse_index_predicate(definition: &str) -> Option<String> {
+    static INDEX_PREDICATE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?is)\)\s*WHERE\s+(.*)$"#).unwrap());
+
+    INDEX_PREDICATE_RE
+        .captures(definition)
+        .map(|captures| unwrap_check_expression(captures.get(1).unwrap().as_str()).to_owned())
+}
+
 /// Returns whether a table is one of the SQLite system tables.
 fn is_system_table(table_name: &str) -> bool {
     SQLITE_SYSTEM_TABLES
@@ -496,3 +645,44 @@ const SQLITE_SYSTEM_TABLES: &[&str] = &[
     "sqlite_stat3",
     "sqlite_stat4",
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_check_constraints_works() {
+        let definition = r#"CREATE TABLE "Product" (
+            "id" INTEGER NOT NULL PRIMARY KEY,
+            "price" REAL NOT NULL,
+            "name" TEXT NOT NULL CHECK (length(name) > 0),
+            CONSTRAINT "price_positive" CHECK ((price > 0)),
+            CONSTRAINT name_not_reserved CHECK (name <> 'a)b')
+        )"#;
+
+        let check_constraints = parse_check_constraints(definition);
+
+        assert_eq!(
+            check_constraints,
+            vec![
+                CheckConstraint {
+                    name: "price_positive".to_owned(),
+                    expression: "price > 0".to_owned(),
+                },
+                CheckConstraint {
+                    name: "name_not_reserved".to_owned(),
+                    expression: "name <> 'a)b'".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parsing_index_predicates_works() {
+        let partial = r#"CREATE INDEX "active_users" ON "User"("email" DESC) WHERE (deleted_at IS NULL)"#;
+        let full = r#"CREATE UNIQUE INDEX "User.email_unique" ON "User"("email")"#;
+
+        assert_eq!(parse_index_predicate(partial).as_deref(), Some("deleted_at IS NULL"));
+        assert_eq!(parse_index_predicate(full), None);
+    }
+}
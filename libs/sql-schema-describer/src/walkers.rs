@@ -1,6 +1,6 @@
 use crate::{
-    Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey, Index, IndexType, PrimaryKey,
-    SqlSchema, Table,
+    CheckConstraint, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey,
+    ForeignKeyAction, Index, IndexType, PrimaryKey, SqlSchema, Table,
 };
 
 pub fn walk_columns<'a>(schema: &'a SqlSchema) -> impl Iterator<Item = ColumnWalker<'a>> + 'a {
@@ -44,8 +44,11 @@ impl<'a> ColumnWalker<'a> {
 
     pub fn column_type_family_as_enum(&self) -> Option<&'a Enum> {
         self.column_type_family().as_enum().map(|enum_name| {
+            // With several schemas, the enum lives in the schema of the table.
             self.schema()
-                .get_enum(enum_name)
+                .enums
+                .iter()
+                .find(|r#enum| r#enum.name == enum_name && r#enum.schema == self.table.schema)
                 .ok_or_else(|| panic!("Cannot find enum referenced in ColumnTypeFamily (`{}`)", enum_name))
                 .unwrap()
         })
@@ -129,10 +132,19 @@ impl<'a> TableWalker<'a> {
         })
     }
 
+    pub fn check_constraints(&self) -> impl Iterator<Item = &'a CheckConstraint> {
+        self.table.check_constraints.iter()
+    }
+
     pub fn name(&self) -> &'a str {
         &self.table.name
     }
 
+    /// The database schema the table lives in, if the schema was described across several schemas.
+    pub fn schema_name(&self) -> Option<&'a str> {
+        self.table.schema.as_deref()
+    }
+
     pub fn foreign_key_for_column(&self, column: &str) -> Option<&'a ForeignKey> {
         self.table.foreign_key_for_column(column)
     }
@@ -162,6 +174,14 @@ impl<'a, 'schema> ForeignKeyWalker<'schema> {
         self.foreign_key.constraint_name.as_deref()
     }
 
+    pub fn on_delete_action(&self) -> &'schema ForeignKeyAction {
+        &self.foreign_key.on_delete_action
+    }
+
+    pub fn on_update_action(&self) -> &'schema ForeignKeyAction {
+        &self.foreign_key.on_update_action
+    }
+
     pub fn inner(&self) -> &'schema ForeignKey {
         self.foreign_key
     }
@@ -176,7 +196,10 @@ impl<'a, 'schema> ForeignKeyWalker<'schema> {
             table: self
                 .table
                 .schema
-                .table(&self.foreign_key.referenced_table)
+                .get_table_in_schema(
+                    self.foreign_key.referenced_schema.as_deref(),
+                    &self.foreign_key.referenced_table,
+                )
                 .expect("foreign key references unknown table"),
         }
     }
@@ -171,6 +171,10 @@ async fn foreign_keys_must_work(api: &TestApi) {
             name: "city".to_owned(),
             columns: vec!["city".to_owned()],
             tpe: IndexType::Normal,
+            column_options: Default::default(),
+            algorithm: None,
+            predicate: None,
+            include: Vec::new(),
         }]
     } else {
         vec![]
@@ -180,6 +184,7 @@ async fn foreign_keys_must_work(api: &TestApi) {
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: expected_indexes,
             primary_key: None,
@@ -193,9 +198,11 @@ async fn foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city".to_string()],
                 referenced_columns: vec!["id".to_string()],
                 referenced_table: "City".to_string(),
+                referenced_schema: None,
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
             }],
+            check_constraints: vec![],
         }
     );
 }
@@ -280,6 +287,10 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             name: "city_name".to_owned(),
             columns: vec!["city_name".to_owned(), "city".to_owned()],
             tpe: IndexType::Normal,
+            column_options: Default::default(),
+            algorithm: None,
+            predicate: None,
+            include: Vec::new(),
         }]
     } else {
         vec![]
@@ -294,6 +305,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: expected_indexes,
             primary_key: None,
@@ -308,9 +320,11 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city_name".to_string(), "city".to_string()],
                 referenced_columns: vec!["name".to_string(), "id".to_string(),],
                 referenced_table: "City".to_string(),
+                referenced_schema: None,
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
             },],
+            check_constraints: vec![],
         }
     );
 }
@@ -415,6 +429,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
         table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
@@ -427,6 +442,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 },
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -491,11 +507,16 @@ async fn indices_must_work(api: &TestApi) {
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![Index {
                 name: "count".to_string(),
                 columns: vec!["count".to_string()],
                 tpe: IndexType::Normal,
+                column_options: Default::default(),
+                algorithm: None,
+                predicate: None,
+                include: Vec::new(),
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
@@ -507,6 +528,7 @@ async fn indices_must_work(api: &TestApi) {
                 },
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -560,12 +582,20 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
         name: "uniq".to_string(),
         columns: vec!["uniq2".to_string()],
         tpe: IndexType::Unique,
+        column_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
     }];
     match api.sql_family() {
         SqlFamily::Mysql => expected_indices.push(Index {
             name: "uniq1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            column_options: Default::default(),
+            algorithm: None,
+            predicate: None,
+            include: Vec::new(),
         }),
         SqlFamily::Postgres => expected_indices.insert(
             0,
@@ -573,12 +603,20 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "User_uniq1_key".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                column_options: Default::default(),
+                algorithm: None,
+                predicate: None,
+                include: Vec::new(),
             },
         ),
         SqlFamily::Sqlite => expected_indices.push(Index {
             name: "sqlite_autoindex_User_1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            column_options: Default::default(),
+            algorithm: None,
+            predicate: None,
+            include: Vec::new(),
         }),
         SqlFamily::Mssql => expected_indices.insert(
             0,
@@ -586,6 +624,10 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "UQ__User__CD572100A176666B".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                column_options: Default::default(),
+                algorithm: None,
+                predicate: None,
+                include: Vec::new(),
             },
         ),
     };
@@ -612,10 +654,12 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 user_table,
                 &Table {
                     name: "User".to_string(),
+                    schema: None,
                     columns: expected_columns,
                     indices: expected_indices,
                     primary_key: None,
                     foreign_keys: vec![],
+                    check_constraints: vec![],
                 }
             );
         }
@@ -663,10 +707,12 @@ async fn defaults_must_work(api: &TestApi) {
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -442,10 +442,15 @@ async fn all_mssql_column_types_must_work() {
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
 
+    // SQL Server generates a unique name for primary keys without an explicit name.
+    let pk_name = table.primary_key.as_mut().and_then(|pk| pk.constraint_name.take());
+    assert!(pk_name.unwrap().starts_with("PK__"));
+
     assert_eq!(
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
@@ -454,6 +459,7 @@ async fn all_mssql_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -477,10 +483,15 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
     let mut table = schema.get_table("User").expect("get User table").to_owned();
     table.foreign_keys.sort_unstable_by_key(|fk| fk.columns.clone());
 
+    // SQL Server generates a unique name for primary keys without an explicit name.
+    let pk_name = table.primary_key.as_mut().and_then(|pk| pk.constraint_name.take());
+    assert!(pk_name.unwrap().starts_with("PK__"));
+
     assert_eq!(
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "id".to_string(),
@@ -535,6 +546,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
@@ -543,10 +555,12 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::Cascade,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
             ],
+            check_constraints: vec![],
         }
     );
 }
@@ -573,7 +587,11 @@ async fn mssql_multi_field_indexes_must_be_inferred() {
         &[Index {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
-            tpe: IndexType::Unique
+            tpe: IndexType::Unique,
+            column_options: Default::default(),
+            algorithm: None,
+            predicate: None,
+            include: Vec::new(),
         }]
     );
 }
@@ -612,6 +630,10 @@ async fn mssql_join_table_unique_indexes_must_be_inferred() {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique,
+            column_options: Default::default(),
+            algorithm: None,
+            predicate: None,
+            include: Vec::new(),
         }]
     );
 }
@@ -153,7 +153,7 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "bigint".to_string(),
                 full_data_type: "bigint(20)".to_string(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
                 native_type: Some(MySqlType::BigInt.to_json()),
             },
@@ -617,6 +617,7 @@ async fn all_mysql_column_types_must_work() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
@@ -625,6 +626,7 @@ async fn all_mysql_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -656,6 +658,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "id".to_string(),
@@ -729,21 +732,37 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     name: "city".to_owned(),
                     columns: vec!["city".to_owned(),],
                     tpe: IndexType::Normal,
+                    column_options: Default::default(),
+                    algorithm: None,
+                    predicate: None,
+                    include: Vec::new(),
                 },
                 Index {
                     name: "city_cascade".to_owned(),
                     columns: vec!["city_cascade".to_owned(),],
                     tpe: IndexType::Normal,
+                    column_options: Default::default(),
+                    algorithm: None,
+                    predicate: None,
+                    include: Vec::new(),
                 },
                 Index {
                     name: "city_restrict".to_owned(),
                     columns: vec!["city_restrict".to_owned(),],
                     tpe: IndexType::Normal,
+                    column_options: Default::default(),
+                    algorithm: None,
+                    predicate: None,
+                    include: Vec::new(),
                 },
                 Index {
                     name: "city_set_null".to_owned(),
                     columns: vec!["city_set_null".to_owned(),],
                     tpe: IndexType::Normal,
+                    column_options: Default::default(),
+                    algorithm: None,
+                    predicate: None,
+                    include: Vec::new(),
                 }
             ],
             primary_key: Some(PrimaryKey {
@@ -757,6 +776,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                     on_update_action: ForeignKeyAction::NoAction,
                 },
@@ -765,6 +785,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                     on_update_action: ForeignKeyAction::NoAction,
                 },
@@ -773,6 +794,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                     on_update_action: ForeignKeyAction::NoAction,
                 },
@@ -781,10 +803,12 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                     on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            check_constraints: vec![],
         }
     );
 }
@@ -812,6 +836,10 @@ async fn mysql_multi_field_indexes_must_be_inferred() {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
             tpe: IndexType::Unique,
+            column_options: Default::default(),
+            algorithm: None,
+            predicate: None,
+            include: Vec::new(),
         }]
     );
 }
@@ -850,6 +878,10 @@ async fn mysql_join_table_unique_indexes_must_be_inferred() {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique,
+            column_options: Default::default(),
+            algorithm: None,
+            predicate: None,
+            include: Vec::new(),
         }]
     );
 }
@@ -887,6 +919,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -919,6 +952,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Restrict,
             on_update_action: ForeignKeyAction::NoAction,
@@ -322,7 +322,7 @@ async fn all_postgres_column_types_must_work() {
                 full_data_type: "int8".into(),
                 character_maximum_length: None,
 
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
                 native_type: Some(PostgresType::BigInt.to_json()),
             },
@@ -336,7 +336,7 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bigint".into(),
                 full_data_type: "int8".into(),
                 character_maximum_length: None,
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
                 native_type: Some(PostgresType::BigInt.to_json()),
             },
@@ -719,11 +719,16 @@ async fn all_postgres_column_types_must_work() {
         table,
         Table {
             name: "User".into(),
+            schema: None,
             columns: expected_columns,
             indices: vec![Index {
                 name: "User_uuid_col_key".into(),
                 columns: vec!["uuid_col".into(),],
                 tpe: IndexType::Unique,
+                column_options: Default::default(),
+                algorithm: None,
+                predicate: None,
+                include: Vec::new(),
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".into()],
@@ -735,6 +740,7 @@ async fn all_postgres_column_types_must_work() {
                 constraint_name: Some("User_pkey".into()),
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -764,6 +770,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".into(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "id".into(),
@@ -863,6 +870,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
@@ -871,6 +879,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
@@ -879,6 +888,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
@@ -887,6 +897,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
@@ -895,10 +906,12 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            check_constraints: vec![],
         }
     );
 }
@@ -919,6 +932,7 @@ async fn postgres_enums_must_work() {
         got_enum,
         &Enum {
             name: "mood".into(),
+            schema: None,
             values,
         }
     );
@@ -1053,3 +1067,39 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn describing_multiple_schemas_must_work(api: &TestApi) -> TestResult {
+    let auth = api.schema_name().to_owned();
+    let billing = format!("{}_billing", api.schema_name());
+
+    let sql = format!(
+        r#"
+            DROP SCHEMA IF EXISTS "{billing}" CASCADE;
+            CREATE SCHEMA "{billing}";
+            CREATE TABLE "{auth}"."User" (id INTEGER PRIMARY KEY);
+            CREATE TABLE "{billing}"."Invoice" (
+                id INTEGER PRIMARY KEY,
+                user_id INTEGER NOT NULL REFERENCES "{auth}"."User"(id)
+            );
+        "#,
+        auth = auth,
+        billing = billing,
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe_schemas(&[auth.clone(), billing.clone()]).await?;
+
+    let user = schema.table_bang("User");
+    assert_eq!(user.schema.as_deref(), Some(auth.as_str()));
+
+    let invoice = schema.table_bang("Invoice");
+    assert_eq!(invoice.schema.as_deref(), Some(billing.as_str()));
+
+    let fk = &invoice.foreign_keys[0];
+    assert_eq!(fk.referenced_table, "User");
+    assert_eq!(fk.referenced_schema.as_deref(), Some(auth.as_str()));
+
+    Ok(())
+}
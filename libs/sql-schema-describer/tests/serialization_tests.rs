@@ -24,6 +24,7 @@ fn database_schema_is_serializable() {
         tables: vec![
             Table {
                 name: "table1".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "column1".to_string(),
@@ -72,6 +73,10 @@ fn database_schema_is_serializable() {
                     name: "column2".to_string(),
                     columns: vec!["column2".to_string()],
                     tpe: IndexType::Normal,
+                    column_options: Default::default(),
+                    algorithm: None,
+                    predicate: None,
+                    include: Vec::new(),
                 }],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["column1".to_string()],
@@ -82,13 +87,16 @@ fn database_schema_is_serializable() {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
                 }],
+                check_constraints: vec![],
             },
             Table {
                 name: "table2".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "id".to_string(),
                     tpe: ColumnType {
@@ -110,10 +118,12 @@ fn database_schema_is_serializable() {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             },
         ],
         enums: vec![Enum {
             name: "enum1".to_string(),
+            schema: None,
             values: enum_values,
         }],
         sequences: vec![Sequence {
@@ -121,6 +131,7 @@ fn database_schema_is_serializable() {
             initial_value: 1,
             allocation_size: 32,
         }],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -139,6 +150,7 @@ fn database_schema_without_primary_key_is_serializable() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns: vec![Column {
                 name: "column1".to_string(),
                 tpe: ColumnType {
@@ -156,9 +168,11 @@ fn database_schema_without_primary_key_is_serializable() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-without-primary-key.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -209,13 +223,16 @@ fn database_schema_is_serializable_for_every_column_type_family() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns,
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-type-families.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -253,13 +270,16 @@ fn database_schema_is_serializable_for_every_column_arity() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns,
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-arities.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -279,6 +299,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "column1".to_string(),
@@ -358,6 +379,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column1".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
@@ -366,6 +388,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column2".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Restrict,
@@ -374,6 +397,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Cascade,
@@ -382,6 +406,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column4".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetNull,
@@ -390,14 +415,17 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column5".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
             ],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-foreign-key-actions.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
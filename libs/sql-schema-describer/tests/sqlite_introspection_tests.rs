@@ -112,6 +112,7 @@ async fn sqlite_column_types_must_work() {
         table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
@@ -120,6 +121,7 @@ async fn sqlite_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -148,6 +150,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "id".to_string(),
@@ -241,6 +244,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
@@ -249,6 +253,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
@@ -257,6 +262,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
@@ -265,6 +271,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
@@ -273,10 +280,12 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            check_constraints: vec![],
         }
     );
 }
@@ -32,6 +32,17 @@ impl TestApi {
         Ok(describer.describe(self.schema_name()).await?)
     }
 
+    pub(crate) async fn describe_schemas(&self, schemas: &[String]) -> Result<SqlSchema, anyhow::Error> {
+        let db = self.database.clone();
+        let describer: Box<dyn sql_schema_describer::SqlSchemaDescriberBackend> = match self.sql_family() {
+            SqlFamily::Postgres => Box::new(sql_schema_describer::postgres::SqlSchemaDescriber::new(db)),
+            SqlFamily::Mssql => Box::new(sql_schema_describer::mssql::SqlSchemaDescriber::new(db)),
+            family => panic!("{:?} does not support describing multiple schemas", family),
+        };
+
+        Ok(describer.describe_schemas(schemas).await?)
+    }
+
     pub(crate) fn db_name(&self) -> &'static str {
         self.db_name
     }
@@ -237,7 +237,7 @@ async fn schema_push(cmd: &SchemaPush) -> anyhow::Result<()> {
 
     let response = api
         .schema_push(&SchemaPushInput {
-            schema,
+            schema: schema.into(),
             force: cmd.force,
             assume_empty: false,
         })
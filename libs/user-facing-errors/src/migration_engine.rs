@@ -44,6 +44,15 @@ pub struct MigrateSystemDatabase {
     pub database_name: String,
 }
 
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P3005",
+    message = "Timed out after {timeout_seconds} seconds trying to acquire the migration lock on the database. Another migration engine is probably applying migrations to the same database. Please retry once it is done, or increase the lock timeout."
+)]
+pub struct MigrationLockTimeout {
+    pub timeout_seconds: u64,
+}
+
 // Tests
 
 #[cfg(test)]
@@ -241,3 +241,25 @@ pub struct TableDoesNotExist {
 pub struct ColumnDoesNotExist {
     pub column: String,
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(code = "P2023", message = "Transaction API error: {details}")]
+pub struct TransactionApiError {
+    pub details: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2024",
+    message = "Transaction `{id}` expired after {timeout_ms}ms and was rolled back."
+)]
+pub struct TransactionExpired {
+    pub id: String,
+    pub timeout_ms: u64,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(code = "P2025", message = "Check constraint failed on the database: `{constraint}`")]
+pub struct CheckConstraintViolation {
+    pub constraint: String,
+}
@@ -4,7 +4,12 @@ mod tests;
 
 use error::CliError;
 use futures::FutureExt;
-use migration_core::migration_api;
+use migration_core::{
+    commands::{DiffInput, DiffSource},
+    error::Error as CoreError,
+    migration_api,
+};
+use std::path::Path;
 use structopt::StructOpt;
 use user_facing_errors::{common::InvalidDatabaseString, KnownError};
 
@@ -19,7 +24,13 @@ pub(crate) struct Cli {
 
 impl Cli {
     pub(crate) async fn run(self) -> ! {
+        let prints_output = matches!(self.command, CliCommand::Diff { .. });
+
         match std::panic::AssertUnwindSafe(self.run_inner()).catch_unwind().await {
+            Ok(Ok(msg)) if prints_output => {
+                println!("{}", msg);
+                std::process::exit(0);
+            }
             Ok(Ok(msg)) => {
                 tracing::info!("{}", msg);
                 std::process::exit(0);
@@ -52,6 +63,7 @@ impl Cli {
                 qe_setup(&self.datasource).await?;
                 Ok(String::new())
             }
+            CliCommand::Diff { from, to, script } => diff(&self.datasource, &from, &to, script).await,
         }
     }
 }
@@ -64,6 +76,20 @@ enum CliCommand {
     CanConnectToDatabase,
     /// Set up the database for connector-test-kit.
     QeSetup,
+    /// Compare two schemas and print the migration between them. A schema is either `empty`, a
+    /// database connection string, a migrations directory, a JSON file containing a serialized SQL
+    /// schema, or a Prisma schema file.
+    Diff {
+        /// The schema to diff from.
+        #[structopt(long)]
+        from: String,
+        /// The schema to diff to.
+        #[structopt(long)]
+        to: String,
+        /// Print the SQL script instead of a summary of the changes.
+        #[structopt(long)]
+        script: bool,
+    },
 }
 
 async fn connect_to_database(database_str: &str) -> Result<String, CliError> {
@@ -87,6 +113,39 @@ async fn qe_setup(database_str: &str) -> Result<(), CliError> {
     Ok(())
 }
 
+async fn diff(database_str: &str, from: &str, to: &str, script: bool) -> Result<String, CliError> {
+    let input = DiffInput {
+        from: diff_source(from)?,
+        to: diff_source(to)?,
+        script,
+        datasource_url: Some(database_str.to_owned()),
+    };
+
+    Ok(migration_core::diff(&input).await?.diff)
+}
+
+fn diff_source(arg: &str) -> Result<DiffSource, CliError> {
+    let path = Path::new(arg);
+
+    let source = if arg == "empty" {
+        DiffSource::Empty
+    } else if arg.contains("://") || arg.starts_with("file:") {
+        DiffSource::Url { url: arg.to_owned() }
+    } else if path.is_dir() {
+        DiffSource::Migrations { path: arg.to_owned() }
+    } else if path.extension().map(|ext| ext == "json").unwrap_or(false) {
+        DiffSource::SqlSchema {
+            json: std::fs::read_to_string(path).map_err(CoreError::from)?,
+        }
+    } else {
+        DiffSource::SchemaDatamodel {
+            schema: std::fs::read_to_string(path).map_err(CoreError::from)?,
+        }
+    };
+
+    Ok(source)
+}
+
 fn datasource_from_database_str(database_str: &str) -> Result<String, CliError> {
     let provider = match database_str.split(':').next() {
         Some("postgres") => "postgresql",
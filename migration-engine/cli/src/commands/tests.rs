@@ -158,3 +158,52 @@ async fn test_create_sqlite_database() {
 
     assert!(sqlite_path.exists());
 }
+
+#[tokio::test]
+async fn test_diff_from_empty_to_prisma_schema_without_a_database() {
+    let base_dir = tempfile::tempdir().unwrap();
+    let sqlite_path = base_dir.path().join("never_created.db");
+    let url = format!("file:{}", sqlite_path.to_string_lossy());
+
+    let schema_path = base_dir.path().join("schema.prisma");
+    let schema = format!(
+        r#"
+            datasource db {{
+                provider = "sqlite"
+                url = "{}"
+            }}
+
+            model Cat {{
+                id Int @id
+                name String
+            }}
+        "#,
+        url
+    );
+    std::fs::write(&schema_path, schema).unwrap();
+    let schema_path = schema_path.to_string_lossy();
+
+    let script = run(&[
+        "--datasource",
+        &url,
+        "diff",
+        "--from",
+        "empty",
+        "--to",
+        &schema_path,
+        "--script",
+    ])
+    .await
+    .unwrap();
+
+    assert!(script.contains("CREATE TABLE"), "{}", script);
+    assert!(script.contains("\"Cat\""), "{}", script);
+
+    let summary = run(&["--datasource", &url, "diff", "--from", &schema_path, "--to", "empty"])
+        .await
+        .unwrap();
+
+    assert_eq!(summary, "[-] Removed table `Cat`\n");
+
+    assert!(!sqlite_path.exists());
+}
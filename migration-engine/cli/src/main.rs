@@ -62,28 +62,23 @@ async fn main() {
 }
 
 async fn start_engine(datamodel_location: &str, single_cmd: bool) -> ! {
-    use std::io::Read as _;
-
     tracing::info!(git_hash = env!("GIT_HASH"), "Starting migration engine RPC server",);
-    let mut file = std::fs::File::open(datamodel_location).expect("error opening datamodel file");
-
-    let mut datamodel = String::new();
-    file.read_to_string(&mut datamodel).unwrap();
+    let files = datamodel::load_schema_files(datamodel_location).expect("error opening datamodel file");
 
     if single_cmd {
-        let api = RpcApi::new(&datamodel).await.unwrap();
+        let api = RpcApi::new_from_files(&files).await.unwrap();
         let response = api.handle().unwrap();
 
         println!("{}", response);
     } else {
-        match RpcApi::new(&datamodel).await {
+        match RpcApi::new_from_files(&files).await {
             // Block the thread and handle IO in async until EOF.
             Ok(api) => json_rpc_stdio::run(api.io_handler()).await.unwrap(),
             Err(err) => {
                 let (error, exit_code) = match &err {
                     CoreError::DatamodelError(errors) => {
                         let error = user_facing_errors::UnknownError {
-                            message: errors.to_pretty_string("schema.prisma", &datamodel),
+                            message: errors.to_pretty_string_for_files(&files),
                             backtrace: Some(format!("{:?}", user_facing_errors::new_backtrace())),
                         };
 
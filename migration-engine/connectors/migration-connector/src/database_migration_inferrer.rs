@@ -40,4 +40,17 @@ pub trait DatabaseMigrationInferrer<T>: Send + Sync {
     /// Check that the current local database's schema matches its expected
     /// state at the end of the passed in migrations history.
     async fn detect_drift(&self, applied_migrations: &[MigrationDirectory]) -> ConnectorResult<bool>;
+
+    /// Infer the database migration creating the current database schema from
+    /// an empty database. This is how existing databases are baselined.
+    async fn infer_baseline(&self) -> ConnectorResult<T>;
+
+    /// Infer the database migration reverting the passed in migration, taking
+    /// the database from its target schema back to its starting schema.
+    fn infer_reverse(&self, migration: &T) -> T;
+
+    /// Adapt the migration to run outside of a transaction, using the
+    /// statements that avoid locking the tables they touch where the database
+    /// has them, e.g. creating indexes concurrently.
+    fn infer_non_transactional(&self, migration: T) -> T;
 }
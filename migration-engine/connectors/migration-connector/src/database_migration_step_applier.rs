@@ -15,9 +15,9 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync {
     /// Render the migration to a runnable script.
     fn render_script(&self, database_migration: &T, diagnostics: &DestructiveChangeDiagnostics) -> String;
 
-    /// Apply a migration script to the database. The migration persistence is
-    /// managed by the core.
-    async fn apply_script(&self, script: &str) -> ConnectorResult<()>;
+    /// Apply a migration script to the database, in the given transaction
+    /// mode. The migration persistence is managed by the core.
+    async fn apply_script(&self, script: &str, transaction_mode: TransactionMode) -> ConnectorResult<()>;
 }
 
 /// A helper struct to serialize a database migration with an additional `raw` field containing the
@@ -1,4 +1,5 @@
 use crate::ConnectorResult;
+use datamodel::Datamodel;
 use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
 
@@ -19,6 +20,16 @@ where
     /// Check the database migration for destructive or unexecutable steps
     /// without performing any IO.
     fn pure_check(&self, database_migration: &T) -> DestructiveChangeDiagnostics;
+
+    /// Check a migration script from the migrations directory for risky
+    /// operations, against the current state of the database. `target_schema`
+    /// is the Prisma schema the migrations lead to. The step indexes in the
+    /// diagnostics are statement indexes, and the diagnostics have spans.
+    async fn lint_script(
+        &self,
+        script: &str,
+        target_schema: &Datamodel,
+    ) -> ConnectorResult<DestructiveChangeDiagnostics>;
 }
 
 /// The errors and warnings emitted by the [DestructiveChangeChecker](trait.DestructiveChangeChecker.html).
@@ -59,6 +70,10 @@ pub struct MigrationWarning {
     pub description: String,
     /// The index of the step in the migration that this warning applies to.
     pub step_index: usize,
+    /// The lines of the migration script that this warning applies to, when
+    /// the warning comes from a script.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<ScriptSpan>,
 }
 
 /// An error emitted by the [DestructiveChangeChecker](trait.DestructiveChangeChecker.html). Errors will
@@ -76,6 +91,20 @@ pub struct UnexecutableMigration {
     pub description: String,
     /// The index of the step in the migration that this message applies to.
     pub step_index: usize,
+    /// The lines of the migration script that this message applies to, when
+    /// the message comes from a script.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<ScriptSpan>,
+}
+
+/// A range of lines in a migration script.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptSpan {
+    /// The first line, starting from 1.
+    pub start_line: usize,
+    /// The last line, inclusive.
+    pub end_line: usize,
 }
 
 /// An implementor of [DestructiveChangeChecker](trait.DestructiveChangeChecker.html) that performs no check.
@@ -96,4 +125,12 @@ where
     fn pure_check(&self, _database_migration: &T) -> DestructiveChangeDiagnostics {
         DestructiveChangeDiagnostics::new()
     }
+
+    async fn lint_script(
+        &self,
+        _script: &str,
+        _target_schema: &Datamodel,
+    ) -> ConnectorResult<DestructiveChangeDiagnostics> {
+        Ok(DestructiveChangeDiagnostics::new())
+    }
 }
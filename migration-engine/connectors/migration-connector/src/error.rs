@@ -57,6 +57,18 @@ impl ConnectorError {
         }
     }
 
+    pub fn migration_lock_timeout(timeout: std::time::Duration) -> Self {
+        let timeout_seconds = timeout.as_secs();
+
+        ConnectorError {
+            user_facing_error: Some(KnownError::new(
+                user_facing_errors::migration_engine::MigrationLockTimeout { timeout_seconds },
+            )),
+            kind: ErrorKind::MigrationLockTimeout { timeout_seconds },
+            context: SpanTrace::capture(),
+        }
+    }
+
     pub fn url_parse_error(err: impl Display, url: &str) -> Self {
         ConnectorError {
             user_facing_error: None,
@@ -116,6 +128,9 @@ pub enum ErrorKind {
     #[error("Operation timed out")]
     Timeout,
 
+    #[error("Timed out after {} seconds trying to acquire the migration lock.", timeout_seconds)]
+    MigrationLockTimeout { timeout_seconds: u64 },
+
     #[error("Error opening a TLS connection. {}", message)]
     TlsError { message: String },
 
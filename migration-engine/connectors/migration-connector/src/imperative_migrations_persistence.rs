@@ -21,10 +21,35 @@ pub trait ImperativeMigrationsPersistence: Send + Sync {
     /// populating the `finished_at` field in the migration record.
     async fn record_migration_finished(&self, id: &str) -> ConnectorResult<()>;
 
+    /// Record that the migration was reverted by its down script. This means
+    /// populating the `rolled_back_at` field in the migration record.
+    async fn record_migration_rolled_back(&self, id: &str) -> ConnectorResult<()>;
+
+    /// Record that an operator resolved a failed migration by hand. The
+    /// relevant timestamp is populated according to the resolution, and the
+    /// logs are replaced with `logs`, which should keep the audit trail.
+    async fn record_migration_resolved(
+        &self,
+        id: &str,
+        resolution: MigrationResolution,
+        logs: &str,
+    ) -> ConnectorResult<()>;
+
     /// List all applied migrations, ordered by `started_at`.
     async fn list_migrations(&self) -> ConnectorResult<Vec<MigrationRecord>>;
 }
 
+/// How a failed migration was resolved by hand.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MigrationResolution {
+    /// The rest of the migration was applied by hand. The migration counts as
+    /// applied.
+    Applied,
+    /// The changes of the migration were reverted by hand. The migration counts
+    /// as unapplied, and will be applied again.
+    RolledBack,
+}
+
 /// An applied migration, as returned by list_migrations.
 #[derive(Debug, Deserialize)]
 pub struct MigrationRecord {
@@ -60,4 +85,9 @@ impl MigrationRecord {
     pub fn is_failed(&self) -> bool {
         self.finished_at.is_none()
     }
+
+    /// Was the migration reverted by its down script?
+    pub fn is_rolled_back(&self) -> bool {
+        self.rolled_back_at.is_some()
+    }
 }
@@ -17,16 +17,20 @@ mod migration_persistence;
 pub mod steps;
 
 mod migrations_directory;
+mod transaction_mode;
 
 pub use database_migration_inferrer::*;
 pub use database_migration_step_applier::*;
 pub use destructive_change_checker::*;
 pub use error::*;
-pub use imperative_migrations_persistence::{ImperativeMigrationsPersistence, MigrationRecord, Timestamp};
+pub use imperative_migrations_persistence::{
+    ImperativeMigrationsPersistence, MigrationRecord, MigrationResolution, Timestamp,
+};
 pub use migration_applier::*;
 pub use migration_persistence::*;
 pub use migrations_directory::{create_migration_directory, list_migrations, ListMigrationsError, MigrationDirectory};
 pub use steps::MigrationStep;
+pub use transaction_mode::{TransactionMode, TRANSACTION_MODE_HEADER_PREFIX};
 
 use std::fmt::Debug;
 
@@ -56,6 +60,14 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// Drop all database state.
     async fn reset(&self) -> ConnectorResult<()>;
 
+    /// Acquire the database-level lock guarding migrations against concurrent
+    /// migration engines, waiting at most `timeout` for it. It must be
+    /// released with `release_lock`.
+    async fn acquire_lock(&self, timeout: std::time::Duration) -> ConnectorResult<()>;
+
+    /// Release the lock acquired with `acquire_lock`.
+    async fn release_lock(&self) -> ConnectorResult<()>;
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(
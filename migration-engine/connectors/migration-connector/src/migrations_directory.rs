@@ -7,6 +7,7 @@
 //! directorys, named after the migration id, and each containing:
 //!
 //! - A migration script
+//! - A down script reverting the migration, for migrations created with one
 
 use sha2::{Digest, Sha256, Sha512};
 use std::{
@@ -23,6 +24,9 @@ use crate::FormatChecksum;
 /// The file name for migration scripts, not including the file extension.
 pub const MIGRATION_SCRIPT_FILENAME: &str = "migration";
 
+/// The file name for down scripts, not including the file extension.
+pub const DOWN_SCRIPT_FILENAME: &str = "down";
+
 /// Create a directory for a new migration.
 pub fn create_migration_directory(
     migrations_directory_path: &Path,
@@ -173,6 +177,31 @@ impl MigrationDirectory {
         Ok(std::fs::read_to_string(&self.path.join("migration.sql"))?)
     }
 
+    /// Write the down script, reverting the migration, to the directory.
+    #[tracing::instrument]
+    pub fn write_down_script(&self, script: &str, extension: &str) -> std::io::Result<()> {
+        let mut path = self.path.join(DOWN_SCRIPT_FILENAME);
+
+        path.set_extension(extension);
+
+        tracing::debug!("Writing down script at {:?}", &path);
+
+        let mut file = std::fs::File::create(&path)?;
+        file.write_all(script.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Read the down script to a string. Returns `None` if the migration has no down script.
+    #[tracing::instrument]
+    pub fn read_down_script(&self) -> Result<Option<String>, ReadMigrationScriptError> {
+        match std::fs::read_to_string(&self.path.join("down.sql")) {
+            Ok(script) => Ok(Some(script)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
     /// The filesystem path to the directory.
     pub fn path(&self) -> &Path {
         &self.path
@@ -0,0 +1,66 @@
+#![deny(missing_docs)]
+
+//! Migration scripts can declare how they must be applied with a header comment, before any
+//! statement:
+//!
+//! ```sql
+//! -- transaction: none
+//! CREATE INDEX CONCURRENTLY "User_email" ON "User"("email");
+//! ```
+
+/// The prefix of the header comment declaring the transaction mode of a migration script.
+pub const TRANSACTION_MODE_HEADER_PREFIX: &str = "-- transaction:";
+
+/// How a migration script is applied to the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionMode {
+    /// The script declares no mode. It is sent to the database as is, and the transaction
+    /// semantics are those of the database for multi-statement commands.
+    Default,
+    /// `-- transaction: all`. The whole script runs in a single transaction, that is rolled back
+    /// if any statement fails. Note that MySQL implicitly commits the transaction on DDL
+    /// statements.
+    Transaction,
+    /// `-- transaction: none`. The statements of the script run one by one, outside of any
+    /// transaction. This is required for statements like `CREATE INDEX CONCURRENTLY` on
+    /// PostgreSQL.
+    NoTransaction,
+}
+
+impl TransactionMode {
+    /// Read the transaction mode from the header comments of a migration script. The header must
+    /// come before the first line that is not a `--` comment.
+    pub fn from_script(script: &str) -> Result<Self, String> {
+        let header = script
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .take_while(|line| line.starts_with("--"))
+            .find(|line| line.starts_with(TRANSACTION_MODE_HEADER_PREFIX));
+
+        let value = match header {
+            Some(header) => header[TRANSACTION_MODE_HEADER_PREFIX.len()..].trim(),
+            None => return Ok(TransactionMode::Default),
+        };
+
+        match value {
+            "all" => Ok(TransactionMode::Transaction),
+            "none" => Ok(TransactionMode::NoTransaction),
+            other => Err(format!(
+                "Unknown transaction mode `{}` in the migration script header. Expected `all` or `none`.",
+                other
+            )),
+        }
+    }
+
+    /// The header comment declaring the mode, to put at the top of a migration script.
+    pub fn render_header(&self) -> Option<String> {
+        let value = match self {
+            TransactionMode::Default => return None,
+            TransactionMode::Transaction => "all",
+            TransactionMode::NoTransaction => "none",
+        };
+
+        Some(format!("{} {}\n", TRANSACTION_MODE_HEADER_PREFIX, value))
+    }
+}
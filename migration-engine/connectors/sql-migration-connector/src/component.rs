@@ -25,6 +25,10 @@ pub(crate) trait Component {
         &self.connector().database_info
     }
 
+    fn shadow_database_url(&self) -> Option<&str> {
+        self.connector().shadow_database_url.as_deref()
+    }
+
     async fn describe(&self) -> ConnectorResult<SqlSchema> {
         self.connector().describe_schema().await
     }
@@ -25,6 +25,14 @@ impl DatabaseInfo {
         })
     }
 
+    /// Database information for a connection string we do not connect to. The database version is unknown.
+    pub(crate) fn without_connection(connection_info: ConnectionInfo) -> Self {
+        DatabaseInfo {
+            connection_info,
+            database_version: None,
+        }
+    }
+
     pub(crate) fn is_mysql_5_6(&self) -> bool {
         self.connection_info.sql_family() == SqlFamily::Mysql
             && self
@@ -18,26 +18,39 @@ use crate::{
     connection_wrapper::Connection, database_info::DatabaseInfo, error::CheckDatabaseInfoResult,
     sql_destructive_change_checker::DestructiveChangeCheckerFlavour, sql_renderer::SqlRenderer,
     sql_schema_calculator::SqlSchemaCalculatorFlavour, sql_schema_differ::SqlSchemaDifferFlavour,
+    sql_script::split_statements,
 };
-use migration_connector::{ConnectorResult, MigrationDirectory};
+use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory, TransactionMode};
 use quaint::{connector::ConnectionInfo, prelude::SqlFamily};
 use sql_schema_describer::SqlSchema;
-use std::fmt::Debug;
+use std::{fmt::Debug, future::Future, time::Duration};
+
+/// The name of the advisory lock taken while applying migrations, on the databases that name their
+/// locks.
+pub(crate) const MIGRATION_LOCK_NAME: &str = "prisma_migrate";
+
+/// How long to wait between two attempts at acquiring the migration lock, on the databases where
+/// we have to poll for it.
+const MIGRATION_LOCK_POLL_INTERVAL: Duration = Duration::from_millis(200);
 
 /// The maximum size of identifiers on MySQL, in bytes.
 ///
 /// reference: https://dev.mysql.com/doc/refman/5.7/en/identifier-length.html
 pub(crate) const MYSQL_IDENTIFIER_SIZE_LIMIT: usize = 64;
 
-pub(crate) fn from_connection_info(connection_info: &ConnectionInfo) -> Box<dyn SqlFlavour + Send + Sync + 'static> {
+pub(crate) fn from_connection_info(
+    connection_info: &ConnectionInfo,
+    schemas: Vec<String>,
+) -> Box<dyn SqlFlavour + Send + Sync + 'static> {
     match connection_info {
         ConnectionInfo::Mysql(url) => Box::new(MysqlFlavour(url.clone())),
-        ConnectionInfo::Postgres(url) => Box::new(PostgresFlavour(url.clone())),
+        ConnectionInfo::Postgres(url) => Box::new(PostgresFlavour(url.clone(), schemas)),
         ConnectionInfo::Sqlite { file_path, db_name } => Box::new(SqliteFlavour {
             file_path: file_path.clone(),
             attached_name: db_name.clone(),
+            lock_connection: Default::default(),
         }),
-        ConnectionInfo::Mssql(url) => Box::new(MssqlFlavour(url.clone())),
+        ConnectionInfo::Mssql(url) => Box::new(MssqlFlavour(url.clone(), schemas)),
     }
 }
 
@@ -75,11 +88,159 @@ pub(crate) trait SqlFlavour:
     /// Drop the database and recreate it empty.
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()>;
 
+    /// Acquire the migration lock, waiting at most `timeout`. Fails with
+    /// `ConnectorError::migration_lock_timeout()` when the timeout elapses.
+    async fn acquire_lock(&self, connection: &Connection, timeout: Duration) -> ConnectorResult<()>;
+
+    /// Release the migration lock.
+    async fn release_lock(&self, connection: &Connection) -> ConnectorResult<()>;
+
+    /// The statement starting a transaction.
+    fn begin_transaction_statement(&self) -> &'static str {
+        "BEGIN"
+    }
+
+    /// Apply a migration script in the given transaction mode.
+    async fn apply_migration_script(
+        &self,
+        connection: &Connection,
+        script: &str,
+        transaction_mode: TransactionMode,
+    ) -> ConnectorResult<()> {
+        match transaction_mode {
+            TransactionMode::Default => connection.raw_cmd(script).await,
+            TransactionMode::Transaction => {
+                connection.raw_cmd(self.begin_transaction_statement()).await?;
+
+                match connection.raw_cmd(script).await {
+                    Ok(()) => connection.raw_cmd("COMMIT").await,
+                    Err(err) => {
+                        // The database may already have rolled back the transaction, so the
+                        // error from this statement is not interesting.
+                        connection.raw_cmd("ROLLBACK").await.ok();
+
+                        Err(err)
+                    }
+                }
+            }
+            TransactionMode::NoTransaction => {
+                for statement in split_statements(script, self.sql_family()) {
+                    connection.raw_cmd(statement).await?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
     /// Apply the given migration history to a temporary database, and return
-    /// the final introspected SQL schema.
+    /// the final introspected SQL schema. When `shadow_database_url` is
+    /// provided, that database is reset and used instead of creating one.
     async fn sql_schema_from_migration_history(
         &self,
         migrations: &[MigrationDirectory],
         connection: &Connection,
+        shadow_database_url: Option<&str>,
     ) -> ConnectorResult<SqlSchema>;
 }
+
+/// Repeatedly call `try_acquire` until it succeeds or `timeout` elapses, for the databases without
+/// a blocking lock acquisition with a timeout.
+pub(crate) async fn poll_lock<F, Fut>(timeout: Duration, mut try_acquire: F) -> ConnectorResult<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ConnectorResult<bool>>,
+{
+    let start = std::time::Instant::now();
+
+    loop {
+        if try_acquire().await? {
+            return Ok(());
+        }
+
+        if start.elapsed() >= timeout {
+            return Err(ConnectorError::migration_lock_timeout(timeout));
+        }
+
+        tokio::time::delay_for(MIGRATION_LOCK_POLL_INTERVAL).await;
+    }
+}
+
+/// Apply the migration scripts in order, failing with the name of the first
+/// migration that could not be applied.
+pub(crate) async fn apply_migration_scripts(
+    migrations: &[MigrationDirectory],
+    connection: &Connection,
+) -> ConnectorResult<()> {
+    for migration in migrations {
+        let script = migration.read_migration_script()?;
+
+        tracing::debug!(
+            "Applying migration `{}` to temporary database.",
+            migration.migration_name()
+        );
+
+        connection
+            .raw_cmd(&script)
+            .await
+            .map_err(|connector_error| connector_error.into_migration_failed(migration.migration_name().to_owned()))?;
+    }
+
+    Ok(())
+}
+
+/// Drops a temporary database created on the connection's server. Call
+/// `drop_database()` on the happy path; if the guard goes out of scope without
+/// that (early return on error, panic), the drop statement is run on a
+/// background task instead, so temporary databases are not left behind.
+pub(crate) struct TemporaryDatabaseGuard {
+    connection: Connection,
+    drop_database: String,
+    dropped: bool,
+}
+
+impl TemporaryDatabaseGuard {
+    /// `drop_database` is the statement that drops the temporary database,
+    /// to be run on `connection`.
+    pub(crate) fn new(connection: &Connection, drop_database: String) -> Self {
+        TemporaryDatabaseGuard {
+            connection: connection.clone(),
+            drop_database,
+            dropped: false,
+        }
+    }
+
+    /// Drop the temporary database. Any connection to it must be closed first.
+    pub(crate) async fn drop_database(mut self) -> ConnectorResult<()> {
+        self.dropped = true;
+        self.connection.raw_cmd(&self.drop_database).await
+    }
+}
+
+impl Drop for TemporaryDatabaseGuard {
+    fn drop(&mut self) {
+        if self.dropped {
+            return;
+        }
+
+        let handle = match tokio::runtime::Handle::try_current() {
+            Ok(handle) => handle,
+            Err(_) => {
+                tracing::warn!(
+                    "Could not clean up the temporary database outside of a runtime. Statement: {}",
+                    self.drop_database
+                );
+                return;
+            }
+        };
+
+        let connection = self.connection.clone();
+        let drop_database = std::mem::take(&mut self.drop_database);
+
+        handle.spawn(async move {
+            if let Err(err) = connection.raw_cmd(&drop_database).await {
+                tracing::warn!("Failed to clean up the temporary database: {}", err);
+            }
+        });
+    }
+}
@@ -1,12 +1,16 @@
-use super::SqlFlavour;
+use super::{apply_migration_scripts, SqlFlavour, TemporaryDatabaseGuard, MIGRATION_LOCK_NAME};
 use crate::{connect, connection_wrapper::Connection};
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
 use quaint::{connector::MssqlUrl, prelude::SqlFamily};
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend, SqlSchemaDescriberError};
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 #[derive(Debug)]
-pub(crate) struct MssqlFlavour(pub(crate) MssqlUrl);
+pub(crate) struct MssqlFlavour(
+    pub(crate) MssqlUrl,
+    /// The schemas from the datasource's `schemas` property, if any.
+    pub(crate) Vec<String>,
+);
 
 impl MssqlFlavour {
     pub(crate) fn schema_name(&self) -> &str {
@@ -34,6 +38,24 @@ impl MssqlFlavour {
 
         (db_name, master_uri)
     }
+
+    /// Create the connection's schema and the datasource's `schemas`, if they
+    /// do not exist yet.
+    async fn create_schemas(&self, connection: &Connection) -> ConnectorResult<()> {
+        let schemas =
+            std::iter::once(connection.connection_info().schema_name()).chain(self.1.iter().map(String::as_str));
+
+        for schema in schemas {
+            connection
+                .raw_cmd(&format!(
+                    "IF NOT EXISTS (SELECT * FROM sys.schemas WHERE name = '{schema}') EXEC('CREATE SCHEMA [{schema}]')",
+                    schema = schema
+                ))
+                .await?;
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -54,54 +76,70 @@ impl SqlFlavour for MssqlFlavour {
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
-        sql_schema_describer::mssql::SqlSchemaDescriber::new(connection.quaint().clone())
-            .describe(connection.connection_info().schema_name())
-            .await
-            .map_err(|err| match err {
-                SqlSchemaDescriberError::UnknownError => {
-                    ConnectorError::query_error(anyhow::anyhow!("An unknown error occurred in sql-schema-describer"))
-                }
-            })
+        let describer = sql_schema_describer::mssql::SqlSchemaDescriber::new(connection.quaint().clone());
+
+        let schema = if self.1.is_empty() {
+            describer.describe(connection.connection_info().schema_name()).await
+        } else {
+            describer.describe_schemas(&self.1).await
+        };
+
+        schema.map_err(|err| match err {
+            SqlSchemaDescriberError::UnknownError => {
+                ConnectorError::query_error(anyhow::anyhow!("An unknown error occurred in sql-schema-describer"))
+            }
+        })
     }
 
-    async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
-        let schema_name = connection.connection_info().schema_name();
-        let drop_fks = format!(
+    async fn acquire_lock(&self, connection: &Connection, timeout: Duration) -> ConnectorResult<()> {
+        // sp_getapplock returns a negative status when the lock could not be granted.
+        let sql = format!(
             r#"
-            DECLARE @stmt NVARCHAR(max)
-            DECLARE @n CHAR(1)
+            DECLARE @status INT
+            EXEC @status = sp_getapplock @Resource = '{name}', @LockMode = 'Exclusive', @LockOwner = 'Session', @LockTimeout = {timeout_ms}
+            SELECT @status
+            "#,
+            name = MIGRATION_LOCK_NAME,
+            timeout_ms = timeout.as_millis(),
+        );
 
-            SET @n = CHAR(10)
+        let result = connection.query_raw(&sql, &[]).await?;
 
-            SELECT @stmt = ISNULL(@stmt + @n, '') +
-                'ALTER TABLE [' + SCHEMA_NAME(schema_id) + '].[' + OBJECT_NAME(parent_object_id) + '] DROP CONSTRAINT [' + name + ']'
-            FROM sys.foreign_keys
-            WHERE SCHEMA_NAME(schema_id) = '{0}'
+        match result.get(0).and_then(|row| row.at(0).and_then(|value| value.as_i64())) {
+            Some(status) if status >= 0 => Ok(()),
+            _ => Err(ConnectorError::migration_lock_timeout(timeout)),
+        }
+    }
 
-            EXEC SP_EXECUTESQL @stmt
-            "#,
-            schema_name
+    async fn release_lock(&self, connection: &Connection) -> ConnectorResult<()> {
+        let sql = format!(
+            "EXEC sp_releaseapplock @Resource = '{}', @LockOwner = 'Session'",
+            MIGRATION_LOCK_NAME
         );
 
-        let drop_tables = format!(
-            r#"
-            DECLARE @stmt NVARCHAR(max)
-            DECLARE @n CHAR(1)
-
-            SET @n = CHAR(10)
+        connection.raw_cmd(&sql).await
+    }
 
-            SELECT @stmt = ISNULL(@stmt + @n, '') +
-                'DROP TABLE [' + SCHEMA_NAME(schema_id) + '].[' + name + ']'
-            FROM sys.tables
-            WHERE SCHEMA_NAME(schema_id) = '{0}'
+    async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
+        let connection_schema = connection.connection_info().schema_name();
+        let schemas: Vec<&str> = std::iter::once(connection_schema)
+            .chain(
+                self.1
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|schema| *schema != connection_schema),
+            )
+            .collect();
 
-            EXEC SP_EXECUTESQL @stmt
-            "#,
-            schema_name
-        );
+        // Foreign keys can point to tables in the other schemas, they are all dropped before the
+        // tables.
+        for schema_name in &schemas {
+            connection.raw_cmd(&render_drop_foreign_keys(schema_name)).await?;
+        }
 
-        connection.raw_cmd(&drop_fks).await?;
-        connection.raw_cmd(&drop_tables).await?;
+        for schema_name in &schemas {
+            connection.raw_cmd(&render_drop_tables(schema_name)).await?;
+        }
 
         Ok(())
     }
@@ -138,6 +176,11 @@ impl SqlFlavour for MssqlFlavour {
         SqlFamily::Mssql
     }
 
+    fn begin_transaction_statement(&self) -> &'static str {
+        // Without XACT_ABORT, a failing statement does not abort the rest of the batch.
+        "SET XACT_ABORT ON; BEGIN TRANSACTION"
+    }
+
     async fn ensure_connection_validity(&self, connection: &Connection) -> ConnectorResult<()> {
         connection.raw_cmd("SELECT 1").await?;
 
@@ -164,9 +207,98 @@ impl SqlFlavour for MssqlFlavour {
 
     async fn sql_schema_from_migration_history(
         &self,
-        _: &[MigrationDirectory],
-        _: &Connection,
+        migrations: &[MigrationDirectory],
+        connection: &Connection,
+        shadow_database_url: Option<&str>,
     ) -> ConnectorResult<SqlSchema> {
-        todo!("Needs the connection string crate, so leaving it unimplemented for now")
+        if let Some(shadow_database_url) = shadow_database_url {
+            tracing::debug!("Connecting to the shadow database at {}", shadow_database_url);
+
+            let shadow_database = connect(shadow_database_url).await?;
+
+            self.reset(&shadow_database).await?;
+            self.create_schemas(&shadow_database).await?;
+            apply_migration_scripts(migrations, &shadow_database).await?;
+
+            return self.describe_schema(&shadow_database).await;
+        }
+
+        // We can't build a connection string for another database from the
+        // parsed URL, so we switch databases on the current connection and
+        // switch back before dropping the temporary database.
+        let original_database = connection
+            .query_raw("SELECT DB_NAME()", &[])
+            .await?
+            .get(0)
+            .and_then(|row| row.at(0).and_then(|value| value.as_str().map(String::from)))
+            .ok_or_else(|| ConnectorError::generic(anyhow::anyhow!("Could not read the current database name.")))?;
+
+        let database_name = format!("prisma_migrations_shadow_database_{}", uuid::Uuid::new_v4());
+        let create_database = format!("CREATE DATABASE [{}]", database_name);
+        let drop_database = format!(
+            "USE [{original}];\nDROP DATABASE IF EXISTS [{temporary}];",
+            original = original_database,
+            temporary = database_name
+        );
+
+        connection.raw_cmd(&create_database).await?;
+
+        let guard = TemporaryDatabaseGuard::new(connection, drop_database);
+
+        let result = async {
+            connection.raw_cmd(&format!("USE [{}]", database_name)).await?;
+
+            self.create_schemas(connection).await?;
+            apply_migration_scripts(migrations, connection).await?;
+
+            self.describe_schema(connection).await
+        }
+        .await;
+
+        // Switch back and drop the temporary database before returning, on the error path too,
+        // so the connection is never left pointing at a database that is about to disappear.
+        let drop_result = guard.drop_database().await;
+        let sql_schema = result?;
+        drop_result?;
+
+        Ok(sql_schema)
     }
 }
+
+fn render_drop_foreign_keys(schema_name: &str) -> String {
+    format!(
+        r#"
+        DECLARE @stmt NVARCHAR(max)
+        DECLARE @n CHAR(1)
+
+        SET @n = CHAR(10)
+
+        SELECT @stmt = ISNULL(@stmt + @n, '') +
+            'ALTER TABLE [' + SCHEMA_NAME(schema_id) + '].[' + OBJECT_NAME(parent_object_id) + '] DROP CONSTRAINT [' + name + ']'
+        FROM sys.foreign_keys
+        WHERE SCHEMA_NAME(schema_id) = '{0}'
+
+        EXEC SP_EXECUTESQL @stmt
+        "#,
+        schema_name
+    )
+}
+
+fn render_drop_tables(schema_name: &str) -> String {
+    format!(
+        r#"
+        DECLARE @stmt NVARCHAR(max)
+        DECLARE @n CHAR(1)
+
+        SET @n = CHAR(10)
+
+        SELECT @stmt = ISNULL(@stmt + @n, '') +
+            'DROP TABLE [' + SCHEMA_NAME(schema_id) + '].[' + name + ']'
+        FROM sys.tables
+        WHERE SCHEMA_NAME(schema_id) = '{0}'
+
+        EXEC SP_EXECUTESQL @stmt
+        "#,
+        schema_name
+    )
+}
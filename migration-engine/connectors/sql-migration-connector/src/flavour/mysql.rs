@@ -1,4 +1,4 @@
-use super::SqlFlavour;
+use super::{apply_migration_scripts, SqlFlavour, TemporaryDatabaseGuard, MIGRATION_LOCK_NAME};
 use crate::{
     connect, connection_wrapper::Connection, database_info::DatabaseInfo, error::CheckDatabaseInfoResult,
     error::SystemDatabase,
@@ -8,6 +8,7 @@ use once_cell::sync::Lazy;
 use quaint::{connector::MysqlUrl, prelude::SqlFamily};
 use regex::RegexSet;
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend, SqlSchemaDescriberError};
+use std::time::Duration;
 use url::Url;
 
 #[derive(Debug)]
@@ -17,6 +18,15 @@ impl MysqlFlavour {
     pub(crate) fn schema_name(&self) -> &str {
         self.0.dbname()
     }
+
+    /// MySQL locks are server-wide, so the lock name includes the database name. Lock names are
+    /// limited to 64 characters.
+    fn migration_lock_name(&self) -> String {
+        format!("{}_{}", MIGRATION_LOCK_NAME, self.schema_name())
+            .chars()
+            .take(64)
+            .collect()
+    }
 }
 
 #[async_trait::async_trait]
@@ -112,6 +122,29 @@ impl SqlFlavour for MysqlFlavour {
         Ok(())
     }
 
+    async fn acquire_lock(&self, connection: &Connection, timeout: Duration) -> ConnectorResult<()> {
+        // GET_LOCK returns 1 if the lock was acquired, 0 if the timeout elapsed.
+        let result = connection
+            .query_raw(
+                "SELECT GET_LOCK(?, ?)",
+                &[self.migration_lock_name().into(), (timeout.as_secs() as i64).into()],
+            )
+            .await?;
+
+        match result.get(0).and_then(|row| row.at(0).and_then(|value| value.as_i64())) {
+            Some(1) => Ok(()),
+            _ => Err(ConnectorError::migration_lock_timeout(timeout)),
+        }
+    }
+
+    async fn release_lock(&self, connection: &Connection) -> ConnectorResult<()> {
+        connection
+            .query_raw("SELECT RELEASE_LOCK(?)", &[self.migration_lock_name().into()])
+            .await?;
+
+        Ok(())
+    }
+
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
         let db_name = connection.connection_info().dbname().unwrap();
 
@@ -131,7 +164,19 @@ impl SqlFlavour for MysqlFlavour {
         &self,
         migrations: &[MigrationDirectory],
         connection: &Connection,
+        shadow_database_url: Option<&str>,
     ) -> ConnectorResult<SqlSchema> {
+        if let Some(shadow_database_url) = shadow_database_url {
+            tracing::debug!("Connecting to the shadow database at {}", shadow_database_url);
+
+            let shadow_database = crate::connect(shadow_database_url).await?;
+
+            self.reset(&shadow_database).await?;
+            apply_migration_scripts(migrations, &shadow_database).await?;
+
+            return self.describe_schema(&shadow_database).await;
+        }
+
         let database_name = format!("prisma_shadow_db{}", uuid::Uuid::new_v4());
         let drop_database = format!("DROP DATABASE IF EXISTS `{}`", database_name);
         let create_database = format!("CREATE DATABASE `{}`", database_name);
@@ -139,30 +184,23 @@ impl SqlFlavour for MysqlFlavour {
         connection.raw_cmd(&drop_database).await?;
         connection.raw_cmd(&create_database).await?;
 
+        let guard = TemporaryDatabaseGuard::new(connection, drop_database);
+
         let mut temporary_database_url = self.0.url().clone();
         temporary_database_url.set_path(&format!("/{}", database_name));
         let temporary_database_url = temporary_database_url.to_string();
 
         tracing::debug!("Connecting to temporary database at {:?}", temporary_database_url);
 
-        let temp_database = crate::connect(&temporary_database_url).await?;
+        let sql_schema = {
+            let temp_database = crate::connect(&temporary_database_url).await?;
 
-        for migration in migrations {
-            let script = migration.read_migration_script()?;
+            apply_migration_scripts(migrations, &temp_database).await?;
 
-            tracing::debug!(
-                "Applying migration `{}` to temporary database.",
-                migration.migration_name()
-            );
+            self.describe_schema(&temp_database).await?
+        };
 
-            temp_database.raw_cmd(&script).await.map_err(|connector_error| {
-                connector_error.into_migration_failed(migration.migration_name().to_owned())
-            })?;
-        }
-
-        let sql_schema = self.describe_schema(&temp_database).await?;
-
-        connection.raw_cmd(&drop_database).await?;
+        guard.drop_database().await?;
 
         Ok(sql_schema)
     }
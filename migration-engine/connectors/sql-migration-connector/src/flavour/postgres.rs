@@ -1,13 +1,21 @@
-use super::SqlFlavour;
+use super::{apply_migration_scripts, poll_lock, SqlFlavour, TemporaryDatabaseGuard};
 use crate::{connect, connection_wrapper::Connection};
 use migration_connector::{ConnectorError, ConnectorResult, ErrorKind, MigrationDirectory};
 use quaint::{connector::PostgresUrl, prelude::SqlFamily};
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend, SqlSchemaDescriberError};
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 use url::Url;
 
+/// The key of the advisory lock taken while applying migrations. Advisory locks are scoped to the
+/// database.
+const MIGRATION_LOCK_KEY: i64 = 72707369;
+
 #[derive(Debug)]
-pub(crate) struct PostgresFlavour(pub(crate) PostgresUrl);
+pub(crate) struct PostgresFlavour(
+    pub(crate) PostgresUrl,
+    /// The schemas from the datasource's `schemas` property, if any.
+    pub(crate) Vec<String>,
+);
 
 impl PostgresFlavour {
     pub(crate) fn schema_name(&self) -> &str {
@@ -45,9 +53,11 @@ impl SqlFlavour for PostgresFlavour {
 
         let conn = connect(&url.to_string()).await?;
 
-        let schema_sql = format!("CREATE SCHEMA IF NOT EXISTS \"{}\";", &self.schema_name());
+        for schema in std::iter::once(self.schema_name()).chain(self.1.iter().map(String::as_str)) {
+            let schema_sql = format!("CREATE SCHEMA IF NOT EXISTS \"{}\";", schema);
 
-        conn.raw_cmd(&schema_sql).await?;
+            conn.raw_cmd(&schema_sql).await?;
+        }
 
         if let Some(err) = database_already_exists_error {
             return Err(err);
@@ -57,14 +67,19 @@ impl SqlFlavour for PostgresFlavour {
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
-        sql_schema_describer::postgres::SqlSchemaDescriber::new(connection.quaint().clone())
-            .describe(connection.connection_info().schema_name())
-            .await
-            .map_err(|err| match err {
-                SqlSchemaDescriberError::UnknownError => {
-                    ConnectorError::query_error(anyhow::anyhow!("An unknown error occurred in sql-schema-describer"))
-                }
-            })
+        let describer = sql_schema_describer::postgres::SqlSchemaDescriber::new(connection.quaint().clone());
+
+        let schema = if self.1.is_empty() {
+            describer.describe(connection.connection_info().schema_name()).await
+        } else {
+            describer.describe_schemas(&self.1).await
+        };
+
+        schema.map_err(|err| match err {
+            SqlSchemaDescriberError::UnknownError => {
+                ConnectorError::query_error(anyhow::anyhow!("An unknown error occurred in sql-schema-describer"))
+            }
+        })
     }
 
     async fn ensure_connection_validity(&self, connection: &Connection) -> ConnectorResult<()> {
@@ -138,6 +153,28 @@ impl SqlFlavour for PostgresFlavour {
         Ok(())
     }
 
+    async fn acquire_lock(&self, connection: &Connection, timeout: Duration) -> ConnectorResult<()> {
+        poll_lock(timeout, move || async move {
+            let result = connection
+                .query_raw("SELECT pg_try_advisory_lock($1)", &[MIGRATION_LOCK_KEY.into()])
+                .await?;
+
+            Ok(result
+                .get(0)
+                .and_then(|row| row.at(0).and_then(|value| value.as_bool()))
+                .unwrap_or(false))
+        })
+        .await
+    }
+
+    async fn release_lock(&self, connection: &Connection) -> ConnectorResult<()> {
+        connection
+            .query_raw("SELECT pg_advisory_unlock($1)", &[MIGRATION_LOCK_KEY.into()])
+            .await?;
+
+        Ok(())
+    }
+
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
         let schema_name = connection.connection_info().schema_name();
 
@@ -149,6 +186,15 @@ impl SqlFlavour for PostgresFlavour {
             .raw_cmd(&format!("CREATE SCHEMA \"{}\"", schema_name))
             .await?;
 
+        for schema in self.1.iter().filter(|schema| schema.as_str() != schema_name) {
+            connection
+                .raw_cmd(&format!(
+                    "DROP SCHEMA IF EXISTS \"{schema}\" CASCADE;\nCREATE SCHEMA \"{schema}\";",
+                    schema = schema
+                ))
+                .await?;
+        }
+
         Ok(())
     }
 
@@ -161,7 +207,27 @@ impl SqlFlavour for PostgresFlavour {
         &self,
         migrations: &[MigrationDirectory],
         connection: &Connection,
+        shadow_database_url: Option<&str>,
     ) -> ConnectorResult<SqlSchema> {
+        if let Some(shadow_database_url) = shadow_database_url {
+            tracing::debug!("Connecting to the shadow database at {}", shadow_database_url);
+
+            let shadow_database = crate::connect(shadow_database_url).await?;
+
+            for schema in std::iter::once(self.schema_name()).chain(self.1.iter().map(String::as_str)) {
+                shadow_database
+                    .raw_cmd(&format!(
+                        "DROP SCHEMA IF EXISTS \"{schema}\" CASCADE;\nCREATE SCHEMA \"{schema}\";",
+                        schema = schema
+                    ))
+                    .await?;
+            }
+
+            apply_migration_scripts(migrations, &shadow_database).await?;
+
+            return self.describe_schema(&shadow_database).await;
+        }
+
         let database_name = format!("prisma_migrations_shadow_database_{}", uuid::Uuid::new_v4());
         let drop_database = format!("DROP DATABASE IF EXISTS \"{}\"", database_name);
         let create_database = format!("CREATE DATABASE \"{}\"", database_name);
@@ -170,6 +236,8 @@ impl SqlFlavour for PostgresFlavour {
         connection.raw_cmd(&drop_database).await?;
         connection.raw_cmd(&create_database).await?;
 
+        let guard = TemporaryDatabaseGuard::new(connection, drop_database);
+
         let mut temporary_database_url = self.0.url().clone();
         temporary_database_url.set_path(&format!("/{}", database_name));
         let temporary_database_url = temporary_database_url.to_string();
@@ -181,25 +249,20 @@ impl SqlFlavour for PostgresFlavour {
 
             temporary_database.raw_cmd(&create_schema).await?;
 
-            for migration in migrations {
-                let script = migration.read_migration_script()?;
-
-                tracing::debug!(
-                    "Applying migration `{}` to temporary database.",
-                    migration.migration_name()
-                );
-
-                temporary_database.raw_cmd(&script).await.map_err(|connector_error| {
-                    connector_error.into_migration_failed(migration.migration_name().to_owned())
-                })?;
+            for schema in &self.1 {
+                temporary_database
+                    .raw_cmd(&format!("CREATE SCHEMA IF NOT EXISTS \"{}\"", schema))
+                    .await?;
             }
 
+            apply_migration_scripts(migrations, &temporary_database).await?;
+
             // the connection to the temporary database is dropped at the end of
             // the block.
             self.describe_schema(&temporary_database).await?
         };
 
-        connection.raw_cmd(&drop_database).await?;
+        guard.drop_database().await?;
 
         Ok(sql_schema)
     }
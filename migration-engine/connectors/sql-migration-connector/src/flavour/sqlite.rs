@@ -3,12 +3,20 @@ use crate::{connect, connection_wrapper::Connection};
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory};
 use quaint::prelude::SqlFamily;
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend, SqlSchemaDescriberError};
-use std::path::Path;
+use std::{path::Path, sync::Mutex, time::Duration};
 
 #[derive(Debug)]
 pub(crate) struct SqliteFlavour {
     pub(super) file_path: String,
     pub(super) attached_name: String,
+    /// The connection holding the migration lock, while it is held.
+    pub(super) lock_connection: Mutex<Option<Connection>>,
+}
+
+impl SqliteFlavour {
+    fn lock_file_path(&self) -> String {
+        format!("{}.migration-lock", self.file_path)
+    }
 }
 
 #[async_trait::async_trait]
@@ -73,6 +81,35 @@ impl SqlFlavour for SqliteFlavour {
         Ok(())
     }
 
+    async fn acquire_lock(&self, _connection: &Connection, timeout: Duration) -> ConnectorResult<()> {
+        // SQLite has no advisory locks, so we hold an exclusive transaction on a separate lock
+        // database next to the database file. The file lock goes away with the connection, so a
+        // crashed migration engine never leaves the lock behind.
+        let lock_connection = connect(&format!("file:{}", self.lock_file_path())).await?;
+
+        lock_connection
+            .query_raw(&format!("PRAGMA busy_timeout = {}", timeout.as_millis()), &[])
+            .await?;
+
+        lock_connection
+            .raw_cmd("BEGIN EXCLUSIVE")
+            .await
+            .map_err(|_| ConnectorError::migration_lock_timeout(timeout))?;
+
+        *self.lock_connection.lock().unwrap() = Some(lock_connection);
+
+        Ok(())
+    }
+
+    async fn release_lock(&self, _connection: &Connection) -> ConnectorResult<()> {
+        let lock_connection = self.lock_connection.lock().unwrap().take();
+
+        match lock_connection {
+            Some(lock_connection) => lock_connection.raw_cmd("COMMIT").await,
+            None => Ok(()),
+        }
+    }
+
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
         let file_path = connection.connection_info().file_path().unwrap();
 
@@ -90,6 +127,10 @@ impl SqlFlavour for SqliteFlavour {
         &self,
         migrations: &[MigrationDirectory],
         _connection: &Connection,
+        // SQLite databases are files: the temporary database lives in a
+        // temporary directory that is cleaned up on drop, so there is no need for
+        // a shadow database.
+        _shadow_database_url: Option<&str>,
     ) -> ConnectorResult<SqlSchema> {
         let temp_dir = tempfile::tempdir().expect("Failed to create temporary directory.");
         let database_url = format!(
@@ -12,14 +12,17 @@ mod flavour;
 mod sql_database_migration_inferrer;
 mod sql_database_step_applier;
 mod sql_destructive_change_checker;
+mod sql_diff;
 mod sql_imperative_migration_persistence;
 mod sql_migration_persistence;
 mod sql_renderer;
 mod sql_schema_calculator;
 mod sql_schema_differ;
+mod sql_script;
 
 use connection_wrapper::Connection;
 use error::quaint_error_to_connector_error;
+pub use sql_diff::{DiffFormat, DiffTarget};
 pub use sql_migration_persistence::MIGRATION_TABLE_NAME;
 
 use component::Component;
@@ -38,13 +41,33 @@ pub struct SqlMigrationConnector {
     connection: Connection,
     database_info: DatabaseInfo,
     flavour: Box<dyn SqlFlavour + Send + Sync + 'static>,
+    /// The database to reset and reuse when applying the migrations history, instead of creating
+    /// a temporary database.
+    shadow_database_url: Option<String>,
 }
 
 impl SqlMigrationConnector {
     pub async fn new(database_str: &str) -> ConnectorResult<Self> {
+        Self::new_with_schemas(database_str, Vec::new()).await
+    }
+
+    /// Construct a connector that manages the given database schemas, instead of only the schema
+    /// from the connection string.
+    pub async fn new_with_schemas(database_str: &str, schemas: Vec<String>) -> ConnectorResult<Self> {
+        Self::new_with_shadow_database(database_str, schemas, None).await
+    }
+
+    /// Construct a connector that applies the migrations history to the database at
+    /// `shadow_database_url`, when provided, instead of a temporary database it creates on the
+    /// server.
+    pub async fn new_with_shadow_database(
+        database_str: &str,
+        schemas: Vec<String>,
+        shadow_database_url: Option<String>,
+    ) -> ConnectorResult<Self> {
         let connection = connect(database_str).await?;
         let database_info = DatabaseInfo::new(connection.quaint(), connection.connection_info().clone()).await?;
-        let flavour = flavour::from_connection_info(database_info.connection_info());
+        let flavour = flavour::from_connection_info(database_info.connection_info(), schemas);
 
         flavour.check_database_info(&database_info)?;
         flavour.ensure_connection_validity(&connection).await?;
@@ -53,13 +76,14 @@ impl SqlMigrationConnector {
             flavour,
             database_info,
             connection,
+            shadow_database_url,
         })
     }
 
     pub async fn create_database(database_str: &str) -> ConnectorResult<String> {
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
-        let flavour = flavour::from_connection_info(&connection_info);
+        let flavour = flavour::from_connection_info(&connection_info, Vec::new());
         flavour.create_database(database_str).await
     }
 
@@ -67,7 +91,7 @@ impl SqlMigrationConnector {
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
 
-        let flavour = flavour::from_connection_info(&connection_info);
+        let flavour = flavour::from_connection_info(&connection_info, Vec::new());
 
         flavour.qe_setup(database_str).await
     }
@@ -111,6 +135,14 @@ impl MigrationConnector for SqlMigrationConnector {
         self.flavour.reset(self.conn()).await
     }
 
+    async fn acquire_lock(&self, timeout: std::time::Duration) -> ConnectorResult<()> {
+        self.flavour.acquire_lock(self.conn(), timeout).await
+    }
+
+    async fn release_lock(&self) -> ConnectorResult<()> {
+        self.flavour.release_lock(self.conn()).await
+    }
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(&self, datamodel: &datamodel::dml::Datamodel) -> Vec<MigrationError> {
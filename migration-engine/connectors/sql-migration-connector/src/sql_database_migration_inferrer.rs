@@ -1,5 +1,8 @@
 use crate::*;
-use crate::{sql_schema_calculator::SqlSchemaCalculator, sql_schema_differ::SqlSchemaDiffer};
+use crate::{
+    sql_schema_calculator::SqlSchemaCalculator,
+    sql_schema_differ::{RenameHints, SqlSchemaDiffer},
+};
 use datamodel::*;
 use migration_connector::steps::MigrationStep;
 use migration_connector::*;
@@ -29,6 +32,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
         Ok(infer(
             current_database_schema,
             expected_database_schema,
+            &SqlSchemaCalculator::rename_hints(next),
             self.database_info(),
             self.flavour(),
         ))
@@ -42,6 +46,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
         Ok(infer(
             current_database_schema,
             expected_database_schema,
+            &SqlSchemaCalculator::rename_hints(next),
             self.database_info(),
             self.flavour(),
         ))
@@ -60,6 +65,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
         Ok(infer(
             current_database_schema,
             expected_database_schema,
+            &SqlSchemaCalculator::rename_hints(next),
             self.database_info(),
             self.flavour(),
         ))
@@ -73,7 +79,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
     ) -> ConnectorResult<SqlMigration> {
         let current_database_schema = self
             .flavour()
-            .sql_schema_from_migration_history(previous_migrations, self.conn())
+            .sql_schema_from_migration_history(previous_migrations, self.conn(), self.shadow_database_url())
             .await?;
         let expected_database_schema =
             SqlSchemaCalculator::calculate(target_schema, self.database_info(), self.flavour());
@@ -81,6 +87,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
         Ok(infer(
             current_database_schema,
             expected_database_schema,
+            &SqlSchemaCalculator::rename_hints(target_schema),
             self.database_info(),
             self.flavour(),
         ))
@@ -89,7 +96,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
     async fn detect_drift(&self, applied_migrations: &[MigrationDirectory]) -> ConnectorResult<bool> {
         let expected_schema = self
             .flavour()
-            .sql_schema_from_migration_history(applied_migrations, self.conn())
+            .sql_schema_from_migration_history(applied_migrations, self.conn(), self.shadow_database_url())
             .await?;
 
         let actual_schema = self.describe().await?;
@@ -99,21 +106,86 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
 
         Ok(!diff.is_empty())
     }
+
+    async fn infer_baseline(&self) -> ConnectorResult<SqlMigration> {
+        let current_database_schema = self.describe().await?;
+
+        Ok(infer(
+            SqlSchema::empty(),
+            current_database_schema,
+            &RenameHints::default(),
+            self.database_info(),
+            self.flavour(),
+        ))
+    }
+
+    fn infer_reverse(&self, migration: &SqlMigration) -> SqlMigration {
+        // `migration.before` already has the renames of the migration applied, so we diff back to
+        // it, then revert the renames in the opposite order.
+        let mut steps = SqlSchemaDiffer::diff(
+            &migration.after,
+            &migration.before,
+            self.flavour(),
+            self.database_info(),
+        )
+        .into_steps();
+
+        steps.extend(migration.steps.iter().rev().filter_map(|step| match step {
+            SqlMigrationStep::RenameTable { name, new_name } => Some(SqlMigrationStep::RenameTable {
+                name: new_name.clone(),
+                new_name: name.clone(),
+            }),
+            SqlMigrationStep::RenameColumn { table, name, new_name } => Some(SqlMigrationStep::RenameColumn {
+                table: table.clone(),
+                name: new_name.clone(),
+                new_name: name.clone(),
+            }),
+            _ => None,
+        }));
+
+        SqlMigration {
+            before: migration.after.clone(),
+            after: migration.before.clone(),
+            steps,
+        }
+    }
+
+    fn infer_non_transactional(&self, mut migration: SqlMigration) -> SqlMigration {
+        // Enum value additions are already rendered as standalone `ALTER TYPE ... ADD VALUE`
+        // statements on PostgreSQL, which is what cannot run in a transaction block.
+        if !self.sql_family().is_postgres() {
+            return migration;
+        }
+
+        // Indexes on new tables are created along with the table, so they lock nothing.
+        for step in migration.steps.iter_mut() {
+            if let SqlMigrationStep::CreateIndex(create_index) = step {
+                create_index.concurrently = !create_index.caused_by_create_table;
+            }
+        }
+
+        migration
+    }
 }
 
-fn infer(
+pub(crate) fn infer(
     current_database_schema: SqlSchema,
     expected_database_schema: SqlSchema,
+    rename_hints: &RenameHints,
     database_info: &DatabaseInfo,
     flavour: &dyn SqlFlavour,
 ) -> SqlMigration {
-    let steps = SqlSchemaDiffer::diff(
-        &current_database_schema,
-        &expected_database_schema,
-        flavour,
-        &database_info,
-    )
-    .into_steps();
+    let (mut steps, current_database_schema) = rename_hints.apply(current_database_schema, &expected_database_schema);
+
+    steps.extend(
+        SqlSchemaDiffer::diff(
+            &current_database_schema,
+            &expected_database_schema,
+            flavour,
+            &database_info,
+        )
+        .into_steps(),
+    );
 
     SqlMigration {
         before: current_database_schema,
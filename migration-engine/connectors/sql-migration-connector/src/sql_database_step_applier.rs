@@ -1,12 +1,12 @@
 use crate::{
     database_info::DatabaseInfo,
-    sql_migration::{CreateTable, DropTable, SqlMigration, SqlMigrationStep},
+    sql_migration::{CreateTable, SqlMigration, SqlMigrationStep},
     sql_schema_differ::SqlSchemaDiffer,
     Component, SqlFlavour,
 };
 use migration_connector::{
     ConnectorResult, DatabaseMigrationMarker, DatabaseMigrationStepApplier, DestructiveChangeDiagnostics,
-    PrettyDatabaseMigrationStep,
+    PrettyDatabaseMigrationStep, TransactionMode,
 };
 use sql_schema_describer::{walkers::SqlSchemaExt, SqlSchema};
 
@@ -48,56 +48,13 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
     }
 
     fn render_script(&self, database_migration: &SqlMigration, diagnostics: &DestructiveChangeDiagnostics) -> String {
-        if database_migration.is_empty() {
-            return "-- This is an empty migration.".to_string();
-        }
-
-        let mut script = String::with_capacity(40 * database_migration.steps.len());
-
-        // Note: it would be much nicer if we could place the warnings next to
-        // the SQL for the steps that triggered them.
-        if diagnostics.has_warnings() || diagnostics.unexecutable_migrations.len() > 0 {
-            script.push_str("/*\n  Warnings:\n\n");
-
-            for warning in &diagnostics.warnings {
-                script.push_str("  - ");
-                script.push_str(&warning.description);
-                script.push_str("\n");
-            }
-
-            for unexecutable in &diagnostics.unexecutable_migrations {
-                script.push_str("  - ");
-                script.push_str(&unexecutable.description);
-                script.push_str("\n");
-            }
-
-            script.push_str("\n*/\n")
-        }
-
-        for step in &database_migration.steps {
-            let statements: Vec<String> = render_raw_sql(
-                step,
-                self.flavour(),
-                self.database_info(),
-                &database_migration.before,
-                &database_migration.after,
-            );
-
-            script.push_str("-- ");
-            script.push_str(step.description());
-            script.push_str("\n");
-
-            for statement in statements {
-                script.push_str(&statement);
-                script.push_str(";\n");
-            }
-        }
-
-        script
+        render_script(database_migration, diagnostics, self.flavour(), self.database_info())
     }
 
-    async fn apply_script(&self, script: &str) -> ConnectorResult<()> {
-        self.conn().raw_cmd(script).await
+    async fn apply_script(&self, script: &str, transaction_mode: TransactionMode) -> ConnectorResult<()> {
+        self.flavour()
+            .apply_migration_script(self.conn(), script, transaction_mode)
+            .await
     }
 }
 
@@ -129,6 +86,60 @@ impl SqlDatabaseStepApplier<'_> {
     }
 }
 
+pub(crate) fn render_script(
+    database_migration: &SqlMigration,
+    diagnostics: &DestructiveChangeDiagnostics,
+    flavour: &(dyn SqlFlavour + Send + Sync),
+    database_info: &DatabaseInfo,
+) -> String {
+    if database_migration.is_empty() {
+        return "-- This is an empty migration.".to_string();
+    }
+
+    let mut script = String::with_capacity(40 * database_migration.steps.len());
+
+    // Note: it would be much nicer if we could place the warnings next to
+    // the SQL for the steps that triggered them.
+    if diagnostics.has_warnings() || diagnostics.unexecutable_migrations.len() > 0 {
+        script.push_str("/*\n  Warnings:\n\n");
+
+        for warning in &diagnostics.warnings {
+            script.push_str("  - ");
+            script.push_str(&warning.description);
+            script.push_str("\n");
+        }
+
+        for unexecutable in &diagnostics.unexecutable_migrations {
+            script.push_str("  - ");
+            script.push_str(&unexecutable.description);
+            script.push_str("\n");
+        }
+
+        script.push_str("\n*/\n")
+    }
+
+    for step in &database_migration.steps {
+        let statements: Vec<String> = render_raw_sql(
+            step,
+            flavour,
+            database_info,
+            &database_migration.before,
+            &database_migration.after,
+        );
+
+        script.push_str("-- ");
+        script.push_str(step.description());
+        script.push_str("\n");
+
+        for statement in statements {
+            script.push_str(&statement);
+            script.push_str(";\n");
+        }
+    }
+
+    script
+}
+
 fn render_steps_pretty(
     database_migration: &SqlMigration,
     renderer: &(dyn SqlFlavour + Send + Sync),
@@ -179,8 +190,20 @@ fn render_raw_sql(
 
             vec![renderer.render_create_table(&table)]
         }
-        SqlMigrationStep::DropTable(DropTable { name }) => renderer.render_drop_table(name),
+        SqlMigrationStep::DropTable(drop_table) => renderer.render_drop_table(drop_table),
         SqlMigrationStep::RenameTable { name, new_name } => vec![renderer.render_rename_table(name, new_name)],
+        SqlMigrationStep::RenameColumn { table, name, new_name } => {
+            // Forward migrations rename before altering, so the column is in the previous schema
+            // with the renames applied. Reversed migrations rename last, so it is in the next one.
+            let column = current_schema
+                .table_walker(table)
+                .and_then(|table| table.column(new_name))
+                .or_else(|| next_schema.table_walker(table).and_then(|table| table.column(new_name)))
+                .ok_or_else(|| anyhow::anyhow!("RenameColumn referring to an unknown column: `{}`.", new_name))
+                .unwrap();
+
+            vec![renderer.render_rename_column(name, column)]
+        }
         SqlMigrationStep::AddForeignKey(add_foreign_key) => vec![renderer.render_add_foreign_key(add_foreign_key)],
         SqlMigrationStep::DropForeignKey(drop_foreign_key) => vec![renderer.render_drop_foreign_key(drop_foreign_key)],
         SqlMigrationStep::AlterTable(alter_table) => renderer.render_alter_table(alter_table, &differ),
@@ -189,5 +212,8 @@ fn render_raw_sql(
         SqlMigrationStep::AlterIndex(alter_index) => {
             renderer.render_alter_index(alter_index, database_info, current_schema)
         }
+        SqlMigrationStep::RenameForeignKey(rename_foreign_key) => {
+            renderer.render_rename_foreign_key(rename_foreign_key)
+        }
     }
 }
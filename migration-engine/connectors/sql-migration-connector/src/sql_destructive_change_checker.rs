@@ -2,6 +2,7 @@ mod check;
 mod database_inspection_results;
 mod destructive_change_checker_flavour;
 mod destructive_check_plan;
+mod script_lint;
 mod unexecutable_step_check;
 mod warning_check;
 
@@ -9,9 +10,11 @@ pub(crate) use destructive_change_checker_flavour::DestructiveChangeCheckerFlavo
 
 use crate::{
     sql_migration::{AlterEnum, CreateIndex, DropTable, SqlMigrationStep, TableChange},
+    sql_schema_calculator::SqlSchemaCalculator,
     sql_schema_differ::{ColumnDiffer, TableDiffer},
     Component, SqlMigration,
 };
+use datamodel::Datamodel;
 use destructive_check_plan::DestructiveCheckPlan;
 use migration_connector::{ConnectorResult, DestructiveChangeChecker, DestructiveChangeDiagnostics};
 use sql_schema_describer::{
@@ -169,14 +172,16 @@ impl SqlDestructiveChangeChecker<'_> {
                         }
                     }
                 }
-                SqlMigrationStep::DropTable(DropTable { name }) => {
+                SqlMigrationStep::DropTable(DropTable { name, schema: _ }) => {
                     self.check_table_drop(name, &mut plan, step_index);
                 }
                 SqlMigrationStep::CreateIndex(CreateIndex {
                     table,
+                    schema: _,
                     index,
                     caused_by_create_table: false,
                     contains_nullable_columns: _,
+                    concurrently: _,
                 }) if index.is_unique() => plan.push_warning(
                     SqlMigrationWarningCheck::UniqueConstraintAddition {
                         table: table.clone(),
@@ -236,4 +241,29 @@ impl DestructiveChangeChecker<SqlMigration> for SqlDestructiveChangeChecker<'_>
 
         plan.pure_check()
     }
+
+    async fn lint_script(
+        &self,
+        script: &str,
+        target_schema: &Datamodel,
+    ) -> ConnectorResult<DestructiveChangeDiagnostics> {
+        let target_schema = SqlSchemaCalculator::calculate(target_schema, self.database_info(), self.flavour());
+        let current_schema = self.describe().await?;
+
+        let (plan, spans) = script_lint::plan_script_lint(script, self.sql_family(), &target_schema, &current_schema);
+
+        // The tables the previous unapplied migrations create do not exist yet, so we only
+        // inspect the ones in the database.
+        let mut diagnostics = plan.execute_against_schema(self.conn(), Some(&current_schema)).await?;
+
+        for warning in diagnostics.warnings.iter_mut() {
+            warning.span = spans.get(warning.step_index).copied();
+        }
+
+        for unexecutable in diagnostics.unexecutable_migrations.iter_mut() {
+            unexecutable.span = spans.get(unexecutable.step_index).copied();
+        }
+
+        Ok(diagnostics)
+    }
 }
@@ -7,6 +7,9 @@ pub(super) struct DatabaseInspectionResults {
     row_counts: HashMap<String, i64>,
     /// HashMap from (table name, column name) to non-null values count.
     value_counts: HashMap<(Cow<'static, str>, Cow<'static, str>), i64>,
+    /// HashMap from (table name, column name) to the count of values that cannot be cast to the
+    /// next column type.
+    failing_cast_counts: HashMap<(String, String), i64>,
 }
 
 impl DatabaseInspectionResults {
@@ -27,6 +30,16 @@ impl DatabaseInspectionResults {
         )
     }
 
+    pub(super) fn get_failing_cast_count(&self, table: &str, column: &str) -> Option<i64> {
+        self.failing_cast_counts
+            .get(&(table.to_owned(), column.to_owned()))
+            .copied()
+    }
+
+    pub(super) fn set_failing_cast_count(&mut self, table: String, column: String, count: i64) {
+        self.failing_cast_counts.insert((table, column), count);
+    }
+
     pub(super) fn set_value_count(&mut self, table: Cow<'static, str>, column: Cow<'static, str>, count: i64) {
         self.value_counts.insert((table, column), count);
     }
@@ -3,11 +3,86 @@ mod mysql;
 mod postgres;
 mod sqlite;
 
-use super::DestructiveCheckPlan;
-use crate::sql_schema_differ::ColumnDiffer;
+use super::{warning_check::SqlMigrationWarningCheck, DestructiveCheckPlan};
+use crate::sql_schema_differ::{ColumnCast, ColumnDiffer};
+use sql_schema_describer::ColumnTypeFamily;
 
 /// Flavour-specific destructive change checks.
 pub(crate) trait DestructiveChangeCheckerFlavour {
     /// Check for potential destructive or unexecutable alter column steps.
     fn check_alter_column(&self, columns: &ColumnDiffer<'_>, plan: &mut DestructiveCheckPlan, step_index: usize);
+
+    /// A query returning the number of existing values in the column that cannot be cast to the
+    /// next column type, if the flavour can express it.
+    fn render_failing_cast_count(&self, columns: &ColumnDiffer<'_>) -> Option<String>;
+
+    /// Check a column type change that converts the existing values with a cast.
+    fn check_column_cast(&self, columns: &ColumnDiffer<'_>, plan: &mut DestructiveCheckPlan, step_index: usize) {
+        match columns.cast() {
+            ColumnCast::Safe => (),
+            ColumnCast::Risky => plan.push_warning(
+                SqlMigrationWarningCheck::RiskyCast {
+                    table: columns.previous.table().name().to_owned(),
+                    column: columns.previous.name().to_owned(),
+                    previous_type: render_type_name(columns.previous.column_type_family()),
+                    next_type: render_type_name(columns.next.column_type_family()),
+                    failing_values_query: self.render_failing_cast_count(columns),
+                },
+                step_index,
+            ),
+            ColumnCast::Impossible => plan.push_warning(
+                SqlMigrationWarningCheck::AlterColumn {
+                    table: columns.previous.table().name().to_owned(),
+                    column: columns.next.name().to_owned(),
+                },
+                step_index,
+            ),
+        }
+    }
+}
+
+/// The condition matching the values that cannot be cast to the next column type, for the casts
+/// that can be checked the same way on every database. `column` is the quoted column name.
+fn common_failing_cast_condition(columns: &ColumnDiffer<'_>, column: &dyn std::fmt::Display) -> Option<String> {
+    use ColumnTypeFamily::*;
+
+    match (columns.previous.column_type_family(), columns.next.column_type_family()) {
+        (String, Enum(_)) | (Enum(_), Enum(_)) => {
+            let next_enum = columns.next.column_type_family_as_enum()?;
+            let values: Vec<std::string::String> = next_enum
+                .values
+                .iter()
+                .map(|value| format!("'{}'", value.replace('\'', "''")))
+                .collect();
+
+            Some(format!("{} NOT IN ({})", column, values.join(", ")))
+        }
+        (BigInt, Int) => Some(format!("{} NOT BETWEEN -2147483648 AND 2147483647", column)),
+        (Float, Int) | (Float, BigInt) | (Decimal, Int) | (Decimal, BigInt) => {
+            Some(format!("{column} <> FLOOR({column})", column = column))
+        }
+        (Int, Boolean) => Some(format!("{} NOT IN (0, 1)", column)),
+        _ => None,
+    }
+}
+
+/// Wrap the condition matching the values that cannot be cast in a counting query.
+fn render_failing_cast_count_query(
+    table: &dyn std::fmt::Display,
+    column: &dyn std::fmt::Display,
+    condition: &str,
+) -> String {
+    format!(
+        "SELECT COUNT(*) FROM {table} WHERE {column} IS NOT NULL AND ({condition})",
+        table = table,
+        column = column,
+        condition = condition
+    )
+}
+
+fn render_type_name(family: &ColumnTypeFamily) -> String {
+    match family {
+        ColumnTypeFamily::Enum(name) => format!("the `{}` enum", name),
+        other => format!("{:?}", other),
+    }
 }
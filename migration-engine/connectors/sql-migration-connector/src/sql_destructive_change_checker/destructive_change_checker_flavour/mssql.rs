@@ -1,11 +1,82 @@
-use super::DestructiveChangeCheckerFlavour;
+use super::{common_failing_cast_condition, render_failing_cast_count_query, DestructiveChangeCheckerFlavour};
 use crate::{
-    flavour::MssqlFlavour, sql_destructive_change_checker::destructive_check_plan::DestructiveCheckPlan,
+    flavour::MssqlFlavour,
+    sql_destructive_change_checker::{
+        destructive_check_plan::DestructiveCheckPlan, unexecutable_step_check::UnexecutableStepCheck,
+        warning_check::SqlMigrationWarningCheck,
+    },
+    sql_migration::expanded_alter_column::{expand_mssql_alter_column, MssqlAlterColumn},
+    sql_renderer::SqlRenderer,
     sql_schema_differ::ColumnDiffer,
 };
+use sql_schema_describer::ColumnTypeFamily;
 
 impl DestructiveChangeCheckerFlavour for MssqlFlavour {
-    fn check_alter_column(&self, _: &ColumnDiffer<'_>, _: &mut DestructiveCheckPlan, _: usize) {
-        todo!("check_alter_column on MSSQL");
+    fn check_alter_column(&self, columns: &ColumnDiffer<'_>, plan: &mut DestructiveCheckPlan, step_index: usize) {
+        match expand_mssql_alter_column(columns) {
+            MssqlAlterColumn::Modify {
+                changes,
+                new_default: _,
+            } => {
+                if changes.arity_changed() && columns.next.arity().is_required() {
+                    plan.push_unexecutable(
+                        UnexecutableStepCheck::MadeOptionalFieldRequired {
+                            table: columns.previous.table().name().to_owned(),
+                            column: columns.previous.name().to_owned(),
+                        },
+                        step_index,
+                    );
+                }
+
+                if changes.type_changed() {
+                    self.check_column_cast(columns, plan, step_index);
+                }
+            }
+            MssqlAlterColumn::DropAndRecreate => {
+                if columns.next.arity().is_required() && columns.next.default().is_none() {
+                    plan.push_unexecutable(
+                        UnexecutableStepCheck::AddedRequiredFieldToTable {
+                            table: columns.previous.table().name().to_owned(),
+                            column: columns.previous.name().to_owned(),
+                        },
+                        step_index,
+                    );
+                } else {
+                    plan.push_warning(
+                        SqlMigrationWarningCheck::AlterColumn {
+                            table: columns.previous.table().name().to_owned(),
+                            column: columns.next.name().to_owned(),
+                        },
+                        step_index,
+                    );
+                }
+            }
+        }
+    }
+
+    fn render_failing_cast_count(&self, columns: &ColumnDiffer<'_>) -> Option<String> {
+        let column = self.quote(columns.previous.name());
+
+        let next_type = match columns.next.column_type_family() {
+            ColumnTypeFamily::Int => Some("int"),
+            ColumnTypeFamily::BigInt => Some("bigint"),
+            ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => Some("decimal(32,16)"),
+            ColumnTypeFamily::Boolean => Some("bit"),
+            ColumnTypeFamily::DateTime => Some("datetime2"),
+            _ => None,
+        };
+
+        let condition = match (columns.previous.column_type_family(), next_type) {
+            (ColumnTypeFamily::String, Some(next_type)) => format!("TRY_CAST({} AS {}) IS NULL", column, next_type),
+            _ => common_failing_cast_condition(columns, &column)?,
+        };
+
+        let table = format!(
+            "{}.{}",
+            self.quote(self.schema_name()),
+            self.quote(columns.previous.table().name())
+        );
+
+        Some(render_failing_cast_count_query(&table, &column, &condition))
     }
 }
@@ -1,4 +1,4 @@
-use super::DestructiveChangeCheckerFlavour;
+use super::{common_failing_cast_condition, render_failing_cast_count_query, DestructiveChangeCheckerFlavour};
 use crate::{
     flavour::MysqlFlavour,
     sql_destructive_change_checker::{
@@ -6,8 +6,10 @@ use crate::{
         warning_check::SqlMigrationWarningCheck,
     },
     sql_migration::expanded_alter_column::{expand_mysql_alter_column, MysqlAlterColumn},
+    sql_renderer::SqlRenderer,
     sql_schema_differ::ColumnDiffer,
 };
+use sql_schema_describer::ColumnTypeFamily;
 
 impl DestructiveChangeCheckerFlavour for MysqlFlavour {
     fn check_alter_column(&self, columns: &ColumnDiffer<'_>, plan: &mut DestructiveCheckPlan, step_index: usize) {
@@ -37,6 +39,13 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
                     return;
                 }
 
+                // MODIFY converts the existing values like a CAST would.
+                if columns.all_changes().only_type_changed() {
+                    self.check_column_cast(columns, plan, step_index);
+
+                    return;
+                }
+
                 plan.push_warning(
                     SqlMigrationWarningCheck::AlterColumn {
                         table: columns.previous.table().name().to_owned(),
@@ -45,8 +54,58 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
                     step_index,
                 );
             }
+
+            MysqlAlterColumn::DropAndRecreate => {
+                if columns.next.arity().is_required() && columns.next.default().is_none() {
+                    plan.push_unexecutable(
+                        UnexecutableStepCheck::AddedRequiredFieldToTable {
+                            table: columns.previous.table().name().to_owned(),
+                            column: columns.previous.name().to_owned(),
+                        },
+                        step_index,
+                    );
+                } else {
+                    plan.push_warning(
+                        SqlMigrationWarningCheck::AlterColumn {
+                            table: columns.previous.table().name().to_owned(),
+                            column: columns.next.name().to_owned(),
+                        },
+                        step_index,
+                    );
+                }
+            }
         }
     }
+
+    fn render_failing_cast_count(&self, columns: &ColumnDiffer<'_>) -> Option<String> {
+        let column = self.quote(columns.previous.name());
+
+        let condition = match (columns.previous.column_type_family(), columns.next.column_type_family()) {
+            (ColumnTypeFamily::String, ColumnTypeFamily::Int)
+            | (ColumnTypeFamily::String, ColumnTypeFamily::BigInt) => {
+                format!("{} NOT REGEXP '^[[:space:]]*[-+]?[0-9]+[[:space:]]*$'", column)
+            }
+            (ColumnTypeFamily::String, ColumnTypeFamily::Float)
+            | (ColumnTypeFamily::String, ColumnTypeFamily::Decimal) => format!(
+                "{} NOT REGEXP '^[[:space:]]*[-+]?([0-9]+[.]?[0-9]*|[.][0-9]+)([eE][-+]?[0-9]+)?[[:space:]]*$'",
+                column
+            ),
+            (ColumnTypeFamily::String, ColumnTypeFamily::Boolean) => format!("TRIM({}) NOT IN ('0', '1')", column),
+            (ColumnTypeFamily::String, ColumnTypeFamily::DateTime) => {
+                format!("CAST({} AS DATETIME(3)) IS NULL", column)
+            }
+            (ColumnTypeFamily::String, ColumnTypeFamily::Json) => format!("JSON_VALID({}) = 0", column),
+            _ => common_failing_cast_condition(columns, &column)?,
+        };
+
+        let table = format!(
+            "{}.{}",
+            self.quote(self.schema_name()),
+            self.quote(columns.previous.table().name())
+        );
+
+        Some(render_failing_cast_count_query(&table, &column, &condition))
+    }
 }
 
 /// If the type change is an enum change, diagnose it, and return whether it _was_ an enum change.
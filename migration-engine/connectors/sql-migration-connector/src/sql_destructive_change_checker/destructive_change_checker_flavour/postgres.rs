@@ -1,4 +1,4 @@
-use super::DestructiveChangeCheckerFlavour;
+use super::{common_failing_cast_condition, render_failing_cast_count_query, DestructiveChangeCheckerFlavour};
 use crate::{
     flavour::PostgresFlavour,
     sql_destructive_change_checker::{
@@ -6,9 +6,10 @@ use crate::{
         warning_check::SqlMigrationWarningCheck,
     },
     sql_migration::expanded_alter_column::{expand_postgres_alter_column, PostgresAlterColumn},
+    sql_renderer::SqlRenderer,
     sql_schema_differ::ColumnDiffer,
 };
-use sql_schema_describer::{ColumnArity, DefaultValue};
+use sql_schema_describer::{ColumnArity, ColumnTypeFamily, DefaultValue};
 
 impl DestructiveChangeCheckerFlavour for PostgresFlavour {
     fn check_alter_column(&self, columns: &ColumnDiffer<'_>, plan: &mut DestructiveCheckPlan, step_index: usize) {
@@ -46,6 +47,7 @@ impl DestructiveChangeCheckerFlavour for PostgresFlavour {
                             );
                         }
                     }
+                    PostgresAlterColumn::Cast(_) => self.check_column_cast(columns, plan, step_index),
                     PostgresAlterColumn::SetDefault(_)
                     | PostgresAlterColumn::AddSequence
                     | PostgresAlterColumn::DropDefault
@@ -78,6 +80,39 @@ impl DestructiveChangeCheckerFlavour for PostgresFlavour {
             }
         }
     }
+
+    fn render_failing_cast_count(&self, columns: &ColumnDiffer<'_>) -> Option<String> {
+        let column = self.quote(columns.previous.name());
+
+        let condition = match (columns.previous.column_type_family(), columns.next.column_type_family()) {
+            (ColumnTypeFamily::String, ColumnTypeFamily::Int)
+            | (ColumnTypeFamily::String, ColumnTypeFamily::BigInt) => {
+                format!(r#"{} !~ '^\s*[-+]?[0-9]+\s*$'"#, column)
+            }
+            (ColumnTypeFamily::String, ColumnTypeFamily::Float)
+            | (ColumnTypeFamily::String, ColumnTypeFamily::Decimal) => format!(
+                r#"{} !~ '^\s*[-+]?([0-9]+\.?[0-9]*|\.[0-9]+)([eE][-+]?[0-9]+)?\s*$'"#,
+                column
+            ),
+            (ColumnTypeFamily::String, ColumnTypeFamily::Boolean) => format!(
+                "lower(trim({})) NOT IN ('t', 'true', 'y', 'yes', 'on', '1', 'f', 'false', 'n', 'no', 'off', '0')",
+                column
+            ),
+            (ColumnTypeFamily::String, ColumnTypeFamily::Uuid) => format!(
+                r#"{} !~* '^\{{?[0-9a-f]{{8}}-?[0-9a-f]{{4}}-?[0-9a-f]{{4}}-?[0-9a-f]{{4}}-?[0-9a-f]{{12}}\}}?$'"#,
+                column
+            ),
+            _ => common_failing_cast_condition(columns, &column)?,
+        };
+
+        let table = format!(
+            "{}.{}",
+            self.quote(self.schema_name()),
+            self.quote(columns.previous.table().name())
+        );
+
+        Some(render_failing_cast_count_query(&table, &column, &condition))
+    }
 }
 
 fn default_can_be_rendered(default: Option<&DefaultValue>) -> bool {
@@ -1,13 +1,14 @@
-use super::DestructiveChangeCheckerFlavour;
+use super::{common_failing_cast_condition, render_failing_cast_count_query, DestructiveChangeCheckerFlavour};
 use crate::{
     flavour::SqliteFlavour,
     sql_destructive_change_checker::{
         destructive_check_plan::DestructiveCheckPlan, unexecutable_step_check::UnexecutableStepCheck,
         warning_check::SqlMigrationWarningCheck,
     },
+    sql_renderer::SqlRenderer,
     sql_schema_differ::ColumnDiffer,
 };
-use sql_schema_describer::ColumnArity;
+use sql_schema_describer::{ColumnArity, ColumnTypeFamily};
 
 impl DestructiveChangeCheckerFlavour for SqliteFlavour {
     fn check_alter_column(&self, columns: &ColumnDiffer<'_>, plan: &mut DestructiveCheckPlan, step_index: usize) {
@@ -22,7 +23,12 @@ impl DestructiveChangeCheckerFlavour for SqliteFlavour {
             (ColumnArity::List, _) | (_, ColumnArity::List) => unreachable!(),
         };
 
-        if !columns.all_changes().type_changed() && arity_change_is_safe {
+        if arity_change_is_safe {
+            // The values are copied to the redefined table with a CAST.
+            if columns.all_changes().type_changed() {
+                self.check_column_cast(columns, plan, step_index);
+            }
+
             return;
         }
 
@@ -47,4 +53,29 @@ impl DestructiveChangeCheckerFlavour for SqliteFlavour {
             step_index,
         );
     }
+
+    fn render_failing_cast_count(&self, columns: &ColumnDiffer<'_>) -> Option<String> {
+        let column = self.quote(columns.previous.name());
+
+        // CAST never fails on SQLite, but it turns the text it cannot convert into 0.
+        let condition = match (columns.previous.column_type_family(), columns.next.column_type_family()) {
+            (ColumnTypeFamily::String, ColumnTypeFamily::Int)
+            | (ColumnTypeFamily::String, ColumnTypeFamily::BigInt)
+            | (ColumnTypeFamily::String, ColumnTypeFamily::DateTime) => format!(
+                "trim({column}) = '' OR trim({column}) GLOB '?*[^0-9]*' OR trim({column}) GLOB '[^0-9+-]*'",
+                column = column
+            ),
+            (ColumnTypeFamily::String, ColumnTypeFamily::Float)
+            | (ColumnTypeFamily::String, ColumnTypeFamily::Decimal) => format!(
+                "trim({column}) = '' OR trim({column}) GLOB '*[^0-9.eE+-]*'",
+                column = column
+            ),
+            (ColumnTypeFamily::String, ColumnTypeFamily::Boolean) => format!("trim({}) NOT IN ('0', '1')", column),
+            _ => common_failing_cast_condition(columns, &column)?,
+        };
+
+        let table = self.quote(columns.previous.table().name());
+
+        Some(render_failing_cast_count_query(&table, &column, &condition))
+    }
 }
@@ -6,6 +6,7 @@ use crate::connection_wrapper::Connection;
 use migration_connector::{
     ConnectorError, ConnectorResult, DestructiveChangeDiagnostics, MigrationWarning, UnexecutableMigration,
 };
+use sql_schema_describer::SqlSchema;
 use std::time::Duration;
 use tokio::time::{timeout, Elapsed};
 
@@ -42,15 +43,29 @@ impl DestructiveCheckPlan {
     /// For example, dropping a table that has 0 rows can be considered safe.
     #[tracing::instrument(skip(conn), level = "debug")]
     pub(super) async fn execute(&self, conn: &Connection) -> ConnectorResult<DestructiveChangeDiagnostics> {
+        self.execute_against_schema(conn, None).await
+    }
+
+    /// Like `execute`, but only inspect the tables and columns that exist in
+    /// `current_schema`. The checks on the others are evaluated as if the
+    /// database could not be inspected.
+    #[tracing::instrument(skip(conn, current_schema), level = "debug")]
+    pub(super) async fn execute_against_schema(
+        &self,
+        conn: &Connection,
+        current_schema: Option<&SqlSchema>,
+    ) -> ConnectorResult<DestructiveChangeDiagnostics> {
         let mut results = DatabaseInspectionResults::default();
 
         let inspection = async {
             for (unexecutable, _idx) in &self.unexecutable_migrations {
-                self.inspect_for_check(unexecutable, &mut results, conn).await?;
+                self.inspect_for_check(unexecutable, &mut results, conn, current_schema)
+                    .await?;
             }
 
             for (warning, _idx) in &self.warnings {
-                self.inspect_for_check(warning, &mut results, conn).await?;
+                self.inspect_for_check(warning, &mut results, conn, current_schema)
+                    .await?;
             }
 
             Ok::<(), ConnectorError>(())
@@ -69,6 +84,7 @@ impl DestructiveCheckPlan {
                 diagnostics.unexecutable_migrations.push(UnexecutableMigration {
                     description: message,
                     step_index: *step_index,
+                    span: None,
                 })
             }
         }
@@ -78,6 +94,7 @@ impl DestructiveCheckPlan {
                 diagnostics.warnings.push(MigrationWarning {
                     description: message,
                     step_index: *step_index,
+                    span: None,
                 })
             }
         }
@@ -91,21 +108,47 @@ impl DestructiveCheckPlan {
         check: &(dyn Check + Send + Sync + 'static),
         results: &mut DatabaseInspectionResults,
         conn: &Connection,
+        current_schema: Option<&SqlSchema>,
     ) -> ConnectorResult<()> {
-        if let Some(table) = check.needed_table_row_count() {
+        let table_exists = |table: &str| current_schema.map(|schema| schema.has_table(table)).unwrap_or(true);
+        let column_exists = |table: &str, column: &str| {
+            current_schema
+                .map(|schema| {
+                    schema
+                        .get_table(table)
+                        .map(|table| table.has_column(column))
+                        .unwrap_or(false)
+                })
+                .unwrap_or(true)
+        };
+
+        if let Some(table) = check.needed_table_row_count().filter(|table| table_exists(table)) {
             if results.get_row_count(table).is_none() {
                 let count = count_rows_in_table(table, conn).await?;
                 results.set_row_count(table.to_owned(), count)
             }
         }
 
-        if let Some((table, column)) = check.needed_column_value_count() {
+        if let Some((table, column)) = check
+            .needed_column_value_count()
+            .filter(|(table, column)| column_exists(table, column))
+        {
             if let (_, None) = results.get_row_and_non_null_value_count(table, column) {
                 let count = count_values_in_column(column, table, conn).await?;
                 results.set_value_count(table.to_owned().into(), column.to_owned().into(), count);
             }
         }
 
+        if let Some((table, column, query)) = check
+            .needed_failing_cast_count()
+            .filter(|(table, column, _)| column_exists(table, column))
+        {
+            if results.get_failing_cast_count(table, column).is_none() {
+                let count = count_failing_cast_values(query, conn).await?;
+                results.set_failing_cast_count(table.to_owned(), column.to_owned(), count);
+            }
+        }
+
         Ok(())
     }
 
@@ -123,6 +166,7 @@ impl DestructiveCheckPlan {
                 diagnostics.unexecutable_migrations.push(UnexecutableMigration {
                     description: message,
                     step_index: *step_index,
+                    span: None,
                 })
             }
         }
@@ -132,6 +176,7 @@ impl DestructiveCheckPlan {
                 diagnostics.warnings.push(MigrationWarning {
                     description: message,
                     step_index: *step_index,
+                    span: None,
                 })
             }
         }
@@ -188,3 +233,18 @@ async fn count_values_in_column(column_name: &str, table: &str, conn: &Connectio
 
     Ok(values_count)
 }
+
+async fn count_failing_cast_values(query: &str, conn: &Connection) -> ConnectorResult<i64> {
+    let result_set = conn.query_raw(query, &[]).await?;
+
+    result_set
+        .first()
+        .as_ref()
+        .and_then(|row| row.at(0))
+        .and_then(|count| count.as_i64())
+        .ok_or_else(|| {
+            ConnectorError::generic(anyhow::anyhow!(
+                "Unexpected result set shape when checking values that cannot be cast."
+            ))
+        })
+}
@@ -0,0 +1,386 @@
+//! Linting of migration scripts as they are in the migrations directory, possibly edited by hand.
+//! We do not parse SQL: the statements are matched against the shapes of the risky operations we
+//! know about, and everything else is ignored.
+
+use super::{
+    destructive_check_plan::DestructiveCheckPlan, unexecutable_step_check::UnexecutableStepCheck,
+    warning_check::SqlMigrationWarningCheck,
+};
+use crate::sql_script::split_statements_with_offsets;
+use migration_connector::ScriptSpan;
+use once_cell::sync::Lazy;
+use quaint::prelude::SqlFamily;
+use regex::Regex;
+use sql_schema_describer::SqlSchema;
+use std::collections::HashSet;
+
+/// A possibly quoted identifier.
+const IDENTIFIER: &str = r#"(?:"[^"]+"|`[^`]+`|\[[^\]]+\]|[\w$]+)"#;
+
+static LAST_IDENTIFIER_RE: Lazy<Regex> = Lazy::new(|| Regex::new(&format!("{}$", IDENTIFIER)).unwrap());
+
+static CREATE_TABLE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r#"(?is)^CREATE\s+(?:TEMP\s+|TEMPORARY\s+)?TABLE\s+(?:IF\s+NOT\s+EXISTS\s+)?(?P<table>{id}(?:\.{id})?)"#,
+        id = IDENTIFIER
+    ))
+    .unwrap()
+});
+
+static ALTER_TABLE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r#"(?is)^ALTER\s+TABLE\s+(?:IF\s+EXISTS\s+)?(?:ONLY\s+)?(?P<table>{id}(?:\.{id})?)\s+(?P<clauses>.*)$"#,
+        id = IDENTIFIER
+    ))
+    .unwrap()
+});
+
+static DROP_TABLE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r#"(?is)^DROP\s+TABLE\s+(?:IF\s+EXISTS\s+)?(?P<table>{id}(?:\.{id})?)\s*(?:CASCADE|RESTRICT)?$"#,
+        id = IDENTIFIER
+    ))
+    .unwrap()
+});
+
+static ADD_COLUMN_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r#"(?is)^ADD\s+(?:COLUMN\s+)?(?:IF\s+NOT\s+EXISTS\s+)?(?P<column>{id})\s+(?P<definition>.*)$"#,
+        id = IDENTIFIER
+    ))
+    .unwrap()
+});
+
+static DROP_COLUMN_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r#"(?is)^DROP\s+(?:COLUMN\s+)?(?:IF\s+EXISTS\s+)?(?P<column>{id})\s*(?:CASCADE|RESTRICT)?$"#,
+        id = IDENTIFIER
+    ))
+    .unwrap()
+});
+
+static POSTGRES_TYPE_CHANGE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r#"(?is)^ALTER\s+(?:COLUMN\s+)?(?P<column>{id})\s+(?:SET\s+DATA\s+)?TYPE\b"#,
+        id = IDENTIFIER
+    ))
+    .unwrap()
+});
+
+static MYSQL_TYPE_CHANGE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r#"(?is)^(?:MODIFY|CHANGE)\s+(?:COLUMN\s+)?(?P<column>{id})\s"#,
+        id = IDENTIFIER
+    ))
+    .unwrap()
+});
+
+/// `CHANGE` renames the column and restates its definition, starting with its type.
+static MYSQL_CHANGE_COLUMN_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r#"(?is)^CHANGE\s+(?:COLUMN\s+)?(?P<column>{id})\s+{id}\s+(?P<type>\w+(?:\s*\([^)]*\))?(?:\s+UNSIGNED)?)"#,
+        id = IDENTIFIER
+    ))
+    .unwrap()
+});
+
+/// The display width of the integer types, that MySQL 8 leaves out of the introspected types.
+static MYSQL_INTEGER_DISPLAY_WIDTH_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^(tinyint|smallint|mediumint|int|integer|bigint)\(\d+\)"#).unwrap());
+
+static MSSQL_ALTER_COLUMN_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r#"(?is)^ALTER\s+COLUMN\s+(?P<column>{id})\s+(?P<next_word>\w+)"#,
+        id = IDENTIFIER
+    ))
+    .unwrap()
+});
+
+static CREATE_INDEX_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r#"(?is)^CREATE\s+(?:UNIQUE\s+)?(?:CLUSTERED\s+|NONCLUSTERED\s+)?INDEX\s+(?P<concurrently>CONCURRENTLY\s+)?(?:IF\s+NOT\s+EXISTS\s+)?(?P<index>{id})\s+ON\s+(?:ONLY\s+)?(?P<table>{id}(?:\.{id})?)"#,
+        id = IDENTIFIER
+    ))
+    .unwrap()
+});
+
+/// The table copy in the statements the SQLite connector renders to redefine a table.
+static SQLITE_TABLE_COPY_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r#"(?is)^INSERT\s+INTO\s+(?P<new_table>{id})\s*\(.*\)\s*SELECT\s.*\sFROM\s+(?P<table>{id})\s*$"#,
+        id = IDENTIFIER
+    ))
+    .unwrap()
+});
+
+static NOT_NULL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?i)\bNOT\s+NULL\b"#).unwrap());
+
+/// Column definitions that provide values for the existing rows without a `DEFAULT`.
+static IMPLICIT_DEFAULT_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)\b(DEFAULT|SERIAL|BIGSERIAL|SMALLSERIAL|GENERATED|AUTO_INCREMENT|IDENTITY)\b"#).unwrap()
+});
+
+/// The keywords that follow `ADD` and `DROP` in the clauses that are not about columns.
+const NON_COLUMN_KEYWORDS: &[&str] = &[
+    "CHECK",
+    "CONSTRAINT",
+    "DEFAULT",
+    "FOREIGN",
+    "FULLTEXT",
+    "INDEX",
+    "KEY",
+    "PRIMARY",
+    "SPATIAL",
+    "UNIQUE",
+];
+
+/// Plan the checks for a migration script. The step indexes in the plan are the indexes of the
+/// statements, and the returned spans are the lines of each statement.
+///
+/// `target_schema` is the schema the Prisma schema describes, to detect the columns that are
+/// dropped while they are still used. `current_schema` is the schema of the database, to tell the
+/// column renamings from the type changes.
+pub(super) fn plan_script_lint(
+    script: &str,
+    sql_family: SqlFamily,
+    target_schema: &SqlSchema,
+    current_schema: &SqlSchema,
+) -> (DestructiveCheckPlan, Vec<ScriptSpan>) {
+    let statements_with_offsets: Vec<(usize, &str)> = split_statements_with_offsets(script, sql_family)
+        .into_iter()
+        .map(|(offset, statement)| {
+            let comments_len = leading_comments_len(statement);
+
+            (offset + comments_len, &statement[comments_len..])
+        })
+        .collect();
+
+    let spans = statements_with_offsets
+        .iter()
+        .map(|(offset, statement)| statement_span(script, *offset, statement))
+        .collect();
+
+    let statements: Vec<&str> = statements_with_offsets
+        .into_iter()
+        .map(|(_, statement)| statement)
+        .collect();
+
+    // Checks on tables created by the script itself are pointless, they are empty.
+    let created_tables: HashSet<String> = statements
+        .iter()
+        .filter_map(|statement| CREATE_TABLE_RE.captures(statement))
+        .map(|captures| unquote_identifier(&captures["table"]))
+        .collect();
+
+    let mut added_columns: HashSet<(String, String)> = HashSet::new();
+    let mut dropped_columns: Vec<(String, String, usize)> = Vec::new();
+    let mut plan = DestructiveCheckPlan::new();
+
+    for (step_index, statement) in statements.iter().enumerate() {
+        if let Some(captures) = ALTER_TABLE_RE.captures(statement) {
+            let table = unquote_identifier(&captures["table"]);
+            let table_is_new = created_tables.contains(&table);
+
+            for clause in split_clauses(&captures["clauses"]) {
+                if let Some(captures) = ADD_COLUMN_RE.captures(clause).filter(is_column_clause) {
+                    let column = unquote_identifier(&captures["column"]);
+                    let definition = &captures["definition"];
+
+                    if !table_is_new && NOT_NULL_RE.is_match(definition) && !IMPLICIT_DEFAULT_RE.is_match(definition) {
+                        plan.push_unexecutable(
+                            UnexecutableStepCheck::AddedRequiredFieldToTable {
+                                table: table.clone(),
+                                column: column.clone(),
+                            },
+                            step_index,
+                        );
+                    }
+
+                    added_columns.insert((table.clone(), column));
+                } else if let Some(captures) = DROP_COLUMN_RE.captures(clause).filter(is_column_clause) {
+                    let column = unquote_identifier(&captures["column"]);
+
+                    if !table_is_new {
+                        plan.push_warning(
+                            SqlMigrationWarningCheck::NonEmptyColumnDrop {
+                                table: table.clone(),
+                                column: column.clone(),
+                            },
+                            step_index,
+                        );
+                    }
+
+                    dropped_columns.push((table.clone(), column, step_index));
+                } else if !table_is_new && is_type_change(clause, &table, sql_family, current_schema) {
+                    plan.push_warning(
+                        SqlMigrationWarningCheck::TableRewrite { table: table.clone() },
+                        step_index,
+                    );
+                }
+            }
+        } else if let Some(captures) = DROP_TABLE_RE.captures(statement) {
+            let table = unquote_identifier(&captures["table"]);
+
+            // SQLite table redefinitions drop the table after copying it to `new_<table>`.
+            if !created_tables.contains(&format!("new_{}", table)) {
+                plan.push_warning(SqlMigrationWarningCheck::NonEmptyTableDrop { table }, step_index);
+            }
+        } else if let Some(captures) = CREATE_INDEX_RE.captures(statement) {
+            let table = unquote_identifier(&captures["table"]);
+
+            if sql_family.is_postgres() && captures.name("concurrently").is_none() && !created_tables.contains(&table) {
+                plan.push_warning(
+                    SqlMigrationWarningCheck::BlockingIndexCreation {
+                        table,
+                        index: unquote_identifier(&captures["index"]),
+                    },
+                    step_index,
+                );
+            }
+        } else if let Some(captures) = SQLITE_TABLE_COPY_RE.captures(statement) {
+            let table = unquote_identifier(&captures["table"]);
+
+            if unquote_identifier(&captures["new_table"]) == format!("new_{}", table) {
+                plan.push_warning(SqlMigrationWarningCheck::TableRewrite { table }, step_index);
+            }
+        }
+    }
+
+    // A column that is dropped then added back, for example to change its type, is not gone.
+    for (table, column, step_index) in dropped_columns {
+        let still_in_schema = target_schema
+            .get_table(&table)
+            .map(|table| table.has_column(&column))
+            .unwrap_or(false);
+
+        if still_in_schema && !added_columns.contains(&(table.clone(), column.clone())) {
+            plan.push_warning(
+                SqlMigrationWarningCheck::DroppedColumnStillInSchema { table, column },
+                step_index,
+            );
+        }
+    }
+
+    (plan, spans)
+}
+
+fn is_column_clause(captures: &regex::Captures<'_>) -> bool {
+    let column = &captures["column"];
+
+    !NON_COLUMN_KEYWORDS
+        .iter()
+        .any(|keyword| keyword.eq_ignore_ascii_case(column))
+}
+
+fn is_type_change(clause: &str, table: &str, sql_family: SqlFamily, current_schema: &SqlSchema) -> bool {
+    match sql_family {
+        SqlFamily::Postgres => POSTGRES_TYPE_CHANGE_RE.is_match(clause),
+        // A `CHANGE` that restates the current type only renames the column.
+        SqlFamily::Mysql => match MYSQL_CHANGE_COLUMN_RE.captures(clause) {
+            Some(captures) => current_schema
+                .get_table(table)
+                .and_then(|table| table.column(&unquote_identifier(&captures["column"])))
+                .map(|column| {
+                    normalize_mysql_type(&captures["type"]) != normalize_mysql_type(&column.tpe.full_data_type)
+                })
+                .unwrap_or(true),
+            None => MYSQL_TYPE_CHANGE_RE.is_match(clause),
+        },
+        // `ALTER COLUMN` is followed by the new column definition, except for the `ADD` and
+        // `DROP` column property clauses.
+        SqlFamily::Mssql => MSSQL_ALTER_COLUMN_RE
+            .captures(clause)
+            .map(|captures| {
+                let next_word = &captures["next_word"];
+                !next_word.eq_ignore_ascii_case("ADD") && !next_word.eq_ignore_ascii_case("DROP")
+            })
+            .unwrap_or(false),
+        SqlFamily::Sqlite => false,
+    }
+}
+
+/// Lowercase the type and remove the whitespace and integer display widths, so the types in the
+/// scripts can be compared with the introspected types.
+fn normalize_mysql_type(tpe: &str) -> String {
+    let tpe: String = tpe
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+
+    MYSQL_INTEGER_DISPLAY_WIDTH_RE.replace(&tpe, "$1").into_owned()
+}
+
+/// The length in bytes of the comments and whitespace before the statement. An unterminated
+/// comment runs until the end of the statement.
+fn leading_comments_len(statement: &str) -> usize {
+    let mut offset = 0;
+
+    loop {
+        let rest = &statement[offset..];
+        let trimmed = rest.trim_start();
+        offset += rest.len() - trimmed.len();
+
+        let comment_len = if trimmed.starts_with("--") {
+            trimmed.find('\n').map(|end| end + 1)
+        } else if trimmed.starts_with("/*") {
+            trimmed.find("*/").map(|end| end + 2)
+        } else {
+            return offset;
+        };
+
+        match comment_len {
+            Some(comment_len) => offset += comment_len,
+            None => return statement.len(),
+        }
+    }
+}
+
+/// The lines of `statement`, starting at byte `offset` in `script`.
+fn statement_span(script: &str, offset: usize, statement: &str) -> ScriptSpan {
+    let start_line = script[..offset].matches('\n').count() + 1;
+
+    ScriptSpan {
+        start_line,
+        end_line: start_line + statement.matches('\n').count(),
+    }
+}
+
+/// Split the clauses of an `ALTER TABLE` statement, on the commas outside of parentheses and
+/// quotes.
+fn split_clauses(clauses: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut clause_start = 0;
+
+    for (idx, c) in clauses.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => (),
+            (None, '\'') | (None, '"') | (None, '`') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                result.push(clauses[clause_start..idx].trim());
+                clause_start = idx + 1;
+            }
+            _ => (),
+        }
+    }
+
+    result.push(clauses[clause_start..].trim());
+
+    result
+}
+
+/// The unquoted name of a possibly quoted and schema-qualified identifier.
+fn unquote_identifier(identifier: &str) -> String {
+    let name = LAST_IDENTIFIER_RE
+        .find(identifier)
+        .map(|name| name.as_str())
+        .unwrap_or(identifier);
+
+    name.trim_matches(|c| c == '"' || c == '`' || c == '[' || c == ']')
+        .to_owned()
+}
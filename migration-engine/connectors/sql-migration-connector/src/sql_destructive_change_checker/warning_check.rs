@@ -2,19 +2,58 @@ use super::{check::Check, database_inspection_results::DatabaseInspectionResults
 
 #[derive(Debug)]
 pub(super) enum SqlMigrationWarningCheck {
-    NonEmptyColumnDrop { table: String, column: String },
-    NonEmptyTableDrop { table: String },
-    AlterColumn { table: String, column: String },
-    PrimaryKeyChange { table: String },
-    UniqueConstraintAddition { table: String, columns: Vec<String> },
-    EnumValueRemoval { enm: String, values: Vec<String> },
+    NonEmptyColumnDrop {
+        table: String,
+        column: String,
+    },
+    NonEmptyTableDrop {
+        table: String,
+    },
+    AlterColumn {
+        table: String,
+        column: String,
+    },
+    PrimaryKeyChange {
+        table: String,
+    },
+    UniqueConstraintAddition {
+        table: String,
+        columns: Vec<String>,
+    },
+    EnumValueRemoval {
+        enm: String,
+        values: Vec<String>,
+    },
+    DroppedColumnStillInSchema {
+        table: String,
+        column: String,
+    },
+    TableRewrite {
+        table: String,
+    },
+    BlockingIndexCreation {
+        table: String,
+        index: String,
+    },
+    /// A column type change where some values of the previous type cannot be cast to the next
+    /// type. `failing_values_query` counts the values that cannot be cast, when the flavour can
+    /// express it.
+    RiskyCast {
+        table: String,
+        column: String,
+        previous_type: String,
+        next_type: String,
+        failing_values_query: Option<String>,
+    },
 }
 
 impl Check for SqlMigrationWarningCheck {
     fn needed_table_row_count(&self) -> Option<&str> {
         match self {
             SqlMigrationWarningCheck::NonEmptyTableDrop { table }
-            | SqlMigrationWarningCheck::PrimaryKeyChange { table } => Some(table),
+            | SqlMigrationWarningCheck::PrimaryKeyChange { table }
+            | SqlMigrationWarningCheck::TableRewrite { table }
+            | SqlMigrationWarningCheck::BlockingIndexCreation { table, .. } => Some(table),
             SqlMigrationWarningCheck::NonEmptyColumnDrop { .. } | SqlMigrationWarningCheck::AlterColumn { .. } => None,
             _ => None,
         }
@@ -23,7 +62,8 @@ impl Check for SqlMigrationWarningCheck {
     fn needed_column_value_count(&self) -> Option<(&str, &str)> {
         match self {
             SqlMigrationWarningCheck::NonEmptyColumnDrop { table, column }
-            | SqlMigrationWarningCheck::AlterColumn { table, column } => Some((table, column)),
+            | SqlMigrationWarningCheck::AlterColumn { table, column }
+            | SqlMigrationWarningCheck::RiskyCast { table, column, .. } => Some((table, column)),
 
             SqlMigrationWarningCheck::NonEmptyTableDrop { .. } | SqlMigrationWarningCheck::PrimaryKeyChange { .. } => {
                 None
@@ -32,6 +72,18 @@ impl Check for SqlMigrationWarningCheck {
         }
     }
 
+    fn needed_failing_cast_count(&self) -> Option<(&str, &str, &str)> {
+        match self {
+            SqlMigrationWarningCheck::RiskyCast {
+                table,
+                column,
+                failing_values_query: Some(query),
+                ..
+            } => Some((table, column, query)),
+            _ => None,
+        }
+    }
+
     fn evaluate(&self, database_check_results: &DatabaseInspectionResults) -> Option<String> {
         match self {
             SqlMigrationWarningCheck::NonEmptyTableDrop { table } => match database_check_results.get_row_count(table) {
@@ -58,6 +110,22 @@ impl Check for SqlMigrationWarningCheck {
             },
             SqlMigrationWarningCheck::UniqueConstraintAddition { table, columns } =>  Some(format!("The migration will add a unique constraint covering the columns `{columns}` on the table `{table}`. If there are existing duplicate values, the migration will fail.", table = table, columns = format!("[{}]",columns.join(",")))),
             SqlMigrationWarningCheck::EnumValueRemoval { enm, values } =>  Some(format!("The migration will remove the values {values} on the enum `{enm}`. If these variants are still used in the database, the migration will fail.", enm = enm, values = format!("[{}]",values.join(",")))),
+            SqlMigrationWarningCheck::DroppedColumnStillInSchema { table, column } => Some(format!("The migration drops the column `{column}` on the `{table}` table, but the Prisma schema still uses it. Queries on that field will fail after the migration.", column = column, table = table)),
+            SqlMigrationWarningCheck::TableRewrite { table } => match database_check_results.get_row_count(table) {
+                Some(0) => None,
+                Some(rows_count) => Some(format!("The migration rewrites the whole `{table}` table ({rows_count} rows). The table will be locked until the rewrite is done.", table = table, rows_count = rows_count)),
+                None => Some(format!("The migration rewrites the whole `{table}` table. On a large table, it will be locked until the rewrite is done.", table = table)),
+            },
+            SqlMigrationWarningCheck::BlockingIndexCreation { table, index } => match database_check_results.get_row_count(table) {
+                Some(0) => None,
+                Some(rows_count) => Some(format!("The index `{index}` is created without `CONCURRENTLY` on the `{table}` table ({rows_count} rows). Writes to the table will be blocked until the index is built.", index = index, table = table, rows_count = rows_count)),
+                None => Some(format!("The index `{index}` is created without `CONCURRENTLY` on the `{table}` table. On a large table, writes will be blocked until the index is built.", index = index, table = table)),
+            },
+            SqlMigrationWarningCheck::RiskyCast { table, column, previous_type, next_type, .. } => match (database_check_results.get_failing_cast_count(table, column), database_check_results.get_row_and_non_null_value_count(table, column)) {
+                (Some(0), _) | (_, (Some(0), _)) | (_, (_, Some(0))) => None, // every existing value can be cast
+                (Some(failing_count), _) => Some(format!("You are about to change the type of the column `{column}` on the `{table}` table from {previous_type} to {next_type}. {failing_count} of the existing values cannot be cast to the new type, the migration will fail or lose them.", column = column, table = table, previous_type = previous_type, next_type = next_type, failing_count = failing_count)),
+                (None, _) => Some(format!("You are about to change the type of the column `{column}` on the `{table}` table from {previous_type} to {next_type}. Existing values that cannot be cast to the new type will make the migration fail or be lost.", column = column, table = table, previous_type = previous_type, next_type = next_type)),
+            },
 
         }
     }
@@ -0,0 +1,227 @@
+//! Diffing two arbitrary schema sources, without going through the connector's own database.
+
+use crate::{
+    flavour::{self, SqlFlavour},
+    sql_database_migration_inferrer::infer,
+    sql_database_step_applier::render_script,
+    sql_migration::{SqlMigration, SqlMigrationStep, TableChange},
+    sql_schema_calculator::SqlSchemaCalculator,
+    sql_schema_differ::RenameHints,
+    DatabaseInfo, SqlMigrationConnector,
+};
+use datamodel::Datamodel;
+use migration_connector::{ConnectorError, ConnectorResult, DestructiveChangeDiagnostics, MigrationDirectory};
+use quaint::prelude::ConnectionInfo;
+use sql_schema_describer::SqlSchema;
+use std::fmt::Write as _;
+
+/// One side of a diff.
+#[derive(Debug)]
+pub enum DiffTarget<'a> {
+    /// An empty database.
+    Empty,
+    /// The schema the given datamodel would produce.
+    Datamodel(&'a Datamodel),
+    /// The schema obtained by applying the migrations to a temporary database.
+    Migrations(&'a [MigrationDirectory]),
+    /// A `SqlSchema`, serialized to JSON.
+    SerializedSchema(&'a str),
+    /// The schema of the database behind the connection string.
+    Database(&'a str),
+}
+
+/// What a diff should be rendered as.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiffFormat {
+    /// The SQL script migrating from the first schema to the second.
+    Script,
+    /// A human-readable summary of the changes.
+    Summary,
+}
+
+impl SqlMigrationConnector {
+    /// Compute and render the migration between two schemas. The connection string determines
+    /// the SQL dialect. It is only connected to when one of the targets is a migrations
+    /// directory: the temporary database the migrations are applied to is created on that server.
+    pub async fn diff(
+        database_str: &str,
+        schemas: Vec<String>,
+        from: DiffTarget<'_>,
+        to: DiffTarget<'_>,
+        format: DiffFormat,
+    ) -> ConnectorResult<String> {
+        let connection_info =
+            ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
+        let flavour = flavour::from_connection_info(&connection_info, schemas.clone());
+        let database_info = DatabaseInfo::without_connection(connection_info);
+
+        let rename_hints = match to {
+            DiffTarget::Datamodel(datamodel) => SqlSchemaCalculator::rename_hints(datamodel),
+            _ => RenameHints::default(),
+        };
+
+        let before = target_schema(from, database_str, &schemas, &database_info, flavour.as_ref()).await?;
+        let after = target_schema(to, database_str, &schemas, &database_info, flavour.as_ref()).await?;
+
+        let migration = infer(before, after, &rename_hints, &database_info, flavour.as_ref());
+
+        Ok(match format {
+            DiffFormat::Script => render_script(
+                &migration,
+                &DestructiveChangeDiagnostics::new(),
+                flavour.as_ref(),
+                &database_info,
+            ),
+            DiffFormat::Summary => render_summary(&migration),
+        })
+    }
+}
+
+async fn target_schema(
+    target: DiffTarget<'_>,
+    database_str: &str,
+    schemas: &[String],
+    database_info: &DatabaseInfo,
+    flavour: &(dyn SqlFlavour + Send + Sync),
+) -> ConnectorResult<SqlSchema> {
+    match target {
+        DiffTarget::Empty => Ok(SqlSchema::empty()),
+        DiffTarget::Datamodel(datamodel) => Ok(SqlSchemaCalculator::calculate(datamodel, database_info, flavour)),
+        DiffTarget::SerializedSchema(json) => serde_json::from_str(json)
+            .map_err(|err| ConnectorError::generic(anyhow::anyhow!("Failed to deserialize the SQL schema: {}", err))),
+        DiffTarget::Database(url) => {
+            let connector = SqlMigrationConnector::new_with_schemas(url, schemas.to_vec()).await?;
+
+            connector.describe_schema().await
+        }
+        DiffTarget::Migrations(migrations) => {
+            let connector = SqlMigrationConnector::new_with_schemas(database_str, schemas.to_vec()).await?;
+
+            connector
+                .flavour
+                .sql_schema_from_migration_history(migrations, &connector.connection, None)
+                .await
+        }
+    }
+}
+
+/// One line per step, with the table-level changes indented under their table.
+fn render_summary(migration: &SqlMigration) -> String {
+    if migration.steps.is_empty() {
+        return "No difference detected.".to_owned();
+    }
+
+    let mut out = String::with_capacity(40 * migration.steps.len());
+
+    for step in &migration.steps {
+        match step {
+            SqlMigrationStep::CreateTable(create_table) => {
+                writeln!(out, "[+] Added table `{}`", create_table.table.name).unwrap()
+            }
+            SqlMigrationStep::DropTable(drop_table) => {
+                writeln!(out, "[-] Removed table `{}`", drop_table.name).unwrap()
+            }
+            SqlMigrationStep::RenameTable { name, new_name } => {
+                writeln!(out, "[*] Renamed table `{}` to `{}`", name, new_name).unwrap()
+            }
+            SqlMigrationStep::RenameColumn { table, name, new_name } => {
+                writeln!(out, "[*] Renamed column `{}` to `{}` on `{}`", name, new_name, table).unwrap()
+            }
+            SqlMigrationStep::RedefineTables { names } => {
+                for name in names {
+                    writeln!(out, "[*] Redefined table `{}`", name).unwrap()
+                }
+            }
+            SqlMigrationStep::AlterTable(alter_table) => {
+                writeln!(out, "[*] Changed table `{}`", alter_table.table.name).unwrap();
+
+                for change in &alter_table.changes {
+                    match change {
+                        TableChange::AddColumn(add_column) => {
+                            writeln!(out, "  [+] Added column `{}`", add_column.column.name).unwrap()
+                        }
+                        TableChange::DropColumn(drop_column) => {
+                            writeln!(out, "  [-] Removed column `{}`", drop_column.name).unwrap()
+                        }
+                        TableChange::AlterColumn(alter_column) => {
+                            writeln!(out, "  [*] Altered column `{}`", alter_column.name).unwrap()
+                        }
+                        TableChange::AddPrimaryKey { columns, .. } => {
+                            writeln!(out, "  [+] Added primary key on ({})", columns.join(", ")).unwrap()
+                        }
+                        TableChange::RenamePrimaryKey {
+                            constraint_name,
+                            new_constraint_name,
+                        } => writeln!(
+                            out,
+                            "  [*] Renamed primary key `{}` to `{}`",
+                            constraint_name, new_constraint_name
+                        )
+                        .unwrap(),
+                        TableChange::DropPrimaryKey { .. } => writeln!(out, "  [-] Removed primary key").unwrap(),
+                        TableChange::AddCheckConstraint { check_constraint } => {
+                            writeln!(out, "  [+] Added check constraint `{}`", check_constraint.name).unwrap()
+                        }
+                        TableChange::DropCheckConstraint { constraint_name } => {
+                            writeln!(out, "  [-] Removed check constraint `{}`", constraint_name).unwrap()
+                        }
+                    }
+                }
+            }
+            SqlMigrationStep::AddForeignKey(add_foreign_key) => writeln!(
+                out,
+                "[+] Added foreign key on `{}`({}) referencing `{}`",
+                add_foreign_key.table,
+                add_foreign_key.foreign_key.columns.join(", "),
+                add_foreign_key.foreign_key.referenced_table
+            )
+            .unwrap(),
+            SqlMigrationStep::DropForeignKey(drop_foreign_key) => writeln!(
+                out,
+                "[-] Removed foreign key `{}` on `{}`",
+                drop_foreign_key.constraint_name, drop_foreign_key.table
+            )
+            .unwrap(),
+            SqlMigrationStep::CreateIndex(create_index) => writeln!(
+                out,
+                "[+] Added index `{}` on `{}`({})",
+                create_index.index.name,
+                create_index.table,
+                create_index.index.columns.join(", ")
+            )
+            .unwrap(),
+            SqlMigrationStep::DropIndex(drop_index) => {
+                writeln!(out, "[-] Removed index `{}` on `{}`", drop_index.name, drop_index.table).unwrap()
+            }
+            SqlMigrationStep::AlterIndex(alter_index) => writeln!(
+                out,
+                "[*] Renamed index `{}` to `{}` on `{}`",
+                alter_index.index_name, alter_index.index_new_name, alter_index.table
+            )
+            .unwrap(),
+            SqlMigrationStep::RenameForeignKey(rename_foreign_key) => writeln!(
+                out,
+                "[*] Renamed foreign key `{}` to `{}` on `{}`",
+                rename_foreign_key.constraint_name, rename_foreign_key.new_constraint_name, rename_foreign_key.table
+            )
+            .unwrap(),
+            SqlMigrationStep::CreateEnum(create_enum) => {
+                writeln!(out, "[+] Added enum `{}`", create_enum.name).unwrap()
+            }
+            SqlMigrationStep::DropEnum(drop_enum) => writeln!(out, "[-] Removed enum `{}`", drop_enum.name).unwrap(),
+            SqlMigrationStep::AlterEnum(alter_enum) => {
+                writeln!(out, "[*] Changed enum `{}`", alter_enum.name).unwrap();
+
+                for variant in &alter_enum.created_variants {
+                    writeln!(out, "  [+] Added variant `{}`", variant).unwrap()
+                }
+
+                for variant in &alter_enum.dropped_variants {
+                    writeln!(out, "  [-] Removed variant `{}`", variant).unwrap()
+                }
+            }
+        }
+    }
+
+    out
+}
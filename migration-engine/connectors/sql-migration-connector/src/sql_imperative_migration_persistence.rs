@@ -1,5 +1,7 @@
 use crate::{component::Component, error::quaint_error_to_connector_error, SqlMigrationConnector};
-use migration_connector::{ConnectorResult, FormatChecksum, ImperativeMigrationsPersistence, MigrationRecord};
+use migration_connector::{
+    ConnectorResult, FormatChecksum, ImperativeMigrationsPersistence, MigrationRecord, MigrationResolution,
+};
 use quaint::ast::*;
 use sha2::{Digest, Sha256};
 use uuid::Uuid;
@@ -68,6 +70,37 @@ impl ImperativeMigrationsPersistence for SqlMigrationConnector {
         Ok(())
     }
 
+    async fn record_migration_rolled_back(&self, id: &str) -> ConnectorResult<()> {
+        let update = Update::table((self.schema_name(), IMPERATIVE_MIGRATIONS_TABLE_NAME))
+            .so_that(Column::from("id").equals(id))
+            .set("rolled_back_at", chrono::Utc::now());
+
+        self.conn().execute(update).await?;
+
+        Ok(())
+    }
+
+    async fn record_migration_resolved(
+        &self,
+        id: &str,
+        resolution: MigrationResolution,
+        logs: &str,
+    ) -> ConnectorResult<()> {
+        let timestamp_column = match resolution {
+            MigrationResolution::Applied => "finished_at",
+            MigrationResolution::RolledBack => "rolled_back_at",
+        };
+
+        let update = Update::table((self.schema_name(), IMPERATIVE_MIGRATIONS_TABLE_NAME))
+            .so_that(Column::from("id").equals(id))
+            .set(timestamp_column, chrono::Utc::now())
+            .set("logs", logs);
+
+        self.conn().execute(update).await?;
+
+        Ok(())
+    }
+
     async fn list_migrations(&self) -> ConnectorResult<Vec<MigrationRecord>> {
         self.flavour.ensure_imperative_migrations_table(self.conn()).await?;
 
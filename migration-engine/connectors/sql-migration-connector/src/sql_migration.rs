@@ -2,7 +2,7 @@ pub(crate) mod expanded_alter_column;
 
 use migration_connector::DatabaseMigrationMarker;
 use serde::{Deserialize, Serialize};
-use sql_schema_describer::{Column, ForeignKey, Index, SqlSchema, Table};
+use sql_schema_describer::{CheckConstraint, Column, ForeignKey, Index, SqlSchema, Table};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SqlMigration {
@@ -40,11 +40,22 @@ pub enum SqlMigrationStep {
     AlterTable(AlterTable),
     DropForeignKey(DropForeignKey),
     DropTable(DropTable),
-    RenameTable { name: String, new_name: String },
-    RedefineTables { names: Vec<String> },
+    RenameTable {
+        name: String,
+        new_name: String,
+    },
+    RenameColumn {
+        table: String,
+        name: String,
+        new_name: String,
+    },
+    RedefineTables {
+        names: Vec<String>,
+    },
     CreateIndex(CreateIndex),
     DropIndex(DropIndex),
     AlterIndex(AlterIndex),
+    RenameForeignKey(RenameForeignKey),
     CreateEnum(CreateEnum),
     DropEnum(DropEnum),
     AlterEnum(AlterEnum),
@@ -59,10 +70,12 @@ impl SqlMigrationStep {
             SqlMigrationStep::DropForeignKey(_) => "DropForeignKey",
             SqlMigrationStep::DropTable(_) => "DropTable",
             SqlMigrationStep::RenameTable { .. } => "RenameTable",
+            SqlMigrationStep::RenameColumn { .. } => "RenameColumn",
             SqlMigrationStep::RedefineTables { .. } => "RedefineTables",
             SqlMigrationStep::CreateIndex(_) => "CreateIndex",
             SqlMigrationStep::DropIndex(_) => "DropIndex",
             SqlMigrationStep::AlterIndex(_) => "AlterIndex",
+            SqlMigrationStep::RenameForeignKey(_) => "RenameForeignKey",
             SqlMigrationStep::CreateEnum(_) => "CreateEnum",
             SqlMigrationStep::DropEnum(_) => "DropEnum",
             SqlMigrationStep::AlterEnum(_) => "AlterEnum",
@@ -78,6 +91,9 @@ pub struct CreateTable {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropTable {
     pub name: String,
+    /// The schema of the table, when the datasource spans several schemas.
+    #[serde(default)]
+    pub schema: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -91,8 +107,25 @@ pub enum TableChange {
     AddColumn(AddColumn),
     AlterColumn(AlterColumn),
     DropColumn(DropColumn),
-    DropPrimaryKey { constraint_name: Option<String> },
-    AddPrimaryKey { columns: Vec<String> },
+    DropPrimaryKey {
+        constraint_name: Option<String>,
+    },
+    AddPrimaryKey {
+        columns: Vec<String>,
+        /// The name of the constraint. The database generates one when it is not set.
+        #[serde(default)]
+        constraint_name: Option<String>,
+    },
+    RenamePrimaryKey {
+        constraint_name: String,
+        new_constraint_name: String,
+    },
+    AddCheckConstraint {
+        check_constraint: CheckConstraint,
+    },
+    DropCheckConstraint {
+        constraint_name: String,
+    },
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -114,45 +147,80 @@ pub struct AlterColumn {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AddForeignKey {
     pub table: String,
+    /// The schema of the table, when the datasource spans several schemas.
+    #[serde(default)]
+    pub schema: Option<String>,
     pub foreign_key: ForeignKey,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropForeignKey {
     pub table: String,
+    /// The schema of the table, when the datasource spans several schemas.
+    #[serde(default)]
+    pub schema: Option<String>,
     pub constraint_name: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateIndex {
     pub table: String,
+    /// The schema of the table, when the datasource spans several schemas.
+    #[serde(default)]
+    pub schema: Option<String>,
     pub index: Index,
     pub caused_by_create_table: bool,
     pub contains_nullable_columns: bool,
+    /// Create the index without locking the table against writes, on the databases that support
+    /// it. This cannot run inside a transaction.
+    #[serde(default)]
+    pub concurrently: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropIndex {
     pub table: String,
+    /// The schema of the table, when the datasource spans several schemas.
+    #[serde(default)]
+    pub schema: Option<String>,
     pub name: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AlterIndex {
     pub table: String,
+    /// The schema of the table, when the datasource spans several schemas.
+    #[serde(default)]
+    pub schema: Option<String>,
     pub index_name: String,
     pub index_new_name: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RenameForeignKey {
+    pub table: String,
+    /// The schema of the table, when the datasource spans several schemas.
+    #[serde(default)]
+    pub schema: Option<String>,
+    pub constraint_name: String,
+    pub new_constraint_name: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateEnum {
     pub name: String,
+    /// The schema of the enum, when the datasource spans several schemas.
+    #[serde(default)]
+    pub schema: Option<String>,
     pub variants: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropEnum {
     pub name: String,
+    /// The schema of the enum, when the datasource spans several schemas.
+    #[serde(default)]
+    pub schema: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -1,5 +1,5 @@
-use crate::sql_schema_differ::{ColumnChange, ColumnChanges, ColumnDiffer};
-use sql_schema_describer::{ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue};
+use crate::sql_schema_differ::{ColumnCast, ColumnChange, ColumnChanges, ColumnDiffer};
+use sql_schema_describer::{ColumnArity, ColumnType, DefaultValue};
 
 pub(crate) fn expand_mysql_alter_column(columns: &ColumnDiffer<'_>) -> MysqlAlterColumn {
     let column_changes = columns.all_changes();
@@ -12,6 +12,10 @@ pub(crate) fn expand_mysql_alter_column(columns: &ColumnDiffer<'_>) -> MysqlAlte
         unreachable!("MySQL column renaming.")
     }
 
+    if column_changes.type_changed() && columns.cast() == ColumnCast::Impossible {
+        return MysqlAlterColumn::DropAndRecreate;
+    }
+
     // @default(dbgenerated()) does not give us the information in the prisma schema, so we have to
     // transfer it from the introspected current state of the database.
     let new_default = match (&columns.previous.default(), &columns.next.default()) {
@@ -56,15 +60,11 @@ pub(crate) fn expand_postgres_alter_column(columns: &ColumnDiffer<'_>) -> Option
                 | (ColumnArity::Required, ColumnArity::Required)
                 | (ColumnArity::List, ColumnArity::List) => (),
             },
-            ColumnChange::TypeChanged => match (
-                &columns.previous.column_type_family(),
-                &columns.next.column_type_family(),
-            ) {
-                // Ints can be cast to text.
-                (ColumnTypeFamily::Int, ColumnTypeFamily::String) => {
-                    changes.push(PostgresAlterColumn::SetType(columns.next.column_type().clone()))
+            ColumnChange::TypeChanged => match columns.cast() {
+                ColumnCast::Safe | ColumnCast::Risky => {
+                    changes.push(PostgresAlterColumn::Cast(columns.next.column_type().clone()))
                 }
-                _ => return None,
+                ColumnCast::Impossible => return None,
             },
             ColumnChange::Sequence => {
                 if columns.previous.is_autoincrement() {
@@ -82,6 +82,36 @@ pub(crate) fn expand_postgres_alter_column(columns: &ColumnDiffer<'_>) -> Option
     Some(changes)
 }
 
+pub(crate) fn expand_mssql_alter_column(columns: &ColumnDiffer<'_>) -> MssqlAlterColumn {
+    let column_changes = columns.all_changes();
+
+    let must_be_recreated = column_changes.iter().any(|change| match change {
+        ColumnChange::TypeChanged => columns.cast() == ColumnCast::Impossible,
+        ColumnChange::Sequence => true,
+        ColumnChange::Arity | ColumnChange::Default => false,
+        ColumnChange::Renaming => unreachable!("column renaming"),
+    });
+
+    if must_be_recreated {
+        return MssqlAlterColumn::DropAndRecreate;
+    }
+
+    // Same as on MySQL: an empty dbgenerated() default carries over the one in the database.
+    let new_default = match (&columns.previous.default(), &columns.next.default()) {
+        (Some(DefaultValue::DBGENERATED(previous)), Some(DefaultValue::DBGENERATED(next)))
+            if next.is_empty() && !previous.is_empty() =>
+        {
+            Some(DefaultValue::DBGENERATED(previous.clone()))
+        }
+        _ => columns.next.default().cloned(),
+    };
+
+    MssqlAlterColumn::Modify {
+        changes: column_changes,
+        new_default,
+    }
+}
+
 #[derive(Debug)]
 /// https://www.postgresql.org/docs/9.1/sql-altertable.html
 pub(crate) enum PostgresAlterColumn {
@@ -89,6 +119,8 @@ pub(crate) enum PostgresAlterColumn {
     DropDefault,
     DropNotNull,
     SetType(ColumnType),
+    /// Change the type of the column, converting the existing values with a cast.
+    Cast(ColumnType),
     SetNotNull,
     /// Add an auto-incrementing sequence as a default on the column.
     AddSequence,
@@ -101,10 +133,31 @@ pub(crate) enum PostgresAlterColumn {
 #[derive(Debug)]
 pub(crate) enum MysqlAlterColumn {
     DropDefault,
+    /// Restate the column with `MODIFY`. The existing values are converted to the next type, with
+    /// an explicit `CONVERT` first for the strings.
+    Modify {
+        new_default: Option<DefaultValue>,
+        changes: ColumnChanges,
+    },
+    /// The values cannot be converted to the next type, the column is dropped and recreated.
+    DropAndRecreate,
+}
+
+/// https://docs.microsoft.com/en-us/sql/t-sql/statements/alter-table-transact-sql
+///
+/// Defaults are constraints on SQL Server. They cannot be altered, only dropped and added again.
+#[derive(Debug)]
+pub(crate) enum MssqlAlterColumn {
+    /// Alter the column in place. Type and nullability changes are restated with `ALTER COLUMN`,
+    /// converting the existing values like `CONVERT` would, and the default constraint is dropped
+    /// and added again around it.
     Modify {
         new_default: Option<DefaultValue>,
         changes: ColumnChanges,
     },
+    /// Identity columns cannot be altered and some casts cannot be performed, the column is
+    /// dropped and recreated.
+    DropAndRecreate,
 }
 
 // Not used yet: SQLite only supports column renamings, which we don't. All
@@ -12,13 +12,14 @@ use crate::{
     database_info::DatabaseInfo,
     sql_migration::{
         AddForeignKey, AlterEnum, AlterIndex, AlterTable, CreateEnum, CreateIndex, DropEnum, DropForeignKey, DropIndex,
+        DropTable, RenameForeignKey,
     },
     sql_schema_differ::SqlSchemaDiffer,
 };
 use common::{Quoted, QuotedWithSchema};
 use sql_schema_describer::{
     walkers::{ColumnWalker, TableWalker},
-    ColumnTypeFamily, DefaultValue, ForeignKey, SqlSchema,
+    CheckConstraint, ColumnTypeFamily, DefaultValue, ForeignKey, Index, SQLSortOrder, SqlSchema,
 };
 use std::borrow::Cow;
 
@@ -27,6 +28,41 @@ pub(crate) trait SqlRenderer {
 
     fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey) -> String;
 
+    /// Render a check constraint, as part of a `CREATE TABLE` or an `ALTER TABLE ... ADD` statement.
+    fn render_check_constraint(&self, check_constraint: &CheckConstraint) -> String {
+        format!(
+            "CONSTRAINT {} CHECK ({})",
+            self.quote(&check_constraint.name),
+            check_constraint.expression
+        )
+    }
+
+    /// Render the `CONSTRAINT <name>` prefix of a constraint definition, or nothing if the
+    /// database picks the name.
+    fn render_constraint_name(&self, constraint_name: Option<&str>) -> String {
+        constraint_name
+            .map(|name| format!("CONSTRAINT {} ", self.quote(name)))
+            .unwrap_or_default()
+    }
+
+    /// Render the key columns of an index, with their prefix length and sort order.
+    fn render_index_columns(&self, index: &Index) -> String {
+        index
+            .columns
+            .iter()
+            .map(|column| {
+                let options = index.column_options(column);
+                let length = options.length.map(|length| format!("({})", length)).unwrap_or_default();
+                let sort_order = match options.sort_order {
+                    SQLSortOrder::Asc => "",
+                    SQLSortOrder::Desc => " DESC",
+                };
+
+                format!("{}{}{}", self.quote(column), length, sort_order)
+            })
+            .join(", ")
+    }
+
     fn render_alter_enum(&self, alter_enum: &AlterEnum, differ: &SqlSchemaDiffer<'_>) -> Vec<String>;
 
     fn render_column(&self, column: ColumnWalker<'_>) -> String;
@@ -64,13 +100,20 @@ pub(crate) trait SqlRenderer {
     fn render_drop_index(&self, drop_index: &DropIndex) -> String;
 
     /// Render a `DropTable` step.
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote(&table_name))]
+    fn render_drop_table(&self, drop_table: &DropTable) -> Vec<String> {
+        vec![format!("DROP TABLE {}", self.quote(&drop_table.name))]
     }
 
+    /// Render a `RenameForeignKey` step.
+    fn render_rename_foreign_key(&self, rename_foreign_key: &RenameForeignKey) -> Vec<String>;
+
     /// Render a `RedefineTables` step.
     fn render_redefine_tables(&self, tables: &[String], differ: SqlSchemaDiffer<'_>) -> Vec<String>;
 
     /// Render a table renaming step.
     fn render_rename_table(&self, name: &str, new_name: &str) -> String;
+
+    /// Render a column renaming step. `column` is the renamed column, with its new name and its
+    /// definition at the time of the rename.
+    fn render_rename_column(&self, name: &str, column: ColumnWalker<'_>) -> String;
 }
@@ -3,8 +3,9 @@ use crate::{
     database_info::DatabaseInfo,
     flavour::MssqlFlavour,
     sql_migration::{
+        expanded_alter_column::{expand_mssql_alter_column, MssqlAlterColumn},
         AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterIndex, AlterTable, CreateEnum, CreateIndex, DropColumn,
-        DropEnum, DropForeignKey, DropIndex, TableChange,
+        DropEnum, DropForeignKey, DropIndex, DropTable, RenameForeignKey, TableChange,
     },
     sql_schema_differ::SqlSchemaDiffer,
 };
@@ -16,9 +17,47 @@ use sql_schema_describer::{
 use std::{borrow::Cow, fmt::Write};
 
 impl MssqlFlavour {
-    fn quote_with_schema<'a, 'b>(&'a self, name: &'b str) -> QuotedWithSchema<'a, &'b str> {
+    /// Constraints are objects of the schema, they are renamed without the table name.
+    fn render_rename_constraint(
+        &self,
+        schema: Option<&str>,
+        constraint_name: &str,
+        new_constraint_name: &str,
+    ) -> String {
+        let schema_name = schema.unwrap_or_else(|| self.schema_name());
+        let with_schema = format!("{}.{}", schema_name, constraint_name);
+
+        format!(
+            "EXEC SP_RENAME N{}, N{}, N'OBJECT'",
+            Quoted::Single(with_schema),
+            Quoted::Single(new_constraint_name),
+        )
+    }
+
+    /// Default constraints created without a name get one generated by SQL Server, so the name is
+    /// looked up in the catalog before dropping the constraint. The lookup and the drop are wrapped
+    /// in a single `EXEC`, so the statement has no top-level semicolon and stays one statement when
+    /// the script is split for migrations running outside of a transaction.
+    fn render_drop_default_constraint(&self, table_name: &QuotedWithSchema<'_, &str>, column_name: &str) -> String {
+        let table_name = escape_string_literal(&table_name.to_string());
+
+        let batch = format!(
+            "DECLARE @default_constraint NVARCHAR(128) = (SELECT [name] FROM [sys].[default_constraints] \
+             WHERE [parent_object_id] = OBJECT_ID(N'{table}') \
+             AND COL_NAME([parent_object_id], [parent_column_id]) = N'{column}'); \
+             IF @default_constraint IS NOT NULL \
+             EXEC(N'ALTER TABLE {table} DROP CONSTRAINT ' + QUOTENAME(@default_constraint))",
+            table = table_name,
+            column = escape_string_literal(column_name),
+        );
+
+        format!("EXEC(N'{}')", escape_string_literal(&batch))
+    }
+
+    /// Qualify the name with the given schema, or with the connection's schema if there is none.
+    fn quote_with_table_schema<'a>(&'a self, schema: Option<&'a str>, name: &'a str) -> QuotedWithSchema<'a, &'a str> {
         QuotedWithSchema {
-            schema_name: self.schema_name(),
+            schema_name: schema.unwrap_or_else(|| self.schema_name()),
             name: self.quote(name),
         }
     }
@@ -32,7 +71,12 @@ impl SqlRenderer for MssqlFlavour {
     fn render_alter_table(&self, alter_table: &AlterTable, differ: &SqlSchemaDiffer<'_>) -> Vec<String> {
         let AlterTable { table, changes } = alter_table;
 
+        let table_name = self.quote_with_table_schema(table.schema.as_deref(), &table.name);
         let mut lines = Vec::new();
+        // ALTER COLUMN and default constraint changes cannot be combined with other changes in the
+        // same statement, they are rendered as separate statements.
+        let mut alter_columns = Vec::new();
+        let mut renames = Vec::new();
 
         for change in changes {
             match change {
@@ -40,9 +84,30 @@ impl SqlRenderer for MssqlFlavour {
                     let constraint = constraint_name.as_ref().unwrap();
                     lines.push(format!("DROP CONSTRAINT {}", self.quote(constraint)));
                 }
-                TableChange::AddPrimaryKey { columns } => {
+                TableChange::AddPrimaryKey {
+                    columns,
+                    constraint_name,
+                } => {
                     let columns = columns.iter().map(|colname| self.quote(colname)).join(", ");
-                    lines.push(format!("ADD PRIMARY KEY ({})", columns));
+                    lines.push(format!(
+                        "ADD {}PRIMARY KEY ({})",
+                        self.render_constraint_name(constraint_name.as_deref()),
+                        columns
+                    ));
+                }
+                TableChange::RenamePrimaryKey {
+                    constraint_name,
+                    new_constraint_name,
+                } => renames.push(self.render_rename_constraint(
+                    table.schema.as_deref(),
+                    constraint_name,
+                    new_constraint_name,
+                )),
+                TableChange::AddCheckConstraint { check_constraint } => {
+                    lines.push(format!("ADD {}", self.render_check_constraint(check_constraint)))
+                }
+                TableChange::DropCheckConstraint { constraint_name } => {
+                    lines.push(format!("DROP CONSTRAINT {}", self.quote(constraint_name)))
                 }
                 TableChange::AddColumn(AddColumn { column }) => {
                     let column = ColumnWalker {
@@ -57,19 +122,83 @@ impl SqlRenderer for MssqlFlavour {
                     let name = self.quote(&name);
                     lines.push(format!("DROP COLUMN {}", name));
                 }
-                TableChange::AlterColumn(AlterColumn { .. }) => todo!("We must handle altering columns in MSSQL"),
+                TableChange::AlterColumn(AlterColumn { name, column: _ }) => {
+                    let columns = differ
+                        .diff_table(&table.name)
+                        .expect("AlterTable on unknown table.")
+                        .diff_column(name)
+                        .expect("AlterColumn on unknown column.");
+
+                    match expand_mssql_alter_column(&columns) {
+                        MssqlAlterColumn::Modify { changes, new_default } => {
+                            let column_changed = changes.type_changed() || changes.arity_changed();
+                            // A default constraint depending on the column prevents altering it.
+                            let recreate_default = changes.default_changed() || column_changed;
+
+                            if recreate_default && columns.previous.default().is_some() {
+                                alter_columns.push(self.render_drop_default_constraint(&table_name, name));
+                            }
+
+                            if column_changed {
+                                let nullability = if columns.next.arity().is_required() {
+                                    "NOT NULL"
+                                } else {
+                                    "NULL"
+                                };
+
+                                alter_columns.push(format!(
+                                    "ALTER TABLE {} ALTER COLUMN {} {} {}",
+                                    table_name,
+                                    self.quote(name),
+                                    render_column_type(columns.next.column_type_family()),
+                                    nullability
+                                ));
+                            }
+
+                            let new_default = new_default.filter(|default| match default {
+                                DefaultValue::DBGENERATED(expression) => !expression.is_empty(),
+                                DefaultValue::SEQUENCE(_) => false,
+                                _ => true,
+                            });
+
+                            if let Some(new_default) = new_default.filter(|_| recreate_default) {
+                                alter_columns.push(format!(
+                                    "ALTER TABLE {} ADD CONSTRAINT {} DEFAULT {} FOR {}",
+                                    table_name,
+                                    self.quote(&format!("DF__{}__{}", table.name, name)),
+                                    self.render_default(&new_default, columns.next.column_type_family()),
+                                    self.quote(name),
+                                ));
+                            }
+                        }
+                        MssqlAlterColumn::DropAndRecreate => {
+                            if columns.previous.default().is_some() {
+                                alter_columns.push(self.render_drop_default_constraint(&table_name, name));
+                            }
+
+                            alter_columns.push(format!("ALTER TABLE {} DROP COLUMN {}", table_name, self.quote(name)));
+                            alter_columns.push(format!(
+                                "ALTER TABLE {} ADD {}",
+                                table_name,
+                                self.render_column(columns.next)
+                            ));
+                        }
+                    }
+                }
             };
         }
 
-        if lines.is_empty() {
-            return Vec::new();
+        let mut statements = Vec::new();
+
+        if !lines.is_empty() {
+            statements.push(format!("ALTER TABLE {} {}", table_name, lines.join(",\n")));
         }
 
-        vec![format!(
-            "ALTER TABLE {} {}",
-            self.quote_with_schema(&table.name),
-            lines.join(",\n")
-        )]
+        statements.extend(alter_columns);
+
+        statements.extend(renames);
+
+        statements
     }
 
     fn render_alter_enum(&self, _: &AlterEnum, _: &SqlSchemaDiffer<'_>) -> Vec<String> {
@@ -79,14 +208,7 @@ impl SqlRenderer for MssqlFlavour {
     fn render_column(&self, column: ColumnWalker<'_>) -> String {
         let column_name = self.quote(column.name());
 
-        let r#type = match &column.column_type().family {
-            ColumnTypeFamily::Boolean => "bit",
-            ColumnTypeFamily::DateTime => "datetime2",
-            ColumnTypeFamily::Float => "decimal(32,16)",
-            ColumnTypeFamily::Int => "int",
-            ColumnTypeFamily::String | ColumnTypeFamily::Json => "nvarchar(1000)",
-            x => unimplemented!("{:?} not handled yet", x),
-        };
+        let r#type = render_column_type(&column.column_type().family);
 
         let nullability = common::render_nullability(&column);
 
@@ -97,7 +219,7 @@ impl SqlRenderer for MssqlFlavour {
             .unwrap_or_else(String::new);
 
         if column.is_autoincrement() {
-            format!("{} int IDENTITY(1,1)", column_name)
+            format!("{} {} IDENTITY(1,1)", column_name, r#type)
         } else {
             format!("{} {} {} {}", column_name, r#type, nullability, default)
         }
@@ -118,7 +240,7 @@ impl SqlRenderer for MssqlFlavour {
 
         format!(
             " REFERENCES {}({}) {} {}",
-            self.quote_with_schema(&foreign_key.referenced_table),
+            self.quote_with_table_schema(foreign_key.referenced_schema.as_deref(), &foreign_key.referenced_table),
             cols,
             on_delete,
             on_update
@@ -152,11 +274,13 @@ impl SqlRenderer for MssqlFlavour {
     ) -> Vec<String> {
         let AlterIndex {
             table,
+            schema,
             index_name,
             index_new_name,
         } = alter_index;
 
-        let index_with_table = Quoted::Single(format!("{}.{}.{}", self.schema_name(), table, index_name));
+        let schema_name = schema.as_deref().unwrap_or_else(|| self.schema_name());
+        let index_with_table = Quoted::Single(format!("{}.{}.{}", schema_name, table, index_name));
 
         vec![format!(
             "EXEC SP_RENAME N{index_with_table}, N{index_new_name}, N'INDEX'",
@@ -172,9 +296,11 @@ impl SqlRenderer for MssqlFlavour {
     fn render_create_index(&self, create_index: &CreateIndex) -> String {
         let CreateIndex {
             table,
+            schema,
             index,
             caused_by_create_table: _,
             contains_nullable_columns,
+            concurrently: _,
         } = create_index;
 
         let index_type = match index.tpe {
@@ -184,10 +310,12 @@ impl SqlRenderer for MssqlFlavour {
 
         let index_name = index.name.replace('.', "_");
         let index_name = self.quote(&index_name);
-        let table_reference = self.quote_with_schema(&table).to_string();
+        let table_reference = self.quote_with_table_schema(schema.as_deref(), &table).to_string();
 
-        let condition = match index.tpe {
-            IndexType::Unique if *contains_nullable_columns => {
+        // A user-defined predicate replaces the filter excluding NULL values.
+        let condition = match (&index.predicate, &index.tpe) {
+            (Some(predicate), _) => Cow::from(format!(" WHERE {}", predicate)),
+            (None, IndexType::Unique) if *contains_nullable_columns => {
                 let columns = index
                     .columns
                     .iter()
@@ -199,14 +327,19 @@ impl SqlRenderer for MssqlFlavour {
             _ => Cow::from(""),
         };
 
-        let columns = index.columns.iter().map(|c| self.quote(c));
+        let include = if index.include.is_empty() {
+            String::new()
+        } else {
+            format!(" INCLUDE ({})", index.include.iter().map(|c| self.quote(c)).join(", "))
+        };
 
         format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns}){condition}",
+            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns}){include}{condition}",
             index_type = index_type,
             index_name = index_name,
             table_reference = table_reference,
-            columns = columns.join(", "),
+            columns = self.render_index_columns(index),
+            include = include,
             condition = condition,
         )
     }
@@ -217,7 +350,10 @@ impl SqlRenderer for MssqlFlavour {
         let primary_columns = table.table.primary_key_columns();
 
         let primary_key = if !primary_columns.is_empty() {
-            let index_name = format!("PK_{}_{}", table.table.name, primary_columns.iter().join("_"));
+            let index_name = match table.primary_key().and_then(|pk| pk.constraint_name.as_deref()) {
+                Some(constraint_name) => self.quote(constraint_name).to_string(),
+                None => format!("PK_{}_{}", table.table.name, primary_columns.iter().join("_")),
+            };
             let column_names = primary_columns.iter().map(|col| self.quote(&col)).join(",");
 
             format!(",\nCONSTRAINT {} PRIMARY KEY ({})", index_name, column_names)
@@ -226,10 +362,15 @@ impl SqlRenderer for MssqlFlavour {
         };
 
         // We only render unique constraints here if the mapped columns can't be
-        // null.
+        // null, and the index has no filter or included columns.
         let constraints = table
             .indexes()
-            .filter(|index| index.index_type().is_unique() && !index.has_nullable_columns())
+            .filter(|index| {
+                index.index_type().is_unique()
+                    && !index.has_nullable_columns()
+                    && index.index().predicate.is_none()
+                    && index.index().include.is_empty()
+            })
             .collect::<Vec<_>>();
 
         let constraints = if !constraints.is_empty() {
@@ -237,9 +378,12 @@ impl SqlRenderer for MssqlFlavour {
                 .iter()
                 .map(|index| {
                     let name = index.name().replace('.', "_");
-                    let columns = index.columns().map(|col| self.quote(col.name()));
 
-                    format!("CONSTRAINT {} UNIQUE ({})", name, columns.join(","))
+                    format!(
+                        "CONSTRAINT {} UNIQUE ({})",
+                        name,
+                        self.render_index_columns(index.index())
+                    )
                 })
                 .join(",\n");
 
@@ -248,12 +392,18 @@ impl SqlRenderer for MssqlFlavour {
             String::new()
         };
 
+        let check_constraints: String = table
+            .check_constraints()
+            .map(|check| format!(",\n{}", self.render_check_constraint(check)))
+            .collect();
+
         format!(
-            "CREATE TABLE {} ({columns}{primary_key}{constraints})",
-            table_name = self.quote_with_schema(table.name()),
+            "CREATE TABLE {} ({columns}{primary_key}{constraints}{check_constraints})",
+            table_name = self.quote_with_table_schema(table.schema_name(), table.name()),
             columns = columns,
             primary_key = primary_key,
             constraints = constraints,
+            check_constraints = check_constraints,
         )
     }
 
@@ -264,7 +414,7 @@ impl SqlRenderer for MssqlFlavour {
     fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> String {
         format!(
             "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
-            table = self.quote_with_schema(&drop_foreign_key.table),
+            table = self.quote_with_table_schema(drop_foreign_key.schema.as_deref(), &drop_foreign_key.table),
             constraint_name = Quoted::mssql_ident(&drop_foreign_key.constraint_name),
         )
     }
@@ -272,8 +422,8 @@ impl SqlRenderer for MssqlFlavour {
     fn render_drop_index(&self, drop_index: &DropIndex) -> String {
         format!(
             "DROP INDEX {} ON {}",
-            self.quote_with_schema(&drop_index.name),
-            self.quote_with_schema(&drop_index.table)
+            self.quote_with_table_schema(drop_index.schema.as_deref(), &drop_index.name),
+            self.quote_with_table_schema(drop_index.schema.as_deref(), &drop_index.table)
         )
     }
 
@@ -281,6 +431,14 @@ impl SqlRenderer for MssqlFlavour {
         unreachable!("render_redefine_table on MSSQL")
     }
 
+    fn render_rename_foreign_key(&self, rename_foreign_key: &RenameForeignKey) -> Vec<String> {
+        vec![self.render_rename_constraint(
+            rename_foreign_key.schema.as_deref(),
+            &rename_foreign_key.constraint_name,
+            &rename_foreign_key.new_constraint_name,
+        )]
+    }
+
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
         let with_schema = format!("{}.{}", self.schema_name(), name);
 
@@ -291,14 +449,28 @@ impl SqlRenderer for MssqlFlavour {
         )
     }
 
+    fn render_rename_column(&self, name: &str, column: ColumnWalker<'_>) -> String {
+        let with_schema = format!("{}.{}.{}", self.schema_name(), column.table().name(), name);
+
+        format!(
+            "EXEC SP_RENAME N{}, N{}, N'COLUMN'",
+            Quoted::Single(with_schema),
+            Quoted::Single(column.name()),
+        )
+    }
+
     fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey) -> String {
-        let AddForeignKey { foreign_key, table } = add_foreign_key;
+        let AddForeignKey {
+            foreign_key,
+            table,
+            schema,
+        } = add_foreign_key;
         let mut add_constraint = String::with_capacity(120);
 
         write!(
             add_constraint,
             "ALTER TABLE {table} ADD ",
-            table = self.quote_with_schema(table)
+            table = self.quote_with_table_schema(schema.as_deref(), table)
         )
         .unwrap();
 
@@ -318,8 +490,24 @@ impl SqlRenderer for MssqlFlavour {
         add_constraint
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote_with_schema(&table_name))]
+    fn render_drop_table(&self, drop_table: &DropTable) -> Vec<String> {
+        vec![format!(
+            "DROP TABLE {}",
+            self.quote_with_table_schema(drop_table.schema.as_deref(), &drop_table.name)
+        )]
+    }
+}
+
+fn render_column_type(family: &ColumnTypeFamily) -> &'static str {
+    match family {
+        ColumnTypeFamily::Boolean => "bit",
+        ColumnTypeFamily::DateTime => "datetime2",
+        ColumnTypeFamily::Float => "decimal(32,16)",
+        ColumnTypeFamily::Int => "int",
+        ColumnTypeFamily::BigInt => "bigint",
+        ColumnTypeFamily::Binary => "varbinary(max)",
+        ColumnTypeFamily::String | ColumnTypeFamily::Json => "nvarchar(1000)",
+        x => unimplemented!("{:?} not handled yet", x),
     }
 }
 
@@ -11,9 +11,10 @@ use crate::{
     sql_migration::TableChange,
     sql_migration::{
         expanded_alter_column::{expand_mysql_alter_column, MysqlAlterColumn},
-        AlterEnum, AlterIndex, CreateEnum, CreateIndex, DropEnum, DropForeignKey, DropIndex,
+        AlterEnum, AlterIndex, CreateEnum, CreateIndex, DropEnum, DropForeignKey, DropIndex, DropTable,
+        RenameForeignKey,
     },
-    sql_schema_differ::{ColumnChanges, SqlSchemaDiffer},
+    sql_schema_differ::{ColumnCast, ColumnChanges, ColumnDiffer, SqlSchemaDiffer},
 };
 use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
@@ -32,7 +33,11 @@ impl SqlRenderer for MysqlFlavour {
     fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey) -> String {
         use std::fmt::Write;
 
-        let AddForeignKey { foreign_key, table } = add_foreign_key;
+        let AddForeignKey {
+            foreign_key,
+            table,
+            schema: _,
+        } = add_foreign_key;
         let mut add_constraint = String::with_capacity(120);
 
         write!(add_constraint, "ALTER TABLE {table} ADD ", table = self.quote(table)).unwrap();
@@ -65,6 +70,7 @@ impl SqlRenderer for MysqlFlavour {
     ) -> Vec<String> {
         let AlterIndex {
             table,
+            schema: _,
             index_name,
             index_new_name,
         } = alter_index;
@@ -97,9 +103,11 @@ impl SqlRenderer for MysqlFlavour {
             vec![
                 self.render_create_index(&CreateIndex {
                     table: table.clone(),
+                    schema: None,
                     index: new_index,
                     caused_by_create_table: false,
                     contains_nullable_columns: false,
+                    concurrently: false,
                 }),
                 mysql_drop_index(self, table, index_name),
             ]
@@ -117,14 +125,28 @@ impl SqlRenderer for MysqlFlavour {
         let AlterTable { table, changes } = alter_table;
 
         let mut lines = Vec::new();
+        // The statements converting the existing values explicitly, before the ALTER TABLE.
+        let mut conversions = Vec::new();
 
         for change in changes {
             match change {
                 TableChange::DropPrimaryKey { constraint_name: _ } => lines.push("DROP PRIMARY KEY".to_owned()),
-                TableChange::AddPrimaryKey { columns } => lines.push(format!(
+                // The primary key is always named `PRIMARY` on MySQL.
+                TableChange::AddPrimaryKey { columns, .. } => lines.push(format!(
                     "ADD PRIMARY KEY ({})",
                     columns.iter().map(|colname| self.quote(colname)).join(", ")
                 )),
+                TableChange::RenamePrimaryKey { .. } => unreachable!("RenamePrimaryKey on MySQL"),
+                TableChange::AddCheckConstraint { check_constraint } => {
+                    lines.push(format!("ADD {}", self.render_check_constraint(check_constraint)))
+                }
+                // `DROP CONSTRAINT` only exists from MySQL 8.0.19, but MariaDB has no `DROP CHECK`.
+                TableChange::DropCheckConstraint { constraint_name } if differ.database_info.is_mariadb() => {
+                    lines.push(format!("DROP CONSTRAINT {}", self.quote(constraint_name)))
+                }
+                TableChange::DropCheckConstraint { constraint_name } => {
+                    lines.push(format!("DROP CHECK {}", self.quote(constraint_name)))
+                }
                 TableChange::AddColumn(AddColumn { column }) => {
                     let column = ColumnWalker {
                         table,
@@ -153,8 +175,16 @@ impl SqlRenderer for MysqlFlavour {
                             column = Quoted::mysql_ident(columns.previous.name())
                         )),
                         MysqlAlterColumn::Modify { new_default, changes } => {
+                            if changes.type_changed() {
+                                conversions.extend(render_mysql_conversion(&columns, self));
+                            }
+
                             lines.push(render_mysql_modify(&changes, new_default.as_ref(), columns.next, self))
                         }
+                        MysqlAlterColumn::DropAndRecreate => {
+                            lines.push(format!("DROP COLUMN {}", self.quote(name)));
+                            lines.push(format!("ADD COLUMN {}", self.render_column(columns.next)));
+                        }
                     };
                 }
             };
@@ -164,11 +194,13 @@ impl SqlRenderer for MysqlFlavour {
             return Vec::new();
         }
 
-        vec![format!(
+        conversions.push(format!(
             "ALTER TABLE {} {}",
             self.quote(&table.name),
             lines.join(",\n    ")
-        )]
+        ));
+
+        conversions
     }
 
     fn render_column(&self, column: ColumnWalker<'_>) -> String {
@@ -208,11 +240,12 @@ impl SqlRenderer for MysqlFlavour {
             .join(",");
 
         format!(
-            " REFERENCES `{}`.`{}`({}) {} ON UPDATE CASCADE",
+            " REFERENCES `{}`.`{}`({}) {} {}",
             self.schema_name(),
             foreign_key.referenced_table,
             referenced_columns,
-            render_on_delete(&foreign_key.on_delete_action)
+            render_on_delete(&foreign_key.on_delete_action),
+            render_on_update(&foreign_key.on_update_action)
         )
     }
 
@@ -236,27 +269,25 @@ impl SqlRenderer for MysqlFlavour {
     }
 
     fn render_create_index(&self, create_index: &CreateIndex) -> String {
-        let Index { name, columns, tpe } = &create_index.index;
-        let name = if name.len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
-            &name[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
+        let index = &create_index.index;
+        let name = if index.name.len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
+            &index.name[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
         } else {
-            &name
+            &index.name
         };
-        let index_type = match tpe {
+        let index_type = match index.tpe {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
         };
         let index_name = self.quote(&name);
         let table_reference = self.quote(&create_index.table);
 
-        let columns = columns.iter().map(|c| self.quote(c));
-
         format!(
             "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
             index_type = index_type,
             index_name = index_name,
             table_reference = table_reference,
-            columns = columns.join(", ")
+            columns = self.render_index_columns(index),
         )
     }
 
@@ -289,7 +320,7 @@ impl SqlRenderer for MysqlFlavour {
                         "{}INDEX {}({})",
                         tpe,
                         self.quote(&index_name),
-                        index.columns.iter().map(|col| self.quote(&col)).join(",\n")
+                        self.render_index_columns(index)
                     )
                 })
                 .join(",\n");
@@ -299,12 +330,18 @@ impl SqlRenderer for MysqlFlavour {
             String::new()
         };
 
+        let check_constraints: String = table
+            .check_constraints()
+            .map(|check| format!(",\n{}", self.render_check_constraint(check)))
+            .collect();
+
         format!(
-            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
+            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}{check_constraints}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
             table_name = self.quote(table.name()),
             columns = columns,
             indexes = indexes,
             primary_key = primary_key,
+            check_constraints = check_constraints,
         )
     }
 
@@ -324,14 +361,18 @@ impl SqlRenderer for MysqlFlavour {
         mysql_drop_index(self, &drop_index.table, &drop_index.name)
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote(&table_name))]
+    fn render_drop_table(&self, drop_table: &DropTable) -> Vec<String> {
+        vec![format!("DROP TABLE {}", self.quote(&drop_table.name))]
     }
 
     fn render_redefine_tables(&self, _names: &[String], _differ: SqlSchemaDiffer<'_>) -> Vec<String> {
         unreachable!("render_redefine_table on MySQL")
     }
 
+    fn render_rename_foreign_key(&self, _rename_foreign_key: &RenameForeignKey) -> Vec<String> {
+        unreachable!("render_rename_foreign_key on MySQL")
+    }
+
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
         format!(
             "ALTER TABLE {} RENAME TO {}",
@@ -339,6 +380,41 @@ impl SqlRenderer for MysqlFlavour {
             new_name = self.quote(&new_name),
         )
     }
+
+    fn render_rename_column(&self, name: &str, column: ColumnWalker<'_>) -> String {
+        // RENAME COLUMN needs MySQL 8 or MariaDB 10.5, CHANGE works everywhere but requires the
+        // full column definition.
+        format!(
+            "ALTER TABLE {} CHANGE {} {}",
+            self.quote(column.table().name()),
+            self.quote(name),
+            self.render_column(column),
+        )
+    }
+}
+
+/// MODIFY converts the existing strings implicitly, with stricter parsing rules than `CONVERT`
+/// (surrounding whitespace, partial datetimes). The strings are first converted explicitly in
+/// place, so MODIFY only has to convert their canonical representation.
+fn render_mysql_conversion(columns: &ColumnDiffer<'_>, renderer: &dyn SqlFlavour) -> Option<String> {
+    if columns.cast() != ColumnCast::Risky || columns.previous.column_type_family() != &ColumnTypeFamily::String {
+        return None;
+    }
+
+    let target = match columns.next.column_type_family() {
+        ColumnTypeFamily::Int | ColumnTypeFamily::BigInt | ColumnTypeFamily::Boolean => "SIGNED",
+        ColumnTypeFamily::Decimal => "DECIMAL(65, 30)",
+        ColumnTypeFamily::DateTime => "DATETIME(3)",
+        ColumnTypeFamily::Json => "JSON",
+        _ => return None,
+    };
+
+    Some(format!(
+        "UPDATE {table} SET {column} = CONVERT({column}, {target})",
+        table = renderer.quote(columns.previous.table().name()),
+        column = renderer.quote(columns.previous.name()),
+        target = target,
+    ))
 }
 
 fn render_mysql_modify(
@@ -392,6 +468,8 @@ pub(crate) fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str>
         ColumnTypeFamily::DateTime => "datetime(3)".into(),
         ColumnTypeFamily::Float => "decimal(65,30)".into(),
         ColumnTypeFamily::Int => "int".into(),
+        ColumnTypeFamily::BigInt => "bigint".into(),
+        ColumnTypeFamily::Binary => "longblob".into(),
         // we use varchar right now as mediumtext doesn't allow default values
         // a bigger length would not allow to use such a column as primary key
         ColumnTypeFamily::String => format!("varchar{}", VARCHAR_LENGTH_PREFIX).into(),
@@ -5,7 +5,7 @@ use crate::{
     sql_migration::{
         expanded_alter_column::{expand_postgres_alter_column, PostgresAlterColumn},
         AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterIndex, AlterTable, CreateEnum, CreateIndex, DropColumn,
-        DropEnum, DropForeignKey, DropIndex, TableChange,
+        DropEnum, DropForeignKey, DropIndex, DropTable, RenameForeignKey, TableChange,
     },
     sql_schema_differ::{ColumnDiffer, SqlSchemaDiffer},
 };
@@ -23,6 +23,14 @@ impl PostgresFlavour {
             name: self.quote(name),
         }
     }
+
+    /// Qualify the name with the given schema, or with the connection's schema if there is none.
+    fn quote_with_table_schema<'a>(&'a self, schema: Option<&'a str>, name: &'a str) -> QuotedWithSchema<'a, &'a str> {
+        QuotedWithSchema {
+            schema_name: schema.unwrap_or_else(|| self.schema_name()),
+            name: self.quote(name),
+        }
+    }
 }
 
 impl SqlRenderer for PostgresFlavour {
@@ -31,13 +39,17 @@ impl SqlRenderer for PostgresFlavour {
     }
 
     fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey) -> String {
-        let AddForeignKey { foreign_key, table } = add_foreign_key;
+        let AddForeignKey {
+            foreign_key,
+            table,
+            schema,
+        } = add_foreign_key;
         let mut add_constraint = String::with_capacity(120);
 
         write!(
             add_constraint,
             "ALTER TABLE {table} ADD ",
-            table = self.quote_with_schema(table)
+            table = self.quote_with_table_schema(schema.as_deref(), table)
         )
         .unwrap();
 
@@ -113,7 +125,8 @@ impl SqlRenderer for PostgresFlavour {
                     "ALTER TABLE {schema_name}.{table_name} \
                             ALTER COLUMN {column_name} TYPE {tmp_name} \
                                 USING ({column_name}::text::{tmp_name})",
-                    schema_name = Quoted::postgres_ident(self.schema_name()),
+                    schema_name =
+                        Quoted::postgres_ident(column.table().schema_name().unwrap_or_else(|| self.schema_name())),
                     table_name = Quoted::postgres_ident(column.table().name()),
                     column_name = Quoted::postgres_ident(column.name()),
                     tmp_name = Quoted::postgres_ident(&tmp_name),
@@ -168,7 +181,7 @@ impl SqlRenderer for PostgresFlavour {
     ) -> Vec<String> {
         vec![format!(
             "ALTER INDEX {} RENAME TO {}",
-            self.quote_with_schema(&alter_index.index_name),
+            self.quote_with_table_schema(alter_index.schema.as_deref(), &alter_index.index_name),
             self.quote(&alter_index.index_new_name)
         )]
     }
@@ -190,10 +203,30 @@ impl SqlRenderer for PostgresFlavour {
                             .expect("Missing constraint name for DROP CONSTRAINT on Postgres.")
                     )
                 )),
-                TableChange::AddPrimaryKey { columns } => lines.push(format!(
-                    "ADD PRIMARY KEY ({})",
+                TableChange::AddPrimaryKey {
+                    columns,
+                    constraint_name,
+                } => lines.push(format!(
+                    "ADD {}PRIMARY KEY ({})",
+                    self.render_constraint_name(constraint_name.as_deref()),
                     columns.iter().map(|colname| self.quote(colname)).join(", ")
                 )),
+                // Renaming cannot be combined with other changes in the same statement.
+                TableChange::RenamePrimaryKey {
+                    constraint_name,
+                    new_constraint_name,
+                } => after_statements.push(format!(
+                    "ALTER TABLE {} RENAME CONSTRAINT {} TO {}",
+                    self.quote_with_table_schema(table.schema.as_deref(), &table.name),
+                    self.quote(constraint_name),
+                    self.quote(new_constraint_name)
+                )),
+                TableChange::AddCheckConstraint { check_constraint } => {
+                    lines.push(format!("ADD {}", self.render_check_constraint(check_constraint)))
+                }
+                TableChange::DropCheckConstraint { constraint_name } => {
+                    lines.push(format!("DROP CONSTRAINT {}", self.quote(constraint_name)))
+                }
                 TableChange::AddColumn(AddColumn { column }) => {
                     let column = ColumnWalker {
                         table,
@@ -226,19 +259,21 @@ impl SqlRenderer for PostgresFlavour {
             };
         }
 
-        if lines.is_empty() {
+        if lines.is_empty() && after_statements.is_empty() {
             return Vec::new();
         }
 
-        let alter_table = format!(
-            "ALTER TABLE {} {}",
-            self.quote_with_schema(&table.name),
-            lines.join(",\n")
-        );
+        let alter_table = Some(lines).filter(|lines| !lines.is_empty()).map(|lines| {
+            format!(
+                "ALTER TABLE {} {}",
+                self.quote_with_table_schema(table.schema.as_deref(), &table.name),
+                lines.join(",\n")
+            )
+        });
 
         let statements = before_statements
             .into_iter()
-            .chain(std::iter::once(alter_table))
+            .chain(alter_table.into_iter())
             .chain(after_statements.into_iter())
             .collect();
 
@@ -247,7 +282,24 @@ impl SqlRenderer for PostgresFlavour {
 
     fn render_column(&self, column: ColumnWalker<'_>) -> String {
         let column_name = self.quote(column.name());
-        let tpe_str = render_column_type(column.column_type());
+        let tpe_str = match (column.column_type_family(), column.table().schema_name()) {
+            // The enum lives in the schema of the table, which is not necessarily on the search path.
+            (ColumnTypeFamily::Enum(name), Some(schema)) => {
+                let array = if matches!(column.arity(), ColumnArity::List) {
+                    "[]"
+                } else {
+                    ""
+                };
+
+                format!(
+                    "{}.{}{}",
+                    Quoted::postgres_ident(schema),
+                    Quoted::postgres_ident(name),
+                    array
+                )
+            }
+            _ => render_column_type(column.column_type()),
+        };
         let nullability_str = render_nullability(&column);
         let default_str = column
             .default()
@@ -256,7 +308,9 @@ impl SqlRenderer for PostgresFlavour {
             .unwrap_or_else(String::new);
         let is_serial = column.is_autoincrement();
 
-        if is_serial {
+        if is_serial && matches!(column.column_type_family(), ColumnTypeFamily::BigInt) {
+            format!("{} BIGSERIAL", column_name)
+        } else if is_serial {
             format!("{} SERIAL", column_name)
         } else {
             format!("{} {} {} {}", column_name, tpe_str, nullability_str, default_str)
@@ -271,10 +325,11 @@ impl SqlRenderer for PostgresFlavour {
             .join(",");
 
         format!(
-            "REFERENCES {}({}) {} ON UPDATE CASCADE",
-            self.quote_with_schema(&foreign_key.referenced_table),
+            "REFERENCES {}({}) {} {}",
+            self.quote_with_table_schema(foreign_key.referenced_schema.as_deref(), &foreign_key.referenced_table),
             referenced_columns,
-            render_on_delete(&foreign_key.on_delete_action)
+            render_on_delete(&foreign_key.on_delete_action),
+            render_on_update(&foreign_key.on_update_action)
         )
     }
 
@@ -297,10 +352,7 @@ impl SqlRenderer for PostgresFlavour {
     fn render_create_enum(&self, create_enum: &CreateEnum) -> Vec<String> {
         let sql = format!(
             r#"CREATE TYPE {enum_name} AS ENUM ({variants})"#,
-            enum_name = QuotedWithSchema {
-                schema_name: &self.0.schema(),
-                name: Quoted::postgres_ident(&create_enum.name)
-            },
+            enum_name = self.quote_with_table_schema(create_enum.schema.as_deref(), &create_enum.name),
             variants = create_enum.variants.iter().map(Quoted::postgres_string).join(", "),
         );
 
@@ -308,21 +360,41 @@ impl SqlRenderer for PostgresFlavour {
     }
 
     fn render_create_index(&self, create_index: &CreateIndex) -> String {
-        let Index { name, columns, tpe } = &create_index.index;
-        let index_type = match tpe {
+        let index = &create_index.index;
+        let index_type = match index.tpe {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
         };
-        let index_name = self.quote(&name).to_string();
-        let table_reference = self.quote_with_schema(&create_index.table).to_string();
-        let columns = columns.iter().map(|c| self.quote(c));
+        let index_name = self.quote(&index.name).to_string();
+        let table_reference = self
+            .quote_with_table_schema(create_index.schema.as_deref(), &create_index.table)
+            .to_string();
+        let concurrently = if create_index.concurrently { "CONCURRENTLY " } else { "" };
+        let using = index
+            .algorithm
+            .map(|algorithm| format!(" USING {}", algorithm.as_access_method()))
+            .unwrap_or_default();
+        let include = if index.include.is_empty() {
+            String::new()
+        } else {
+            format!(" INCLUDE ({})", index.include.iter().map(|c| self.quote(c)).join(", "))
+        };
+        let predicate = index
+            .predicate
+            .as_ref()
+            .map(|predicate| format!(" WHERE {}", predicate))
+            .unwrap_or_default();
 
         format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
+            "CREATE {index_type}INDEX {concurrently}{index_name} ON {table_reference}{using}({columns}){include}{predicate}",
             index_type = index_type,
+            concurrently = concurrently,
             index_name = index_name,
             table_reference = table_reference,
-            columns = columns.join(", ")
+            using = using,
+            columns = self.render_index_columns(index),
+            include = include,
+            predicate = predicate,
         )
     }
 
@@ -332,23 +404,35 @@ impl SqlRenderer for PostgresFlavour {
         let primary_columns = table.table.primary_key_columns();
         let pk_column_names = primary_columns.iter().map(|col| self.quote(&col)).join(",");
         let pk = if pk_column_names.len() > 0 {
-            format!(",\nPRIMARY KEY ({})", pk_column_names)
+            let constraint_name = table.primary_key().and_then(|pk| pk.constraint_name.as_deref());
+
+            format!(
+                ",\n{}PRIMARY KEY ({})",
+                self.render_constraint_name(constraint_name),
+                pk_column_names
+            )
         } else {
             String::new()
         };
 
+        let check_constraints: String = table
+            .check_constraints()
+            .map(|check| format!(",\n{}", self.render_check_constraint(check)))
+            .collect();
+
         format!(
-            "CREATE TABLE {table_name} (\n{columns}{primary_key}\n)",
-            table_name = self.quote_with_schema(table.name()),
+            "CREATE TABLE {table_name} (\n{columns}{primary_key}{check_constraints}\n)",
+            table_name = self.quote_with_table_schema(table.schema_name(), table.name()),
             columns = columns,
             primary_key = pk,
+            check_constraints = check_constraints,
         )
     }
 
     fn render_drop_enum(&self, drop_enum: &DropEnum) -> Vec<String> {
         let sql = format!(
             "DROP TYPE {enum_name}",
-            enum_name = Quoted::postgres_ident(&drop_enum.name),
+            enum_name = self.quote_with_table_schema(drop_enum.schema.as_deref(), &drop_enum.name),
         );
 
         vec![sql]
@@ -357,28 +441,52 @@ impl SqlRenderer for PostgresFlavour {
     fn render_drop_foreign_key(&self, drop_foreign_key: &DropForeignKey) -> String {
         format!(
             "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
-            table = self.quote_with_schema(&drop_foreign_key.table),
+            table = self.quote_with_table_schema(drop_foreign_key.schema.as_deref(), &drop_foreign_key.table),
             constraint_name = Quoted::postgres_ident(&drop_foreign_key.constraint_name),
         )
     }
 
     fn render_drop_index(&self, drop_index: &DropIndex) -> String {
-        format!("DROP INDEX {}", self.quote_with_schema(&drop_index.name))
+        format!(
+            "DROP INDEX {}",
+            self.quote_with_table_schema(drop_index.schema.as_deref(), &drop_index.name)
+        )
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote_with_schema(&table_name))]
+    fn render_drop_table(&self, drop_table: &DropTable) -> Vec<String> {
+        vec![format!(
+            "DROP TABLE {}",
+            self.quote_with_table_schema(drop_table.schema.as_deref(), &drop_table.name)
+        )]
     }
 
     fn render_redefine_tables(&self, _names: &[String], _differ: SqlSchemaDiffer<'_>) -> Vec<String> {
         unreachable!("render_redefine_table on Postgres")
     }
 
+    fn render_rename_foreign_key(&self, rename_foreign_key: &RenameForeignKey) -> Vec<String> {
+        vec![format!(
+            "ALTER TABLE {} RENAME CONSTRAINT {} TO {}",
+            self.quote_with_table_schema(rename_foreign_key.schema.as_deref(), &rename_foreign_key.table),
+            self.quote(&rename_foreign_key.constraint_name),
+            self.quote(&rename_foreign_key.new_constraint_name)
+        )]
+    }
+
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
         format!(
             "ALTER TABLE {} RENAME TO {}",
             self.quote_with_schema(&name),
-            new_name = self.quote_with_schema(&new_name).to_string(),
+            new_name = self.quote(&new_name),
+        )
+    }
+
+    fn render_rename_column(&self, name: &str, column: ColumnWalker<'_>) -> String {
+        format!(
+            "ALTER TABLE {} RENAME COLUMN {} TO {}",
+            self.quote_with_schema(column.table().name()),
+            self.quote(name),
+            self.quote(column.name()),
         )
     }
 }
@@ -398,6 +506,8 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
         ColumnTypeFamily::DateTime => format!("timestamp(3) {}", array),
         ColumnTypeFamily::Float => format!("Decimal(65,30) {}", array),
         ColumnTypeFamily::Int => format!("integer {}", array),
+        ColumnTypeFamily::BigInt => format!("bigint {}", array),
+        ColumnTypeFamily::Binary => format!("bytea {}", array),
         ColumnTypeFamily::String => format!("text {}", array),
         ColumnTypeFamily::Enum(name) => format!("{}{}", Quoted::postgres_ident(name), array),
         ColumnTypeFamily::Json => format!("jsonb {}", array),
@@ -460,6 +570,24 @@ fn render_alter_column(
                 &alter_column_prefix,
                 render_column_type(&ty)
             )),
+            PostgresAlterColumn::Cast(ty) => {
+                // Enums only have casts from and to text.
+                let through_text = if differ.previous.column_type_family().as_enum().is_some()
+                    || differ.next.column_type_family().as_enum().is_some()
+                {
+                    "::text"
+                } else {
+                    ""
+                };
+
+                clauses.push(format!(
+                    "{prefix} SET DATA TYPE {ty} USING {column}{through_text}::{ty}",
+                    prefix = &alter_column_prefix,
+                    ty = render_column_type(&ty).trim_end(),
+                    column = column_name,
+                    through_text = through_text,
+                ))
+            }
             PostgresAlterColumn::AddSequence => {
                 // We imitate the sequence that would be automatically created on a `SERIAL` column.
                 //
@@ -4,9 +4,9 @@ use crate::{
     flavour::SqliteFlavour,
     sql_migration::{
         AddColumn, AddForeignKey, AlterEnum, AlterIndex, AlterTable, CreateEnum, CreateIndex, DropEnum, DropForeignKey,
-        DropIndex, TableChange,
+        DropIndex, DropTable, RenameForeignKey, TableChange,
     },
-    sql_schema_differ::{ColumnDiffer, SqlSchemaDiffer, TableDiffer},
+    sql_schema_differ::{ColumnCast, ColumnDiffer, SqlSchemaDiffer, TableDiffer},
 };
 use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
@@ -33,34 +33,46 @@ impl SqlRenderer for SqliteFlavour {
     }
 
     fn render_create_index(&self, create_index: &CreateIndex) -> String {
-        let Index { name, columns, tpe } = &create_index.index;
-        let index_type = match tpe {
+        let index = &create_index.index;
+        let index_type = match index.tpe {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
         };
-        let index_name = self.quote(&name).to_string();
+        let index_name = self.quote(&index.name).to_string();
         let table_reference = self.quote(&create_index.table).to_string();
-        let columns = columns.iter().map(|c| self.quote(c));
+        let predicate = index
+            .predicate
+            .as_ref()
+            .map(|predicate| format!(" WHERE {}", predicate))
+            .unwrap_or_default();
 
         format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
+            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns}){predicate}",
             index_type = index_type,
             index_name = index_name,
             table_reference = table_reference,
-            columns = columns.join(", ")
+            columns = self.render_index_columns(index),
+            predicate = predicate,
         )
     }
 
     fn render_column(&self, column: ColumnWalker<'_>) -> String {
         let column_name = self.quote(column.name());
-        let tpe_str = render_column_type(column.column_type());
+        let is_autoincrementing_primary_key = column.is_autoincrement() && column.is_single_primary_key();
+        // SQLite only allows AUTOINCREMENT on columns declared exactly as `INTEGER PRIMARY KEY`,
+        // which are 64 bit wide anyway.
+        let tpe_str = if is_autoincrementing_primary_key {
+            "INTEGER"
+        } else {
+            render_column_type(column.column_type())
+        };
         let nullability_str = render_nullability(&column);
         let default_str = column
             .default()
             .filter(|default| !matches!(default, DefaultValue::DBGENERATED(_) | DefaultValue::SEQUENCE(_)))
             .map(|default| format!(" DEFAULT {}", self.render_default(default, column.column_type_family())))
             .unwrap_or_else(String::new);
-        let auto_increment_str = if column.is_autoincrement() && column.is_single_primary_key() {
+        let auto_increment_str = if is_autoincrementing_primary_key {
             " PRIMARY KEY AUTOINCREMENT"
         } else {
             ""
@@ -85,10 +97,11 @@ impl SqlRenderer for SqliteFlavour {
             .join(",");
 
         format!(
-            "REFERENCES {referenced_table}({referenced_fields}) {on_delete_action} ON UPDATE CASCADE",
+            "REFERENCES {referenced_table}({referenced_fields}) {on_delete_action} {on_update_action}",
             referenced_table = self.quote(&foreign_key.referenced_table),
             referenced_fields = referenced_fields,
-            on_delete_action = render_on_delete(&foreign_key.on_delete_action)
+            on_delete_action = render_on_delete(&foreign_key.on_delete_action),
+            on_update_action = render_on_update(&foreign_key.on_update_action)
         )
     }
 
@@ -96,6 +109,7 @@ impl SqlRenderer for SqliteFlavour {
         match (default, family) {
             (DefaultValue::DBGENERATED(val), _) => val.as_str().into(),
             (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
+            | (DefaultValue::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json)
             | (DefaultValue::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
                 format!("'{}'", escape_quotes(&val)).into()
             }
@@ -138,8 +152,11 @@ impl SqlRenderer for SqliteFlavour {
                 }
                 TableChange::DropPrimaryKey { .. } => unreachable!("DropPrimaryKey on SQLite"),
                 TableChange::AddPrimaryKey { .. } => unreachable!("AddPrimaryKey on SQLite"),
+                TableChange::RenamePrimaryKey { .. } => unreachable!("RenamePrimaryKey on SQLite"),
                 TableChange::DropColumn(_) => unreachable!("DropColumn on SQLite"),
                 TableChange::AlterColumn(_) => unreachable!("AlterColumn on SQLite"),
+                TableChange::AddCheckConstraint { .. } => unreachable!("AddCheckConstraint on SQLite"),
+                TableChange::DropCheckConstraint { .. } => unreachable!("DropCheckConstraint on SQLite"),
             };
         }
 
@@ -186,12 +203,18 @@ impl SqlRenderer for SqliteFlavour {
             String::new()
         };
 
+        let check_constraints: String = table
+            .check_constraints()
+            .map(|check| format!(",\n{}", self.render_check_constraint(check)))
+            .collect();
+
         format!(
-            "CREATE TABLE {table_name} (\n{columns}{foreign_keys}{primary_key}\n)",
+            "CREATE TABLE {table_name} (\n{columns}{foreign_keys}{primary_key}{check_constraints}\n)",
             table_name = self.quote(table.name()),
             columns = columns,
             foreign_keys = foreign_keys,
             primary_key = primary_key,
+            check_constraints = check_constraints,
         )
     }
 
@@ -207,14 +230,14 @@ impl SqlRenderer for SqliteFlavour {
         format!("DROP INDEX {}", self.quote(&drop_index.name))
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
+    fn render_drop_table(&self, drop_table: &DropTable) -> Vec<String> {
         // Turning off the pragma is safe, because schema validation would forbid foreign keys
         // to a non-existent model. There appears to be no other way to deal with cyclic
         // dependencies in the dropping order of tables in the presence of foreign key
         // constraints on SQLite.
         vec![
             "PRAGMA foreign_keys=off".to_string(),
-            format!("DROP TABLE {}", self.quote(&table_name)),
+            format!("DROP TABLE {}", self.quote(&drop_table.name)),
             "PRAGMA foreign_keys=on".to_string(),
         ]
     }
@@ -257,9 +280,11 @@ impl SqlRenderer for SqliteFlavour {
             result.extend(differ.next.table.indices.iter().map(|index| {
                 self.render_create_index(&CreateIndex {
                     table: differ.next.name().to_owned(),
+                    schema: None,
                     index: index.clone(),
                     caused_by_create_table: false,
                     contains_nullable_columns: false,
+                    concurrently: false,
                 })
             }));
         }
@@ -270,9 +295,22 @@ impl SqlRenderer for SqliteFlavour {
         result
     }
 
+    fn render_rename_foreign_key(&self, _rename_foreign_key: &RenameForeignKey) -> Vec<String> {
+        unreachable!("render_rename_foreign_key on SQLite")
+    }
+
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
         format!("ALTER TABLE {} RENAME TO {}", self.quote(&name), self.quote(new_name),)
     }
+
+    fn render_rename_column(&self, name: &str, column: ColumnWalker<'_>) -> String {
+        format!(
+            "ALTER TABLE {} RENAME COLUMN {} TO {}",
+            self.quote(column.table().name()),
+            self.quote(name),
+            self.quote(column.name()),
+        )
+    }
 }
 
 fn render_column_type(t: &ColumnType) -> &'static str {
@@ -281,7 +319,10 @@ fn render_column_type(t: &ColumnType) -> &'static str {
         ColumnTypeFamily::DateTime => "DATETIME",
         ColumnTypeFamily::Float => "REAL",
         ColumnTypeFamily::Int => "INTEGER",
+        ColumnTypeFamily::BigInt => "BIGINT",
+        ColumnTypeFamily::Binary => "BLOB",
         ColumnTypeFamily::String => "TEXT",
+        ColumnTypeFamily::Json => "JSON",
         x => unimplemented!("{:?} not handled yet", x),
     }
 }
@@ -296,7 +337,8 @@ fn escape_quotes(s: &str) -> Cow<'_, str> {
 ///
 /// The process is complicated by the migrations that add make an optional column required with a
 /// default value. In this case, we need to treat them differently and `coalesce`ing them with the
-/// default value, since SQLite does not have the `DEFAULT` keyword.
+/// default value, since SQLite does not have the `DEFAULT` keyword. The columns whose type changed
+/// are converted with a `CAST`.
 fn copy_current_table_into_new_table(
     steps: &mut Vec<String>,
     differ: &TableDiffer<'_>,
@@ -314,14 +356,13 @@ fn copy_current_table_into_new_table(
         })
         .collect();
 
-    let intersection_columns: Vec<&str> = differ
+    let intersection_columns: Vec<ColumnDiffer<'_>> = differ
         .column_pairs()
         .filter(|columns| {
             !columns_that_became_required_with_a_default
                 .iter()
                 .any(|excluded| excluded.name() == columns.name())
         })
-        .map(|columns| columns.name())
         .collect();
 
     let mut query = String::with_capacity(40);
@@ -330,7 +371,7 @@ fn copy_current_table_into_new_table(
 
     let mut destination_columns = intersection_columns
         .iter()
-        .copied()
+        .map(|columns| columns.name())
         .chain(
             columns_that_became_required_with_a_default
                 .iter()
@@ -350,10 +391,21 @@ fn copy_current_table_into_new_table(
 
     let mut source_columns = intersection_columns
         .iter()
-        .map(|s| format!("{}", Quoted::sqlite_ident(s)))
+        .map(|columns| {
+            if columns.all_changes().type_changed() {
+                format!(
+                    "{value} AS {column_name}",
+                    value = render_copied_value(columns),
+                    column_name = Quoted::sqlite_ident(columns.name())
+                )
+            } else {
+                format!("{}", Quoted::sqlite_ident(columns.name()))
+            }
+        })
         .chain(columns_that_became_required_with_a_default.iter().map(|columns| {
             format!(
-                "coalesce({column_name}, {default_value}) AS {column_name}",
+                "coalesce({value}, {default_value}) AS {column_name}",
+                value = render_copied_value(columns),
                 column_name = Quoted::sqlite_ident(columns.name()),
                 default_value = flavour.render_default(
                     columns.next.default().expect("default on required column with default"),
@@ -377,3 +429,17 @@ fn copy_current_table_into_new_table(
 
     Ok(())
 }
+
+/// The value of the column in the current table, cast to the type of the column in the new table if
+/// it changed and SQLite can cast it. The values that cannot be cast are copied as they are.
+fn render_copied_value(columns: &ColumnDiffer<'_>) -> String {
+    if columns.all_changes().type_changed() && columns.cast() != ColumnCast::Impossible {
+        format!(
+            "CAST({column_name} AS {column_type})",
+            column_name = Quoted::sqlite_ident(columns.name()),
+            column_type = render_column_type(columns.next.column_type())
+        )
+    } else {
+        format!("{}", Quoted::sqlite_ident(columns.name()))
+    }
+}
@@ -2,10 +2,11 @@ mod sql_schema_calculator_flavour;
 
 pub(super) use sql_schema_calculator_flavour::SqlSchemaCalculatorFlavour;
 
-use crate::{flavour::SqlFlavour, sql_renderer::IteratorJoin, DatabaseInfo};
+use crate::{flavour::SqlFlavour, sql_renderer::IteratorJoin, sql_schema_differ::RenameHints, DatabaseInfo};
 use datamodel::{
     walkers::{walk_models, walk_relations, ModelWalker, ScalarFieldWalker, TypeWalker},
-    Datamodel, DefaultValue, FieldArity, IndexDefinition, IndexType, ScalarType, ValueGenerator, ValueGeneratorFn,
+    Datamodel, DefaultValue, FieldArity, IndexAlgorithm, IndexDefinition, IndexType, ScalarType, SortOrder,
+    ValueGenerator, ValueGeneratorFn,
 };
 use prisma_value::PrismaValue;
 use quaint::prelude::SqlFamily;
@@ -31,6 +32,31 @@ impl<'a> SqlSchemaCalculator<'a> {
         calculator.calculate_internal()
     }
 
+    /// The renames requested in the datamodel with `@map(previous: ...)` and `@@map(previous: ...)`.
+    pub(crate) fn rename_hints(data_model: &Datamodel) -> RenameHints {
+        let mut hints = RenameHints::default();
+
+        for model in walk_table_models(data_model) {
+            if let Some(previous_name) = model.previous_db_name() {
+                hints
+                    .tables
+                    .push((previous_name.to_owned(), model.db_name().to_owned()));
+            }
+
+            for field in model.scalar_fields() {
+                if let Some(previous_name) = field.previous_db_name() {
+                    hints.columns.push((
+                        model.db_name().to_owned(),
+                        previous_name.to_owned(),
+                        field.db_name().to_owned(),
+                    ));
+                }
+            }
+        }
+
+        hints
+    }
+
     fn calculate_internal(&self) -> sql::SqlSchema {
         let mut tables = Vec::with_capacity(self.data_model.models().len());
         let model_tables_without_inline_relations = self.calculate_model_tables();
@@ -49,11 +75,12 @@ impl<'a> SqlSchemaCalculator<'a> {
             tables,
             enums,
             sequences,
+            views: Vec::new(),
         }
     }
 
     fn calculate_model_tables<'iter>(&'iter self) -> impl Iterator<Item = (ModelWalker<'a>, sql::Table)> + 'iter {
-        walk_models(self.data_model).map(move |model| {
+        walk_table_models(self.data_model).map(move |model| {
             let columns = model
                 .scalar_fields()
                 .flat_map(|f| match f.field_type() {
@@ -92,6 +119,12 @@ impl<'a> SqlSchemaCalculator<'a> {
                             auto_increment: has_auto_increment_default || is_sqlite_integer_primary_key
                         })
                     } ,
+                    TypeWalker::CompositeType(_) => Some(sql::Column {
+                        name: f.db_name().to_owned(),
+                        tpe: composite_type_column_type(&f),
+                        default: None,
+                        auto_increment: false,
+                    }),
                     _ => None,
                 })
                 .collect();
@@ -102,14 +135,21 @@ impl<'a> SqlSchemaCalculator<'a> {
                     .map(|field| field.db_name().to_owned())
                     .collect(),
                 sequence: None,
-                constraint_name: None,
+                constraint_name: model.primary_key_constraint_name().map(String::from),
             }).filter(|pk| !pk.columns.is_empty());
 
             let single_field_indexes = model.scalar_fields().filter(|f| f.is_unique()).map(|f| {
                 sql::Index {
-                    name: format!("{}.{}_unique", &model.db_name(), &f.db_name()),
+                    name: f
+                        .unique_constraint_name()
+                        .map(String::from)
+                        .unwrap_or_else(|| format!("{}.{}_unique", &model.db_name(), &f.db_name())),
                     columns: vec![f.db_name().to_owned()],
                     tpe: sql::IndexType::Unique,
+                    column_options: Default::default(),
+                    algorithm: None,
+                    predicate: None,
+                    include: Vec::new(),
                 }
             });
 
@@ -125,7 +165,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                     IndexType::Normal => sql::IndexType::Normal,
                 };
 
-                let index_name = index_definition.name.clone().unwrap_or_else(|| {
+                let index_name = index_definition.db_name().map(String::from).unwrap_or_else(|| {
                     format!(
                         "{table}.{fields}_{qualifier}",
                         table = &model.db_name(),
@@ -143,15 +183,58 @@ impl<'a> SqlSchemaCalculator<'a> {
                         .map(|field| field.db_name().to_owned())
                         .collect(),
                     tpe: index_type,
+                    column_options: referenced_fields
+                        .iter()
+                        .filter_map(|field| {
+                            let options = index_definition.field_options.get(field.name())?;
+
+                            Some((
+                                field.db_name().to_owned(),
+                                sql::IndexColumnOptions {
+                                    sort_order: match options.sort_order {
+                                        SortOrder::Asc => sql::SQLSortOrder::Asc,
+                                        SortOrder::Desc => sql::SQLSortOrder::Desc,
+                                    },
+                                    length: options.length,
+                                },
+                            ))
+                        })
+                        .collect(),
+                    algorithm: index_definition.algorithm.map(|algorithm| match algorithm {
+                        IndexAlgorithm::Hash => sql::IndexAlgorithm::Hash,
+                        IndexAlgorithm::Gist => sql::IndexAlgorithm::Gist,
+                        IndexAlgorithm::Gin => sql::IndexAlgorithm::Gin,
+                        IndexAlgorithm::Brin => sql::IndexAlgorithm::Brin,
+                    }),
+                    predicate: index_definition.predicate.clone(),
+                    include: index_definition
+                        .include
+                        .iter()
+                        .map(|field_name| {
+                            model
+                                .find_scalar_field(field_name)
+                                .expect("Unknown field in index directive.")
+                                .db_name()
+                                .to_owned()
+                        })
+                        .collect(),
                 }
             });
 
             let table = sql::Table {
                 name: model.database_name().to_owned(),
+                schema: model.schema().map(String::from),
                 columns,
                 indices: single_field_indexes.chain(multiple_field_indexes).collect(),
                 primary_key,
                 foreign_keys: Vec::new(),
+                check_constraints: model
+                    .check_constraints()
+                    .map(|check| sql::CheckConstraint {
+                        name: check.name.clone(),
+                        expression: check.expression.clone(),
+                    })
+                    .collect(),
             };
 
             (model, table)
@@ -173,15 +256,28 @@ impl<'a> SqlSchemaCalculator<'a> {
 
             // Foreign key
             {
+                // MSSQL will crash when creating a cyclic cascade, self-relations are rendered with NO ACTION.
+                let is_mssql_self_relation = self.flavour.sql_family() == SqlFamily::Mssql
+                    && relation_field.referenced_table_name() == model.db_name();
+
                 let fk = sql::ForeignKey {
-                    constraint_name: None,
+                    constraint_name: relation_field.foreign_key_constraint_name().map(String::from),
                     columns: fk_columns,
                     referenced_table: relation_field.referenced_table_name().to_owned(),
+                    referenced_schema: relation_field.referenced_schema().map(String::from),
                     referenced_columns: relation_field.referenced_columns().map(String::from).collect(),
-                    on_update_action: sql::ForeignKeyAction::Cascade,
-                    on_delete_action: match column_arity(relation_field.arity()) {
-                        ColumnArity::Required => sql::ForeignKeyAction::Cascade,
-                        _ => sql::ForeignKeyAction::SetNull,
+                    on_update_action: match relation_field.on_update_action() {
+                        _ if is_mssql_self_relation => sql::ForeignKeyAction::NoAction,
+                        Some(action) => self.flavour.foreign_key_action(action),
+                        None => sql::ForeignKeyAction::Cascade,
+                    },
+                    on_delete_action: match relation_field.on_delete_action() {
+                        _ if is_mssql_self_relation => sql::ForeignKeyAction::NoAction,
+                        Some(action) => self.flavour.foreign_key_action(action),
+                        None => match column_arity(relation_field.arity()) {
+                            ColumnArity::Required => sql::ForeignKeyAction::Cascade,
+                            _ => sql::ForeignKeyAction::SetNull,
+                        },
                     },
                 };
 
@@ -219,6 +315,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                         constraint_name: None,
                         columns: vec![m2m.model_a_column().into()],
                         referenced_table: model_a.db_name().into(),
+                        referenced_schema: model_a.schema().map(String::from),
                         referenced_columns: vec![model_a_id.db_name().into()],
                         on_update_action: Self::m2m_foreign_key_action(family, &model_a, &model_b),
                         on_delete_action: Self::m2m_foreign_key_action(family, &model_a, &model_b),
@@ -227,6 +324,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                         constraint_name: None,
                         columns: vec![m2m.model_b_column().into()],
                         referenced_table: model_b.db_name().into(),
+                        referenced_schema: model_b.schema().map(String::from),
                         referenced_columns: vec![model_b_id.db_name().into()],
                         on_update_action: Self::m2m_foreign_key_action(family, &model_a, &model_b),
                         on_delete_action: Self::m2m_foreign_key_action(family, &model_a, &model_b),
@@ -238,11 +336,19 @@ impl<'a> SqlSchemaCalculator<'a> {
                         name: format!("{}_AB_unique", &table_name),
                         columns: vec![m2m.model_a_column().into(), m2m.model_b_column().into()],
                         tpe: sql::IndexType::Unique,
+                        column_options: Default::default(),
+                        algorithm: None,
+                        predicate: None,
+                        include: Vec::new(),
                     },
                     sql::Index {
                         name: format!("{}_B_index", &table_name),
                         columns: vec![m2m.model_b_column().into()],
                         tpe: sql::IndexType::Normal,
+                        column_options: Default::default(),
+                        algorithm: None,
+                        predicate: None,
+                        include: Vec::new(),
                     },
                 ];
 
@@ -263,15 +369,23 @@ impl<'a> SqlSchemaCalculator<'a> {
 
                 sql::Table {
                     name: table_name,
+                    // Join tables live next to the first model of the relation.
+                    schema: model_a.schema().map(String::from),
                     columns,
                     indices: indexes,
                     primary_key: None,
                     foreign_keys,
+                    check_constraints: vec![],
                 }
             })
     }
 }
 
+/// Models backed by tables. Views are not managed by migrations.
+fn walk_table_models(datamodel: &Datamodel) -> impl Iterator<Item = ModelWalker<'_>> {
+    walk_models(datamodel).filter(|model| !model.is_view())
+}
+
 fn migration_value_new(field: &ScalarFieldWalker<'_>) -> Option<sql_schema_describer::DefaultValue> {
     let value = match &field.default_value()? {
         datamodel::DefaultValue::Single(s) => match field.field_type() {
@@ -317,6 +431,16 @@ fn enum_column_type(field: &ScalarFieldWalker<'_>, database_info: &DatabaseInfo,
     }
 }
 
+/// Composite type values are stored inline as Json. Lists of them are stored as a single Json array.
+fn composite_type_column_type(field: &ScalarFieldWalker<'_>) -> sql::ColumnType {
+    let arity = match field.arity() {
+        FieldArity::Required => sql::ColumnArity::Required,
+        FieldArity::Optional | FieldArity::List => sql::ColumnArity::Nullable,
+    };
+
+    sql::ColumnType::pure(sql::ColumnTypeFamily::Json, arity)
+}
+
 fn column_type(field: &ScalarFieldWalker<'_>) -> sql::ColumnType {
     column_type_for_scalar_type(&scalar_type_for_field(field), column_arity(field.arity()))
 }
@@ -345,6 +469,7 @@ fn column_arity(arity: FieldArity) -> sql::ColumnArity {
 fn column_type_for_scalar_type(scalar_type: &ScalarType, column_arity: ColumnArity) -> sql::ColumnType {
     match scalar_type {
         ScalarType::Int => sql::ColumnType::pure(sql::ColumnTypeFamily::Int, column_arity),
+        ScalarType::BigInt => sql::ColumnType::pure(sql::ColumnTypeFamily::BigInt, column_arity),
         ScalarType::Float => sql::ColumnType::pure(sql::ColumnTypeFamily::Float, column_arity),
         ScalarType::Boolean => sql::ColumnType::pure(sql::ColumnTypeFamily::Boolean, column_arity),
         ScalarType::String => sql::ColumnType::pure(sql::ColumnTypeFamily::String, column_arity),
@@ -372,6 +497,10 @@ fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_names: &[
         name: format!("{}_{}_unique", table.name, columns_suffix),
         columns: column_names.to_owned(),
         tpe: sql::IndexType::Unique,
+        column_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
     };
 
     table.indices.push(index);
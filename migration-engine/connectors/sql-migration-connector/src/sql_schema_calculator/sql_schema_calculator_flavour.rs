@@ -4,7 +4,7 @@ mod postgres;
 mod sqlite;
 
 use super::SqlSchemaCalculator;
-use datamodel::{walkers::ScalarFieldWalker, ScalarType};
+use datamodel::{walkers::ScalarFieldWalker, ReferentialAction, ScalarType};
 use datamodel_connector::NativeTypeInstance;
 use sql_schema_describer as sql;
 
@@ -13,6 +13,17 @@ pub(crate) trait SqlSchemaCalculatorFlavour {
         Vec::new()
     }
 
+    /// Maps a referential action of the datamodel to the foreign key action of the database.
+    fn foreign_key_action(&self, action: ReferentialAction) -> sql::ForeignKeyAction {
+        match action {
+            ReferentialAction::Cascade => sql::ForeignKeyAction::Cascade,
+            ReferentialAction::Restrict => sql::ForeignKeyAction::Restrict,
+            ReferentialAction::NoAction => sql::ForeignKeyAction::NoAction,
+            ReferentialAction::SetNull => sql::ForeignKeyAction::SetNull,
+            ReferentialAction::SetDefault => sql::ForeignKeyAction::SetDefault,
+        }
+    }
+
     fn column_type_for_native_type(
         &self,
         _field: &ScalarFieldWalker<'_>,
@@ -1,11 +1,21 @@
 use super::SqlSchemaCalculatorFlavour;
 use crate::flavour::MssqlFlavour;
-use datamodel::{walkers::ScalarFieldWalker, FieldArity, ScalarType};
+use datamodel::{walkers::ScalarFieldWalker, FieldArity, ReferentialAction, ScalarType};
 use datamodel_connector::NativeTypeInstance;
 use native_types::MssqlType;
-use sql_schema_describer::{ColumnArity, ColumnType, ColumnTypeFamily};
+use sql_schema_describer::{ColumnArity, ColumnType, ColumnTypeFamily, ForeignKeyAction};
 
 impl SqlSchemaCalculatorFlavour for MssqlFlavour {
+    fn foreign_key_action(&self, action: ReferentialAction) -> ForeignKeyAction {
+        match action {
+            // SQL Server has no RESTRICT, but NO ACTION is never deferred there.
+            ReferentialAction::Restrict | ReferentialAction::NoAction => ForeignKeyAction::NoAction,
+            ReferentialAction::Cascade => ForeignKeyAction::Cascade,
+            ReferentialAction::SetNull => ForeignKeyAction::SetNull,
+            ReferentialAction::SetDefault => ForeignKeyAction::SetDefault,
+        }
+    }
+
     fn column_type_for_native_type(
         &self,
         field: &ScalarFieldWalker<'_>,
@@ -24,6 +24,7 @@ impl SqlSchemaCalculatorFlavour for MysqlFlavour {
                     model_name = field.model().database_name(),
                     field_name = field.db_name()
                 ),
+                schema: None,
                 values: enum_tpe.r#enum.database_values(),
             };
 
@@ -1,6 +1,9 @@
 use super::SqlSchemaCalculatorFlavour;
 use crate::{flavour::PostgresFlavour, sql_schema_calculator::SqlSchemaCalculator};
-use datamodel::{walkers::ScalarFieldWalker, ScalarType, WithDatabaseName};
+use datamodel::{
+    walkers::{walk_scalar_fields, ScalarFieldWalker},
+    Enum, ScalarType, WithDatabaseName,
+};
 use datamodel_connector::NativeTypeInstance;
 use native_types::PostgresType;
 use sql_schema_describer::{self as sql};
@@ -10,9 +13,14 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
         calculator
             .data_model
             .enums()
-            .map(|r#enum| sql::Enum {
-                name: r#enum.final_database_name().to_owned(),
-                values: r#enum.database_values(),
+            .flat_map(|r#enum| {
+                enum_schemas(self, calculator, r#enum)
+                    .into_iter()
+                    .map(move |schema| sql::Enum {
+                        name: r#enum.final_database_name().to_owned(),
+                        schema,
+                        values: r#enum.database_values(),
+                    })
             })
             .collect()
     }
@@ -76,3 +84,32 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
         }
     }
 }
+
+/// The schemas an enum has to be created in. Enum columns are only matched with enums from the
+/// schema of their table, so with several schemas, the enum is created in every schema where a
+/// table uses it.
+fn enum_schemas(flavour: &PostgresFlavour, calculator: &SqlSchemaCalculator<'_>, r#enum: &Enum) -> Vec<Option<String>> {
+    if flavour.1.is_empty() {
+        return vec![None];
+    }
+
+    let mut schemas: Vec<Option<String>> = walk_scalar_fields(calculator.data_model)
+        .filter(|field| {
+            field
+                .field_type()
+                .as_enum()
+                .map(|enum_ref| enum_ref.r#enum.name == r#enum.name)
+                .unwrap_or(false)
+        })
+        .map(|field| field.model().schema().map(String::from))
+        .collect();
+
+    schemas.sort();
+    schemas.dedup();
+
+    if schemas.is_empty() {
+        schemas.push(flavour.1.first().cloned());
+    }
+
+    schemas
+}
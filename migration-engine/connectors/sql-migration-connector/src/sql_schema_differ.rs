@@ -1,10 +1,12 @@
 mod column;
 mod enums;
 mod index;
+mod renames;
 mod sql_schema_differ_flavour;
 mod table;
 
-pub(crate) use column::{ColumnChange, ColumnChanges, ColumnDiffer};
+pub(crate) use column::{ColumnCast, ColumnChange, ColumnChanges, ColumnDiffer};
+pub(crate) use renames::RenameHints;
 pub(crate) use sql_schema_differ_flavour::SqlSchemaDifferFlavour;
 pub(crate) use table::TableDiffer;
 
@@ -12,7 +14,7 @@ use crate::*;
 use enums::EnumDiffer;
 use sql_migration::{
     AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterIndex, AlterTable, CreateEnum, CreateIndex, CreateTable,
-    DropColumn, DropEnum, DropForeignKey, DropIndex, DropTable, SqlMigrationStep, TableChange,
+    DropColumn, DropEnum, DropForeignKey, DropIndex, DropTable, RenameForeignKey, SqlMigrationStep, TableChange,
 };
 use sql_schema_describer::{
     walkers::{ForeignKeyWalker, TableWalker},
@@ -39,6 +41,7 @@ pub struct SqlSchemaDiff {
     pub create_indexes: Vec<CreateIndex>,
     pub drop_indexes: Vec<DropIndex>,
     pub alter_indexes: Vec<AlterIndex>,
+    pub rename_foreign_keys: Vec<RenameForeignKey>,
     pub create_enums: Vec<CreateEnum>,
     pub drop_enums: Vec<DropEnum>,
     pub alter_enums: Vec<AlterEnum>,
@@ -76,6 +79,10 @@ impl SqlSchemaDiff {
             .chain(wrap_as_step(self.add_foreign_keys, SqlMigrationStep::AddForeignKey))
             .chain(wrap_as_step(self.drop_tables, SqlMigrationStep::DropTable))
             .chain(wrap_as_step(self.alter_indexes, SqlMigrationStep::AlterIndex))
+            .chain(wrap_as_step(
+                self.rename_foreign_keys,
+                SqlMigrationStep::RenameForeignKey,
+            ))
             .collect()
     }
 }
@@ -120,6 +127,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             create_indexes: self.create_indexes(&tables_to_redefine),
             drop_indexes: self.drop_indexes(),
             alter_indexes,
+            rename_foreign_keys: self.rename_foreign_keys(&tables_to_redefine),
             create_enums: self.create_enums(),
             drop_enums: self.drop_enums(),
             alter_enums: self.alter_enums(),
@@ -147,6 +155,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         for dropped_table in self.dropped_tables() {
             let drop_table = DropTable {
                 name: dropped_table.name.clone(),
+                schema: dropped_table.schema.clone(),
             };
 
             dropped_tables.push(drop_table);
@@ -158,6 +167,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             {
                 let drop_foreign_key = DropForeignKey {
                     table: dropped_table.name.clone(),
+                    schema: dropped_table.schema.clone(),
                     constraint_name: fk_name.clone(),
                 };
 
@@ -188,12 +198,14 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .filter(|tables| !tables_to_redefine.contains(tables.next.name()))
             .filter_map(|tables| {
                 // Order matters.
-                let changes: Vec<TableChange> = Self::drop_primary_key(&tables)
-                    .into_iter()
+                let changes: Vec<TableChange> = Self::drop_check_constraints(&tables)
+                    .chain(Self::drop_primary_key(&tables))
                     .chain(Self::drop_columns(&tables))
                     .chain(Self::add_columns(&tables))
                     .chain(Self::alter_columns(&tables))
                     .chain(Self::add_primary_key(&tables))
+                    .chain(Self::rename_primary_key(&tables))
+                    .chain(Self::add_check_constraints(&tables))
                     .collect();
 
                 Some(changes)
@@ -257,6 +269,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             {
                 drop_foreign_keys.push(DropForeignKey {
                     table: table_name.to_owned(),
+                    schema: differ.previous.schema_name().map(String::from),
                     constraint_name: dropped_foreign_key_name.to_owned(),
                 })
             }
@@ -269,6 +282,16 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .filter(|pk| !pk.columns.is_empty())
             .map(|pk| TableChange::AddPrimaryKey {
                 columns: pk.columns.clone(),
+                constraint_name: pk.constraint_name.clone(),
+            })
+    }
+
+    fn rename_primary_key(differ: &TableDiffer<'_>) -> Option<TableChange> {
+        differ
+            .renamed_primary_key()
+            .map(|(previous_name, next_name)| TableChange::RenamePrimaryKey {
+                constraint_name: previous_name.to_owned(),
+                new_constraint_name: next_name.to_owned(),
             })
     }
 
@@ -278,6 +301,22 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         })
     }
 
+    fn add_check_constraints<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ
+            .created_check_constraints()
+            .map(|check_constraint| TableChange::AddCheckConstraint {
+                check_constraint: check_constraint.clone(),
+            })
+    }
+
+    fn drop_check_constraints<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ
+            .dropped_check_constraints()
+            .map(|check_constraint| TableChange::DropCheckConstraint {
+                constraint_name: check_constraint.name.clone(),
+            })
+    }
+
     fn create_indexes(&self, tables_to_redefine: &HashSet<String>) -> Vec<CreateIndex> {
         let mut steps = Vec::new();
 
@@ -290,15 +329,24 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                 for walker in walker.indexes() {
                     let contains_nullable_columns = walker.has_nullable_columns();
 
-                    if family.is_mssql() && walker.index_type().is_unique() && !contains_nullable_columns {
+                    // Unique constraints without a filter or included columns are part of the
+                    // `CREATE TABLE` statement on SQL Server.
+                    if family.is_mssql()
+                        && walker.index_type().is_unique()
+                        && !contains_nullable_columns
+                        && walker.index().predicate.is_none()
+                        && walker.index().include.is_empty()
+                    {
                         continue;
                     }
 
                     steps.push(CreateIndex {
                         table: table.name.clone(),
+                        schema: table.schema.clone(),
                         index: walker.index().clone(),
                         caused_by_create_table: true,
                         contains_nullable_columns,
+                        concurrently: false,
                     });
                 }
             }
@@ -311,9 +359,11 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             for index in tables.created_indexes() {
                 steps.push(CreateIndex {
                     table: tables.next.name().to_owned(),
+                    schema: tables.next.schema_name().map(String::from),
                     index: index.index().clone(),
                     caused_by_create_table: false,
                     contains_nullable_columns: index.has_nullable_columns(),
+                    concurrently: false,
                 })
             }
         }
@@ -334,6 +384,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
 
                 drop_indexes.push(DropIndex {
                     table: tables.previous.name().to_owned(),
+                    schema: tables.previous.schema_name().map(String::from),
                     name: index.name().to_owned(),
                 })
             }
@@ -346,6 +397,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         self.created_enums()
             .map(|r#enum| CreateEnum {
                 name: r#enum.name.clone(),
+                schema: r#enum.schema.clone(),
                 variants: r#enum.values.clone(),
             })
             .collect()
@@ -355,6 +407,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         self.dropped_enums()
             .map(|r#enum| DropEnum {
                 name: r#enum.name.clone(),
+                schema: r#enum.schema.clone(),
             })
             .collect()
     }
@@ -398,6 +451,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                             index_name: previous_index.name().to_owned(),
                             index_new_name: renamed_index.name().to_owned(),
                             table: differ.next.name().to_owned(),
+                            schema: differ.next.schema_name().map(String::from),
                         })
                     })
             });
@@ -405,9 +459,36 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         alter_indexes
     }
 
+    fn rename_foreign_keys(&self, tables_to_redefine: &HashSet<String>) -> Vec<RenameForeignKey> {
+        let mut rename_foreign_keys = Vec::new();
+
+        for differ in self
+            .table_pairs()
+            .filter(|tables| !tables_to_redefine.contains(tables.next.name()))
+        {
+            for (previous_fk, next_fk) in differ.renamed_foreign_keys() {
+                if let (Some(previous_name), Some(next_name)) =
+                    (previous_fk.constraint_name(), next_fk.constraint_name())
+                {
+                    rename_foreign_keys.push(RenameForeignKey {
+                        table: differ.next.name().to_owned(),
+                        schema: differ.next.schema_name().map(String::from),
+                        constraint_name: previous_name.to_owned(),
+                        new_constraint_name: next_name.to_owned(),
+                    })
+                }
+            }
+        }
+
+        rename_foreign_keys
+    }
+
     fn created_tables<'a>(&'a self) -> impl Iterator<Item = &'a Table> + 'a {
-        self.next_tables()
-            .filter(move |next_table| !self.previous.has_table(&next_table.name))
+        self.next_tables().filter(move |next_table| {
+            !self
+                .previous_tables()
+                .any(|previous_table| tables_match(previous_table, next_table))
+        })
     }
 
     fn dropped_tables(&self) -> impl Iterator<Item = &Table> {
@@ -454,6 +535,16 @@ impl<'schema> SqlSchemaDiffer<'schema> {
     fn dropped_enums(&self) -> impl Iterator<Item = &Enum> {
         self.previous_enums()
             .filter(move |previous| !self.next_enums().any(|next| enums_match(previous, next)))
+            // Views are not managed by migrations, so the enums they use must stay.
+            .filter(move |previous| !self.enum_is_used_by_a_view(&previous.name))
+    }
+
+    fn enum_is_used_by_a_view(&self, enum_name: &str) -> bool {
+        self.previous
+            .views
+            .iter()
+            .flat_map(|view| view.columns.iter())
+            .any(|column| matches!(&column.tpe.family, ColumnTypeFamily::Enum(name) if name == enum_name))
     }
 
     fn previous_enums(&self) -> impl Iterator<Item = &Enum> {
@@ -472,6 +563,7 @@ fn push_created_foreign_keys<'a, 'schema>(
     table_pairs.for_each(|differ| {
         added_foreign_keys.extend(differ.created_foreign_keys().map(|created_fk| AddForeignKey {
             table: differ.next.name().to_owned(),
+            schema: differ.next.schema_name().map(String::from),
             foreign_key: created_fk.inner().clone(),
         }))
     })
@@ -484,6 +576,7 @@ fn push_foreign_keys_from_created_tables<'a>(
     for table in created_tables {
         steps.extend(table.foreign_keys.iter().map(|fk| AddForeignKey {
             table: table.name.clone(),
+            schema: table.schema.clone(),
             foreign_key: fk.clone(),
         }));
     }
@@ -493,7 +586,9 @@ fn push_foreign_keys_from_created_tables<'a>(
 /// should be considered equivalent for schema diffing purposes.
 fn foreign_keys_match(previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'_>) -> bool {
     // Foreign keys point to different tables.
-    if previous.referenced_table().name() != next.referenced_table().name() {
+    if previous.referenced_table().name() != next.referenced_table().name()
+        || previous.inner().referenced_schema != next.inner().referenced_schema
+    {
         return false;
     }
 
@@ -507,6 +602,14 @@ fn foreign_keys_match(previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'
         return false;
     }
 
+    // Foreign keys have different referential actions. RESTRICT and NO ACTION only differ in
+    // whether the check can be deferred, and some databases report one for the other.
+    if !foreign_key_actions_match(previous.on_delete_action(), next.on_delete_action())
+        || !foreign_key_actions_match(previous.on_update_action(), next.on_update_action())
+    {
+        return false;
+    }
+
     // Foreign keys constrain the same columns in a different order, or their types changed.
     for (previous_column, next_column) in previous.constrained_columns().zip(next.constrained_columns()) {
         if previous_column.name() != next_column.name()
@@ -519,10 +622,16 @@ fn foreign_keys_match(previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'
     true
 }
 
+fn foreign_key_actions_match(previous: &ForeignKeyAction, next: &ForeignKeyAction) -> bool {
+    use ForeignKeyAction::{NoAction, Restrict};
+
+    matches!((previous, next), (NoAction, Restrict) | (Restrict, NoAction)) || previous == next
+}
+
 fn tables_match(previous: &Table, next: &Table) -> bool {
-    previous.name == next.name
+    previous.name == next.name && previous.schema == next.schema
 }
 
 fn enums_match(previous: &Enum, next: &Enum) -> bool {
-    previous.name == next.name
+    previous.name == next.name && previous.schema == next.schema
 }
@@ -48,6 +48,53 @@ impl<'a> ColumnDiffer<'a> {
         ColumnChanges { changes }
     }
 
+    /// How the existing values of the column can be converted to the next column type. This is
+    /// only meaningful when the type changed.
+    pub(crate) fn cast(&self) -> ColumnCast {
+        self.flavour.column_cast(self)
+    }
+
+    /// How the existing values of the column are converted by a `CAST` on most databases.
+    pub(crate) fn common_cast(&self) -> ColumnCast {
+        use ColumnTypeFamily::*;
+
+        match (self.previous.column_type_family(), self.next.column_type_family()) {
+            (previous, next) if previous == next => ColumnCast::Safe,
+            (Int, BigInt)
+            | (Int, Float)
+            | (Int, Decimal)
+            | (BigInt, Float)
+            | (BigInt, Decimal)
+            | (Boolean, Int)
+            | (Int, String)
+            | (BigInt, String)
+            | (Float, String)
+            | (Decimal, String)
+            | (Boolean, String)
+            | (DateTime, String)
+            | (Json, String)
+            | (Uuid, String)
+            | (Enum(_), String) => ColumnCast::Safe,
+            (BigInt, Int)
+            | (Float, Int)
+            | (Float, BigInt)
+            | (Decimal, Int)
+            | (Decimal, BigInt)
+            | (Int, Boolean)
+            | (String, Int)
+            | (String, BigInt)
+            | (String, Float)
+            | (String, Decimal)
+            | (String, Boolean)
+            | (String, DateTime)
+            | (String, Json)
+            | (String, Uuid)
+            | (String, Enum(_))
+            | (Enum(_), Enum(_)) => ColumnCast::Risky,
+            _ => ColumnCast::Impossible,
+        }
+    }
+
     fn column_type_changed(&self) -> bool {
         match (self.previous.column_type_family(), self.next.column_type_family()) {
             (ColumnTypeFamily::Decimal, ColumnTypeFamily::Decimal) => false,
@@ -129,6 +176,17 @@ pub(crate) enum ColumnChange {
     Sequence = 0b0010000,
 }
 
+/// What happens to the existing values of a column when its type changes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ColumnCast {
+    /// Every value of the previous type can be cast to the next type.
+    Safe,
+    /// Some values of the previous type cannot be cast to the next type.
+    Risky,
+    /// The values cannot be cast, the column has to be dropped and recreated.
+    Impossible,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct ColumnChanges {
     changes: BitFlags<ColumnChange>,
@@ -147,6 +205,10 @@ impl ColumnChanges {
         self.changes.contains(ColumnChange::Arity)
     }
 
+    pub(crate) fn default_changed(&self) -> bool {
+        self.changes.contains(ColumnChange::Default)
+    }
+
     pub(crate) fn only_default_changed(&self) -> bool {
         self.changes == BitFlags::from(ColumnChange::Default)
     }
@@ -0,0 +1,114 @@
+use crate::sql_migration::SqlMigrationStep;
+use sql_schema_describer::SqlSchema;
+
+/// Renames requested in the target datamodel through the `previous` argument of `@map` and
+/// `@@map`. A hint only applies when the previous schema has the old name and not the new one,
+/// and the next schema has the new name and not the old one. Hints left in the datamodel after
+/// the rename has been applied are therefore ignored.
+#[derive(Debug, Default)]
+pub(crate) struct RenameHints {
+    /// (previous table name, next table name)
+    pub(crate) tables: Vec<(String, String)>,
+    /// (next table name, previous column name, next column name)
+    pub(crate) columns: Vec<(String, String, String)>,
+}
+
+impl RenameHints {
+    /// Returns the rename steps, and the previous schema with the renames applied. The rest of the
+    /// migration should be diffed from that schema, since it runs after the renames.
+    pub(crate) fn apply(&self, mut previous: SqlSchema, next: &SqlSchema) -> (Vec<SqlMigrationStep>, SqlSchema) {
+        let mut steps = Vec::new();
+
+        for (name, new_name) in &self.tables {
+            if !previous.has_table(name)
+                || previous.has_table(new_name)
+                || !next.has_table(new_name)
+                || next.has_table(name)
+            {
+                continue;
+            }
+
+            rename_table(&mut previous, name, new_name);
+
+            steps.push(SqlMigrationStep::RenameTable {
+                name: name.clone(),
+                new_name: new_name.clone(),
+            });
+        }
+
+        for (table, name, new_name) in &self.columns {
+            let previous_table = match previous.get_table(table) {
+                Some(table) => table,
+                None => continue,
+            };
+            let next_table = match next.get_table(table) {
+                Some(table) => table,
+                None => continue,
+            };
+
+            if previous_table.column(name).is_none()
+                || previous_table.column(new_name).is_some()
+                || next_table.column(new_name).is_none()
+                || next_table.column(name).is_some()
+            {
+                continue;
+            }
+
+            rename_column(&mut previous, table, name, new_name);
+
+            steps.push(SqlMigrationStep::RenameColumn {
+                table: table.clone(),
+                name: name.clone(),
+                new_name: new_name.clone(),
+            });
+        }
+
+        (steps, previous)
+    }
+}
+
+fn rename_table(schema: &mut SqlSchema, name: &str, new_name: &str) {
+    for table in schema.tables.iter_mut() {
+        if table.name == name {
+            table.name = new_name.to_owned();
+        }
+
+        for foreign_key in table.foreign_keys.iter_mut() {
+            if foreign_key.referenced_table == name {
+                foreign_key.referenced_table = new_name.to_owned();
+            }
+        }
+    }
+}
+
+fn rename_column(schema: &mut SqlSchema, table_name: &str, name: &str, new_name: &str) {
+    let rename = |column: &mut String| {
+        if *column == name {
+            *column = new_name.to_owned();
+        }
+    };
+
+    for table in schema.tables.iter_mut() {
+        if table.name == table_name {
+            table.columns.iter_mut().for_each(|column| rename(&mut column.name));
+            table
+                .indices
+                .iter_mut()
+                .for_each(|index| index.columns.iter_mut().for_each(rename));
+            table
+                .primary_key
+                .iter_mut()
+                .for_each(|pk| pk.columns.iter_mut().for_each(rename));
+            table
+                .foreign_keys
+                .iter_mut()
+                .for_each(|fk| fk.columns.iter_mut().for_each(rename));
+        }
+
+        for foreign_key in table.foreign_keys.iter_mut() {
+            if foreign_key.referenced_table == table_name {
+                foreign_key.referenced_columns.iter_mut().for_each(rename);
+            }
+        }
+    }
+}
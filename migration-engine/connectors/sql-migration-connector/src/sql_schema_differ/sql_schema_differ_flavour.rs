@@ -1,7 +1,9 @@
-use sql_schema_describer::walkers::IndexWalker;
+use sql_schema_describer::{unwrap_check_expression, walkers::IndexWalker};
 
-use super::{ColumnDiffer, SqlSchemaDiffer};
+use super::{ColumnCast, ColumnDiffer, SqlSchemaDiffer};
 use crate::sql_migration::AlterEnum;
+use once_cell::sync::Lazy;
+use regex::Regex;
 use std::collections::HashSet;
 
 mod mssql;
@@ -21,11 +23,28 @@ pub(crate) trait SqlSchemaDifferFlavour {
         differ.previous.column_type_family() != differ.next.column_type_family()
     }
 
+    /// How the existing values of a column can be converted to its next type.
+    fn column_cast(&self, differ: &ColumnDiffer<'_>) -> ColumnCast {
+        differ.common_cast()
+    }
+
     /// Return whether an index should be renamed by the migration.
     fn index_should_be_renamed(&self, previous: &IndexWalker<'_>, next: &IndexWalker<'_>) -> bool {
         previous.name() != next.name()
     }
 
+    /// Bring an index predicate or a check constraint expression to a form that can be compared
+    /// with another expression. Databases rewrite the expressions they store, so the introspected
+    /// text has to be normalized the same way as the text from the datamodel.
+    fn normalize_expression(&self, expression: &str) -> String {
+        normalize_sql_expression(expression)
+    }
+
+    /// Whether foreign keys can be renamed in place. If not, they are dropped and recreated.
+    fn can_rename_foreign_key(&self) -> bool {
+        true
+    }
+
     /// Whether `AddForeignKey` steps should be generated for created tables.
     fn should_push_foreign_keys_from_created_tables(&self) -> bool {
         true
@@ -41,3 +60,33 @@ pub(crate) trait SqlSchemaDifferFlavour {
         false
     }
 }
+
+/// Parentheses around a single identifier, number or string literal, like `(0)` or `(title)`.
+static PARENTHESIZED_ATOM_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\(('[^']*'|[\w.]+)\)").unwrap());
+
+/// Normalize a SQL expression for comparison: whitespace and identifier quotes are removed and
+/// keywords lowercased outside of string literals, then the parentheses around single atoms and
+/// around the whole expression are unwrapped.
+pub(crate) fn normalize_sql_expression(expression: &str) -> String {
+    let mut normalized = String::with_capacity(expression.len());
+    let mut in_string_literal = false;
+
+    for c in expression.chars() {
+        match c {
+            '\'' => {
+                in_string_literal = !in_string_literal;
+                normalized.push(c);
+            }
+            c if in_string_literal => normalized.push(c),
+            '"' | '`' => (),
+            c if c.is_whitespace() => (),
+            c => normalized.extend(c.to_lowercase()),
+        }
+    }
+
+    while PARENTHESIZED_ATOM_RE.is_match(&normalized) {
+        normalized = PARENTHESIZED_ATOM_RE.replace_all(&normalized, "$1").into_owned();
+    }
+
+    unwrap_check_expression(&normalized).to_owned()
+}
@@ -1,4 +1,9 @@
-use super::SqlSchemaDifferFlavour;
+use super::{normalize_sql_expression, SqlSchemaDifferFlavour};
 use crate::flavour::MssqlFlavour;
 
-impl SqlSchemaDifferFlavour for MssqlFlavour {}
+impl SqlSchemaDifferFlavour for MssqlFlavour {
+    /// SQL Server quotes the identifiers of the expressions it stores in brackets.
+    fn normalize_expression(&self, expression: &str) -> String {
+        normalize_sql_expression(&expression.replace('[', "").replace(']', ""))
+    }
+}
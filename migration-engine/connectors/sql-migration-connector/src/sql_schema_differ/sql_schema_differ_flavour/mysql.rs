@@ -28,6 +28,11 @@ impl SqlSchemaDifferFlavour for MysqlFlavour {
         false
     }
 
+    fn can_rename_foreign_key(&self) -> bool {
+        // MySQL has no statement to rename a foreign key.
+        false
+    }
+
     fn index_should_be_renamed(&self, previous: &IndexWalker<'_>, next: &IndexWalker<'_>) -> bool {
         // Implements correct comparison for truncated index names.
         if previous.name().len() == MYSQL_IDENTIFIER_SIZE_LIMIT && next.name().len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
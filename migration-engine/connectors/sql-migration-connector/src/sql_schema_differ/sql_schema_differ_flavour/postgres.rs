@@ -1,7 +1,7 @@
-use super::SqlSchemaDifferFlavour;
+use super::{normalize_sql_expression, SqlSchemaDifferFlavour};
 use crate::{flavour::PostgresFlavour, sql_migration::AlterEnum, sql_schema_differ::SqlSchemaDiffer};
 use once_cell::sync::Lazy;
-use regex::RegexSet;
+use regex::{Regex, RegexSet};
 use sql_schema_describer::walkers::IndexWalker;
 
 /// The maximum length of postgres identifiers, in bytes.
@@ -39,6 +39,15 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         }
     }
 
+    fn normalize_expression(&self, expression: &str) -> String {
+        // Postgres adds explicit casts to the expressions it stores, e.g. `(title)::text`. They are
+        // matched after whitespace removal, so multi-word types like `character varying` are
+        // covered too.
+        static POSTGRES_CAST_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"::\w+(\[\])*").unwrap());
+
+        normalize_sql_expression(&POSTGRES_CAST_RE.replace_all(&normalize_sql_expression(expression), ""))
+    }
+
     fn table_should_be_ignored(&self, table_name: &str) -> bool {
         static POSTGRES_IGNORED_TABLES: Lazy<RegexSet> = Lazy::new(|| {
             RegexSet::new(&[
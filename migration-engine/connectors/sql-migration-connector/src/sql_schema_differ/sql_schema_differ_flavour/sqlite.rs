@@ -1,5 +1,9 @@
 use super::SqlSchemaDifferFlavour;
-use crate::{flavour::SqliteFlavour, sql_schema_differ::SqlSchemaDiffer};
+use crate::{
+    flavour::SqliteFlavour,
+    sql_schema_differ::{ColumnCast, ColumnDiffer, SqlSchemaDiffer},
+};
+use sql_schema_describer::ColumnTypeFamily;
 use std::collections::HashSet;
 
 impl SqlSchemaDifferFlavour for SqliteFlavour {
@@ -16,11 +20,23 @@ impl SqlSchemaDifferFlavour for SqliteFlavour {
                     || differ.index_pairs().filter(|(previous, next)| self.index_should_be_renamed(previous, next)).next().is_some()
                     || differ.created_foreign_keys().next().is_some()
                     || differ.dropped_foreign_keys().next().is_some()
+                    // Check constraints cannot be added or dropped with ALTER TABLE on SQLite
+                    || differ.created_check_constraints().next().is_some()
+                    || differ.dropped_check_constraints().next().is_some()
             })
             .map(|table| table.next.name().to_owned())
             .collect()
     }
 
+    fn column_cast(&self, differ: &ColumnDiffer<'_>) -> ColumnCast {
+        match (differ.previous.column_type_family(), differ.next.column_type_family()) {
+            // DATETIME is not a type on SQLite: `CAST(col AS DATETIME)` has numeric affinity and
+            // turns the text into a number.
+            (ColumnTypeFamily::String, ColumnTypeFamily::DateTime) => ColumnCast::Impossible,
+            _ => differ.common_cast(),
+        }
+    }
+
     fn should_push_foreign_keys_from_created_tables(&self) -> bool {
         false
     }
@@ -2,7 +2,7 @@ use super::column::ColumnDiffer;
 use crate::{database_info::DatabaseInfo, flavour::SqlFlavour};
 use sql_schema_describer::{
     walkers::{ColumnWalker, ForeignKeyWalker, IndexWalker, TableWalker},
-    PrimaryKey,
+    CheckConstraint, PrimaryKey,
 };
 
 pub(crate) struct TableDiffer<'a> {
@@ -56,7 +56,7 @@ impl<'schema> TableDiffer<'schema> {
     pub(crate) fn created_foreign_keys<'a>(&'a self) -> impl Iterator<Item = ForeignKeyWalker<'schema>> + 'a {
         self.next_foreign_keys().filter(move |next_fk| {
             self.previous_foreign_keys()
-                .find(|previous_fk| super::foreign_keys_match(previous_fk, next_fk))
+                .find(|previous_fk| self.foreign_keys_match(previous_fk, next_fk))
                 .is_none()
         })
     }
@@ -64,16 +64,35 @@ impl<'schema> TableDiffer<'schema> {
     pub(crate) fn dropped_foreign_keys<'a>(&'a self) -> impl Iterator<Item = ForeignKeyWalker<'schema>> + 'a {
         self.previous_foreign_keys().filter(move |previous_fk| {
             self.next_foreign_keys()
-                .find(|next_fk| super::foreign_keys_match(previous_fk, next_fk))
+                .find(|next_fk| self.foreign_keys_match(previous_fk, next_fk))
                 .is_none()
         })
     }
 
+    /// The foreign keys that stay, but get a new constraint name.
+    pub(crate) fn renamed_foreign_keys<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (ForeignKeyWalker<'schema>, ForeignKeyWalker<'schema>)> + 'a {
+        self.previous_foreign_keys().filter_map(move |previous_fk| {
+            self.next_foreign_keys()
+                .find(|next_fk| self.foreign_keys_match(&previous_fk, next_fk))
+                .filter(|next_fk| foreign_key_renamed(&previous_fk, next_fk))
+                .map(|next_fk| (previous_fk, next_fk))
+        })
+    }
+
+    /// Foreign keys with a new name count as different foreign keys on the databases that cannot
+    /// rename them, so they are dropped and recreated.
+    fn foreign_keys_match(&self, previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'_>) -> bool {
+        super::foreign_keys_match(previous, next)
+            && (self.flavour.can_rename_foreign_key() || !foreign_key_renamed(previous, next))
+    }
+
     pub(crate) fn created_indexes<'a>(&'a self) -> impl Iterator<Item = IndexWalker<'schema>> + 'a {
         self.next_indexes().filter(move |next_index| {
             !self
                 .previous_indexes()
-                .any(move |previous_index| indexes_match(&previous_index, next_index))
+                .any(move |previous_index| self.indexes_match(&previous_index, next_index))
         })
     }
 
@@ -81,18 +100,62 @@ impl<'schema> TableDiffer<'schema> {
         self.previous_indexes().filter(move |previous_index| {
             !self
                 .next_indexes()
-                .any(|next_index| indexes_match(previous_index, &next_index))
+                .any(|next_index| self.indexes_match(previous_index, &next_index))
         })
     }
 
     pub(crate) fn index_pairs<'a>(&'a self) -> impl Iterator<Item = (IndexWalker<'schema>, IndexWalker<'schema>)> + 'a {
         self.previous_indexes().filter_map(move |previous_index| {
             self.next_indexes()
-                .find(|next_index| indexes_match(&previous_index, next_index))
+                .find(|next_index| self.indexes_match(&previous_index, next_index))
                 .map(|renamed_index| (previous_index, renamed_index))
         })
     }
 
+    /// Compare two SQL indexes and return whether they only differ by name. Indexes that differ in
+    /// anything else have to be rebuilt.
+    ///
+    /// Predicates of partial indexes are normalized by the flavour before they are compared,
+    /// because databases rewrite them (Postgres adds casts, SQL Server adds brackets).
+    fn indexes_match(&self, first: &IndexWalker<'_>, second: &IndexWalker<'_>) -> bool {
+        let (first, second) = (first.index(), second.index());
+        let normalize_predicate = |predicate: &String| self.flavour.normalize_expression(predicate);
+
+        first.columns == second.columns
+            && first.tpe == second.tpe
+            && first.column_options == second.column_options
+            && first.algorithm == second.algorithm
+            && first.predicate.as_ref().map(normalize_predicate) == second.predicate.as_ref().map(normalize_predicate)
+            && first.include == second.include
+    }
+
+    pub(crate) fn created_check_constraints<'a>(&'a self) -> impl Iterator<Item = &'schema CheckConstraint> + 'a {
+        self.next.check_constraints().filter(move |next_check| {
+            !self
+                .previous
+                .check_constraints()
+                .any(|previous_check| self.check_constraints_match(previous_check, next_check))
+        })
+    }
+
+    pub(crate) fn dropped_check_constraints<'a>(&'a self) -> impl Iterator<Item = &'schema CheckConstraint> + 'a {
+        self.previous.check_constraints().filter(move |previous_check| {
+            !self
+                .next
+                .check_constraints()
+                .any(|next_check| self.check_constraints_match(previous_check, next_check))
+        })
+    }
+
+    /// Check constraints match when they have the same name and expression. A constraint whose
+    /// expression changed is dropped and added again. Databases rewrite the expressions they
+    /// store, so both expressions are normalized by the flavour before they are compared.
+    fn check_constraints_match(&self, previous: &CheckConstraint, next: &CheckConstraint) -> bool {
+        previous.name == next.name
+            && self.flavour.normalize_expression(&previous.expression)
+                == self.flavour.normalize_expression(&next.expression)
+    }
+
     /// The primary key present in `next` but not `previous`, if applicable.
     pub(crate) fn created_primary_key(&self) -> Option<&'schema PrimaryKey> {
         match (self.previous.primary_key(), self.next.primary_key()) {
@@ -125,6 +188,18 @@ impl<'schema> TableDiffer<'schema> {
         }
     }
 
+    /// The previous and next name of the primary key, if it stays but gets a new name.
+    pub(crate) fn renamed_primary_key(&self) -> Option<(&'schema str, &'schema str)> {
+        if self.created_primary_key().is_some() {
+            return None;
+        }
+
+        let previous_name = self.previous.primary_key()?.constraint_name.as_deref()?;
+        let next_name = self.next.primary_key()?.constraint_name.as_deref()?;
+
+        Some((previous_name, next_name)).filter(|(previous_name, next_name)| previous_name != next_name)
+    }
+
     /// Returns true if any of the columns of the primary key changed type.
     fn primary_key_column_changed(&self, previous_pk: &PrimaryKey) -> bool {
         self.column_pairs()
@@ -162,11 +237,15 @@ impl<'schema> TableDiffer<'schema> {
     }
 }
 
-pub(crate) fn columns_match(a: &ColumnWalker<'_>, b: &ColumnWalker<'_>) -> bool {
-    a.name() == b.name()
+/// Constraint names are only compared when the next schema sets one. Without a name in the
+/// datamodel, the database picks it.
+fn foreign_key_renamed(previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'_>) -> bool {
+    match (previous.constraint_name(), next.constraint_name()) {
+        (previous_name, Some(next_name)) => previous_name != Some(next_name),
+        (_, None) => false,
+    }
 }
 
-/// Compare two SQL indexes and return whether they only differ by name.
-fn indexes_match(first: &IndexWalker<'_>, second: &IndexWalker<'_>) -> bool {
-    first.column_names() == second.column_names() && first.index_type() == second.index_type()
+pub(crate) fn columns_match(a: &ColumnWalker<'_>, b: &ColumnWalker<'_>) -> bool {
+    a.name() == b.name()
 }
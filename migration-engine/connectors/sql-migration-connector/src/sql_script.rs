@@ -0,0 +1,146 @@
+//! Splitting migration scripts into statements, for the scripts that run outside of a transaction
+//! and must be sent to the database one statement at a time.
+
+use quaint::prelude::SqlFamily;
+
+/// Split a SQL script into its statements, on the semicolons outside of string literals, quoted
+/// identifiers and comments. Fragments without any statement (only whitespace and comments) are
+/// skipped.
+///
+/// Statements that contain semicolons outside of quotes, like MySQL trigger bodies, are not
+/// supported.
+pub(crate) fn split_statements(script: &str, sql_family: SqlFamily) -> Vec<&str> {
+    split_statements_with_offsets(script, sql_family)
+        .into_iter()
+        .map(|(_, statement)| statement)
+        .collect()
+}
+
+/// Like `split_statements`, with the byte offset of each statement in the script.
+pub(crate) fn split_statements_with_offsets(script: &str, sql_family: SqlFamily) -> Vec<(usize, &str)> {
+    let bytes = script.as_bytes();
+    let mut statements = Vec::new();
+    let mut statement_start = 0;
+    let mut has_code = false;
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'-' if bytes.get(idx + 1) == Some(&b'-') => {
+                idx = script[idx..].find('\n').map(|end| idx + end + 1).unwrap_or(bytes.len());
+                continue;
+            }
+            b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+                idx = skip_block_comment(bytes, idx);
+                continue;
+            }
+            quote @ b'\'' | quote @ b'"' | quote @ b'`' => {
+                has_code = true;
+                idx = skip_quoted(bytes, idx, quote, sql_family.is_mysql());
+                continue;
+            }
+            b'$' if sql_family.is_postgres() => {
+                has_code = true;
+
+                if let Some(tag_len) = dollar_quote_tag_len(bytes, idx) {
+                    let tag = &script[idx..idx + tag_len];
+                    idx = script[idx + tag_len..]
+                        .find(tag)
+                        .map(|end| idx + tag_len + end + tag_len)
+                        .unwrap_or(bytes.len());
+                    continue;
+                }
+            }
+            b';' => {
+                if has_code {
+                    statements.push(trimmed_statement(script, statement_start, idx));
+                }
+
+                statement_start = idx + 1;
+                has_code = false;
+            }
+            byte if byte.is_ascii_whitespace() => (),
+            _ => has_code = true,
+        }
+
+        idx += 1;
+    }
+
+    if has_code {
+        statements.push(trimmed_statement(script, statement_start, script.len()));
+    }
+
+    statements
+}
+
+/// The statement between `start` and `end` without surrounding whitespace, with its offset.
+fn trimmed_statement(script: &str, start: usize, end: usize) -> (usize, &str) {
+    let statement = &script[start..end];
+    let leading_whitespace = statement.len() - statement.trim_start().len();
+
+    (start + leading_whitespace, statement.trim())
+}
+
+/// Returns the index right after the end of the (possibly nested) block comment starting at
+/// `start`.
+fn skip_block_comment(bytes: &[u8], start: usize) -> usize {
+    let mut depth = 0;
+    let mut idx = start;
+
+    while idx < bytes.len() {
+        match (bytes[idx], bytes.get(idx + 1)) {
+            (b'/', Some(b'*')) => {
+                depth += 1;
+                idx += 2;
+            }
+            (b'*', Some(b'/')) => {
+                depth -= 1;
+                idx += 2;
+
+                if depth == 0 {
+                    return idx;
+                }
+            }
+            _ => idx += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+/// Returns the index right after the closing quote of the literal or identifier starting at
+/// `start`. Doubled quotes are escaped quotes.
+fn skip_quoted(bytes: &[u8], start: usize, quote: u8, backslash_escapes: bool) -> usize {
+    let mut idx = start + 1;
+
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'\\' if backslash_escapes => idx += 2,
+            byte if byte == quote && bytes.get(idx + 1) == Some(&quote) => idx += 2,
+            byte if byte == quote => return idx + 1,
+            _ => idx += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+/// The length of the PostgreSQL dollar quote tag (`$$` or `$tag$`) starting at `start`, if there is
+/// one. Positional parameters like `$1` are not tags.
+fn dollar_quote_tag_len(bytes: &[u8], start: usize) -> Option<usize> {
+    if bytes.get(start + 1).map(u8::is_ascii_digit).unwrap_or(false) {
+        return None;
+    }
+
+    let mut idx = start + 1;
+
+    while idx < bytes.len() && (bytes[idx].is_ascii_alphanumeric() || bytes[idx] == b'_') {
+        idx += 1;
+    }
+
+    if bytes.get(idx) == Some(&b'$') {
+        Some(idx - start + 1)
+    } else {
+        None
+    }
+}
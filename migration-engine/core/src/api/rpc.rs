@@ -1,4 +1,5 @@
 use crate::{commands::*, CoreResult, GenericApi};
+use datamodel::SourceFile;
 use futures::{FutureExt, TryFutureExt};
 use jsonrpc_core::{types::error::Error as JsonRpcError, IoHandler, Params};
 use std::{io, sync::Arc};
@@ -12,18 +13,24 @@ pub struct RpcApi {
 enum RpcCommand {
     GetDatabaseVersion,
     ApplyMigrations,
+    Baseline,
     CreateMigration,
     DebugPanic,
     DiagnoseMigrationHistory,
+    Diff,
     EvaluateDataLoss,
     InferMigrationSteps,
     Initialize,
+    LintMigrations,
     ListMigrations,
+    MarkMigrationApplied,
+    MarkMigrationRolledBack,
     MigrationProgress,
     PlanMigration,
     ApplyMigration,
     UnapplyMigration,
     Reset,
+    RollbackMigrations,
     SchemaPush,
     CalculateDatamodel,
     CalculateDatabaseSteps,
@@ -34,18 +41,24 @@ impl RpcCommand {
         match self {
             RpcCommand::GetDatabaseVersion => "getDatabaseVersion",
             RpcCommand::ApplyMigrations => "applyMigrations",
+            RpcCommand::Baseline => "baseline",
             RpcCommand::CreateMigration => "createMigration",
             RpcCommand::DebugPanic => "debugPanic",
             RpcCommand::DiagnoseMigrationHistory => "diagnoseMigrationHistory",
+            RpcCommand::Diff => "diff",
             RpcCommand::EvaluateDataLoss => "evaluateDataLoss",
             RpcCommand::InferMigrationSteps => "inferMigrationSteps",
+            RpcCommand::LintMigrations => "lintMigrations",
             RpcCommand::ListMigrations => "listMigrations",
+            RpcCommand::MarkMigrationApplied => "markMigrationApplied",
+            RpcCommand::MarkMigrationRolledBack => "markMigrationRolledBack",
             RpcCommand::MigrationProgress => "migrationProgress",
             RpcCommand::ApplyMigration => "applyMigration",
             RpcCommand::UnapplyMigration => "unapplyMigration",
             RpcCommand::Initialize => "initialize",
             RpcCommand::PlanMigration => "planMigration",
             RpcCommand::Reset => "reset",
+            RpcCommand::RollbackMigrations => "rollbackMigrations",
             RpcCommand::SchemaPush => "schemaPush",
             RpcCommand::CalculateDatamodel => "calculateDatamodel",
             RpcCommand::CalculateDatabaseSteps => "calculateDatabaseSteps",
@@ -57,17 +70,23 @@ const AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::GetDatabaseVersion,
     RpcCommand::ApplyMigration,
     RpcCommand::ApplyMigrations,
+    RpcCommand::Baseline,
     RpcCommand::CreateMigration,
     RpcCommand::DiagnoseMigrationHistory,
+    RpcCommand::Diff,
     RpcCommand::EvaluateDataLoss,
     RpcCommand::DebugPanic,
     RpcCommand::InferMigrationSteps,
     RpcCommand::Initialize,
+    RpcCommand::LintMigrations,
     RpcCommand::ListMigrations,
+    RpcCommand::MarkMigrationApplied,
+    RpcCommand::MarkMigrationRolledBack,
     RpcCommand::MigrationProgress,
     RpcCommand::PlanMigration,
     RpcCommand::UnapplyMigration,
     RpcCommand::Reset,
+    RpcCommand::RollbackMigrations,
     RpcCommand::SchemaPush,
     RpcCommand::CalculateDatamodel,
     RpcCommand::CalculateDatabaseSteps,
@@ -75,9 +94,14 @@ const AVAILABLE_COMMANDS: &[RpcCommand] = &[
 
 impl RpcApi {
     pub async fn new(datamodel: &str) -> CoreResult<Self> {
+        Self::new_from_files(&[SourceFile::new("schema.prisma", datamodel)]).await
+    }
+
+    /// Start the API on a schema split across several files.
+    pub async fn new_from_files(files: &[SourceFile]) -> CoreResult<Self> {
         let mut rpc_api = Self {
             io_handler: IoHandler::default(),
-            executor: crate::migration_api(datamodel).await?,
+            executor: crate::migration_api_from_files(files).await?,
         };
 
         for cmd in AVAILABLE_COMMANDS {
@@ -146,6 +170,10 @@ impl RpcApi {
                 let input: ApplyMigrationsInput = params.clone().parse()?;
                 render(executor.apply_migrations(&input).await?)
             }
+            RpcCommand::Baseline => {
+                let input: BaselineInput = params.clone().parse()?;
+                render(executor.baseline(&input).await?)
+            }
             RpcCommand::CreateMigration => {
                 let input: CreateMigrationInput = params.clone().parse()?;
                 render(executor.create_migration(&input).await?)
@@ -155,6 +183,10 @@ impl RpcApi {
                 let input: DiagnoseMigrationHistoryInput = params.clone().parse()?;
                 render(executor.diagnose_migration_history(&input).await?)
             }
+            RpcCommand::Diff => {
+                let input: DiffInput = params.clone().parse()?;
+                render(crate::diff(&input).await?)
+            }
             RpcCommand::InferMigrationSteps => {
                 let input: InferMigrationStepsInput = params.clone().parse()?;
                 render(executor.infer_migration_steps(&input).await?)
@@ -167,7 +199,19 @@ impl RpcApi {
                 let input: EvaluateDataLossInput = params.clone().parse()?;
                 render(executor.evaluate_data_loss(&input).await?)
             }
+            RpcCommand::LintMigrations => {
+                let input: LintMigrationsInput = params.clone().parse()?;
+                render(executor.lint_migrations(&input).await?)
+            }
             RpcCommand::ListMigrations => render(executor.list_migrations(&serde_json::Value::Null).await?),
+            RpcCommand::MarkMigrationApplied => {
+                let input: MarkMigrationAppliedInput = params.clone().parse()?;
+                render(executor.mark_migration_applied(&input).await?)
+            }
+            RpcCommand::MarkMigrationRolledBack => {
+                let input: MarkMigrationRolledBackInput = params.clone().parse()?;
+                render(executor.mark_migration_rolled_back(&input).await?)
+            }
             RpcCommand::MigrationProgress => {
                 let input: MigrationProgressInput = params.clone().parse()?;
                 render(executor.migration_progress(&input).await?)
@@ -183,6 +227,10 @@ impl RpcApi {
                 render(executor.unapply_migration(&input).await?)
             }
             RpcCommand::Reset => render(executor.reset(&()).await?),
+            RpcCommand::RollbackMigrations => {
+                let input: RollbackMigrationsInput = params.clone().parse()?;
+                render(executor.rollback_migrations(&input).await?)
+            }
             RpcCommand::SchemaPush => {
                 let input: SchemaPushInput = params.clone().parse()?;
                 render(executor.schema_push(&input).await?)
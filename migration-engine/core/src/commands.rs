@@ -6,6 +6,7 @@
 #[allow(missing_docs)]
 mod apply_migration;
 mod apply_migrations;
+mod baseline;
 #[allow(missing_docs)]
 mod calculate_database_steps;
 #[allow(missing_docs)]
@@ -14,23 +15,30 @@ mod command;
 mod create_migration;
 mod debug_panic;
 mod diagnose_migration_history;
+mod diff;
 mod evaluate_data_loss;
 mod get_database_version;
 #[allow(missing_docs)]
 mod infer_migration_steps;
 mod initialize;
+mod lint_migrations;
 #[allow(missing_docs)]
 mod list_migrations;
+mod mark_migration_applied;
+mod mark_migration_rolled_back;
+mod migration_lock;
 #[allow(missing_docs)]
 mod migration_progress;
 mod plan_migration;
 mod reset;
+mod rollback_migrations;
 mod schema_push;
 #[allow(missing_docs)]
 mod unapply_migration;
 
 pub use apply_migration::*;
 pub use apply_migrations::{ApplyMigrationsCommand, ApplyMigrationsInput, ApplyMigrationsOutput};
+pub use baseline::{BaselineCommand, BaselineInput, BaselineOutput};
 pub use calculate_database_steps::*;
 pub use calculate_datamodel::*;
 pub use command::{CommandError, CommandResult, MigrationCommand};
@@ -40,21 +48,65 @@ pub use diagnose_migration_history::{
     DiagnoseMigrationHistoryCommand, DiagnoseMigrationHistoryInput, DiagnoseMigrationHistoryOutput, DriftDiagnostic,
     HistoryDiagnostic,
 };
+pub(crate) use diff::LoadedDiffSource;
+pub use diff::{DiffInput, DiffOutput, DiffSource};
 pub use evaluate_data_loss::*;
 pub use get_database_version::*;
 pub use infer_migration_steps::*;
 pub use initialize::{InitializeCommand, InitializeInput, InitializeOutput};
+pub use lint_migrations::{LintMigrationsCommand, LintMigrationsInput, LintMigrationsOutput, MigrationLintResult};
 pub use list_migrations::*;
+pub use mark_migration_applied::{MarkMigrationAppliedCommand, MarkMigrationAppliedInput, MarkMigrationAppliedOutput};
+pub use mark_migration_rolled_back::{
+    MarkMigrationRolledBackCommand, MarkMigrationRolledBackInput, MarkMigrationRolledBackOutput,
+};
 pub use migration_progress::*;
 pub use plan_migration::{PlanMigrationCommand, PlanMigrationInput, PlanMigrationOutput};
 pub use reset::ResetCommand;
+pub(crate) use rollback_migrations::render_irreversible_migration_header;
+pub use rollback_migrations::{RollbackMigrationsCommand, RollbackMigrationsInput, RollbackMigrationsOutput};
 pub use schema_push::{SchemaPushCommand, SchemaPushInput, SchemaPushOutput};
 pub use unapply_migration::*;
 
+use datamodel::SourceFile;
 use migration_connector::{
     MigrationError, MigrationStep, MigrationWarning, PrettyDatabaseMigrationStep, UnexecutableMigration,
 };
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// A Prisma schema passed to a command, either as a single string or as the files of a schema
+/// split with `import` declarations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PrismaSchema {
+    /// The whole schema in one string.
+    Single(String),
+    /// The files of a multi-file schema.
+    Files(Vec<SourceFile>),
+}
+
+impl PrismaSchema {
+    /// The files of the schema. A single string is one `schema.prisma` file.
+    pub fn files(&self) -> Cow<'_, [SourceFile]> {
+        match self {
+            PrismaSchema::Single(schema) => Cow::Owned(vec![SourceFile::new("schema.prisma", schema.as_str())]),
+            PrismaSchema::Files(files) => Cow::Borrowed(files),
+        }
+    }
+}
+
+impl From<String> for PrismaSchema {
+    fn from(schema: String) -> Self {
+        PrismaSchema::Single(schema)
+    }
+}
+
+impl From<&str> for PrismaSchema {
+    fn from(schema: &str) -> Self {
+        PrismaSchema::Single(schema.to_owned())
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1,8 +1,11 @@
-use std::path::Path;
+use std::{path::Path, time::Duration};
 
-use super::{CommandError, CommandResult, MigrationCommand};
+use super::{
+    migration_lock::{with_migration_lock, DEFAULT_LOCK_TIMEOUT},
+    CommandError, CommandResult, MigrationCommand,
+};
 use crate::migration_engine::MigrationEngine;
-use migration_connector::{ConnectorError, MigrationDirectory, MigrationRecord};
+use migration_connector::{ConnectorError, MigrationConnector, MigrationDirectory, MigrationRecord, TransactionMode};
 use serde::{Deserialize, Serialize};
 
 /// The input to the `ApplyMigrations` command.
@@ -11,6 +14,10 @@ use serde::{Deserialize, Serialize};
 pub struct ApplyMigrationsInput {
     /// The location of the migrations directory.
     pub migrations_directory_path: String,
+    /// How long to wait for another migration engine applying migrations to the same database to
+    /// release the migration lock, in seconds. Defaults to 10 seconds.
+    #[serde(default)]
+    pub lock_timeout_seconds: Option<u64>,
 }
 
 /// The output of the `ApplyMigrations` command.
@@ -38,68 +45,97 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
         D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
     {
         let connector = engine.connector();
-        let applier = connector.database_migration_step_applier();
-        let migration_persistence = connector.new_migration_persistence();
-
-        let migrations_from_filesystem =
-            migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
-        let migrations_from_database = migration_persistence.list_migrations().await?;
-
-        diagnose_migration_history(&migrations_from_database, &migrations_from_filesystem)?;
-
-        // We are now on the Happy Path™.
-        tracing::debug!("Migration history is OK, applying unapplied migrations.");
-        let unapplied_migrations: Vec<&MigrationDirectory> = migrations_from_filesystem
-            .iter()
-            .filter(|fs_migration| {
-                !migrations_from_database
-                    .iter()
-                    .any(|db_migration| fs_migration.migration_name() == db_migration.migration_name)
-            })
-            .collect();
-
-        let mut applied_migration_names: Vec<String> = Vec::new();
-
-        for unapplied_migration in unapplied_migrations {
-            let script = unapplied_migration
-                .read_migration_script()
-                .map_err(ConnectorError::from)?;
-
-            tracing::info!(
-                script = script.as_str(),
-                "Applying `{}`",
-                unapplied_migration.migration_name()
-            );
-
-            let migration_id = migration_persistence
-                .record_migration_started(unapplied_migration.migration_name(), &script)
-                .await?;
-
-            match applier.apply_script(&script).await {
-                Ok(()) => {
-                    tracing::debug!("Successfully applied the script.");
-                    migration_persistence
-                        .record_successful_step(&migration_id, &script)
-                        .await?;
-                    migration_persistence.record_migration_finished(&migration_id).await?;
-                    applied_migration_names.push(unapplied_migration.migration_name().to_owned());
-                }
-                Err(err) => {
-                    tracing::debug!("Failed to apply the script.");
-
-                    let logs = format!("script:\n{}\n\nerror:\n{}", script, err);
-
-                    migration_persistence.record_failed_step(&migration_id, &logs).await?;
-
-                    return Err(err.into()); // todo: give more context
-                }
-            }
-        }
+        let lock_timeout = input
+            .lock_timeout_seconds
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_LOCK_TIMEOUT);
+
+        // Hold the migration lock while reading and applying the history, so concurrent
+        // migration engines never apply the same migration twice.
+        with_migration_lock(connector, lock_timeout, apply_migrations(input, connector)).await
+    }
+}
 
-        Ok(ApplyMigrationsOutput {
-            applied_migration_names,
+async fn apply_migrations<C, D>(input: &ApplyMigrationsInput, connector: &C) -> CommandResult<ApplyMigrationsOutput>
+where
+    C: MigrationConnector<DatabaseMigration = D>,
+    D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+{
+    let applier = connector.database_migration_step_applier();
+    let migration_persistence = connector.new_migration_persistence();
+
+    let migrations_from_filesystem =
+        migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
+    // Rolled back migrations count as unapplied.
+    let migrations_from_database: Vec<MigrationRecord> = migration_persistence
+        .list_migrations()
+        .await?
+        .into_iter()
+        .filter(|migration| !migration.is_rolled_back())
+        .collect();
+
+    diagnose_migration_history(&migrations_from_database, &migrations_from_filesystem)?;
+
+    // We are now on the Happy Path™.
+    tracing::debug!("Migration history is OK, applying unapplied migrations.");
+    let unapplied_migrations: Vec<&MigrationDirectory> = migrations_from_filesystem
+        .iter()
+        .filter(|fs_migration| {
+            !migrations_from_database
+                .iter()
+                .any(|db_migration| fs_migration.migration_name() == db_migration.migration_name)
         })
+        .collect();
+
+    let mut applied_migration_names: Vec<String> = Vec::new();
+
+    for unapplied_migration in unapplied_migrations {
+        let script = unapplied_migration
+            .read_migration_script()
+            .map_err(ConnectorError::from)?;
+        let transaction_mode = TransactionMode::from_script(&script).map_err(|err| {
+            CommandError::Input(anyhow::anyhow!(
+                "Could not apply `{}`: {}",
+                unapplied_migration.migration_name(),
+                err
+            ))
+        })?;
+
+        tracing::info!(
+            script = script.as_str(),
+            ?transaction_mode,
+            "Applying `{}`",
+            unapplied_migration.migration_name()
+        );
+
+        let migration_id = migration_persistence
+            .record_migration_started(unapplied_migration.migration_name(), &script)
+            .await?;
+
+        match applier.apply_script(&script, transaction_mode).await {
+            Ok(()) => {
+                tracing::debug!("Successfully applied the script.");
+                migration_persistence
+                    .record_successful_step(&migration_id, &script)
+                    .await?;
+                migration_persistence.record_migration_finished(&migration_id).await?;
+                applied_migration_names.push(unapplied_migration.migration_name().to_owned());
+            }
+            Err(err) => {
+                tracing::debug!("Failed to apply the script.");
+
+                let logs = format!("script:\n{}\n\nerror:\n{}", script, err);
+
+                migration_persistence.record_failed_step(&migration_id, &logs).await?;
+
+                return Err(err.into()); // todo: give more context
+            }
+        }
     }
+
+    Ok(ApplyMigrationsOutput {
+        applied_migration_names,
+    })
 }
 
 fn diagnose_migration_history(
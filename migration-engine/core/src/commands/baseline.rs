@@ -0,0 +1,86 @@
+use std::path::Path;
+
+use super::{CommandError, CommandResult, MigrationCommand};
+use crate::migration_engine::MigrationEngine;
+use migration_connector::{DestructiveChangeDiagnostics, MigrationConnector};
+use serde::{Deserialize, Serialize};
+
+/// The input to the `baseline` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineInput {
+    /// The filesystem path of the migrations directory to use. It must not contain any migration.
+    pub migrations_directory_path: String,
+    /// The user-given name for the baseline migration. This will be used in the migration directory.
+    pub migration_name: String,
+}
+
+/// The output of the `baseline` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineOutput {
+    /// The name of the generated baseline migration directory.
+    pub baseline_migration_name: String,
+}
+
+/// Start the migrations history of an existing database. The initial migration is generated
+/// from the current database schema, and recorded as applied without being executed.
+pub struct BaselineCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for BaselineCommand {
+    type Input = BaselineInput;
+
+    type Output = BaselineOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let applier = connector.database_migration_step_applier();
+        let migration_persistence = connector.new_migration_persistence();
+        let migrations_directory_path = Path::new(&input.migrations_directory_path);
+
+        if !migration_connector::list_migrations(migrations_directory_path)?.is_empty() {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "The migrations directory already contains migrations. Only a database without a migrations history can be baselined."
+            )));
+        }
+
+        if !migration_persistence.list_migrations().await?.is_empty() {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "Migrations have already been applied to the database. Only a database without a migrations history can be baselined."
+            )));
+        }
+
+        let migration = connector.database_migration_inferrer().infer_baseline().await?;
+        let script = applier.render_script(&migration, &DestructiveChangeDiagnostics::new());
+
+        // Write the migration script to a file. The baseline has no down script: reverting it
+        // would drop the whole database.
+        let directory =
+            migration_connector::create_migration_directory(migrations_directory_path, &input.migration_name)
+                .map_err(|_| CommandError::Generic(anyhow::anyhow!("Failed to create a new migration directory.")))?;
+        directory
+            .write_migration_script(&script, D::FILE_EXTENSION)
+            .map_err(|err| {
+                CommandError::Generic(anyhow::anyhow!(
+                    "Failed to write the migration script to `{:?}`. {}",
+                    directory.path(),
+                    err
+                ))
+            })?;
+
+        // Record the migration as applied, without executing it.
+        let migration_id = migration_persistence
+            .record_migration_started(directory.migration_name(), &script)
+            .await?;
+        migration_persistence.record_migration_finished(&migration_id).await?;
+
+        Ok(BaselineOutput {
+            baseline_migration_name: directory.migration_name().to_owned(),
+        })
+    }
+}
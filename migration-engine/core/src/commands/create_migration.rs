@@ -1,7 +1,8 @@
 use std::path::Path;
 
-use super::{CommandError, MigrationCommand};
-use crate::{migration_engine::MigrationEngine, parse_datamodel};
+use super::{render_irreversible_migration_header, CommandError, MigrationCommand, PrismaSchema};
+use crate::{migration_engine::MigrationEngine, parse_prisma_schema};
+use migration_connector::TransactionMode;
 use serde::{Deserialize, Serialize};
 
 /// Create and potentially apply a new migration.
@@ -14,11 +15,15 @@ pub struct CreateMigrationInput {
     /// The filesystem path of the migrations directory to use.
     pub migrations_directory_path: String,
     /// The current prisma schema to use as a target for the generated migration.
-    pub prisma_schema: String,
+    pub prisma_schema: PrismaSchema,
     /// The user-given name for the migration. This will be used in the migration directory.
     pub migration_name: String,
     /// If true, always generate a migration, but do not apply.
     pub draft: bool,
+    /// If true, generate a migration that runs without a transaction, creating indexes without
+    /// locking large tables where the database supports it.
+    #[serde(default)]
+    pub non_transactional: bool,
 }
 
 /// The output of the `createMigration` command.
@@ -46,12 +51,19 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
 
         // Infer the migration.
         let previous_migrations = migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
-        let target_schema = parse_datamodel(&input.prisma_schema)?;
+        let target_schema = parse_prisma_schema(&input.prisma_schema)?;
 
-        let migration = database_migration_inferrer
+        let mut migration = database_migration_inferrer
             .infer_next_migration(&previous_migrations, &target_schema)
             .await?;
 
+        let transaction_mode = if input.non_transactional {
+            migration = database_migration_inferrer.infer_non_transactional(migration);
+            TransactionMode::NoTransaction
+        } else {
+            TransactionMode::Default
+        };
+
         if migration.is_empty() && !input.draft {
             return Ok(CreateMigrationOutput {
                 generated_migration_name: None,
@@ -60,7 +72,8 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
 
         let destructive_change_diagnostics = checker.pure_check(&migration);
 
-        let migration_script = applier.render_script(&migration, &destructive_change_diagnostics);
+        let mut migration_script = transaction_mode.render_header().unwrap_or_default();
+        migration_script.push_str(&applier.render_script(&migration, &destructive_change_diagnostics));
 
         // Write the migration script to a file.
         let directory = migration_connector::create_migration_directory(
@@ -78,6 +91,41 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
                 ))
             })?;
 
+        // Write the down script. Data dropped by the migration cannot be brought back, and the
+        // reverse migration may not be executable, so those cases are marked as irreversible.
+        let reverse_migration = database_migration_inferrer.infer_reverse(&migration);
+        let reverse_diagnostics = checker.pure_check(&reverse_migration);
+
+        let irreversibility_reasons: Vec<String> = destructive_change_diagnostics
+            .warnings
+            .iter()
+            .map(|warning| warning.description.clone())
+            .chain(
+                reverse_diagnostics
+                    .unexecutable_migrations
+                    .iter()
+                    .map(|unexecutable| unexecutable.description.clone()),
+            )
+            .collect();
+
+        let mut down_script = String::new();
+
+        if !irreversibility_reasons.is_empty() {
+            down_script.push_str(&render_irreversible_migration_header(&irreversibility_reasons));
+        }
+
+        down_script.push_str(&applier.render_script(&reverse_migration, &reverse_diagnostics));
+
+        directory
+            .write_down_script(&down_script, D::FILE_EXTENSION)
+            .map_err(|err| {
+                CommandError::Generic(anyhow::anyhow!(
+                    "Failed to write the down script to `{:?}`. {}",
+                    directory.path(),
+                    err
+                ))
+            })?;
+
         Ok(CreateMigrationOutput {
             generated_migration_name: Some(directory.migration_name().to_owned()),
         })
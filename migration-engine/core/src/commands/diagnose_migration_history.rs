@@ -65,7 +65,13 @@ impl<'a> MigrationCommand for DiagnoseMigrationHistoryCommand {
         // Load the migrations.
         let migrations_from_filesystem =
             migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
-        let migrations_from_database = migration_persistence.list_migrations().await?;
+        // Rolled back migrations count as unapplied.
+        let migrations_from_database: Vec<MigrationRecord> = migration_persistence
+            .list_migrations()
+            .await?
+            .into_iter()
+            .filter(|migration| !migration.is_rolled_back())
+            .collect();
 
         let mut diagnostics = Diagnostics::new(&migrations_from_filesystem);
 
@@ -0,0 +1,127 @@
+use super::{CommandError, CommandResult};
+use crate::parse_datamodel;
+use datamodel::dml::Datamodel;
+use migration_connector::MigrationDirectory;
+use serde::{Deserialize, Serialize};
+use sql_migration_connector::DiffTarget;
+use std::path::Path;
+
+/// The input to the `diff` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffInput {
+    /// The schema to diff from.
+    pub from: DiffSource,
+    /// The schema to diff to.
+    pub to: DiffSource,
+    /// If true, render the SQL script. Otherwise, render a human-readable summary.
+    #[serde(default)]
+    pub script: bool,
+    /// The connection string that determines the SQL dialect. Migrations directories are applied
+    /// to a temporary database on that server. Defaults to the URL of the first Prisma schema or
+    /// database source.
+    #[serde(default)]
+    pub datasource_url: Option<String>,
+}
+
+/// A schema source for the `diff` command.
+#[derive(Deserialize, Debug)]
+#[serde(tag = "tag", rename_all = "camelCase")]
+pub enum DiffSource {
+    /// An empty database.
+    Empty,
+    /// The schema a Prisma schema would produce.
+    SchemaDatamodel {
+        /// The contents of the Prisma schema.
+        schema: String,
+    },
+    /// The schema produced by applying a migrations directory.
+    Migrations {
+        /// The filesystem path of the migrations directory.
+        path: String,
+    },
+    /// A SQL schema, as serialized by the schema describer.
+    SqlSchema {
+        /// The serialized SQL schema.
+        json: String,
+    },
+    /// The schema of a live database.
+    Url {
+        /// The connection string of the database.
+        url: String,
+    },
+}
+
+/// The output of the `diff` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffOutput {
+    /// The migration script or the summary of the changes, depending on the input.
+    pub diff: String,
+}
+
+/// A diff source with its files read and its schema parsed.
+pub(crate) enum LoadedDiffSource<'a> {
+    Empty,
+    Datamodel {
+        datamodel: Datamodel,
+        url: String,
+        schemas: Vec<String>,
+    },
+    Migrations(Vec<MigrationDirectory>),
+    SqlSchema(&'a str),
+    Url(&'a str),
+}
+
+impl<'a> LoadedDiffSource<'a> {
+    pub(crate) fn load(source: &'a DiffSource) -> CommandResult<Self> {
+        Ok(match source {
+            DiffSource::Empty => LoadedDiffSource::Empty,
+            DiffSource::SchemaDatamodel { schema } => {
+                let config = datamodel::parse_configuration(schema)
+                    .map_err(|err| CommandError::ReceivedBadDatamodel(err.to_pretty_string("schema.prisma", schema)))?;
+                let datasource = config.datasources.first().ok_or_else(|| {
+                    CommandError::Input(anyhow::anyhow!("There is no datasource in the schema to diff."))
+                })?;
+
+                LoadedDiffSource::Datamodel {
+                    datamodel: parse_datamodel(schema)?,
+                    url: datasource.url().value.clone(),
+                    schemas: datasource.schemas.clone(),
+                }
+            }
+            DiffSource::Migrations { path } => {
+                LoadedDiffSource::Migrations(migration_connector::list_migrations(Path::new(path))?)
+            }
+            DiffSource::SqlSchema { json } => LoadedDiffSource::SqlSchema(json),
+            DiffSource::Url { url } => LoadedDiffSource::Url(url),
+        })
+    }
+
+    /// The connection string this source implies, if any.
+    pub(crate) fn url(&self) -> Option<&str> {
+        match self {
+            LoadedDiffSource::Datamodel { url, .. } => Some(url),
+            LoadedDiffSource::Url(url) => Some(url),
+            _ => None,
+        }
+    }
+
+    /// The database schemas the datasource of a Prisma schema spans.
+    pub(crate) fn schemas(&self) -> Option<&[String]> {
+        match self {
+            LoadedDiffSource::Datamodel { schemas, .. } => Some(schemas),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_target(&self) -> DiffTarget<'_> {
+        match self {
+            LoadedDiffSource::Empty => DiffTarget::Empty,
+            LoadedDiffSource::Datamodel { datamodel, .. } => DiffTarget::Datamodel(datamodel),
+            LoadedDiffSource::Migrations(migrations) => DiffTarget::Migrations(migrations),
+            LoadedDiffSource::SqlSchema(json) => DiffTarget::SerializedSchema(json),
+            LoadedDiffSource::Url(url) => DiffTarget::Database(url),
+        }
+    }
+}
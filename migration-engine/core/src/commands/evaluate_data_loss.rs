@@ -1,5 +1,5 @@
-use super::{CommandResult, MigrationCommand};
-use crate::{migration_engine::MigrationEngine, parse_datamodel};
+use super::{CommandResult, MigrationCommand, PrismaSchema};
+use crate::{migration_engine::MigrationEngine, parse_prisma_schema};
 use migration_connector::list_migrations;
 use serde::{Deserialize, Serialize};
 
@@ -17,7 +17,7 @@ pub struct EvaluateDataLossInput {
     /// The location of the migrations directory.
     pub migrations_directory_path: String,
     /// The prisma schema to migrate to.
-    pub prisma_schema: String,
+    pub prisma_schema: PrismaSchema,
 }
 
 /// The output of the `evaluateDataLoss` command.
@@ -61,7 +61,7 @@ impl MigrationCommand for EvaluateDataLoss {
         let checker = connector.destructive_change_checker();
 
         let migrations_from_directory = list_migrations(input.migrations_directory_path.as_ref())?;
-        let target_schema = parse_datamodel(&input.prisma_schema)?;
+        let target_schema = parse_prisma_schema(&input.prisma_schema)?;
 
         let migration = inferrer
             .infer_next_migration(&migrations_from_directory, &target_schema)
@@ -0,0 +1,89 @@
+use std::path::Path;
+
+use super::{CommandResult, MigrationCommand, PrismaSchema};
+use crate::{migration_engine::MigrationEngine, parse_prisma_schema};
+use migration_connector::{ConnectorError, MigrationRecord, MigrationWarning, UnexecutableMigration};
+use serde::{Deserialize, Serialize};
+
+/// The input to the `lintMigrations` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LintMigrationsInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// The prisma schema the migrations lead to.
+    pub prisma_schema: PrismaSchema,
+}
+
+/// The output of the `lintMigrations` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LintMigrationsOutput {
+    /// The lint results for each unapplied migration, in the order they would be applied.
+    pub migrations: Vec<MigrationLintResult>,
+}
+
+/// The problems found in the script of a single migration.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationLintResult {
+    /// The name of the migration.
+    pub migration_name: String,
+    /// The risky operations in the script. The step indexes are statement indexes.
+    pub warnings: Vec<MigrationWarning>,
+    /// The statements that would fail on the database.
+    pub unexecutable_migrations: Vec<UnexecutableMigration>,
+}
+
+/// Check the scripts of the unapplied migrations in the migrations directory for risky
+/// operations, against the current contents of the database. The migration engine does not
+/// write to the migrations directory, and only writes to the database to create the migrations
+/// table if it does not exist yet.
+pub struct LintMigrationsCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for LintMigrationsCommand {
+    type Input = LintMigrationsInput;
+
+    type Output = LintMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let checker = connector.destructive_change_checker();
+        let migration_persistence = connector.new_migration_persistence();
+
+        let target_schema = parse_prisma_schema(&input.prisma_schema)?;
+        let migrations_from_filesystem =
+            migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
+        // Rolled back migrations count as unapplied.
+        let migrations_from_database: Vec<MigrationRecord> = migration_persistence
+            .list_migrations()
+            .await?
+            .into_iter()
+            .filter(|migration| !migration.is_rolled_back())
+            .collect();
+
+        let mut migrations = Vec::new();
+
+        for migration in migrations_from_filesystem.iter().filter(|fs_migration| {
+            !migrations_from_database
+                .iter()
+                .any(|db_migration| fs_migration.migration_name() == db_migration.migration_name)
+        }) {
+            let script = migration.read_migration_script().map_err(ConnectorError::from)?;
+            let diagnostics = checker.lint_script(&script, &target_schema).await?;
+
+            migrations.push(MigrationLintResult {
+                migration_name: migration.migration_name().to_owned(),
+                warnings: diagnostics.warnings,
+                unexecutable_migrations: diagnostics.unexecutable_migrations,
+            });
+        }
+
+        Ok(LintMigrationsOutput { migrations })
+    }
+}
@@ -0,0 +1,112 @@
+use std::path::Path;
+
+use super::{CommandError, CommandResult, MigrationCommand};
+use crate::migration_engine::MigrationEngine;
+use migration_connector::{DatabaseMigrationMarker, MigrationConnector, MigrationResolution};
+use serde::{Deserialize, Serialize};
+
+/// The input to the `markMigrationApplied` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkMigrationAppliedInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// The name of the failed migration, i.e. the name of its directory.
+    pub migration_name: String,
+    /// Who resolved the migration, for the audit trail.
+    pub resolved_by: String,
+}
+
+/// The output of the `markMigrationApplied` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkMigrationAppliedOutput {}
+
+/// Mark a failed migration as applied, after an operator finished applying it by hand.
+pub struct MarkMigrationAppliedCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for MarkMigrationAppliedCommand {
+    type Input = MarkMigrationAppliedInput;
+
+    type Output = MarkMigrationAppliedOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        resolve_failed_migration(
+            engine,
+            &input.migrations_directory_path,
+            &input.migration_name,
+            &input.resolved_by,
+            MigrationResolution::Applied,
+        )
+        .await?;
+
+        Ok(MarkMigrationAppliedOutput {})
+    }
+}
+
+/// Check that the migration exists in the migrations directory and is currently failed, then
+/// record the resolution, with an entry in the logs saying who resolved it and when.
+pub(super) async fn resolve_failed_migration<C, D>(
+    engine: &MigrationEngine<C, D>,
+    migrations_directory_path: &str,
+    migration_name: &str,
+    resolved_by: &str,
+    resolution: MigrationResolution,
+) -> CommandResult<()>
+where
+    C: MigrationConnector<DatabaseMigration = D>,
+    D: DatabaseMigrationMarker + Send + Sync + 'static,
+{
+    let migration_persistence = engine.connector().new_migration_persistence();
+
+    let migrations_from_filesystem = migration_connector::list_migrations(&Path::new(migrations_directory_path))?;
+
+    if !migrations_from_filesystem
+        .iter()
+        .any(|migration| migration.migration_name() == migration_name)
+    {
+        return Err(CommandError::Input(anyhow::anyhow!(
+            "Migration `{}` cannot be found in the migrations directory.",
+            migration_name
+        )));
+    }
+
+    let migrations_from_database = migration_persistence.list_migrations().await?;
+
+    // The latest record for the migration is the one that reflects its current state.
+    let record = migrations_from_database
+        .iter()
+        .rev()
+        .find(|record| record.migration_name == migration_name && !record.is_rolled_back())
+        .filter(|record| record.is_failed())
+        .ok_or_else(|| {
+            CommandError::Input(anyhow::anyhow!(
+                "Migration `{}` is not in a failed state in the database.",
+                migration_name
+            ))
+        })?;
+
+    let action = match resolution {
+        MigrationResolution::Applied => "applied",
+        MigrationResolution::RolledBack => "rolled back",
+    };
+
+    let logs = format!(
+        "{logs}\n\nMarked as {action} by `{resolved_by}` at {resolved_at}.",
+        logs = record.logs,
+        action = action,
+        resolved_by = resolved_by,
+        resolved_at = chrono::Utc::now(),
+    );
+
+    migration_persistence
+        .record_migration_resolved(&record.id, resolution, &logs)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use super::{mark_migration_applied::resolve_failed_migration, CommandResult, MigrationCommand};
+use crate::migration_engine::MigrationEngine;
+use migration_connector::{DatabaseMigrationMarker, MigrationConnector, MigrationResolution};
+use serde::{Deserialize, Serialize};
+
+/// The input to the `markMigrationRolledBack` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkMigrationRolledBackInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// The name of the failed migration, i.e. the name of its directory.
+    pub migration_name: String,
+    /// Who resolved the migration, for the audit trail.
+    pub resolved_by: String,
+}
+
+/// The output of the `markMigrationRolledBack` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkMigrationRolledBackOutput {}
+
+/// Mark a failed migration as rolled back, after an operator reverted its changes by hand. The
+/// migration will be applied again by the next `applyMigrations`.
+pub struct MarkMigrationRolledBackCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for MarkMigrationRolledBackCommand {
+    type Input = MarkMigrationRolledBackInput;
+
+    type Output = MarkMigrationRolledBackOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        resolve_failed_migration(
+            engine,
+            &input.migrations_directory_path,
+            &input.migration_name,
+            &input.resolved_by,
+            MigrationResolution::RolledBack,
+        )
+        .await?;
+
+        Ok(MarkMigrationRolledBackOutput {})
+    }
+}
@@ -0,0 +1,31 @@
+use super::CommandResult;
+use futures::FutureExt;
+use migration_connector::MigrationConnector;
+use std::{future::Future, panic::AssertUnwindSafe, time::Duration};
+
+/// How long to wait for the migration lock when the input does not say.
+pub(crate) const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Run `command` while holding the migration lock. The lock is released whether the command
+/// succeeds, fails or panics, and errors from the command take precedence over errors from
+/// releasing the lock.
+pub(crate) async fn with_migration_lock<C, F, T>(connector: &C, timeout: Duration, command: F) -> CommandResult<T>
+where
+    C: MigrationConnector,
+    F: Future<Output = CommandResult<T>>,
+{
+    connector.acquire_lock(timeout).await?;
+
+    let result = AssertUnwindSafe(command).catch_unwind().await;
+    let release_result = connector.release_lock().await;
+
+    match result {
+        Ok(result) => {
+            let output = result?;
+            release_result?;
+
+            Ok(output)
+        }
+        Err(panic) => std::panic::resume_unwind(panic),
+    }
+}
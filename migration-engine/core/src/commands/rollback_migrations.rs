@@ -0,0 +1,221 @@
+use std::{path::Path, time::Duration};
+
+use super::{
+    migration_lock::{with_migration_lock, DEFAULT_LOCK_TIMEOUT},
+    CommandError, CommandResult, MigrationCommand,
+};
+use crate::migration_engine::MigrationEngine;
+use migration_connector::{ConnectorError, MigrationConnector, MigrationDirectory, MigrationRecord, TransactionMode};
+use serde::{Deserialize, Serialize};
+
+/// The first line of a down script that must not be applied. The lines that follow it list the
+/// reasons why.
+pub(crate) const IRREVERSIBLE_MIGRATION_HEADER: &str = "-- This migration cannot be reversed safely:";
+
+/// The input to the `rollbackMigrations` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RollbackMigrationsInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// The number of applied migrations to roll back, starting from the latest.
+    #[serde(default)]
+    pub count: Option<u32>,
+    /// Roll back all the migrations applied after this one. The migration itself stays applied.
+    #[serde(default)]
+    pub to_migration: Option<String>,
+    /// How long to wait for another migration engine applying or rolling back migrations on the
+    /// same database to release the migration lock, in seconds. Defaults to 10 seconds.
+    #[serde(default)]
+    pub lock_timeout_seconds: Option<u64>,
+}
+
+/// The output of the `rollbackMigrations` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RollbackMigrationsOutput {
+    /// The names of the migrations that were rolled back, in the order they were rolled back.
+    pub rolled_back_migration_names: Vec<String>,
+}
+
+/// Revert applied migrations by running their down scripts, latest first. Nothing is
+/// rolled back unless every selected migration can be reversed safely.
+pub struct RollbackMigrationsCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for RollbackMigrationsCommand {
+    type Input = RollbackMigrationsInput;
+
+    type Output = RollbackMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: migration_connector::MigrationConnector<DatabaseMigration = D>,
+        D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let lock_timeout = input
+            .lock_timeout_seconds
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_LOCK_TIMEOUT);
+
+        // Hold the migration lock like applyMigrations, so the history can't change while we
+        // roll it back.
+        with_migration_lock(connector, lock_timeout, rollback_migrations(input, connector)).await
+    }
+}
+
+async fn rollback_migrations<C, D>(
+    input: &RollbackMigrationsInput,
+    connector: &C,
+) -> CommandResult<RollbackMigrationsOutput>
+where
+    C: MigrationConnector<DatabaseMigration = D>,
+    D: migration_connector::DatabaseMigrationMarker + Send + Sync + 'static,
+{
+    let applier = connector.database_migration_step_applier();
+    let migration_persistence = connector.new_migration_persistence();
+
+    let migrations_from_filesystem =
+        migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
+    let migrations_from_database = migration_persistence.list_migrations().await?;
+
+    if let Some(failed_migration) = migrations_from_database
+        .iter()
+        .find(|migration| migration.is_failed() && !migration.is_rolled_back())
+    {
+        return Err(CommandError::Input(anyhow::anyhow!(
+            "The `{}` migration failed. Resolve it before rolling back migrations.",
+            failed_migration.migration_name
+        )));
+    }
+
+    // The records are sorted by start date, so the latest applied migration comes last.
+    let applied_migrations: Vec<&MigrationRecord> = migrations_from_database
+        .iter()
+        .filter(|migration| !migration.is_rolled_back())
+        .collect();
+
+    let to_roll_back = select_migrations(input, &applied_migrations)?;
+
+    // Check every migration before touching the database, so we never stop halfway.
+    let mut down_scripts: Vec<(&MigrationRecord, String, TransactionMode)> = Vec::with_capacity(to_roll_back.len());
+    let mut problems: Vec<String> = Vec::new();
+
+    for record in to_roll_back {
+        match down_script(record, &migrations_from_filesystem) {
+            Ok((script, transaction_mode)) => down_scripts.push((record, script, transaction_mode)),
+            Err(problem) => problems.push(problem),
+        }
+    }
+
+    if !problems.is_empty() {
+        return Err(CommandError::Input(anyhow::anyhow!(
+            "Refusing to roll back:\n\n{}",
+            problems.join("\n\n")
+        )));
+    }
+
+    let mut rolled_back_migration_names = Vec::with_capacity(down_scripts.len());
+
+    for (record, script, transaction_mode) in down_scripts {
+        tracing::info!(script = script.as_str(), "Rolling back `{}`", record.migration_name);
+
+        applier.apply_script(&script, transaction_mode).await?;
+        migration_persistence.record_migration_rolled_back(&record.id).await?;
+
+        rolled_back_migration_names.push(record.migration_name.clone());
+    }
+
+    Ok(RollbackMigrationsOutput {
+        rolled_back_migration_names,
+    })
+}
+
+/// The applied migrations to roll back, latest first.
+fn select_migrations<'a>(
+    input: &RollbackMigrationsInput,
+    applied_migrations: &[&'a MigrationRecord],
+) -> CommandResult<Vec<&'a MigrationRecord>> {
+    let latest_first = applied_migrations.iter().rev().copied();
+
+    match (input.count, &input.to_migration) {
+        (Some(count), None) => Ok(latest_first.take(count as usize).collect()),
+        (None, Some(to_migration)) => {
+            if !applied_migrations
+                .iter()
+                .any(|migration| &migration.migration_name == to_migration)
+            {
+                return Err(CommandError::Input(anyhow::anyhow!(
+                    "The `{}` migration is not applied to the database.",
+                    to_migration
+                )));
+            }
+
+            Ok(latest_first
+                .take_while(|migration| &migration.migration_name != to_migration)
+                .collect())
+        }
+        _ => Err(CommandError::Input(anyhow::anyhow!(
+            "Exactly one of `count` and `toMigration` must be provided."
+        ))),
+    }
+}
+
+/// The down script of the migration and its transaction mode, or an explanation of why it cannot be
+/// applied.
+fn down_script(
+    record: &MigrationRecord,
+    migrations_from_filesystem: &[MigrationDirectory],
+) -> Result<(String, TransactionMode), String> {
+    let directory = migrations_from_filesystem
+        .iter()
+        .find(|directory| directory.migration_name() == record.migration_name)
+        .ok_or_else(|| {
+            format!(
+                "The `{}` migration is applied to the database but missing from the migrations directory.",
+                record.migration_name
+            )
+        })?;
+
+    let script = directory
+        .read_down_script()
+        .map_err(|err| ConnectorError::from(err).to_string())?
+        .ok_or_else(|| format!("The `{}` migration has no down script.", record.migration_name))?;
+
+    let transaction_mode = TransactionMode::from_script(&script)
+        .map_err(|err| format!("The down script of `{}` is invalid. {}", record.migration_name, err))?;
+
+    if script.starts_with(IRREVERSIBLE_MIGRATION_HEADER) {
+        let reasons: Vec<&str> = script
+            .lines()
+            .skip(1)
+            .take_while(|line| line.starts_with("-- "))
+            .map(|line| &line[3..])
+            .collect();
+
+        return Err(format!(
+            "The `{}` migration cannot be reversed safely:\n{}",
+            record.migration_name,
+            reasons.join("\n")
+        ));
+    }
+
+    Ok((script, transaction_mode))
+}
+
+/// Render the header of a down script that must not be applied.
+pub(crate) fn render_irreversible_migration_header(reasons: &[String]) -> String {
+    let mut header = String::from(IRREVERSIBLE_MIGRATION_HEADER);
+    header.push_str("\n");
+
+    for reason in reasons {
+        header.push_str("-- - ");
+        header.push_str(reason);
+        header.push_str("\n");
+    }
+
+    header.push_str("\n");
+
+    header
+}
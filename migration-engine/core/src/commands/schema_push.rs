@@ -1,5 +1,5 @@
-use super::MigrationCommand;
-use crate::parse_datamodel;
+use super::{MigrationCommand, PrismaSchema};
+use crate::parse_prisma_schema;
 use migration_connector::{DatabaseMigrationMarker, MigrationConnector};
 use serde::{Deserialize, Serialize};
 
@@ -21,7 +21,7 @@ impl MigrationCommand for SchemaPushCommand {
         D: DatabaseMigrationMarker + Send + Sync + 'static,
     {
         let connector = engine.connector();
-        let schema = parse_datamodel(&input.schema)?;
+        let schema = parse_prisma_schema(&input.schema)?;
         let inferrer = connector.database_migration_inferrer();
         let applier = connector.database_migration_step_applier();
         let checker = connector.destructive_change_checker();
@@ -67,7 +67,7 @@ impl MigrationCommand for SchemaPushCommand {
 #[serde(rename_all = "camelCase")]
 pub struct SchemaPushInput {
     /// The prisma schema.
-    pub schema: String,
+    pub schema: PrismaSchema,
     /// Push the schema ignoring destructive change warnings.
     pub force: bool,
     /// Expect the schema to be empty, skipping describing the existing schema.
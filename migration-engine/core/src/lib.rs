@@ -15,25 +15,33 @@ pub use api::GenericApi;
 pub use commands::{ApplyMigrationInput, InferMigrationStepsInput, MigrationStepsResultOutput};
 pub use error::CoreResult;
 
-use commands::{CommandError, CommandResult};
+use commands::{CommandError, CommandResult, DiffInput, DiffOutput, LoadedDiffSource, PrismaSchema};
 use datamodel::{
     common::provider_names::{MSSQL_SOURCE_NAME, MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     dml::Datamodel,
+    SourceFile,
 };
 use error::Error;
 use migration_connector::ConnectorError;
-use sql_migration_connector::SqlMigrationConnector;
+use sql_migration_connector::{DiffFormat, SqlMigrationConnector};
 use std::sync::Arc;
 
 /// Top-level constructor for the migration engine API.
 pub async fn migration_api(datamodel: &str) -> CoreResult<Arc<dyn api::GenericApi>> {
-    let config = datamodel::parse_configuration(datamodel)?;
+    migration_api_from_files(&[SourceFile::new("schema.prisma", datamodel)]).await
+}
+
+/// Top-level constructor for the migration engine API, for a schema split across several files.
+pub async fn migration_api_from_files(files: &[SourceFile]) -> CoreResult<Arc<dyn api::GenericApi>> {
+    let config = datamodel::parse_configuration_from_files(files)?;
 
     let source = config
         .datasources
         .first()
         .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?;
 
+    let shadow_database_url = source.shadow_database_url.as_ref().map(|url| url.value.clone());
+
     let connector = match &source.active_provider {
         #[cfg(feature = "sql")]
         provider if POSTGRES_SOURCE_NAME == provider => {
@@ -57,11 +65,17 @@ pub async fn migration_api(datamodel: &str) -> CoreResult<Arc<dyn api::GenericAp
                 u.query_pairs_mut().append_pair("statement_cache_size", "0");
             }
 
-            SqlMigrationConnector::new(u.as_str()).await?
+            SqlMigrationConnector::new_with_shadow_database(u.as_str(), source.schemas.clone(), shadow_database_url)
+                .await?
         }
         #[cfg(feature = "sql")]
         provider if [MYSQL_SOURCE_NAME, SQLITE_SOURCE_NAME, MSSQL_SOURCE_NAME].contains(&provider.as_str()) => {
-            SqlMigrationConnector::new(&source.url().value).await?
+            SqlMigrationConnector::new_with_shadow_database(
+                &source.url().value,
+                source.schemas.clone(),
+                shadow_database_url,
+            )
+            .await?
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
@@ -123,7 +137,42 @@ pub async fn qe_setup(prisma_schema: &str) -> CoreResult<()> {
     Ok(())
 }
 
+/// Compare two schema sources and render the migration between them. Only the sources that need
+/// a database are connected to.
+pub async fn diff(input: &DiffInput) -> CoreResult<DiffOutput> {
+    let from = LoadedDiffSource::load(&input.from)?;
+    let to = LoadedDiffSource::load(&input.to)?;
+
+    let datasource_url = input
+        .datasource_url
+        .as_deref()
+        .or_else(|| from.url())
+        .or_else(|| to.url())
+        .ok_or_else(|| {
+            CommandError::Input(anyhow::anyhow!(
+                "Could not determine which database to diff for. Pass a datasource URL, a Prisma schema or a database URL."
+            ))
+        })?;
+    let schemas = from.schemas().or_else(|| to.schemas()).unwrap_or_default().to_vec();
+    let format = if input.script {
+        DiffFormat::Script
+    } else {
+        DiffFormat::Summary
+    };
+
+    let diff = SqlMigrationConnector::diff(datasource_url, schemas, from.as_target(), to.as_target(), format).await?;
+
+    Ok(DiffOutput { diff })
+}
+
 pub(crate) fn parse_datamodel(datamodel: &str) -> CommandResult<Datamodel> {
     datamodel::parse_datamodel(&datamodel)
         .map_err(|err| CommandError::ReceivedBadDatamodel(err.to_pretty_string("schema.prisma", datamodel)))
 }
+
+pub(crate) fn parse_prisma_schema(schema: &PrismaSchema) -> CommandResult<Datamodel> {
+    let files = schema.files();
+
+    datamodel::parse_datamodel_from_files(&files)
+        .map_err(|err| CommandError::ReceivedBadDatamodel(err.to_pretty_string_for_files(&files)))
+}
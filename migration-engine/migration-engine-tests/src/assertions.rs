@@ -68,6 +68,16 @@ impl SchemaAssertion {
         Ok(self)
     }
 
+    pub fn assert_has_no_table(self, table_name: &str) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.get_table(table_name).is_none(),
+            "Assertion failed. Expected no table named `{}`.",
+            table_name
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_has_no_enum(self, enum_name: &str) -> AssertionResult<Self> {
         assert!(self.0.get_enum(enum_name).is_none());
 
@@ -462,6 +472,17 @@ impl<'a> PrimaryKeyAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_constraint_name(self, constraint_name: &str) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.pk.constraint_name.as_deref() == Some(constraint_name),
+            "Assertion failed: expected the primary key to be named {:?}, found {:?}.",
+            constraint_name,
+            self.pk.constraint_name,
+        );
+
+        Ok(self)
+    }
+
     pub fn debug_print(self) -> AssertionResult<Self> {
         dbg!(&self.pk);
         Ok(self)
@@ -492,6 +513,39 @@ impl<'a> ForeignKeyAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_on_delete(self, action: ForeignKeyAction) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.on_delete_action == action,
+            "Assertion failed: expected foreign key to have on delete action {:?}, found {:?}.",
+            action,
+            self.0.on_delete_action,
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_on_update(self, action: ForeignKeyAction) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.on_update_action == action,
+            "Assertion failed: expected foreign key to have on update action {:?}, found {:?}.",
+            action,
+            self.0.on_update_action,
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_constraint_name(self, constraint_name: &str) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.constraint_name.as_deref() == Some(constraint_name),
+            "Assertion failed: expected foreign key to be named {:?}, found {:?}.",
+            constraint_name,
+            self.0.constraint_name,
+        );
+
+        Ok(self)
+    }
 }
 
 pub struct IndexAssertion<'a>(&'a Index);
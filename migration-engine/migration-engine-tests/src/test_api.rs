@@ -6,7 +6,9 @@ mod diagnose_migration_history;
 mod evaluate_data_loss;
 mod infer;
 mod infer_apply;
+mod lint_migrations;
 mod reset;
+mod rollback_migrations;
 mod schema_push;
 mod unapply_migration;
 
@@ -18,7 +20,9 @@ pub use diagnose_migration_history::DiagnoseMigrationHistory;
 pub use evaluate_data_loss::EvaluateDataLoss;
 pub use infer::Infer;
 pub use infer_apply::InferApply;
+pub use lint_migrations::LintMigrations;
 pub use reset::Reset;
+pub use rollback_migrations::RollbackMigrations;
 pub use schema_push::SchemaPush;
 pub use unapply_migration::UnapplyMigration;
 
@@ -26,14 +30,20 @@ use crate::AssertionResult;
 
 use super::assertions::SchemaAssertion;
 use super::{
-    misc_helpers::{mysql_migration_connector, postgres_migration_connector, sqlite_migration_connector, test_api},
+    misc_helpers::{
+        mssql_migration_connector, mysql_migration_connector, postgres_migration_connector, sqlite_migration_connector,
+        test_api,
+    },
     sql::barrel_migration_executor::BarrelMigrationExecutor,
     InferAndApplyOutput,
 };
 use migration_connector::{ImperativeMigrationsPersistence, MigrationPersistence, MigrationRecord, MigrationStep};
 use migration_core::{
     api::{GenericApi, MigrationApi},
-    commands::ApplyMigrationInput,
+    commands::{
+        ApplyMigrationInput, BaselineInput, BaselineOutput, MarkMigrationAppliedInput, MarkMigrationRolledBackInput,
+        PrismaSchema,
+    },
 };
 use quaint::{
     prelude::{ConnectionInfo, Queryable, SqlFamily},
@@ -159,6 +169,62 @@ impl TestApi {
         CreateMigration::new(&self.api, name, prisma_schema, migrations_directory)
     }
 
+    /// Call the Baseline command.
+    pub async fn baseline(
+        &self,
+        migrations_directory: &TempDir,
+        migration_name: &str,
+    ) -> anyhow::Result<BaselineOutput> {
+        Ok(self
+            .api
+            .baseline(&BaselineInput {
+                migrations_directory_path: migrations_directory.path().to_str().unwrap().to_owned(),
+                migration_name: migration_name.to_owned(),
+            })
+            .await?)
+    }
+
+    /// Call the MarkMigrationApplied command, resolving as `resolved_by`.
+    pub async fn mark_migration_applied(
+        &self,
+        migrations_directory: &TempDir,
+        migration_name: &str,
+        resolved_by: &str,
+    ) -> anyhow::Result<()> {
+        self.api
+            .mark_migration_applied(&MarkMigrationAppliedInput {
+                migrations_directory_path: migrations_directory.path().to_str().unwrap().to_owned(),
+                migration_name: migration_name.to_owned(),
+                resolved_by: resolved_by.to_owned(),
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    /// Call the MarkMigrationRolledBack command, resolving as `resolved_by`.
+    pub async fn mark_migration_rolled_back(
+        &self,
+        migrations_directory: &TempDir,
+        migration_name: &str,
+        resolved_by: &str,
+    ) -> anyhow::Result<()> {
+        self.api
+            .mark_migration_rolled_back(&MarkMigrationRolledBackInput {
+                migrations_directory_path: migrations_directory.path().to_str().unwrap().to_owned(),
+                migration_name: migration_name.to_owned(),
+                resolved_by: resolved_by.to_owned(),
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    /// Builder and assertions to call the RollbackMigrations command.
+    pub fn rollback_migrations<'a>(&'a self, migrations_directory: &'a TempDir) -> RollbackMigrations<'a> {
+        RollbackMigrations::new(&self.api, migrations_directory)
+    }
+
     /// Builder and assertions to call the DiagnoseMigrationHistory command.
     pub fn diagnose_migration_history<'a>(&'a self, migrations_directory: &'a TempDir) -> DiagnoseMigrationHistory<'a> {
         DiagnoseMigrationHistory::new(&self.api, migrations_directory)
@@ -215,11 +281,20 @@ impl TestApi {
         EvaluateDataLoss::new(&self.api, migrations_directory, prisma_schema.into())
     }
 
+    /// Builder and assertions to call the LintMigrations command.
+    pub fn lint_migrations<'a>(
+        &'a self,
+        migrations_directory: &'a TempDir,
+        prisma_schema: impl Into<String>,
+    ) -> LintMigrations<'a> {
+        LintMigrations::new(&self.api, migrations_directory, prisma_schema.into())
+    }
+
     pub fn reset<'a>(&'a self) -> Reset<'a> {
         Reset::new(&self.api)
     }
 
-    pub fn schema_push<'a>(&'a self, dm: impl Into<String>) -> SchemaPush<'a> {
+    pub fn schema_push<'a>(&'a self, dm: impl Into<PrismaSchema>) -> SchemaPush<'a> {
         SchemaPush::new(&self.api, dm.into())
     }
 
@@ -455,6 +530,32 @@ pub async fn postgres13_test_api(db_name: &str) -> TestApi {
     }
 }
 
+pub async fn mssql_2017_test_api(db_name: &str) -> TestApi {
+    let url = mssql_2017_url(db_name);
+    let connection_info = ConnectionInfo::from_url(&url).unwrap();
+    let connector = mssql_migration_connector(&url).await;
+
+    TestApi {
+        connector_name: "mssql_2017",
+        connection_info,
+        database: connector.quaint().clone(),
+        api: test_api(connector).await,
+    }
+}
+
+pub async fn mssql_2019_test_api(db_name: &str) -> TestApi {
+    let url = mssql_2019_url(db_name);
+    let connection_info = ConnectionInfo::from_url(&url).unwrap();
+    let connector = mssql_migration_connector(&url).await;
+
+    TestApi {
+        connector_name: "mssql_2019",
+        connection_info,
+        database: connector.quaint().clone(),
+        api: test_api(connector).await,
+    }
+}
+
 pub async fn sqlite_test_api(db_name: &str) -> TestApi {
     let connection_info = ConnectionInfo::from_url(&sqlite_test_url(db_name)).unwrap();
     let connector = sqlite_migration_connector(db_name).await;
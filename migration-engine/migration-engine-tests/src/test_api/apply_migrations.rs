@@ -7,6 +7,7 @@ use crate::AssertionResult;
 pub struct ApplyMigrations<'a> {
     api: &'a dyn GenericApi,
     migrations_directory: &'a TempDir,
+    lock_timeout_seconds: Option<u64>,
 }
 
 impl<'a> ApplyMigrations<'a> {
@@ -14,14 +15,21 @@ impl<'a> ApplyMigrations<'a> {
         ApplyMigrations {
             api,
             migrations_directory,
+            lock_timeout_seconds: None,
         }
     }
 
+    pub fn lock_timeout_seconds(mut self, lock_timeout_seconds: u64) -> Self {
+        self.lock_timeout_seconds = Some(lock_timeout_seconds);
+        self
+    }
+
     pub async fn send(self) -> anyhow::Result<ApplyMigrationsAssertion<'a>> {
         let output = self
             .api
             .apply_migrations(&ApplyMigrationsInput {
                 migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                lock_timeout_seconds: self.lock_timeout_seconds,
             })
             .await?;
 
@@ -11,6 +11,7 @@ pub struct CreateMigration<'a> {
     schema: &'a str,
     migrations_directory: &'a TempDir,
     draft: bool,
+    non_transactional: bool,
     name: &'a str,
 }
 
@@ -21,6 +22,7 @@ impl<'a> CreateMigration<'a> {
             schema,
             migrations_directory,
             draft: false,
+            non_transactional: false,
             name,
         }
     }
@@ -31,13 +33,20 @@ impl<'a> CreateMigration<'a> {
         self
     }
 
+    pub fn non_transactional(mut self, non_transactional: bool) -> Self {
+        self.non_transactional = non_transactional;
+
+        self
+    }
+
     pub async fn send(self) -> anyhow::Result<CreateMigrationAssertion<'a>> {
         let output = self
             .api
             .create_migration(&CreateMigrationInput {
                 migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
-                prisma_schema: self.schema.to_owned(),
+                prisma_schema: self.schema.into(),
                 draft: self.draft,
+                non_transactional: self.non_transactional,
                 migration_name: self.name.to_owned(),
             })
             .await?;
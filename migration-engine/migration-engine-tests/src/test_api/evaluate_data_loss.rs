@@ -27,7 +27,7 @@ impl<'a> EvaluateDataLoss<'a> {
             .api
             .evaluate_data_loss(&EvaluateDataLossInput {
                 migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
-                prisma_schema: self.prisma_schema,
+                prisma_schema: self.prisma_schema.into(),
             })
             .await?;
 
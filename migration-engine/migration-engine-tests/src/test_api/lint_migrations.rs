@@ -0,0 +1,124 @@
+use crate::AssertionResult;
+use migration_core::{
+    commands::{LintMigrationsInput, LintMigrationsOutput, MigrationLintResult},
+    GenericApi,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See LintMigrations::send()"]
+pub struct LintMigrations<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+    prisma_schema: String,
+}
+
+impl<'a> LintMigrations<'a> {
+    pub fn new(api: &'a dyn GenericApi, migrations_directory: &'a TempDir, prisma_schema: String) -> Self {
+        LintMigrations {
+            api,
+            migrations_directory,
+            prisma_schema,
+        }
+    }
+
+    pub async fn send(self) -> anyhow::Result<LintMigrationsAssertion<'a>> {
+        let output = self
+            .api
+            .lint_migrations(&LintMigrationsInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                prisma_schema: self.prisma_schema.into(),
+            })
+            .await?;
+
+        Ok(LintMigrationsAssertion {
+            output,
+            _api: self.api,
+            _migrations_directory: self.migrations_directory,
+        })
+    }
+}
+
+pub struct LintMigrationsAssertion<'a> {
+    output: LintMigrationsOutput,
+    _api: &'a dyn GenericApi,
+    _migrations_directory: &'a TempDir,
+}
+
+impl std::fmt::Debug for LintMigrationsAssertion<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LintMigrationsAssertion").finish()
+    }
+}
+
+impl<'a> LintMigrationsAssertion<'a> {
+    pub fn assert_linted_migrations(self, names: &[&str]) -> AssertionResult<Self> {
+        let found_names: Vec<&str> = self
+            .output
+            .migrations
+            .iter()
+            .map(|migration| &migration.migration_name[15..])
+            .collect();
+
+        anyhow::ensure!(
+            found_names == names,
+            "Assertion failed. The linted migrations do not match the expectations. ({:?} vs {:?})",
+            found_names,
+            names
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_no_warnings(self) -> AssertionResult<Self> {
+        for migration in &self.output.migrations {
+            anyhow::ensure!(
+                migration.warnings.is_empty() && migration.unexecutable_migrations.is_empty(),
+                "Assertion failed. Expected no warnings for `{}`, got:\n{:#?}\n{:#?}",
+                migration.migration_name,
+                migration.warnings,
+                migration.unexecutable_migrations,
+            );
+        }
+
+        Ok(self)
+    }
+
+    /// Assert the warnings of the migration whose name ends with `name`, as
+    /// (description, start line of the statement) pairs.
+    pub fn assert_warnings(self, name: &str, warnings: &[(&str, usize)]) -> AssertionResult<Self> {
+        let migration = self.migration(name)?;
+
+        let found: Vec<(&str, usize)> = migration
+            .warnings
+            .iter()
+            .map(|warning| {
+                (
+                    warning.description.as_str(),
+                    warning.span.map(|span| span.start_line).unwrap_or(0),
+                )
+            })
+            .collect();
+
+        anyhow::ensure!(
+            found == warnings,
+            "Assertion failed. The warnings for `{}` do not match the expectations.\n{:#?}\nvs\n{:#?}",
+            name,
+            found,
+            warnings
+        );
+
+        Ok(self)
+    }
+
+    fn migration(&self, name: &str) -> AssertionResult<&MigrationLintResult> {
+        self.output
+            .migrations
+            .iter()
+            .find(|migration| migration.migration_name.ends_with(name))
+            .ok_or_else(|| anyhow::anyhow!("Assertion failed. `{}` was not linted.", name))
+    }
+
+    pub fn into_output(self) -> LintMigrationsOutput {
+        self.output
+    }
+}
@@ -0,0 +1,93 @@
+use migration_core::{
+    commands::{RollbackMigrationsInput, RollbackMigrationsOutput},
+    GenericApi,
+};
+use tempfile::TempDir;
+
+use crate::AssertionResult;
+
+#[must_use = "This struct does nothing on its own. See RollbackMigrations::send()"]
+pub struct RollbackMigrations<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+    count: Option<u32>,
+    to_migration: Option<String>,
+    lock_timeout_seconds: Option<u64>,
+}
+
+impl<'a> RollbackMigrations<'a> {
+    pub fn new(api: &'a dyn GenericApi, migrations_directory: &'a TempDir) -> Self {
+        RollbackMigrations {
+            api,
+            migrations_directory,
+            count: None,
+            to_migration: None,
+            lock_timeout_seconds: None,
+        }
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn to_migration(mut self, migration_name: impl Into<String>) -> Self {
+        self.to_migration = Some(migration_name.into());
+        self
+    }
+
+    pub fn lock_timeout_seconds(mut self, lock_timeout_seconds: u64) -> Self {
+        self.lock_timeout_seconds = Some(lock_timeout_seconds);
+        self
+    }
+
+    pub async fn send(self) -> anyhow::Result<RollbackMigrationsAssertion<'a>> {
+        let output = self
+            .api
+            .rollback_migrations(&RollbackMigrationsInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                count: self.count,
+                to_migration: self.to_migration,
+                lock_timeout_seconds: self.lock_timeout_seconds,
+            })
+            .await?;
+
+        Ok(RollbackMigrationsAssertion {
+            output,
+            _api: self.api,
+            _migrations_directory: self.migrations_directory,
+        })
+    }
+}
+
+pub struct RollbackMigrationsAssertion<'a> {
+    output: RollbackMigrationsOutput,
+    _api: &'a dyn GenericApi,
+    _migrations_directory: &'a TempDir,
+}
+
+impl std::fmt::Debug for RollbackMigrationsAssertion<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RollbackMigrationsAssertion {{ .. }}")
+    }
+}
+
+impl<'a> RollbackMigrationsAssertion<'a> {
+    pub fn assert_rolled_back_migrations(self, names: &[&str]) -> AssertionResult<Self> {
+        let found_names: Vec<&str> = self
+            .output
+            .rolled_back_migration_names
+            .iter()
+            .map(|name| &name[15..])
+            .collect();
+
+        anyhow::ensure!(
+            found_names == names,
+            "Assertion failed. The rolled back migrations do not match the expectations. ({:?} vs {:?})",
+            found_names,
+            names
+        );
+
+        Ok(self)
+    }
+}
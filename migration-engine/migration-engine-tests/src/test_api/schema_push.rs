@@ -1,18 +1,18 @@
 use super::super::assertions::AssertionResult;
 use migration_core::{
     api::GenericApi,
-    commands::{SchemaPushInput, SchemaPushOutput},
+    commands::{PrismaSchema, SchemaPushInput, SchemaPushOutput},
 };
 use std::borrow::Cow;
 
 pub struct SchemaPush<'a> {
     api: &'a dyn GenericApi,
-    schema: String,
+    schema: PrismaSchema,
     force: bool,
 }
 
 impl<'a> SchemaPush<'a> {
-    pub fn new(api: &'a dyn GenericApi, schema: String) -> Self {
+    pub fn new(api: &'a dyn GenericApi, schema: PrismaSchema) -> Self {
         SchemaPush {
             api,
             schema,
@@ -1,5 +1,9 @@
 use crate::*;
+use migration_connector::MigrationConnector;
 use pretty_assertions::assert_eq;
+use quaint::prelude::ConnectionInfo;
+use sql_migration_connector::SqlMigrationConnector;
+use std::time::Duration;
 
 #[test_each_connector]
 async fn apply_migrations_with_an_empty_migrations_folder_works(api: &TestApi) -> TestResult {
@@ -182,3 +186,167 @@ async fn migrations_should_fail_to_apply_if_modified(api: &TestApi) -> TestResul
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn applying_migrations_waits_for_the_migration_lock(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?;
+
+    // Another migration engine, on its own connection, holds the lock.
+    let file_path = match api.connection_info() {
+        ConnectionInfo::Sqlite { file_path, .. } => file_path.clone(),
+        _ => unreachable!(),
+    };
+    let other_engine = SqlMigrationConnector::new(&format!("file:{}", file_path)).await?;
+
+    other_engine.acquire_lock(Duration::from_secs(1)).await?;
+
+    let err = api
+        .apply_migrations(&migrations_directory)
+        .lock_timeout_seconds(1)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        err.contains("Timed out after 1 seconds trying to acquire the migration lock."),
+        "{}",
+        err
+    );
+
+    other_engine.release_lock().await?;
+
+    api.apply_migrations(&migrations_directory)
+        .lock_timeout_seconds(1)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn the_migration_lock_does_not_outlive_its_holder(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?;
+
+    // Another migration engine takes the lock and goes away without releasing it.
+    let file_path = match api.connection_info() {
+        ConnectionInfo::Sqlite { file_path, .. } => file_path.clone(),
+        _ => unreachable!(),
+    };
+    let other_engine = SqlMigrationConnector::new(&format!("file:{}", file_path)).await?;
+
+    other_engine.acquire_lock(Duration::from_secs(1)).await?;
+    drop(other_engine);
+
+    api.apply_migrations(&migrations_directory)
+        .lock_timeout_seconds(1)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite"))]
+async fn migrations_in_a_transaction_are_rolled_back_entirely_on_failure(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| {
+            contents.insert_str(0, "-- transaction: all\n");
+            contents.push_str("\nSELECT (^.^)_n;\n");
+        })?;
+
+    let result = api.apply_migrations(&migrations_directory).send().await;
+
+    assert!(result.is_err());
+
+    api.assert_schema().await?.assert_has_no_table("Cat")?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn non_transactional_migrations_create_indexes_concurrently(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+
+            @@index([name])
+        }
+    "#;
+
+    let output = api
+        .create_migration("add-index", dm2, &migrations_directory)
+        .non_transactional(true)
+        .send()
+        .await?
+        .into_output();
+
+    let script = std::fs::read_to_string(
+        migrations_directory
+            .path()
+            .join(output.generated_migration_name.unwrap())
+            .join("migration.sql"),
+    )?;
+
+    assert!(script.starts_with("-- transaction: none\n"));
+    assert!(script.contains("CREATE INDEX CONCURRENTLY"));
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "add-index"])?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_index_on_columns(&["name"], |index| Ok(index))
+    })?;
+
+    Ok(())
+}
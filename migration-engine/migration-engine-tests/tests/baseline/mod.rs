@@ -0,0 +1,91 @@
+use crate::*;
+use migration_core::commands::DiagnoseMigrationHistoryOutput;
+
+#[test_each_connector]
+async fn baselining_an_existing_database_works(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+            boxId   Int?
+            box     Box? @relation(fields: [boxId], references: [id])
+        }
+
+        model Box {
+            id      Int @id
+            cats    Cat[]
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let output = api.baseline(&migrations_directory, "baseline").await?;
+
+    assert!(output.baseline_migration_name.ends_with("_baseline"));
+
+    // The baseline is recorded as applied, so nothing is left to apply.
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    let DiagnoseMigrationHistoryOutput {
+        drift,
+        failed_migration_names,
+        edited_migration_names,
+        ..
+    } = api
+        .diagnose_migration_history(&migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(drift.is_none());
+    assert!(failed_migration_names.is_empty());
+    assert!(edited_migration_names.is_empty());
+
+    // The baseline migration reproduces the database schema.
+    api.create_migration("second-migration", dm, &migrations_directory)
+        .send()
+        .await?
+        .assert_migration_directories_count(1)?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn baselining_a_database_with_a_migrations_history_is_rejected(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    let err = api
+        .baseline(&migrations_directory, "baseline")
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        err.contains("Only a database without a migrations history can be baselined."),
+        "{}",
+        err
+    );
+
+    Ok(())
+}
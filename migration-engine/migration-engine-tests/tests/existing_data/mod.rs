@@ -4,7 +4,7 @@ use migration_engine_tests::sql::*;
 use pretty_assertions::assert_eq;
 use prisma_value::PrismaValue;
 use quaint::{ast::*, prelude::Queryable};
-use sql_schema_describer::DefaultValue;
+use sql_schema_describer::{ColumnTypeFamily, DefaultValue};
 
 #[test_each_connector]
 async fn dropping_a_table_with_rows_should_warn(api: &TestApi) -> TestResult {
@@ -102,11 +102,11 @@ async fn altering_a_column_without_non_null_values_should_not_warn(api: &TestApi
 }
 
 #[test_each_connector]
-async fn altering_a_column_with_non_null_values_should_warn(api: &TestApi) -> TestResult {
+async fn casting_a_column_with_values_that_cannot_be_cast_should_warn(api: &TestApi) -> TestResult {
     let dm = r#"
         model Test {
             id String @id @default(cuid())
-            age Int?
+            age String?
         }
     "#;
 
@@ -114,21 +114,21 @@ async fn altering_a_column_with_non_null_values_should_warn(api: &TestApi) -> Te
     let original_database_schema = api.describe_database().await?;
 
     let insert = Insert::multi_into(api.render_table_name("Test"), vec!["id", "age"])
-        .values(("a", 12))
-        .values(("b", 22));
+        .values(("a", "12"))
+        .values(("b", "twenty-two"));
 
     api.database().query(insert.into()).await.unwrap();
 
     let dm2 = r#"
         model Test {
             id String @id @default(cuid())
-            age Float?
+            age Int?
         }
     "#;
 
     api.infer_apply(&dm2).send().await?.assert_warnings(&[
-        "You are about to alter the column `age` on the `Test` table, which still contains 2 non-null values. \
-         The data in that column could be lost."
+        "You are about to change the type of the column `age` on the `Test` table from String to Int. \
+         1 of the existing values cannot be cast to the new type, the migration will fail or lose them."
             .into(),
     ])?;
 
@@ -142,6 +142,146 @@ async fn altering_a_column_with_non_null_values_should_warn(api: &TestApi) -> Te
     Ok(())
 }
 
+#[test_each_connector]
+async fn casting_a_column_preserves_the_data(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Test {
+            id String @id @default(cuid())
+            age Int?
+        }
+    "#;
+
+    api.infer_apply(&dm).send().await?.assert_green()?;
+
+    let insert = Insert::multi_into(api.render_table_name("Test"), vec!["id", "age"])
+        .values(("a", 12))
+        .values(("b", 22));
+
+    api.database().query(insert.into()).await.unwrap();
+
+    let dm2 = r#"
+        model Test {
+            id String @id @default(cuid())
+            age String?
+        }
+    "#;
+
+    api.infer_apply(&dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Test", |table| {
+        table.assert_column("age", |column| column.assert_type_family(ColumnTypeFamily::String))
+    })?;
+
+    let rows = api.select("Test").column("age").send_debug().await?;
+    assert_eq!(rows, &[[r#"Text(Some("12"))"#], [r#"Text(Some("22"))"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn casting_a_string_column_to_an_enum_on_postgres_preserves_the_data(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id String @id
+            mood String
+        }
+    "#;
+
+    api.infer_apply(&dm).send().await?.assert_green()?;
+
+    let insert = Insert::multi_into(api.render_table_name("Cat"), vec!["id", "mood"])
+        .values(("felix", "HAPPY"))
+        .values(("garfield", "HUNGRY"));
+
+    api.database().query(insert.into()).await.unwrap();
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+
+    api.infer_apply(&dm2).send().await?.assert_green()?;
+
+    let rows = api.select("Cat").column("mood").send_debug().await?;
+    assert_eq!(rows, &[[r#"Enum(Some("HAPPY"))"#], [r#"Enum(Some("HUNGRY"))"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn casting_a_string_column_to_int_on_mysql_converts_the_values(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id  String  @id
+            age String?
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "a")
+        .value("age", "12")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id  String @id
+            age Int?
+        }
+    "#;
+
+    let result = api.infer_apply(dm2).send().await?.assert_green()?.into_inner();
+    assert!(result
+        .database_steps
+        .iter()
+        .any(|step| step.raw.contains("CONVERT(`age`, SIGNED)")));
+
+    let rows = api.select("Test").column("age").send_debug().await?;
+    assert_eq!(rows, &[[r#"Integer(Some(12))"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn casting_a_string_column_to_datetime_on_sqlite_warns_about_data_loss(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id        String  @id
+            createdAt String?
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "a")
+        .value("createdAt", "2020-06-15T14:50:00Z")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id        String    @id
+            createdAt DateTime?
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_warnings(&[
+        "You are about to alter the column `createdAt` on the `Test` table, which still contains 1 non-null values. The data in that column could be lost.".into(),
+    ])?;
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn column_defaults_can_safely_be_changed(api: &TestApi) -> TestResult {
     let combinations = &[
@@ -628,12 +768,12 @@ async fn making_a_column_required_in_an_empty_table_should_not_warn(api: &TestAp
 }
 
 #[test_each_connector]
-async fn altering_the_type_of_a_column_in_a_non_empty_table_always_warns(api: &TestApi) -> TestResult {
+async fn altering_the_type_of_a_column_whose_values_can_all_be_cast_does_not_warn(api: &TestApi) -> TestResult {
     let dm1 = r#"
         model User {
             id String @id @default(cuid())
             name String
-            dogs Int
+            dogs String
         }
     "#;
 
@@ -642,7 +782,7 @@ async fn altering_the_type_of_a_column_in_a_non_empty_table_always_warns(api: &T
     let insert = quaint::ast::Insert::single_into(api.render_table_name("User"))
         .value("id", "abc")
         .value("name", "Shinzo")
-        .value("dogs", 7);
+        .value("dogs", "7");
 
     api.database().query(insert.into()).await?;
 
@@ -650,15 +790,12 @@ async fn altering_the_type_of_a_column_in_a_non_empty_table_always_warns(api: &T
         model User {
             id String @id @default(cuid())
             name String
-            dogs String
+            dogs Int
         }
     "#;
 
-    api.infer_apply(dm2).send().await?.assert_warnings(&[
-        // TODO: the message should say that altering the type of a column is not guaranteed to preserve the data, but the database is going to do its best.
-        // Also think about timeouts.
-        "You are about to alter the column `dogs` on the `User` table, which still contains 1 non-null values. The data in that column could be lost.".into()
-    ])?;
+    // String to Int can fail, but the existing values are all integers.
+    api.infer_apply(dm2).send().await?.assert_green()?;
 
     let rows = api.select("User").column("dogs").send_debug().await?;
     assert_eq!(rows, &[["Integer(Some(7))"]]);
@@ -671,7 +808,7 @@ async fn altering_the_type_of_a_column_in_a_non_empty_table_always_warns(api: &T
 }
 
 #[test_each_connector(ignore("mysql"))]
-async fn migrating_a_required_column_from_int_to_string_should_warn_and_cast(api: &TestApi) -> TestResult {
+async fn migrating_a_required_column_from_int_to_string_should_cast_without_warning(api: &TestApi) -> TestResult {
     let dm1 = r#"
         model Test {
             id String @id
@@ -694,8 +831,6 @@ async fn migrating_a_required_column_from_int_to_string_should_warn_and_cast(api
         r#"Some(Text(Some("abcd"))) Some(Integer(Some(47)))"#
     );
 
-    let original_schema = api.assert_schema().await?.into_schema();
-
     let dm2 = r#"
         model Test {
             id String @id
@@ -703,36 +838,19 @@ async fn migrating_a_required_column_from_int_to_string_should_warn_and_cast(api
         }
     "#;
 
-    let expected_warning = "You are about to alter the column `serialNumber` on the `Test` table, which still contains 1 non-null values. The data in that column could be lost.";
-
-    // Apply once without forcing
-    {
-        api.infer_apply(dm2)
-            .send()
-            .await?
-            .assert_warnings(&[expected_warning.into()])?;
-        api.assert_schema().await?.assert_equals(&original_schema)?;
-    }
+    // Every integer can be cast to a string.
+    api.infer_apply(dm2).send().await?.assert_green()?;
 
-    // Force apply
-    {
-        api.infer_apply(dm2)
-            .force(Some(true))
-            .send()
-            .await?
-            .assert_warnings(&[expected_warning.into()])?;
-
-        api.assert_schema().await?.assert_table("Test", |table| {
-            table.assert_column("serialNumber", |col| col.assert_type_is_string())
-        })?;
+    api.assert_schema().await?.assert_table("Test", |table| {
+        table.assert_column("serialNumber", |col| col.assert_type_is_string())
+    })?;
 
-        let test = api.dump_table("Test").await?;
-        let first_row = test.get(0).unwrap();
-        assert_eq!(
-            format!("{:?} {:?}", first_row.get("id"), first_row.get("serialNumber")),
-            r#"Some(Text(Some("abcd"))) Some(Text(Some("47")))"#
-        );
-    }
+    let test = api.dump_table("Test").await?;
+    let first_row = test.get(0).unwrap();
+    assert_eq!(
+        format!("{:?} {:?}", first_row.get("id"), first_row.get("serialNumber")),
+        r#"Some(Text(Some("abcd"))) Some(Text(Some("47")))"#
+    );
 
     Ok(())
 }
@@ -1181,8 +1299,6 @@ async fn primary_key_migrations_do_not_cause_data_loss(api: &TestApi) -> TestRes
         .assert_no_error()?
         .assert_warnings(&[
             "The migration will change the primary key for the `Dog` table. If it partially fails, the table could be left without primary key constraint.".into(),
-            "You are about to alter the column `passportNumber` on the `Dog` table, which still contains 1 non-null values. The data in that column could be lost.".into(),
-            "You are about to alter the column `motherPassportNumber` on the `Puppy` table, which still contains 1 non-null values. The data in that column could be lost.".into(),
         ])?;
 
     api.assert_schema().await?.assert_table("Dog", |table| {
@@ -0,0 +1,187 @@
+use crate::*;
+
+const CATS_DM: &str = r#"
+    model Cat {
+        id      Int @id
+        name    String
+    }
+"#;
+
+#[test_each_connector]
+async fn lint_migrations_only_lints_unapplied_migrations(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS_DM, &migrations_directory)
+        .send()
+        .await?;
+
+    api.lint_migrations(&migrations_directory, CATS_DM)
+        .send()
+        .await?
+        .assert_linted_migrations(&["initial"])?
+        .assert_no_warnings()?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    api.lint_migrations(&migrations_directory, CATS_DM)
+        .send()
+        .await?
+        .assert_linted_migrations(&[])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn lint_migrations_reports_risky_statements_with_their_lines(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS_DM, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let script = indoc::indoc! {r#"
+        -- Hand-written migration
+        ALTER TABLE "Cat" ADD COLUMN "age" INTEGER NOT NULL;
+
+        CREATE INDEX "Cat_name_idx" ON "Cat"("name");
+
+        CREATE INDEX CONCURRENTLY "Cat_id_name_idx" ON "Cat"("id", "name");
+
+        ALTER TABLE "Cat"
+            DROP COLUMN "name";
+    "#};
+
+    write_migration(&migrations_directory, "20991231000000_hand-written", script)?;
+
+    let output = api
+        .lint_migrations(&migrations_directory, CATS_DM)
+        .send()
+        .await?
+        .assert_linted_migrations(&["hand-written"])?
+        .assert_warnings(
+            "hand-written",
+            &[
+                ("The index `Cat_name_idx` is created without `CONCURRENTLY` on the `Cat` table (1 rows). Writes to the table will be blocked until the index is built.", 4),
+                ("You are about to drop the column `name` on the `Cat` table, which still contains 1 non-null values.", 8),
+                ("The migration drops the column `name` on the `Cat` table, but the Prisma schema still uses it. Queries on that field will fail after the migration.", 8),
+            ],
+        )?
+        .into_output();
+
+    let unexecutable = &output.migrations[0].unexecutable_migrations;
+
+    assert_eq!(unexecutable.len(), 1);
+    assert_eq!(unexecutable[0].step_index, 0);
+    assert_eq!(unexecutable[0].span.map(|span| span.start_line), Some(2));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn lint_migrations_ignores_tables_created_in_the_same_script(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let script = indoc::indoc! {r#"
+        CREATE TABLE "Cat" (
+            "id" INTEGER NOT NULL PRIMARY KEY
+        );
+
+        ALTER TABLE "Cat" ADD COLUMN "name" TEXT NOT NULL;
+    "#};
+
+    write_migration(&migrations_directory, "20201231000000_initial", script)?;
+
+    api.lint_migrations(&migrations_directory, CATS_DM)
+        .send()
+        .await?
+        .assert_linted_migrations(&["initial"])?
+        .assert_no_warnings()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn lint_migrations_handles_scripts_ending_in_a_comment(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let script = indoc::indoc! {r#"
+        /* Create the cats */
+        CREATE TABLE "Cat" (
+            "id" INTEGER NOT NULL PRIMARY KEY,
+            "name" TEXT NOT NULL
+        );
+
+        -- TODO: add the dogs"#};
+
+    write_migration(&migrations_directory, "20201231000000_initial", script)?;
+
+    api.lint_migrations(&migrations_directory, CATS_DM)
+        .send()
+        .await?
+        .assert_linted_migrations(&["initial"])?
+        .assert_no_warnings()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn lint_migrations_does_not_report_column_renamings_as_table_rewrites(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS_DM, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let dm = r#"
+        model Cat {
+            id       Int @id
+            fullName String
+        }
+    "#;
+
+    let script = "ALTER TABLE `Cat` CHANGE `name` `fullName` VARCHAR(191) NOT NULL;";
+
+    write_migration(&migrations_directory, "20991231000000_rename", script)?;
+
+    api.lint_migrations(&migrations_directory, dm)
+        .send()
+        .await?
+        .assert_linted_migrations(&["rename"])?
+        .assert_no_warnings()?;
+
+    Ok(())
+}
+
+fn write_migration(migrations_directory: &tempfile::TempDir, migration_name: &str, script: &str) -> anyhow::Result<()> {
+    let migration_directory = migrations_directory.path().join(migration_name);
+
+    std::fs::create_dir_all(&migration_directory)?;
+    std::fs::write(migration_directory.join("migration.sql"), script)?;
+
+    Ok(())
+}
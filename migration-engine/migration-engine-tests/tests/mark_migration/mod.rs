@@ -0,0 +1,143 @@
+use crate::*;
+use migration_core::commands::DiagnoseMigrationHistoryOutput;
+
+const DM1: &str = r#"
+    model Cat {
+        id      Int @id
+        name    String
+    }
+"#;
+
+const DM2: &str = r#"
+    model Cat {
+        id          Int @id
+        name        String
+        fluffiness  Float
+    }
+"#;
+
+#[test_each_connector]
+async fn marking_a_failed_migration_as_rolled_back_lets_it_be_applied_again(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+
+    let second_migration_name = api
+        .create_migration("second-migration", DM2, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| contents.push_str("\nSELECT (^.^)_n;\n"))?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    assert!(api.apply_migrations(&migrations_directory).send().await.is_err());
+
+    api.mark_migration_rolled_back(&migrations_directory, &second_migration_name, "ops@example.com")
+        .await?;
+
+    let DiagnoseMigrationHistoryOutput {
+        failed_migration_names, ..
+    } = api
+        .diagnose_migration_history(&migrations_directory)
+        .send()
+        .await?
+        .into_output();
+
+    assert!(failed_migration_names.is_empty());
+
+    let migrations = api.imperative_migration_persistence().list_migrations().await?;
+    let second = migrations.last().unwrap();
+
+    assert!(second.is_rolled_back());
+    assert!(second.logs.contains("Marked as rolled back by `ops@example.com` at"));
+
+    // Fix the script, and apply it again.
+    let script_path = migrations_directory
+        .path()
+        .join(&second_migration_name)
+        .join("migration.sql");
+    let script = std::fs::read_to_string(&script_path)?.replace("\nSELECT (^.^)_n;\n", "");
+    std::fs::write(&script_path, script)?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["second-migration"])?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn marking_a_failed_migration_as_applied_works(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?;
+
+    let second_migration_name = api
+        .create_migration("second-migration", DM2, &migrations_directory)
+        .send()
+        .await?
+        .modify_migration(|contents| contents.push_str("\nSELECT (^.^)_n;\n"))?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    assert!(api.apply_migrations(&migrations_directory).send().await.is_err());
+
+    api.mark_migration_applied(&migrations_directory, &second_migration_name, "ops@example.com")
+        .await?;
+
+    let migrations = api.imperative_migration_persistence().list_migrations().await?;
+    let second = migrations.last().unwrap();
+
+    assert!(!second.is_failed());
+    assert!(second.logs.contains("Marked as applied by `ops@example.com` at"));
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn marking_a_migration_that_did_not_fail_is_rejected(api: &TestApi) -> TestResult {
+    let migrations_directory = api.create_migrations_directory()?;
+
+    let migration_name = api
+        .create_migration("initial", DM1, &migrations_directory)
+        .send()
+        .await?
+        .into_output()
+        .generated_migration_name
+        .unwrap();
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial"])?;
+
+    let err = api
+        .mark_migration_applied(&migrations_directory, &migration_name, "ops@example.com")
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("is not in a failed state"), "{}", err);
+
+    let err = api
+        .mark_migration_rolled_back(&migrations_directory, "20201231000000_missing", "ops@example.com")
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("cannot be found in the migrations directory"), "{}", err);
+
+    Ok(())
+}
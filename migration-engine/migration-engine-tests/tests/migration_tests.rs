@@ -1,5 +1,6 @@
 mod apply_migration;
 mod apply_migrations;
+mod baseline;
 mod calculate_database_steps;
 mod create_migration;
 mod datamodel_calculator;
@@ -11,9 +12,12 @@ mod existing_data;
 mod existing_databases;
 mod infer_migration_steps;
 mod initialization;
+mod lint_migrations;
+mod mark_migration;
 mod migration_persistence;
 mod migrations;
 mod reset;
+mod rollback_migrations;
 mod schema_push;
 mod unapply_migration;
 
@@ -128,6 +132,32 @@ async fn json_fields_can_be_created(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_each_connector]
+async fn bigint_and_bytes_fields_can_be_created(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Test {
+            id       BigInt @id
+            counter  BigInt @default(42)
+            checksum Bytes?
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Test", |table| {
+        table
+            .assert_column("id", |c| c.assert_type_family(ColumnTypeFamily::BigInt))?
+            .assert_column("counter", |c| {
+                c.assert_is_required()?.assert_type_family(ColumnTypeFamily::BigInt)
+            })?
+            .assert_column("checksum", |c| c.assert_type_family(ColumnTypeFamily::Binary))
+    })?;
+
+    api.infer(dm).send_assert().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn adding_an_optional_field_must_work(api: &TestApi) -> TestResult {
     let dm2 = r#"
@@ -615,6 +645,7 @@ async fn changing_a_relation_field_to_a_scalar_field_must_work(api: &TestApi) ->
                 },
                 columns: vec!["b".to_owned()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
                 on_update_action: ForeignKeyAction::NoAction,
@@ -688,6 +719,7 @@ async fn changing_a_scalar_field_to_a_relation_field_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -798,6 +830,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 },
                 columns: vec![b_column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade, // required relations can't set ON DELETE SET NULL
                 on_update_action: ForeignKeyAction::NoAction,
@@ -811,6 +844,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 },
                 columns: vec![c_column.name.clone()],
                 referenced_table: "C".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
                 on_update_action: ForeignKeyAction::NoAction,
@@ -850,6 +884,7 @@ async fn specifying_a_db_name_for_an_inline_relation_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -885,6 +920,7 @@ async fn adding_an_inline_relation_to_a_model_with_an_exotic_id_type(api: &TestA
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -959,6 +995,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             },
             columns: vec!["b_id".to_string()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -989,6 +1026,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             },
             columns: vec!["a_id".to_string()],
             referenced_table: "A".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -1335,6 +1373,10 @@ async fn foreign_keys_of_inline_one_to_one_relations_have_a_unique_constraint(ap
         name: "Box_cat_id_unique".into(),
         columns: vec!["cat_id".into()],
         tpe: IndexType::Unique,
+        column_options: Default::default(),
+        algorithm: None,
+        predicate: None,
+        include: Vec::new(),
     }];
 
     assert_eq!(box_table.indices, expected_indexes);
@@ -0,0 +1,81 @@
+use migration_engine_tests::sql::*;
+use sql_migration_connector::sql_migration::{AlterTable, SqlMigrationStep, TableChange};
+
+#[test_each_connector(tags("postgres", "sqlite"))]
+async fn check_constraints_are_idempotent(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Product {
+            id    Int    @id
+            name  String
+            price Int
+
+            @@check(name: "price_positive", expression: "price > 0")
+            @@check(name: "name_not_empty", expression: "name <> ''")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector_mssql(tags("mssql_2017", "mssql_2019"))]
+async fn check_constraints_are_idempotent_on_mssql(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Product {
+            id    Int    @id
+            price Int
+
+            @@check(name: "price_positive", expression: "price > 0")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn changing_the_expression_of_a_check_constraint_recreates_it(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check(name: "price_positive", expression: "price > 0")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check(name: "price_positive", expression: "price > 10")
+        }
+    "#;
+
+    let result = api.infer_apply(dm2).send().await?.assert_green()?.into_inner();
+    let steps = result.sql_migration();
+
+    let changes = match steps.as_slice() {
+        [SqlMigrationStep::AlterTable(AlterTable { changes, .. })] => changes,
+        other => panic!("Expected a single AlterTable step, got {:?}", other),
+    };
+
+    assert!(matches!(
+        changes.as_slice(),
+        [
+            TableChange::DropCheckConstraint { constraint_name },
+            TableChange::AddCheckConstraint { check_constraint },
+        ] if constraint_name == "price_positive" && check_constraint.expression == "price > 10"
+    ));
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -273,11 +273,13 @@ async fn index_renaming_must_work(api: &TestApi) -> TestResult {
         let expected_steps = &[
             SqlMigrationStep::AlterIndex(AlterIndex {
                 table: "A".into(),
+                schema: None,
                 index_new_name: "customNameA".into(),
                 index_name: "customName".into(),
             }),
             SqlMigrationStep::AlterIndex(AlterIndex {
                 table: "A".into(),
+                schema: None,
                 index_new_name: "customNameNonUniqueA".into(),
                 index_name: "customNameNonUnique".into(),
             }),
@@ -332,6 +334,7 @@ async fn index_renaming_must_work_when_renaming_to_default(api: &TestApi) {
     if !api.is_sqlite() {
         let expected_steps = vec![SqlMigrationStep::AlterIndex(AlterIndex {
             table: "A".into(),
+            schema: None,
             index_new_name: "A.field_secondField_unique".into(),
             index_name: "customName".into(),
         })];
@@ -382,6 +385,7 @@ async fn index_renaming_must_work_when_renaming_to_custom(api: &TestApi) -> Test
     if !api.is_sqlite() {
         let expected_steps = &[SqlMigrationStep::AlterIndex(AlterIndex {
             table: "A".into(),
+            schema: None,
             index_name: "A.field_secondField_unique".into(),
             index_new_name: "somethingCustom".into(),
         })];
@@ -435,17 +439,24 @@ async fn index_updates_with_rename_must_work(api: &TestApi) {
         let expected_steps = vec![
             SqlMigrationStep::DropIndex(DropIndex {
                 table: "A".into(),
+                schema: None,
                 name: "customName".into(),
             }),
             SqlMigrationStep::CreateIndex(CreateIndex {
                 table: "A".into(),
+                schema: None,
                 index: Index {
                     name: "customNameA".into(),
                     columns: vec!["field".into(), "id".into()],
                     tpe: IndexType::Unique,
+                    column_options: Default::default(),
+                    algorithm: None,
+                    predicate: None,
+                    include: Vec::new(),
                 },
                 caused_by_create_table: false,
                 contains_nullable_columns: false,
+                concurrently: false,
             }),
         ];
         let actual_steps = result.sql_migration();
@@ -519,3 +530,111 @@ async fn indexes_with_an_automatically_truncated_name_are_idempotent(api: &TestA
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn index_options_are_idempotent_on_postgres(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id    Int    @id
+            title String
+            score Int
+
+            @@index([title, score(sort: Desc)], where: "score > 0")
+            @@index([score], type: Hash)
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite"))]
+async fn changing_the_predicate_of_an_index_recreates_it(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Post {
+            id    Int    @id
+            score Int
+
+            @@index([score], name: "score_idx", where: "score > 0")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Post {
+            id    Int    @id
+            score Int
+
+            @@index([score], name: "score_idx", where: "score > 10")
+        }
+    "#;
+
+    let result = api.infer_apply(dm2).send().await?.assert_green()?.into_inner();
+    let steps = result.sql_migration();
+
+    assert!(steps
+        .iter()
+        .any(|step| matches!(step, SqlMigrationStep::DropIndex(DropIndex { name, .. }) if name == "score_idx")));
+    assert!(steps.iter().any(
+        |step| matches!(step, SqlMigrationStep::CreateIndex(CreateIndex { index, .. }) if index.name == "score_idx")
+    ));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn index_options_are_idempotent_on_mysql(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id    Int    @id
+            title String
+            score Int
+
+            @@index([title(length: 10), score])
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn index_options_are_idempotent_on_sqlite(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id    Int    @id
+            title String
+            score Int
+
+            @@index([title, score(sort: Desc)], where: "score > 0")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector_mssql(tags("mssql_2017", "mssql_2019"))]
+async fn index_options_are_idempotent_on_mssql(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id    Int    @id
+            title String
+            score Int
+
+            @@index([title, score(sort: Desc)], where: "score > 0", include: [id])
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
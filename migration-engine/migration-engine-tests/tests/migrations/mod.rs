@@ -1,8 +1,12 @@
+mod check_constraints;
 mod enums;
 mod indexes;
 mod json;
 mod mariadb;
+mod mssql;
 mod mysql;
 mod postgres;
+mod relations;
+mod renames;
 mod sql;
 mod sqlite;
@@ -0,0 +1,158 @@
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+use prisma_value::PrismaValue;
+use sql_schema_describer::{ColumnTypeFamily, DefaultValue};
+
+#[test_each_connector_mssql(tags("mssql_2017", "mssql_2019"))]
+async fn changing_defaults_and_types_on_mssql_preserves_the_data(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String @default("Felix")
+            age  Int    @default(3)
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Garfield")
+        .value("age", 5)
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String @default("Tom")
+            age  String @default("4")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_column("name", |column| {
+                column.assert_default(Some(DefaultValue::VALUE(PrismaValue::String("Tom".into()))))
+            })?
+            .assert_column("age", |column| {
+                column
+                    .assert_type_family(ColumnTypeFamily::String)?
+                    .assert_default(Some(DefaultValue::VALUE(PrismaValue::String("4".into()))))
+            })
+    })?;
+
+    let rows = api.select("Cat").column("name").column("age").send_debug().await?;
+    assert_eq!(rows, &[[r#"Text(Some("Garfield"))"#, r#"Text(Some("5"))"#]]);
+
+    let dm3 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+            age  String @default("4")
+        }
+    "#;
+
+    api.infer_apply(dm3).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("name", |column| column.assert_default(None))
+    })?;
+
+    let rows = api.select("Cat").column("name").send_debug().await?;
+    assert_eq!(rows, &[[r#"Text(Some("Garfield"))"#]]);
+
+    // Nothing is left to migrate, the defaults in the database match the schema.
+    let result = api.infer_apply(dm3).send().await?.assert_green()?.into_inner();
+    assert!(result.sql_migration().is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector_mssql(tags("mssql_2017", "mssql_2019"))]
+async fn a_failing_migration_history_does_not_leave_the_connection_on_the_temporary_database(
+    api: &TestApi,
+) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+    let migration_directory = migrations_directory.path().join("20201231000000_broken");
+
+    std::fs::create_dir_all(&migration_directory)?;
+    std::fs::write(
+        migration_directory.join("migration.sql"),
+        "SELECT * FROM [DoesNotExist];",
+    )?;
+
+    api.create_migration("second", dm, &migrations_directory)
+        .send()
+        .await
+        .unwrap_err();
+
+    // The engine's connection must be back on the original database.
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.assert_schema().await?.assert_has_table("Cat")?;
+
+    Ok(())
+}
+
+#[test_each_connector_mssql(tags("mssql_2017", "mssql_2019"))]
+async fn changing_a_default_in_a_non_transactional_migration_works(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String @default("Felix")
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String @default("Tom")
+        }
+    "#;
+
+    let output = api
+        .create_migration("change-default", dm2, &migrations_directory)
+        .non_transactional(true)
+        .send()
+        .await?
+        .into_output();
+
+    let script = std::fs::read_to_string(
+        migrations_directory
+            .path()
+            .join(output.generated_migration_name.unwrap())
+            .join("migration.sql"),
+    )?;
+
+    assert!(script.starts_with("-- transaction: none\n"));
+
+    // The statements are sent one by one: dropping the unnamed default constraint must not be
+    // split in the middle.
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "change-default"])?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("name", |column| {
+            column.assert_default(Some(DefaultValue::VALUE(PrismaValue::String("Tom".into()))))
+        })
+    })?;
+
+    Ok(())
+}
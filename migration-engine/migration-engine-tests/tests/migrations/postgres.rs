@@ -104,14 +104,14 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
     let types = &[
         ("smallint", "Int", "SmallInt", "int2"),
         ("int", "Int", "Integer", "int4"),
-        ("bigint", "Int", "BigInt", "int8"),
+        ("bigint", "BigInt", "BigInt", "int8"),
         ("decimal", "Decimal", "Decimal(4, 2)", "numeric"),
         ("numeric", "Decimal", "Numeric(4, 2)", "numeric"),
         ("real", "Float", "Real", "float4"),
         ("doublePrecision", "Float", "DoublePrecision", "float8"),
         ("smallSerial", "Int", "SmallSerial", "int2"),
         ("serial", "Int", "Serial", "int4"),
-        ("bigSerial", "Int", "BigSerial", "int8"),
+        ("bigSerial", "BigInt", "BigSerial", "int8"),
         ("varChar", "String", "VarChar(200)", "varchar"),
         ("char", "String", "Char(200)", "bpchar"),
         ("text", "String", "Text", "text"),
@@ -0,0 +1,81 @@
+use migration_engine_tests::sql::*;
+use sql_schema_describer::ForeignKeyAction;
+
+#[test_each_connector]
+async fn referential_actions_are_rendered_on_foreign_keys(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id       Int   @id
+            authorId Int?
+            author   User? @relation(fields: [authorId], references: [id], onDelete: Cascade, onUpdate: SetNull)
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_fk_on_columns(&["authorId"], |fk| {
+            fk.assert_references("User", &["id"])?
+                .assert_on_delete(ForeignKeyAction::Cascade)?
+                .assert_on_update(ForeignKeyAction::SetNull)
+        })
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn changing_a_referential_action_migrates_the_foreign_key(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id])
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_fk_on_columns(&["authorId"], |fk| fk.assert_on_delete(ForeignKeyAction::Cascade))
+    })?;
+
+    let dm2 = r#"
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id], onDelete: NoAction)
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_fk_on_columns(&["authorId"], |fk| fk.assert_on_delete(ForeignKeyAction::NoAction))
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
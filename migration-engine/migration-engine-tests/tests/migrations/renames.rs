@@ -0,0 +1,183 @@
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+use prisma_value::PrismaValue;
+use sql_migration_connector::sql_migration::{RenameForeignKey, SqlMigrationStep, TableChange};
+use sql_schema_describer::DefaultValue;
+
+#[test_each_connector]
+async fn columns_with_a_previous_name_are_renamed_and_keep_their_data(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id       Int    @id
+            fullName String @map("full_name", previous: "name")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_has_column("full_name")?
+            .assert_does_not_have_column("name")
+    })?;
+
+    let rows = api.select("Cat").column("full_name").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text(Some("Felix"))"#]]);
+
+    // The hint is ignored once the rename has been applied.
+    api.infer_apply(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn tables_with_a_previous_name_are_renamed_and_keep_their_data(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Kitten {
+            id   Int    @id
+            name String
+
+            @@map("Kitten", previous: "Cat")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_has_no_table("Cat")?
+        .assert_table("Kitten", |table| table.assert_has_column("name"))?;
+
+    let rows = api.select("Kitten").column("name").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text(Some("Felix"))"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn constraints_with_a_new_map_name_are_renamed_instead_of_recreated(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int    @id(map: "cat_pk")
+            ownerId Int
+            owner   Person @relation(fields: [ownerId], references: [id], map: "cat_owner_fk")
+        }
+
+        model Person {
+            id   Int   @id
+            cats Cat[]
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int    @id(map: "cats_primary")
+            ownerId Int
+            owner   Person @relation(fields: [ownerId], references: [id], map: "cats_owner_fkey")
+        }
+
+        model Person {
+            id   Int   @id
+            cats Cat[]
+        }
+    "#;
+
+    let result = api.infer_apply(dm2).send().await?.assert_green()?.into_inner();
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_pk(|pk| pk.assert_constraint_name("cats_primary"))?
+            .assert_foreign_keys_count(1)?
+            .assert_fk_on_columns(&["ownerId"], |fk| fk.assert_constraint_name("cats_owner_fkey"))
+    })?;
+
+    let steps = result.sql_migration();
+
+    assert!(steps.iter().any(|step| matches!(
+        step,
+        SqlMigrationStep::RenameForeignKey(RenameForeignKey { constraint_name, new_constraint_name, .. })
+            if constraint_name == "cat_owner_fk" && new_constraint_name == "cats_owner_fkey"
+    )));
+    assert!(steps.iter().any(|step| matches!(
+        step,
+        SqlMigrationStep::AlterTable(alter_table) if alter_table.changes.iter().any(|change| matches!(
+            change,
+            TableChange::RenamePrimaryKey { .. }
+        ))
+    )));
+    assert!(!steps.iter().any(|step| matches!(
+        step,
+        SqlMigrationStep::DropForeignKey(_) | SqlMigrationStep::AddForeignKey(_)
+    )));
+
+    // The names now match, so there is no drift left to migrate.
+    let result = api.infer_apply(dm2).send().await?.assert_green()?.into_inner();
+    assert!(result.sql_migration().is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn renamed_columns_keep_their_definition_on_mysql(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int     @id
+            name String? @default("Felix")
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id       Int     @id
+            fullName String? @default("Felix") @map("full_name", previous: "name")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("full_name", |column| {
+            column
+                .assert_is_nullable()?
+                .assert_default(Some(DefaultValue::VALUE(PrismaValue::String("Felix".into()))))
+        })
+    })?;
+
+    api.infer_apply(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -0,0 +1,199 @@
+use crate::*;
+use migration_connector::MigrationConnector;
+use quaint::prelude::ConnectionInfo;
+use sql_migration_connector::SqlMigrationConnector;
+use std::time::Duration;
+
+#[test_each_connector]
+async fn rolling_back_the_last_migration_works(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id          Int @id
+            name        String
+            fluffiness  Float
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.create_migration("second-migration", dm2, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "second-migration"])?;
+
+    api.rollback_migrations(&migrations_directory)
+        .count(1)
+        .send()
+        .await?
+        .assert_rolled_back_migrations(&["second-migration"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_has_no_table("Dog")?
+        .assert_table("Cat", |table| table.assert_columns_count(2))?;
+
+    // The rolled back migration counts as unapplied again.
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["second-migration"])?;
+
+    api.rollback_migrations(&migrations_directory)
+        .to_migration(full_migration_name(&migrations_directory, "initial")?)
+        .send()
+        .await?
+        .assert_rolled_back_migrations(&["second-migration"])?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rolling_back_a_migration_that_drops_data_is_refused(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+        }
+    "#;
+
+    api.create_migration("drop-name", dm2, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "drop-name"])?;
+
+    let err = api
+        .rollback_migrations(&migrations_directory)
+        .count(1)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("cannot be reversed safely"), "{}", err);
+    assert!(err.contains("You are about to drop the column `name`"), "{}", err);
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_columns_count(1))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn rolling_back_migrations_waits_for_the_migration_lock(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.create_migration("second-migration", dm2, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "second-migration"])?;
+
+    // Another migration engine, on its own connection, holds the lock.
+    let file_path = match api.connection_info() {
+        ConnectionInfo::Sqlite { file_path, .. } => file_path.clone(),
+        _ => unreachable!(),
+    };
+    let other_engine = SqlMigrationConnector::new(&format!("file:{}", file_path)).await?;
+
+    other_engine.acquire_lock(Duration::from_secs(1)).await?;
+
+    let err = api
+        .rollback_migrations(&migrations_directory)
+        .count(1)
+        .lock_timeout_seconds(1)
+        .send()
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        err.contains("Timed out after 1 seconds trying to acquire the migration lock."),
+        "{}",
+        err
+    );
+
+    other_engine.release_lock().await?;
+
+    api.rollback_migrations(&migrations_directory)
+        .count(1)
+        .lock_timeout_seconds(1)
+        .send()
+        .await?
+        .assert_rolled_back_migrations(&["second-migration"])?;
+
+    Ok(())
+}
+
+/// The full name of a migration directory, with its timestamp prefix.
+fn full_migration_name(migrations_directory: &tempfile::TempDir, name: &str) -> anyhow::Result<String> {
+    for entry in std::fs::read_dir(migrations_directory.path())? {
+        let file_name = entry?.file_name().to_string_lossy().into_owned();
+
+        if file_name.ends_with(name) {
+            return Ok(file_name);
+        }
+    }
+
+    anyhow::bail!("No migration named `{}` in the migrations directory.", name)
+}
@@ -1,3 +1,5 @@
+use datamodel::SourceFile;
+use migration_core::commands::PrismaSchema;
 use migration_engine_tests::sql::*;
 use sql_schema_describer::ColumnTypeFamily;
 
@@ -174,3 +176,47 @@ async fn multi_column_indexes_and_unique_constraints_on_the_same_fields_do_not_c
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn schema_push_accepts_a_schema_split_across_files(api: &TestApi) -> TestResult {
+    let files = vec![
+        SourceFile::new(
+            "prisma/schema.prisma",
+            r#"
+            import "./boxes/box.prisma"
+
+            model Cat {
+                id Int @id
+                boxId Int?
+                box Box? @relation(fields: [boxId], references: [id])
+            }
+            "#,
+        ),
+        SourceFile::new(
+            "prisma/boxes/box.prisma",
+            r#"
+            model Box {
+                id Int @id
+                material String
+            }
+            "#,
+        ),
+    ];
+
+    api.schema_push(PrismaSchema::Files(files))
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| {
+            table.assert_column("boxId", |col| col.assert_type_family(ColumnTypeFamily::Int))
+        })?
+        .assert_table("Box", |table| {
+            table.assert_column("material", |col| col.assert_type_family(ColumnTypeFamily::String))
+        })?;
+
+    Ok(())
+}
@@ -7,6 +7,16 @@ use std::{
 use crate::FormatOpts;
 
 pub fn run(opts: FormatOpts) {
+    if let Some(path) = &opts.schema {
+        let files = crate::load_schema_files(path);
+
+        for file in Reformatter::reformat_files(&files, opts.tabwidth) {
+            fs::write(&file.path, &file.content).expect(&format!("Unable to write file {}", file.path));
+        }
+
+        return;
+    }
+
     let datamodel_string = match opts.input {
         Some(file_name) => {
             fs::read_to_string(&file_name).expect(&format!("Unable to open file {}", file_name.display()))
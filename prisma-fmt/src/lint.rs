@@ -1,19 +1,26 @@
 use crate::{LintOpts, MiniError};
-use datamodel::error::DatamodelError;
+use datamodel::{error::DatamodelError, SourceFile};
 use serde_json;
 use std::io::{self, Read};
 
 pub fn run(opts: LintOpts) {
-    let mut datamodel_string = String::new();
+    let files = match &opts.schema {
+        Some(path) => crate::load_schema_files(path),
+        None => {
+            let mut datamodel_string = String::new();
 
-    io::stdin()
-        .read_to_string(&mut datamodel_string)
-        .expect("Unable to read from stdin.");
+            io::stdin()
+                .read_to_string(&mut datamodel_string)
+                .expect("Unable to read from stdin.");
+
+            vec![SourceFile::new("schema.prisma", datamodel_string)]
+        }
+    };
 
     let datamodel_result = if opts.no_env_errors {
-        datamodel::parse_datamodel_and_ignore_datasource_urls(&datamodel_string)
+        datamodel::parse_datamodel_from_files_and_ignore_datasource_urls(&files)
     } else {
-        datamodel::parse_datamodel(&datamodel_string)
+        datamodel::parse_datamodel_from_files(&files)
     };
 
     match datamodel_result {
@@ -25,6 +32,11 @@ pub fn run(opts: LintOpts) {
                     start: err.span().start,
                     end: err.span().end,
                     text: format!("{}", err),
+                    file: opts
+                        .schema
+                        .as_ref()
+                        .and_then(|_| files.get(err.span().file_id.0))
+                        .map(|file| file.path.clone()),
                 })
                 .collect();
 
@@ -3,7 +3,8 @@ mod lint;
 mod native;
 mod preview;
 
-use std::path::PathBuf;
+use datamodel::SourceFile;
+use std::path::{Path, PathBuf};
 
 use serde;
 use structopt::StructOpt;
@@ -13,6 +14,10 @@ pub struct LintOpts {
     /// If set, silences all `environment variable not found` errors
     #[structopt(long)]
     no_env_errors: bool,
+    /// Lints the schema in this file and the files it imports. If none is
+    /// given, the schema is read from STDIN
+    #[structopt(long)]
+    schema: Option<PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -28,6 +33,10 @@ pub struct FormatOpts {
     /// Specifies which tab width to use when formatting
     #[structopt(short = "s", long, default_value = "2")]
     tabwidth: usize,
+    /// Formats the schema in this file and the files it imports, writing
+    /// each file back to its own path. Replaces input and output
+    #[structopt(long, conflicts_with_all = &["input", "output"])]
+    schema: Option<PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -56,6 +65,13 @@ pub struct MiniError {
     pub start: usize,
     pub end: usize,
     pub text: String,
+    /// The path of the file the error is in, for schemas loaded with `--schema`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+}
+
+fn load_schema_files(path: &Path) -> Vec<SourceFile> {
+    datamodel::load_schema_files(path).expect(&format!("Unable to open file {}", path.display()))
 }
 
 fn main() {
@@ -0,0 +1,174 @@
+package queries.aggregation
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class GroupByQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """model Item {
+      |  id    String @id @default(cuid())
+      |  float Float
+      |  int   Int
+      |  str   String
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  def createItem(float: Double, int: Int, str: String, id: Option[String] = None) = {
+    val idString = id match {
+      case Some(i) => s"""id: "$i","""
+      case None    => ""
+    }
+
+    server.query(
+      s"""mutation {
+         |  createItem(data: { $idString float: $float, int: $int, str: "$str" }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+
+  "Grouping with no records in the database" should "return no groups" in {
+    val result = server.query(
+      s"""{
+         |  groupByItem(by: [str]) {
+         |    str
+         |    count
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[]}}""")
+  }
+
+  "Grouping with some records in the database" should "return the correct aggregations per group" in {
+    createItem(5.5, 5, "group1", Some("1"))
+    createItem(4.5, 10, "group1", Some("2"))
+    createItem(1.5, 2, "group2", Some("3"))
+    createItem(0.0, 1, "group3", Some("4"))
+
+    val result = server.query(
+      s"""{
+         |  groupByItem(by: [str], orderBy: { str: asc }) {
+         |    str
+         |    count
+         |    sum {
+         |      int
+         |    }
+         |    max {
+         |      int
+         |    }
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"groupByItem":[{"str":"group1","count":2,"sum":{"int":15},"max":{"int":10}},{"str":"group2","count":1,"sum":{"int":2},"max":{"int":2}},{"str":"group3","count":1,"sum":{"int":1},"max":{"int":1}}]}}""")
+  }
+
+  "Grouping by multiple fields" should "return a group per distinct combination" in {
+    createItem(5.5, 5, "group1", Some("1"))
+    createItem(4.5, 5, "group1", Some("2"))
+    createItem(1.5, 2, "group1", Some("3"))
+    createItem(0.0, 2, "group2", Some("4"))
+
+    val result = server.query(
+      s"""{
+         |  groupByItem(by: [str, int], orderBy: [{ str: asc }, { int: desc }]) {
+         |    str
+         |    int
+         |    count
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"groupByItem":[{"str":"group1","int":5,"count":2},{"str":"group1","int":2,"count":1},{"str":"group2","int":2,"count":1}]}}""")
+  }
+
+  "Grouping with a having filter" should "only return groups matching the aggregation filter" in {
+    createItem(5.5, 5, "group1", Some("1"))
+    createItem(4.5, 10, "group1", Some("2"))
+    createItem(1.5, 2, "group2", Some("3"))
+    createItem(0.0, 1, "group3", Some("4"))
+
+    var result = server.query(
+      s"""{
+         |  groupByItem(by: [str], orderBy: { str: asc }, having: { int: { avg: { gt: 1.5 } } }) {
+         |    str
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"str":"group1"},{"str":"group2"}]}}""")
+
+    result = server.query(
+      s"""{
+         |  groupByItem(by: [str], orderBy: { str: asc }, having: { id: { count: { gte: 2 } } }) {
+         |    str
+         |    count
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"str":"group1","count":2}]}}""")
+  }
+
+  "Grouping with where, skip and take" should "apply the arguments to the records and groups" in {
+    createItem(5.5, 5, "group1", Some("1"))
+    createItem(4.5, 10, "group1", Some("2"))
+    createItem(1.5, 2, "group2", Some("3"))
+    createItem(0.0, 1, "group3", Some("4"))
+
+    val result = server.query(
+      s"""{
+         |  groupByItem(by: [str], where: { int: { gt: 1 } }, orderBy: { str: desc }, skip: 1, take: 1) {
+         |    str
+         |    count
+         |  }
+         |}""".stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"groupByItem":[{"str":"group1","count":2}]}}""")
+  }
+
+  "Selecting a field that is not grouped by" should "fail" in {
+    server.queryThatMustFail(
+      s"""{
+         |  groupByItem(by: [str]) {
+         |    str
+         |    int
+         |  }
+         |}""".stripMargin,
+      project,
+      errorCode = 2019,
+      errorContains = "Every selected scalar field that is not part of an aggregation must be included in the by-arguments of the query. Missing fields: int"
+    )
+  }
+
+  "Ordering by a field that is not grouped by" should "fail" in {
+    server.queryThatMustFail(
+      s"""{
+         |  groupByItem(by: [str], orderBy: { int: asc }) {
+         |    str
+         |  }
+         |}""".stripMargin,
+      project,
+      errorCode = 2019,
+      errorContains = "Every field used for orderBy must be included in the by-arguments of the query. Missing fields: int"
+    )
+  }
+}
@@ -0,0 +1,147 @@
+package queries.aggregation
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class RelationCountQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id    Int     @id
+      |  posts Post[]
+      |}
+      |
+      |model Post {
+      |  id         Int        @id
+      |  published  Boolean
+      |  authorId   Int?
+      |  author     User?      @relation(fields: [authorId], references: [id])
+      |  categories Category[]
+      |}
+      |
+      |model Category {
+      |  id    Int    @id
+      |  posts Post[]
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+    createTestData()
+  }
+
+  "Selecting _count" should "count the related records of one-to-many relations" in {
+    val result = server.query(
+      """{
+        |  findManyUser(orderBy: { id: asc }) {
+        |    id
+        |    _count { posts }
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString should be(
+      """{"data":{"findManyUser":[{"id":1,"_count":{"posts":2}},{"id":2,"_count":{"posts":1}},{"id":3,"_count":{"posts":0}}]}}""")
+  }
+
+  "Selecting _count" should "count the related records of many-to-many relations" in {
+    val result = server.query(
+      """{
+        |  findManyPost(orderBy: { id: asc }) {
+        |    id
+        |    _count { categories }
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString should be(
+      """{"data":{"findManyPost":[{"id":1,"_count":{"categories":2}},{"id":2,"_count":{"categories":0}},{"id":3,"_count":{"categories":1}}]}}""")
+  }
+
+  "Selecting _count" should "only count the related records matching the filter" in {
+    val result = server.query(
+      """{
+        |  findManyUser(orderBy: { id: asc }) {
+        |    id
+        |    _count { posts(where: { published: true }) }
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString should be(
+      """{"data":{"findManyUser":[{"id":1,"_count":{"posts":1}},{"id":2,"_count":{"posts":0}},{"id":3,"_count":{"posts":0}}]}}""")
+
+    val m2mResult = server.query(
+      """{
+        |  findManyCategory(orderBy: { id: asc }) {
+        |    id
+        |    _count { posts(where: { published: true }) }
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    m2mResult.toString should be("""{"data":{"findManyCategory":[{"id":1,"_count":{"posts":1}},{"id":2,"_count":{"posts":1}}]}}""")
+  }
+
+  "Selecting _count" should "work on nested and single records" in {
+    val result = server.query(
+      """{
+        |  findOnePost(where: { id: 1 }) {
+        |    author {
+        |      _count { posts }
+        |    }
+        |    _count { categories }
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString should be("""{"data":{"findOnePost":{"author":{"_count":{"posts":2}},"_count":{"categories":2}}}}""")
+  }
+
+  // User 1 has posts 1 (published) and 2, user 2 has post 3, user 3 has no posts.
+  // Post 1 is in categories 1 and 2, post 3 in category 2.
+  def createTestData(): Unit = {
+    server.query(
+      """mutation { createOneCategory(data: { id: 1 }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.query(
+      """mutation { createOneCategory(data: { id: 2 }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.query(
+      """mutation { createOneUser(data: { id: 1, posts: { create: [{ id: 1, published: true, categories: { connect: [{ id: 1 }, { id: 2 }] } }, { id: 2, published: false }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.query(
+      """mutation { createOneUser(data: { id: 2, posts: { create: [{ id: 3, published: false, categories: { connect: [{ id: 2 }] } }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.query("""mutation { createOneUser(data: { id: 3 }) { id } }""", project, legacy = false)
+  }
+}
@@ -174,4 +174,32 @@ class SumAggregationQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
     result.pathAsDouble("data.aggregateItem.sum.float") should be(1.5)
     result.pathAsInt("data.aggregateItem.sum.int") should be(3)
   }
+
+  "Summing BigInt fields" should "return a BigInt" in {
+    val bigIntProject = SchemaDsl.fromStringV11() {
+      """model Counter {
+        |  id    Int    @id
+        |  value BigInt
+        |}
+      """.stripMargin
+    }
+    database.setup(bigIntProject)
+
+    server.query("""mutation { createCounter(data: { id: 1, value: "5000000000" }) { id } }""", bigIntProject)
+    server.query("""mutation { createCounter(data: { id: 2, value: "10" }) { id } }""", bigIntProject)
+
+    val result = server.query(
+      """{
+        |  aggregateCounter {
+        |    sum {
+        |      value
+        |    }
+        |  }
+        |}
+      """.stripMargin,
+      bigIntProject
+    )
+
+    result.pathAsString("data.aggregateCounter.sum.value") should be("5000000010")
+  }
 }
@@ -0,0 +1,101 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.{MySqlConnectorTag, PostgresConnectorTag, SQLiteConnectorTag}
+import util._
+
+class JsonPathFilterSpec extends FlatSpec with Matchers with ApiSpecBase with ConnectorAwareTest {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(PostgresConnectorTag, MySqlConnectorTag, SQLiteConnectorTag)
+
+  val project = SchemaDsl.fromStringV11() {
+    s"""model Model {
+       |   id   Int   @id
+       |   json Json?
+       |}"""
+  }
+
+  override def beforeEach(): Unit = {
+    database.setup(project)
+
+    super.beforeEach()
+  }
+
+  "A path filter" should "compare the nested value" in {
+    create(1, """{\"a\":{\"b\":\"c\"}}""")
+    create(2, """{\"a\":{\"b\":\"d\"}}""")
+    create(3, """{\"a\":\"b\"}""")
+
+    server
+      .query(s"""query { findManyModel(where: { json: { path: ${path("a", "b")}, equals: "\\"c\\"" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+  }
+
+  "A path filter" should "compare nested numbers with gt and lt" in {
+    create(1, """{\"a\":{\"b\":1}}""")
+    create(2, """{\"a\":{\"b\":5}}""")
+    create(3, """{\"a\":{\"b\":10}}""")
+
+    server
+      .query(
+        s"""query { findManyModel(where: { json: { path: ${path("a", "b")}, gt: "1", lt: "10" }}, orderBy: { id: asc }) { id }}""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyModel":[{"id":2}]}}""")
+  }
+
+  "The string filters" should "match nested string values" in {
+    create(1, """{\"a\":\"foobar\"}""")
+    create(2, """{\"a\":\"barfoo\"}""")
+    create(3, """{\"a\":[\"foo\"]}""")
+
+    server
+      .query(s"""query { findManyModel(where: { json: { path: ${path("a")}, string_contains: "foo" }}, orderBy: { id: asc }) { id }}""",
+             project,
+             legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1},{"id":2}]}}""")
+
+    server
+      .query(s"""query { findManyModel(where: { json: { path: ${path("a")}, string_starts_with: "foo" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+  }
+
+  "The array filters" should "match nested array values" in {
+    create(1, """{\"a\":[1,2,3]}""")
+    create(2, """{\"a\":[2,3]}""")
+    create(3, """{\"a\":\"1\"}""")
+
+    // SQLite has no array_contains filter.
+    ifConnectorIsNotSQLite {
+      server
+        .query(s"""query { findManyModel(where: { json: { path: ${path("a")}, array_contains: "[3]" }}, orderBy: { id: asc }) { id }}""",
+               project,
+               legacy = false)
+        .toString should be("""{"data":{"findManyModel":[{"id":1},{"id":2}]}}""")
+    }
+
+    server
+      .query(s"""query { findManyModel(where: { json: { path: ${path("a")}, array_starts_with: "1" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+  }
+
+  "The string filters" should "require a path" in {
+    server.queryThatMustFail(
+      """query { findManyModel(where: { json: { string_contains: "foo" }}) { id }}""",
+      project,
+      errorCode = 2019,
+      errorContains = "The JSON filter operation string_contains requires a path to a string value.",
+      legacy = false
+    )
+  }
+
+  // Postgres addresses nested values with a list of keys, MySQL and SQLite with a JSON path string.
+  def path(keys: String*): String = connectorTag match {
+    case PostgresConnectorTag => keys.map(key => s""""$key"""").mkString("[", ", ", "]")
+    case _                    => s""""$$.${keys.mkString(".")}""""
+  }
+
+  def create(id: Int, json: String): Unit = {
+    server.query(s"""mutation { createOneModel(data: { id: $id, json: "$json" }) { id }}""", project, legacy = false)
+  }
+}
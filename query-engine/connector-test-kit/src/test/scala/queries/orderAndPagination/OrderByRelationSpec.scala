@@ -0,0 +1,129 @@
+package queries.orderAndPagination
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class OrderByRelationSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id    Int     @id
+      |  name  String
+      |  posts Post[]
+      |}
+      |
+      |model Post {
+      |  id       Int       @id
+      |  title    String
+      |  authorId Int?
+      |  author   User?     @relation(fields: [authorId], references: [id])
+      |  comments Comment[]
+      |}
+      |
+      |model Comment {
+      |  id     Int  @id
+      |  postId Int
+      |  post   Post @relation(fields: [postId], references: [id])
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+    createTestData()
+  }
+
+  "Ordering by a field of a to-one relation" should "order by the related records" in {
+    val result = server.query(
+      """{
+        |  findManyPost(orderBy: [{ author: { name: asc } }, { id: asc }]) {
+        |    id
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    // Post 4 has no author, nulls are ordered depending on the database.
+    result.pathAsJsValue("data.findManyPost").toString should (be("""[{"id":4},{"id":3},{"id":1},{"id":2}]""") or be(
+      """[{"id":3},{"id":1},{"id":2},{"id":4}]"""))
+  }
+
+  "Ordering by a field of a nested to-one relation" should "order by the related records" in {
+    val result = server.query(
+      """{
+        |  findManyComment(orderBy: [{ post: { author: { name: desc } } }, { id: asc }]) {
+        |    id
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString should be("""{"data":{"findManyComment":[{"id":1},{"id":2},{"id":3}]}}""")
+  }
+
+  "Ordering by the count of a to-many relation" should "order by the number of related records" in {
+    val result = server.query(
+      """{
+        |  findManyUser(orderBy: { posts: { count: desc } }) {
+        |    id
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString should be("""{"data":{"findManyUser":[{"id":1},{"id":2}]}}""")
+  }
+
+  "Cursor pagination" should "work with orderings on relations" in {
+    val result = server.query(
+      """{
+        |  findManyPost(where: { authorId: { not: null } }, orderBy: [{ author: { name: desc } }, { id: asc }], cursor: { id: 1 }, take: 2) {
+        |    id
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString should be("""{"data":{"findManyPost":[{"id":1},{"id":2}]}}""")
+
+    val countResult = server.query(
+      """{
+        |  findManyUser(orderBy: { posts: { count: asc } }, cursor: { id: 2 }, take: 2) {
+        |    id
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    countResult.toString should be("""{"data":{"findManyUser":[{"id":2},{"id":1}]}}""")
+  }
+
+  // User 1 "Bob" has posts 1 and 2, user 2 "Alice" has post 3, post 4 has no author.
+  // Comment 1 and 2 are on post 1, comment 3 on post 3.
+  def createTestData(): Unit = {
+    server.query(
+      """mutation { createOneUser(data: { id: 1, name: "Bob", posts: { create: [{ id: 1, title: "a", comments: { create: [{ id: 1 }, { id: 2 }] } }, { id: 2, title: "b" }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.query(
+      """mutation { createOneUser(data: { id: 2, name: "Alice", posts: { create: [{ id: 3, title: "c", comments: { create: [{ id: 3 }] } }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.query("""mutation { createOnePost(data: { id: 4, title: "d" }) { id } }""", project, legacy = false)
+  }
+}
@@ -0,0 +1,127 @@
+package writes.topLevelMutations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class CreateManySpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """model Test {
+      |  id  Int     @id
+      |  str String
+      |  opt String?
+      |  def String  @default("default")
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  "createMany" should "insert all given records and return the count" in {
+    val result = server.query(
+      """mutation {
+        |  createManyTest(data: [
+        |    { id: 1, str: "1" },
+        |    { id: 2, str: "2", opt: "opt" },
+        |    { id: 3, str: "3", def: "custom" }
+        |  ]) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsLong("data.createManyTest.count") should equal(3)
+
+    val records = server.query(
+      """{
+        |  findManyTest(orderBy: { id: asc }) {
+        |    id
+        |    str
+        |    opt
+        |    def
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    records.toString should be(
+      """{"data":{"findManyTest":[{"id":1,"str":"1","opt":null,"def":"default"},{"id":2,"str":"2","opt":"opt","def":"default"},{"id":3,"str":"3","opt":null,"def":"custom"}]}}""")
+  }
+
+  "createMany" should "fail on duplicates by default" in {
+    server.query("""mutation { createManyTest(data: [{ id: 1, str: "1" }]) { count } }""", project)
+
+    server.queryThatMustFail(
+      """mutation {
+        |  createManyTest(data: [{ id: 1, str: "1" }, { id: 2, str: "2" }]) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      errorCode = 2002
+    )
+  }
+
+  "createMany" should "skip duplicates if skipDuplicates is set" in {
+    server.query("""mutation { createManyTest(data: [{ id: 1, str: "1" }]) { count } }""", project)
+
+    val result = server.query(
+      """mutation {
+        |  createManyTest(data: [{ id: 1, str: "1" }, { id: 2, str: "2" }], skipDuplicates: true) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsLong("data.createManyTest.count") should equal(1)
+  }
+
+  "createMany" should "insert more records than fit into a single statement" in {
+    val records = (1 to 2000).map(i => s"""{ id: $i, str: "$i" }""").mkString(", ")
+
+    val result = server.query(
+      s"""mutation {
+         |  createManyTest(data: [$records]) {
+         |    count
+         |  }
+         |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsLong("data.createManyTest.count") should equal(2000)
+  }
+
+  "createMany" should "insert more single-column records than fit into a single statement" in {
+    // SQL Server caps the rows of an `INSERT ... VALUES` at 1000, independently of the bind parameters.
+    val singleColumnProject = SchemaDsl.fromStringV11() {
+      """model Single {
+        |  id Int @id
+        |}
+      """.stripMargin
+    }
+    database.setup(singleColumnProject)
+
+    val records = (1 to 1500).map(i => s"{ id: $i }").mkString(", ")
+
+    val result = server.query(
+      s"""mutation {
+         |  createManySingle(data: [$records]) {
+         |    count
+         |  }
+         |}
+      """.stripMargin,
+      singleColumnProject
+    )
+
+    result.pathAsLong("data.createManySingle.count") should equal(1500)
+  }
+}
@@ -1,4 +1,4 @@
-use crate::filter::Filter;
+use crate::filter::{Filter, JsonFilterPath, JsonTargetType, ScalarCondition};
 use prisma_models::PrismaValue;
 
 /// Comparing methods for scalar fields.
@@ -97,3 +97,16 @@ pub trait ScalarListCompare {
 
     fn contains_none(&self) -> Filter;
 }
+
+/// Comparison methods for JSON fields.
+pub trait JsonCompare {
+    /// Applies the condition to the value at `path` of the JSON document,
+    /// or to the whole document if no path is given. If a target type is
+    /// given, the value must additionally be of that JSON type.
+    fn json_compare(
+        &self,
+        condition: ScalarCondition,
+        path: Option<JsonFilterPath>,
+        target_type: Option<JsonTargetType>,
+    ) -> Filter;
+}
@@ -20,6 +20,11 @@ impl ConnectorError {
                     constraint: constraint.to_owned(),
                 },
             )),
+            ErrorKind::CheckConstraintViolation { constraint } => Some(KnownError::new(
+                user_facing_errors::query_engine::CheckConstraintViolation {
+                    constraint: constraint.clone(),
+                },
+            )),
             ErrorKind::TableDoesNotExist { table } => {
                 Some(KnownError::new(user_facing_errors::query_engine::TableDoesNotExist {
                     table: table.clone(),
@@ -51,6 +56,9 @@ pub enum ErrorKind {
     #[error("Foreign key constraint failed")]
     ForeignKeyConstraintViolation { constraint: DatabaseConstraint },
 
+    #[error("Check constraint failed: {}", constraint)]
+    CheckConstraintViolation { constraint: String },
+
     #[error("Record does not exist.")]
     RecordDoesNotExist,
 
@@ -0,0 +1,19 @@
+use super::Filter;
+
+/// Filters on aggregated values of a group, e.g. in the `having` clause of a
+/// group by query:
+///
+/// ```graphql
+/// groupByUser(by: [name], having: { age: { avg: { gt: 20 } } })
+/// ```
+///
+/// The wrapped filter is expressed in terms of the aggregated field and is
+/// applied to the aggregated value instead of the field value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AggregationFilter {
+    Count(Box<Filter>),
+    Average(Box<Filter>),
+    Sum(Box<Filter>),
+    Min(Box<Filter>),
+    Max(Box<Filter>),
+}
@@ -4,11 +4,13 @@
 //! [ScalarCompare](/query-connector/trait.ScalarCompare.html) and
 //! [RelationCompare](/query-connector/trait.RelationCompare.html).
 
+mod aggregation;
 mod id_filter;
 mod list;
 mod relation;
 mod scalar;
 
+pub use aggregation::*;
 pub use id_filter::*;
 pub use list::*;
 pub use relation::*;
@@ -23,6 +25,7 @@ pub enum Filter {
     ScalarList(ScalarListFilter),
     OneRelationIsNull(OneRelationIsNullFilter),
     Relation(RelationFilter),
+    Aggregation(AggregationFilter),
     NodeSubscription,
     BoolFilter(bool),
     Empty,
@@ -129,6 +132,7 @@ impl Filter {
             Filter::ScalarList(_) => {}
             Filter::OneRelationIsNull(_) => {}
             Filter::Relation(_) => {}
+            Filter::Aggregation(_) => {}
             Filter::NodeSubscription => {}
             Filter::BoolFilter(_) => {}
             Filter::Empty => {}
@@ -160,6 +164,12 @@ impl From<RelationFilter> for Filter {
     }
 }
 
+impl From<AggregationFilter> for Filter {
+    fn from(af: AggregationFilter) -> Self {
+        Filter::Aggregation(af)
+    }
+}
+
 impl From<bool> for Filter {
     fn from(b: bool) -> Self {
         Filter::BoolFilter(b)
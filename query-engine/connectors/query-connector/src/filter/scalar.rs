@@ -1,5 +1,5 @@
 use super::Filter;
-use crate::compare::ScalarCompare;
+use crate::compare::{JsonCompare, ScalarCompare};
 use once_cell::sync::Lazy;
 use prisma_models::{ModelProjection, PrismaListValue, PrismaValue, ScalarFieldRef};
 use std::{collections::BTreeSet, env, sync::Arc};
@@ -126,6 +126,52 @@ pub enum ScalarCondition {
     GreaterThanOrEquals(PrismaValue),
     In(PrismaListValue),
     NotIn(PrismaListValue),
+    JsonCompare(JsonCondition),
+}
+
+/// A condition on a JSON field, optionally applied to a nested value of the document.
+///
+/// The `contains` and `startsWith` conditions (and their negations) are
+/// interpreted based on the target type: On strings they match substrings, on
+/// arrays they match elements.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonCondition {
+    pub condition: Box<ScalarCondition>,
+    pub path: Option<JsonFilterPath>,
+    pub target_type: Option<JsonTargetType>,
+}
+
+/// Path to a nested value of a JSON document. Postgres addresses nested values
+/// with an array of keys (`{a,b}`), MySQL and SQLite with a JSON path string (`$.a.b`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonFilterPath {
+    String(String),
+    Array(Vec<String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonTargetType {
+    String,
+    Array,
+}
+
+impl JsonCompare for ScalarFieldRef {
+    fn json_compare(
+        &self,
+        condition: ScalarCondition,
+        path: Option<JsonFilterPath>,
+        target_type: Option<JsonTargetType>,
+    ) -> Filter {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::JsonCompare(JsonCondition {
+                condition: Box::new(condition),
+                path,
+                target_type,
+            }),
+            mode: QueryMode::Default,
+        })
+    }
 }
 
 impl ScalarCompare for ScalarFieldRef {
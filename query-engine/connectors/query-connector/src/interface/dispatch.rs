@@ -39,6 +39,18 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    async fn count_related_records(
+        &self,
+        from_field: &RelationFieldRef,
+        from_record_links: &[RecordProjection],
+        filter: Option<Filter>,
+    ) -> crate::Result<Vec<(RecordProjection, usize)>> {
+        match self {
+            Self::Connection(c) => c.count_related_records(from_field, from_record_links, filter).await,
+            Self::Transaction(tx) => tx.count_related_records(from_field, from_record_links, filter).await,
+        }
+    }
+
     async fn aggregate_records(
         &self,
         model: &ModelRef,
@@ -50,6 +62,26 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
             Self::Transaction(tx) => tx.aggregate_records(model, aggregators, query_arguments).await,
         }
     }
+
+    async fn group_by_records(
+        &self,
+        model: &ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+        having: Option<Filter>,
+    ) -> crate::Result<Vec<AggregationRow>> {
+        match self {
+            Self::Connection(c) => {
+                c.group_by_records(model, group_by, aggregators, query_arguments, having)
+                    .await
+            }
+            Self::Transaction(tx) => {
+                tx.group_by_records(model, group_by, aggregators, query_arguments, having)
+                    .await
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -61,6 +93,18 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    async fn create_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> crate::Result<usize> {
+        match self {
+            Self::Connection(c) => c.create_records(model, args, skip_duplicates).await,
+            Self::Transaction(tx) => tx.create_records(model, args, skip_duplicates).await,
+        }
+    }
+
     async fn update_records(
         &self,
         model: &ModelRef,
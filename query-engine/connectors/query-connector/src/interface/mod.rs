@@ -130,10 +130,12 @@ impl Aggregator {
 
 /// Result of an aggregation operation on a model or field.
 /// It is expected that the type of a `PrismaValue` matches the `TypeIdentifier`
-/// of the accompanying `ScalarFieldRef` for `Sum`, `Min` and `Max`.
+/// of the accompanying `ScalarFieldRef` for `Field`, `Sum`, `Min` and `Max`.
 /// `Count` and `Average` are expected to be of `int` and `float` types, respectively.
 #[derive(Debug, Clone)]
 pub enum AggregationResult {
+    /// Value of a field the records have been grouped by.
+    Field(ScalarFieldRef, PrismaValue),
     Count(PrismaValue),
     Average(ScalarFieldRef, PrismaValue),
     Sum(ScalarFieldRef, PrismaValue),
@@ -141,6 +143,9 @@ pub enum AggregationResult {
     Max(ScalarFieldRef, PrismaValue),
 }
 
+/// All aggregation results of a single group, as returned by a group by operation.
+pub type AggregationRow = Vec<AggregationResult>;
+
 #[async_trait]
 pub trait ReadOperations {
     /// Gets a single record or `None` back from the database.
@@ -182,6 +187,20 @@ pub trait ReadOperations {
         from_record_ids: &[RecordProjection],
     ) -> crate::Result<Vec<(RecordProjection, RecordProjection)>>;
 
+    /// Counts the related records of a to-many relation field for a set of parent records,
+    /// optionally restricted by a filter on the related records.
+    ///
+    /// The parent records are given as projections of the linking fields of `from_field`.
+    /// Returns the linking values of every parent with at least one matching related
+    /// record, together with the number of those records. Parents without any
+    /// matching related records are not contained in the result.
+    async fn count_related_records(
+        &self,
+        from_field: &RelationFieldRef,
+        from_record_links: &[RecordProjection],
+        filter: Option<Filter>,
+    ) -> crate::Result<Vec<(RecordProjection, usize)>>;
+
     /// Aggregates records for a specific model based on the given aggregators.
     /// Whether or not the aggregations can be executed in a single query or
     /// requires multiple roundtrips to the underlying data source is at the
@@ -192,6 +211,24 @@ pub trait ReadOperations {
         aggregators: Vec<Aggregator>,
         query_arguments: QueryArguments,
     ) -> crate::Result<Vec<AggregationResult>>;
+
+    /// Groups the records of a specific model by the values of the `group_by` fields
+    /// and aggregates every group based on the given aggregators.
+    ///
+    /// - The `QueryArguments` filter the records before grouping (`where`) and page
+    ///   through the resulting groups (`orderBy`, `skip`, `take`).
+    /// - The `having` filter is applied to the groups and may contain aggregation filters.
+    ///
+    /// Returns one row per group, containing the values of the grouped fields first,
+    /// followed by the aggregation results in the order of the aggregators.
+    async fn group_by_records(
+        &self,
+        model: &ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+        having: Option<Filter>,
+    ) -> crate::Result<Vec<AggregationRow>>;
 }
 
 #[async_trait]
@@ -199,6 +236,17 @@ pub trait WriteOperations {
     /// Insert a single record to the database.
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> crate::Result<RecordProjection>;
 
+    /// Insert many records to the database at once. If `skip_duplicates` is set,
+    /// records violating a unique constraint are ignored instead of failing the operation.
+    ///
+    /// Returns the number of records inserted.
+    async fn create_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> crate::Result<usize>;
+
     /// Update records in the `Model` with the given `WriteArgs` filtered by the
     /// `Filter`.
     async fn update_records(
@@ -63,7 +63,7 @@ impl QueryArguments {
 
     /// A null cursor is a cursor that is used in conjunction with a nullable order by (i.e. a field is optional).
     pub fn contains_null_cursor(&self) -> bool {
-        self.cursor.is_some() && self.order_by.iter().any(|o| !o.field.is_required)
+        self.cursor.is_some() && self.order_by.iter().any(|o| o.is_nullable())
     }
 
     /// Checks if the orderBy provided is guaranteeing a stable ordering of records for the model. Assumes that `model`
@@ -71,10 +71,11 @@ impl QueryArguments {
     /// `true` if at least one unique field is present, or contains a combination of fields that is marked as unique.
     /// `false` otherwise.
     pub fn is_stable_ordering(&self) -> bool {
-        let order_fields: Vec<_> = self.order_by.iter().map(|o| &o.field).collect();
+        // Only orderings on fields of the model itself can guarantee uniqueness.
+        let order_fields: Vec<_> = self.order_by.iter().filter_map(|o| o.model_field()).collect();
 
-        !self.order_by.is_empty()
-            && (self.order_by.iter().any(|o| o.field.unique())
+        !order_fields.is_empty()
+            && (order_fields.iter().any(|f| f.unique())
                 || self
                     .model
                     .unique_indexes()
@@ -86,8 +87,11 @@ impl QueryArguments {
         self.take.clone().map(|t| if t < 0 { t * -1 } else { t })
     }
 
+    /// Batched results are ordered in memory, which is only possible on the fields of the model itself.
     pub fn can_batch(&self) -> bool {
-        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false) && self.cursor.is_none()
+        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false)
+            && self.cursor.is_none()
+            && self.order_by.iter().all(|o| o.model_field().is_some())
     }
 
     pub fn batched(self) -> Vec<Self> {
@@ -1,4 +1,4 @@
-use crate::query_arguments_ext::QueryArgumentsExt;
+use crate::{ordering, query_arguments_ext::QueryArgumentsExt};
 use connector_interface::QueryArguments;
 use prisma_models::*;
 use quaint::ast::*;
@@ -62,6 +62,13 @@ static ORDER_TABLE_ALIAS: &'static str = "order_cmp";
 ///   )
 ///   -- ...
 /// ```
+///
+/// Orderings on related records (see `ordering::order_by_expression`) can't be selected as columns of the ordered
+/// model. Their comparison value is instead computed with a subquery on the cursor record, e.g. for `orderBy: { author: { name: asc } }`:
+/// ```sql
+///   (SELECT `orderby_0_0`.`name` FROM `User` AS `orderby_0_0` WHERE `orderby_0_0`.`id` = `Post`.`authorId`)
+///     >= (SELECT (SELECT `orderby_0_0`.`name` FROM `User` AS `orderby_0_0` WHERE `orderby_0_0`.`id` = `Post`.`authorId`) FROM `Post` WHERE (`Post`.`id`) = (4))
+/// ```
 pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Table<'static>>, ConditionTree<'static>) {
     match query_arguments.cursor {
         None => (None, ConditionTree::NoCondition),
@@ -78,32 +85,43 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
             let cursor_condition = cursor_row.clone().equals(cursor_values.clone());
 
             // Orderings for this query. Influences which fields we need to fetch for comparing order fields.
-            let mut order_definitions = order_definitions(query_arguments, model);
+            let mut order_definitions = order_definitions(query_arguments, model, &cursor_condition);
 
             // Subquery to find the value of the order field(s) that we need for comparison. Builds part #1 of the query example in the docs.
-            let order_subquery = order_definitions
+            // Orderings on related records compare against their own subquery and don't need the table.
+            let order_fields: Vec<_> = order_definitions
                 .iter()
-                .fold(Select::from_table(model.as_table()), |select, (field, _)| {
-                    select.column(field.as_column())
-                })
-                .so_that(cursor_condition);
+                .filter_map(|definition| definition.order_by.model_field())
+                .collect();
+
+            let subquery_table = if order_fields.is_empty() {
+                None
+            } else {
+                let order_subquery = order_fields
+                    .into_iter()
+                    .fold(Select::from_table(model.as_table()), |select, field| {
+                        select.column(field.as_column())
+                    })
+                    .so_that(cursor_condition);
+
+                Some(Table::from(order_subquery).alias(ORDER_TABLE_ALIAS))
+            };
 
-            let subquery_table = Table::from(order_subquery).alias(ORDER_TABLE_ALIAS);
             let len = order_definitions.len();
             let reverse = query_arguments.needs_reversed_order();
 
             // Builds part #2 of the example query.
             // If we only have one ordering, we only want a single, slightly different, condition of (orderField [<= / >=] cmp_field).
             let condition_tree = if len == 1 {
-                let (field, order) = order_definitions.pop().unwrap();
-                ConditionTree::Single(Box::new(map_orderby_condition(&field, &order, reverse, true)))
+                let definition = order_definitions.pop().unwrap();
+                ConditionTree::Single(Box::new(map_orderby_condition(&definition, reverse, true)))
             } else {
                 let or_conditions = (0..len).fold(Vec::with_capacity(len), |mut conditions_acc, n| {
                     let (head, tail) = order_definitions.split_at(len - n - 1);
                     let mut and_conditions = Vec::with_capacity(head.len() + 1);
 
-                    for (field, _) in head {
-                        and_conditions.push(map_equality_condition(field));
+                    for definition in head {
+                        and_conditions.push(map_equality_condition(definition));
                     }
 
                     if head.len() == len - 1 {
@@ -131,12 +149,12 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
                         //
                         // Said differently, we handle all the cases in which the prefixes are equal to len - 1 to account for possible identical comparators,
                         // but everything else must come strictly "after" the cursor.
-                        let (field, order) = tail.first().unwrap();
+                        let definition = tail.first().unwrap();
 
-                        and_conditions.push(map_orderby_condition(field, order, reverse, true));
+                        and_conditions.push(map_orderby_condition(definition, reverse, true));
                     } else {
-                        let (field, order) = tail.first().unwrap();
-                        and_conditions.push(map_orderby_condition(field, order, reverse, false));
+                        let definition = tail.first().unwrap();
+                        and_conditions.push(map_orderby_condition(definition, reverse, false));
                     }
 
                     conditions_acc.push(ConditionTree::And(and_conditions));
@@ -146,54 +164,60 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
                 ConditionTree::Or(or_conditions.into_iter().map(Into::into).collect())
             };
 
-            (Some(subquery_table), condition_tree)
+            (subquery_table, condition_tree)
         }
     }
 }
 
+/// An ordering of the query with the expressions to compare the records and the cursor record by.
+struct CursorOrderDefinition {
+    order_by: OrderBy,
+
+    /// The value of the ordering for the compared records.
+    order_expr: Expression<'static>,
+
+    /// The value of the ordering for the cursor record.
+    cmp_expr: Expression<'static>,
+}
+
 // A negative `take` value signifies that values should be taken before the cursor,
 // requiring the correct comarison operator to be used to fit the reversed order.
-fn map_orderby_condition(
-    field: &ScalarFieldRef,
-    order: &SortOrder,
-    reverse: bool,
-    include_eq: bool,
-) -> Expression<'static> {
-    let order_column = field.as_column();
-    let cmp_column = Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned()));
-
-    let order_expr: Expression<'static> = match order {
+fn map_orderby_condition(definition: &CursorOrderDefinition, reverse: bool, include_eq: bool) -> Expression<'static> {
+    let order_expr = definition.order_expr.clone();
+    let cmp_expr = definition.cmp_expr.clone();
+
+    let order_expr: Expression<'static> = match definition.order_by.sort_order {
         // If it's ASC but we want to take from the back, the ORDER BY will be DESC, meaning that comparisons done need to be lt(e).
         SortOrder::Ascending if reverse => {
             if include_eq {
-                order_column.less_than_or_equals(cmp_column)
+                order_expr.less_than_or_equals(cmp_expr)
             } else {
-                order_column.less_than(cmp_column)
+                order_expr.less_than(cmp_expr)
             }
         }
 
         // If it's DESC but we want to take from the back, the ORDER BY will be ASC, meaning that comparisons done need to be gt(e).
         SortOrder::Descending if reverse => {
             if include_eq {
-                order_column.greater_than_or_equals(cmp_column)
+                order_expr.greater_than_or_equals(cmp_expr)
             } else {
-                order_column.greater_than(cmp_column)
+                order_expr.greater_than(cmp_expr)
             }
         }
 
         SortOrder::Ascending => {
             if include_eq {
-                order_column.greater_than_or_equals(cmp_column)
+                order_expr.greater_than_or_equals(cmp_expr)
             } else {
-                order_column.greater_than(cmp_column)
+                order_expr.greater_than(cmp_expr)
             }
         }
 
         SortOrder::Descending => {
             if include_eq {
-                order_column.less_than_or_equals(cmp_column)
+                order_expr.less_than_or_equals(cmp_expr)
             } else {
-                order_column.less_than(cmp_column)
+                order_expr.less_than(cmp_expr)
             }
         }
     }
@@ -201,48 +225,68 @@ fn map_orderby_condition(
 
     // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
     // statement over their order relative to the cursor.
-    if !field.is_required {
+    if definition.order_by.is_nullable() {
         order_expr
-            .or(field.as_column().is_null())
-            .or(Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned())).is_null())
+            .or(definition.order_expr.clone().is_null())
+            .or(definition.cmp_expr.clone().is_null())
             .into()
     } else {
         order_expr
     }
 }
 
-fn map_equality_condition(field: &ScalarFieldRef) -> Expression<'static> {
-    let order_column = field.as_column();
-    let cmp_column = Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned()));
+fn map_equality_condition(definition: &CursorOrderDefinition) -> Expression<'static> {
+    let order_expr = definition.order_expr.clone();
+    let cmp_expr = definition.cmp_expr.clone();
 
     // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
     // statement over their order relative to the cursor.
-    if !field.is_required {
-        order_column
+    if definition.order_by.is_nullable() {
+        order_expr
             .clone()
-            .equals(cmp_column.clone())
-            .or(cmp_column.is_null())
-            .or(order_column.is_null())
+            .equals(cmp_expr.clone())
+            .or(cmp_expr.is_null())
+            .or(order_expr.is_null())
             .into()
     } else {
-        order_column.equals(cmp_column).into()
+        order_expr.equals(cmp_expr).into()
     }
 }
 
-fn order_definitions(query_arguments: &QueryArguments, model: &ModelRef) -> Vec<(ScalarFieldRef, SortOrder)> {
-    let defined_ordering: Vec<_> = query_arguments
-        .order_by
-        .iter()
-        .map(|o| (o.field.clone(), o.sort_order))
-        .collect();
-
-    if defined_ordering.is_empty() {
+fn order_definitions(
+    query_arguments: &QueryArguments,
+    model: &ModelRef,
+    cursor_condition: &Compare<'static>,
+) -> Vec<CursorOrderDefinition> {
+    let order_bys: Vec<OrderBy> = if query_arguments.order_by.is_empty() {
         model
             .primary_identifier()
             .scalar_fields()
-            .map(|f| (f, SortOrder::Ascending))
+            .map(|f| OrderBy::new(f, SortOrder::Ascending))
             .collect()
     } else {
-        defined_ordering
-    }
+        query_arguments.order_by.clone()
+    };
+
+    order_bys
+        .into_iter()
+        .enumerate()
+        .map(|(index, order_by)| {
+            let order_expr = ordering::order_by_expression(&order_by, index);
+
+            let cmp_expr: Expression<'static> = match order_by.model_field() {
+                Some(field) => Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned())).into(),
+                None => Select::from_table(model.as_table())
+                    .value(order_expr.clone())
+                    .so_that(cursor_condition.clone())
+                    .into(),
+            };
+
+            CursorOrderDefinition {
+                order_by,
+                order_expr,
+                cmp_expr,
+            }
+        })
+        .collect()
 }
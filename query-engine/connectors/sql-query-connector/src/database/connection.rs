@@ -2,8 +2,8 @@ use super::transaction::SqlConnectorTransaction;
 use crate::{database::operations::*, QueryExt, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationResult, Aggregator, Connection, QueryArguments, ReadOperations,
-    RecordFilter, Transaction, WriteArgs, WriteOperations,
+    self as connector, filter::Filter, AggregationResult, AggregationRow, Aggregator, Connection, QueryArguments,
+    ReadOperations, RecordFilter, Transaction, WriteArgs, WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
@@ -62,8 +62,10 @@ where
         filter: &Filter,
         selected_fields: &ModelProjection,
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
-            .await
+        self.catch(async move {
+            read::get_single_record(&self.inner, &self.connection_info, model, filter, selected_fields).await
+        })
+        .await
     }
 
     async fn get_many_records(
@@ -72,8 +74,17 @@ where
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        self.catch(async move {
+            read::get_many_records(
+                &self.inner,
+                &self.connection_info,
+                model,
+                query_arguments,
+                selected_fields,
+            )
             .await
+        })
+        .await
     }
 
     async fn get_related_m2m_record_ids(
@@ -85,14 +96,58 @@ where
             .await
     }
 
+    async fn count_related_records(
+        &self,
+        from_field: &RelationFieldRef,
+        from_record_links: &[RecordProjection],
+        filter: Option<Filter>,
+    ) -> connector::Result<Vec<(RecordProjection, usize)>> {
+        self.catch(async move {
+            read::count_related_records(
+                &self.inner,
+                &self.connection_info,
+                from_field,
+                from_record_links,
+                filter,
+            )
+            .await
+        })
+        .await
+    }
+
     async fn aggregate_records(
         &self,
         model: &ModelRef,
         aggregators: Vec<Aggregator>,
         query_arguments: QueryArguments,
     ) -> connector::Result<Vec<AggregationResult>> {
-        self.catch(async move { read::aggregate(&self.inner, model, aggregators, query_arguments).await })
+        self.catch(async move {
+            read::aggregate(&self.inner, &self.connection_info, model, aggregators, query_arguments).await
+        })
+        .await
+    }
+
+    async fn group_by_records(
+        &self,
+        model: &ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+        having: Option<Filter>,
+    ) -> connector::Result<Vec<AggregationRow>> {
+        self.catch(async move {
+            read::group_by_aggregate(
+                &self.inner,
+                &self.connection_info,
+                model,
+                group_by,
+                aggregators,
+                query_arguments,
+                having,
+            )
             .await
+        })
+        .await
     }
 }
 
@@ -106,18 +161,32 @@ where
             .await
     }
 
+    async fn create_records(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> connector::Result<usize> {
+        self.catch(async move {
+            write::create_records(&self.inner, &self.connection_info, model, args, skip_duplicates).await
+        })
+        .await
+    }
+
     async fn update_records(
         &self,
         model: &ModelRef,
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
-            .await
+        self.catch(async move {
+            write::update_records(&self.inner, &self.connection_info, model, record_filter, args).await
+        })
+        .await
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+        self.catch(async move { write::delete_records(&self.inner, &self.connection_info, model, record_filter).await })
             .await
     }
 
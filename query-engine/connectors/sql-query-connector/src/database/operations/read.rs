@@ -4,17 +4,24 @@ use crate::{
     QueryExt, SqlError,
 };
 use connector_interface::*;
+use datamodel::FieldArity;
 use futures::stream::{FuturesUnordered, StreamExt};
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, connector::ConnectionInfo};
 
 pub async fn get_single_record(
     conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
     model: &ModelRef,
     filter: &Filter,
     selected_fields: &ModelProjection,
 ) -> crate::Result<Option<SingleRecord>> {
-    let query = read::get_records(&model, selected_fields.as_columns(), filter);
+    let query = read::get_records(
+        &model,
+        selected_fields.as_columns(),
+        filter,
+        connection_info.sql_family(),
+    );
     let field_names = selected_fields.db_names().map(String::from).collect();
     let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
 
@@ -32,6 +39,7 @@ pub async fn get_single_record(
 
 pub async fn get_many_records(
     conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
     model: &ModelRef,
     mut query_arguments: QueryArguments,
     selected_fields: &ModelProjection,
@@ -56,7 +64,7 @@ pub async fn get_many_records(
         let mut futures = FuturesUnordered::new();
 
         for args in batches.into_iter() {
-            let query = read::get_records(model, selected_fields.as_columns(), args);
+            let query = read::get_records(model, selected_fields.as_columns(), args, connection_info.sql_family());
             futures.push(conn.filter(query.into(), idents.as_slice()));
         }
 
@@ -70,7 +78,12 @@ pub async fn get_many_records(
             records.order_by(&order)
         }
     } else {
-        let query = read::get_records(model, selected_fields.as_columns(), query_arguments);
+        let query = read::get_records(
+            model,
+            selected_fields.as_columns(),
+            query_arguments,
+            connection_info.sql_family(),
+        );
 
         for item in conn.filter(query.into(), idents.as_slice()).await?.into_iter() {
             records.push(Record::from(item))
@@ -145,13 +158,54 @@ pub async fn get_related_m2m_record_ids(
         .collect())
 }
 
+pub async fn count_related_records(
+    conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
+    from_field: &RelationFieldRef,
+    from_record_links: &[RecordProjection],
+    filter: Option<Filter>,
+) -> crate::Result<Vec<(RecordProjection, usize)>> {
+    let link_fields = from_field.linking_fields();
+    let link_sfs: Vec<_> = link_fields.scalar_fields().collect();
+
+    let mut idents = link_fields.type_identifiers_with_arities();
+    idents.push((TypeIdentifier::Int, FieldArity::Required));
+
+    // [DTODO] To verify: We might need chunked fetch here (too many parameters in the query).
+    let query = read::count_related(from_field, from_record_links, filter, connection_info.sql_family());
+
+    Ok(conn
+        .filter(query.into(), idents.as_slice())
+        .await?
+        .into_iter()
+        .map(|row| {
+            let mut values = row.values;
+
+            let count = match values.pop() {
+                Some(PrismaValue::Int(count)) => count as usize,
+                count => unreachable!("Expected the related record count to be an integer, got {:?}.", count),
+            };
+
+            let links: RecordProjection = link_sfs
+                .iter()
+                .zip(values)
+                .map(|(sf, val)| (sf.clone(), val))
+                .collect::<Vec<_>>()
+                .into();
+
+            (links, count)
+        })
+        .collect())
+}
+
 pub async fn aggregate(
     conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
     model: &ModelRef,
     aggregators: Vec<Aggregator>,
     query_arguments: QueryArguments,
 ) -> crate::Result<Vec<AggregationResult>> {
-    let query = read::aggregate(model, &aggregators, query_arguments);
+    let query = read::aggregate(model, &aggregators, query_arguments, connection_info.sql_family());
     let idents: Vec<_> = aggregators
         .iter()
         .flat_map(|aggregator| aggregator.identifiers())
@@ -164,3 +218,50 @@ pub async fn aggregate(
 
     Ok(row.into_aggregation_results(&aggregators))
 }
+
+pub async fn group_by_aggregate(
+    conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
+    model: &ModelRef,
+    group_by: Vec<ScalarFieldRef>,
+    aggregators: Vec<Aggregator>,
+    query_arguments: QueryArguments,
+    having: Option<Filter>,
+) -> crate::Result<Vec<AggregationRow>> {
+    let reversed = query_arguments.needs_reversed_order();
+    let query = read::group_by_aggregate(
+        model,
+        query_arguments,
+        &group_by,
+        &aggregators,
+        having,
+        connection_info.sql_family(),
+    );
+
+    let idents: Vec<_> = group_by
+        .iter()
+        .map(|field| {
+            let arity = if field.is_list {
+                FieldArity::List
+            } else {
+                FieldArity::Optional
+            };
+
+            (field.type_identifier.clone(), arity)
+        })
+        .chain(aggregators.iter().flat_map(|aggregator| aggregator.identifiers()))
+        .collect();
+
+    let mut rows: Vec<AggregationRow> = conn
+        .filter(query.into(), idents.as_slice())
+        .await?
+        .into_iter()
+        .map(|row| row.into_group_by_results(&group_by, &aggregators))
+        .collect();
+
+    if reversed {
+        rows.reverse();
+    }
+
+    Ok(rows)
+}
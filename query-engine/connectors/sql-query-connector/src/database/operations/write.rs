@@ -2,7 +2,10 @@ use crate::{error::SqlError, query_builder::write, QueryExt};
 use connector_interface::*;
 use prisma_models::*;
 use prisma_value::PrismaValue;
-use quaint::error::ErrorKind;
+use quaint::{
+    connector::{ConnectionInfo, SqlFamily},
+    error::ErrorKind,
+};
 use std::{collections::HashMap, convert::TryFrom};
 use user_facing_errors::query_engine::DatabaseConstraint;
 
@@ -63,16 +66,62 @@ pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArg
     }
 }
 
+/// Create multiple records in the database defined in `conn` with chunked multi-row
+/// `INSERT` statements, respecting the bind parameter limit of the database.
+/// Returns the number of records inserted, which excludes skipped duplicates.
+pub async fn create_records(
+    conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    skip_duplicates: bool,
+) -> crate::Result<usize> {
+    if args.is_empty() {
+        return Ok(0);
+    }
+
+    let max_bind_values = max_bind_values(connection_info);
+    let max_rows = max_insert_rows(connection_info);
+    let mut count = 0;
+
+    for insert in write::create_records(model, args, skip_duplicates, max_bind_values, max_rows) {
+        count += conn.execute(insert.into()).await?;
+    }
+
+    Ok(count as usize)
+}
+
+/// The maximum number of bind parameters a single statement may contain.
+fn max_bind_values(connection_info: &ConnectionInfo) -> usize {
+    match connection_info.sql_family() {
+        SqlFamily::Postgres => 32766,
+        SqlFamily::Mysql => 65535,
+        SqlFamily::Sqlite => 999,
+        SqlFamily::Mssql => 2099,
+    }
+}
+
+/// The maximum number of rows a single `INSERT ... VALUES` statement may contain.
+fn max_insert_rows(connection_info: &ConnectionInfo) -> usize {
+    match connection_info.sql_family() {
+        SqlFamily::Mssql => 1000,
+        _ => usize::MAX,
+    }
+}
+
 /// Update multiple records in a database defined in `conn` and the records
 /// defined in `args`, resulting the identifiers that were modified in the
 /// operation.
 pub async fn update_records(
     conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
     model: &ModelRef,
     record_filter: RecordFilter,
     args: WriteArgs,
 ) -> crate::Result<Vec<RecordProjection>> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
+    let ids = conn
+        .filter_selectors(model, record_filter, connection_info.sql_family())
+        .await?;
     let id_args = pick_args(&model.primary_identifier(), &args);
 
     if ids.len() == 0 {
@@ -94,10 +143,13 @@ pub async fn update_records(
 /// Delete multiple records in `conn`, defined in the `Filter`. Result is the number of items deleted.
 pub async fn delete_records(
     conn: &dyn QueryExt,
+    connection_info: &ConnectionInfo,
     model: &ModelRef,
     record_filter: RecordFilter,
 ) -> crate::Result<usize> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
+    let ids = conn
+        .filter_selectors(model, record_filter, connection_info.sql_family())
+        .await?;
     let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
     let count = ids.len();
 
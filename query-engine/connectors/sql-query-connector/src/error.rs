@@ -49,6 +49,9 @@ pub enum SqlError {
     #[error("Foreign key constraint failed")]
     ForeignKeyConstraintViolation { constraint: DatabaseConstraint },
 
+    #[error("Check constraint failed: {}", constraint)]
+    CheckConstraintViolation { constraint: String },
+
     #[error("Record does not exist.")]
     RecordDoesNotExist,
 
@@ -126,6 +129,9 @@ impl SqlError {
             SqlError::ForeignKeyConstraintViolation { constraint } => {
                 ConnectorError::from_kind(ErrorKind::ForeignKeyConstraintViolation { constraint })
             }
+            SqlError::CheckConstraintViolation { constraint } => {
+                ConnectorError::from_kind(ErrorKind::CheckConstraintViolation { constraint })
+            }
             SqlError::RecordDoesNotExist => ConnectorError::from_kind(ErrorKind::RecordDoesNotExist),
             SqlError::TableDoesNotExist(table) => ConnectorError::from_kind(ErrorKind::TableDoesNotExist { table }),
             SqlError::ColumnDoesNotExist(column) => ConnectorError::from_kind(ErrorKind::ColumnDoesNotExist { column }),
@@ -186,6 +192,12 @@ impl SqlError {
 
 impl From<quaint::error::Error> for SqlError {
     fn from(e: quaint::error::Error) -> Self {
+        // Quaint has no dedicated kind for check constraint violations, so we
+        // detect them from the original database error.
+        if let Some(constraint) = check_constraint_name(&e) {
+            return Self::CheckConstraintViolation { constraint };
+        }
+
         match QuaintKind::from(e) {
             QuaintKind::FromRowError(_) => todo!("QuaintKind::FromRowError"),
             QuaintKind::QueryError(qe) => Self::QueryError(qe),
@@ -225,6 +237,24 @@ impl From<quaint::error::Error> for SqlError {
     }
 }
 
+/// Extracts the constraint name from a check constraint violation raised by
+/// Postgres (23514), MySQL (3819), SQL Server (547) or SQLite.
+fn check_constraint_name(e: &quaint::error::Error) -> Option<String> {
+    let message = e.original_message()?;
+
+    let name = match e.original_code()? {
+        "23514" => message.split('"').nth(3),
+        "3819" => message.split('\'').nth(1),
+        "547" if message.contains("CHECK constraint") => message.split('"').nth(1),
+        _ if message.starts_with("CHECK constraint failed: ") => {
+            Some(message.trim_start_matches("CHECK constraint failed: "))
+        }
+        _ => None,
+    }?;
+
+    Some(name.to_owned())
+}
+
 impl From<DomainError> for SqlError {
     fn from(e: DomainError) -> SqlError {
         SqlError::DomainError(e)
@@ -1,6 +1,8 @@
 use connector_interface::filter::*;
 use prisma_models::prelude::*;
-use quaint::ast::*;
+use quaint::{ast::*, connector::SqlFamily};
+use rust_decimal::Decimal;
+use std::str::FromStr;
 
 #[derive(Clone, Copy, Debug)]
 /// A distinction in aliasing to separate the parent table and the joined data
@@ -59,8 +61,8 @@ pub trait AliasedCondition {
     /// alias if provided, otherwise using the fully qualified path.
     ///
     /// Alias should be used only when nesting, making the top level queries
-    /// more explicit.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static>;
+    /// more explicit. The SQL family selects the dialect of JSON filters.
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static>;
 }
 
 trait AliasedSelect {
@@ -69,20 +71,20 @@ trait AliasedSelect {
     ///
     /// Alias should be used only when nesting, making the top level queries
     /// more explicit.
-    fn aliased_sel(self, alias: Option<Alias>) -> Select<'static>;
+    fn aliased_sel(self, alias: Option<Alias>, sql_family: SqlFamily) -> Select<'static>;
 }
 
 impl AliasedCondition for Filter {
     /// Conversion from a `Filter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static> {
         match self {
             Filter::And(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NoCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, sql_family),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias))
+                        .map(|f| f.aliased_cond(alias, sql_family))
                         .map(Expression::from)
                         .collect();
 
@@ -91,11 +93,11 @@ impl AliasedCondition for Filter {
             },
             Filter::Or(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NegativeCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, sql_family),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias))
+                        .map(|f| f.aliased_cond(alias, sql_family))
                         .map(Expression::from)
                         .collect();
 
@@ -104,20 +106,21 @@ impl AliasedCondition for Filter {
             },
             Filter::Not(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NoCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias).not(),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, sql_family).not(),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias).not())
+                        .map(|f| f.aliased_cond(alias, sql_family).not())
                         .map(Expression::from)
                         .collect();
 
                     ConditionTree::And(exprs)
                 }
             },
-            Filter::Scalar(filter) => filter.aliased_cond(alias),
-            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
-            Filter::Relation(filter) => filter.aliased_cond(alias),
+            Filter::Scalar(filter) => filter.aliased_cond(alias, sql_family),
+            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias, sql_family),
+            Filter::Relation(filter) => filter.aliased_cond(alias, sql_family),
+            Filter::Aggregation(filter) => filter.aliased_cond(alias, sql_family),
             Filter::BoolFilter(b) => {
                 if b {
                     ConditionTree::NoCondition
@@ -133,7 +136,21 @@ impl AliasedCondition for Filter {
 
 impl AliasedCondition for ScalarFilter {
     /// Conversion from a `ScalarFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static> {
+        if let ScalarCondition::JsonCompare(json_condition) = self.condition {
+            let field = match self.projection {
+                ScalarProjection::Single(field) => field,
+                ScalarProjection::Compound(_) => unreachable!("JSON filters can only be applied to a single field."),
+            };
+
+            let column = match alias {
+                Some(alias) => field.as_column().table(alias.to_string(None)),
+                None => field.as_column(),
+            };
+
+            return convert_json_filter(column, json_condition, &field, sql_family);
+        }
+
         match (alias, self.projection) {
             (Some(alias), ScalarProjection::Single(field)) => {
                 let comparable: Expression = match self.mode {
@@ -171,7 +188,7 @@ impl AliasedCondition for ScalarFilter {
 
 impl AliasedCondition for RelationFilter {
     /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static> {
         let ids = self.field.model().primary_identifier().as_columns();
         let columns: Vec<Column<'static>> = match alias {
             Some(alias) => ids.map(|c| c.table(alias.to_string(None))).collect(),
@@ -179,7 +196,7 @@ impl AliasedCondition for RelationFilter {
         };
 
         let condition = self.condition.clone();
-        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)));
+        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)), sql_family);
 
         let comparison = match condition {
             RelationCondition::AtLeastOneRelatedRecord => Row::from(columns).in_selection(sub_select),
@@ -194,7 +211,7 @@ impl AliasedCondition for RelationFilter {
 
 impl AliasedSelect for RelationFilter {
     /// The subselect part of the `RelationFilter` `ConditionTree`.
-    fn aliased_sel<'a>(self, alias: Option<Alias>) -> Select<'static> {
+    fn aliased_sel<'a>(self, alias: Option<Alias>, sql_family: SqlFamily) -> Select<'static> {
         let alias = alias.unwrap_or(Alias::default());
         let condition = self.condition.clone();
 
@@ -222,7 +239,7 @@ impl AliasedSelect for RelationFilter {
 
         let nested_conditions = self
             .nested_filter
-            .aliased_cond(Some(alias.flip(AliasMode::Join)))
+            .aliased_cond(Some(alias.flip(AliasMode::Join)), sql_family)
             .invert_if(condition.invert_of_subselect());
 
         let conditions = selected_identifier
@@ -243,7 +260,7 @@ impl AliasedSelect for RelationFilter {
 
 impl AliasedCondition for OneRelationIsNullFilter {
     /// Conversion from a `OneRelationIsNullFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, _sql_family: SqlFamily) -> ConditionTree<'static> {
         let alias = alias.map(|a| a.to_string(None));
 
         let condition = if self.field.relation_is_inlined_in_parent() {
@@ -295,6 +312,56 @@ impl AliasedCondition for OneRelationIsNullFilter {
     }
 }
 
+impl AliasedCondition for AggregationFilter {
+    /// Conversion from an `AggregationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
+    fn aliased_cond(self, alias: Option<Alias>, _sql_family: SqlFamily) -> ConditionTree<'static> {
+        match self {
+            AggregationFilter::Count(filter) => aggregate_conditions(*filter, alias, |column| count(column).into()),
+            AggregationFilter::Average(filter) => aggregate_conditions(*filter, alias, |column| avg(column).into()),
+            AggregationFilter::Sum(filter) => aggregate_conditions(*filter, alias, |column| sum(column).into()),
+            AggregationFilter::Min(filter) => aggregate_conditions(*filter, alias, |column| min(column).into()),
+            AggregationFilter::Max(filter) => aggregate_conditions(*filter, alias, |column| max(column).into()),
+        }
+    }
+}
+
+/// Converts the filter wrapped by an aggregation filter, comparing against the
+/// aggregated column (as built by `field_transformer`) instead of the column itself.
+fn aggregate_conditions<T>(filter: Filter, alias: Option<Alias>, field_transformer: T) -> ConditionTree<'static>
+where
+    T: Fn(Column<'static>) -> Expression<'static> + Copy,
+{
+    match filter {
+        Filter::And(mut filters) => match filters.len() {
+            n if n == 0 => ConditionTree::NoCondition,
+            n if n == 1 => aggregate_conditions(filters.pop().unwrap(), alias, field_transformer),
+            _ => {
+                let exprs = filters
+                    .into_iter()
+                    .map(|f| aggregate_conditions(f, alias, field_transformer))
+                    .map(Expression::from)
+                    .collect();
+
+                ConditionTree::And(exprs)
+            }
+        },
+        Filter::Scalar(filter) => match filter.projection {
+            ScalarProjection::Single(field) => {
+                let column = match alias {
+                    Some(alias) => field.as_column().table(alias.to_string(None)),
+                    None => field.as_column(),
+                };
+
+                convert_scalar_filter(field_transformer(column), filter.condition, filter.mode, &[field])
+            }
+            ScalarProjection::Compound(_) => {
+                unreachable!("Aggregation filters can only be applied to a single field.")
+            }
+        },
+        _ => unreachable!("Aggregation filters can only contain scalar filters."),
+    }
+}
+
 fn convert_scalar_filter(
     comparable: impl Comparable<'static>,
     cond: ScalarCondition,
@@ -353,6 +420,7 @@ fn default_scalar_filter(
             }
             _ => comparable.not_in_selection(convert_values(fields, values)),
         },
+        ScalarCondition::JsonCompare(_) => unreachable!("JSON filters are converted by `convert_json_filter`."),
     };
 
     ConditionTree::single(condition)
@@ -424,11 +492,268 @@ fn insensitive_scalar_filter(
                     .collect::<Vec<_>>(),
             ),
         },
+        ScalarCondition::JsonCompare(_) => unreachable!("JSON filters don't support insensitive mode."),
     };
 
     ConditionTree::single(condition)
 }
 
+/// Converts a filter on a JSON field. If the condition has a path, it is
+/// applied to the value extracted from the document at that path. String and
+/// array conditions additionally require the value to be of the target type.
+///
+/// The JSON functions are rendered with raw comparisons: the function name is
+/// part of the operator and its arguments are the right-hand `Row`.
+fn convert_json_filter(
+    column: Column<'static>,
+    json_condition: JsonCondition,
+    field: &ScalarFieldRef,
+    sql_family: SqlFamily,
+) -> ConditionTree<'static> {
+    let JsonCondition {
+        condition,
+        path,
+        target_type,
+    } = json_condition;
+
+    let fields = &[field.clone()];
+
+    match (sql_family, path) {
+        (SqlFamily::Postgres, Some(JsonFilterPath::Array(keys))) => {
+            postgres_json_filter(column, *condition, keys, target_type, fields)
+        }
+        (SqlFamily::Postgres, None) => postgres_json_filter(column, *condition, Vec::new(), target_type, fields),
+        (SqlFamily::Mysql, Some(JsonFilterPath::String(path)))
+        | (SqlFamily::Sqlite, Some(JsonFilterPath::String(path))) => {
+            json_path_filter(column, *condition, path, target_type, fields, sql_family)
+        }
+        (SqlFamily::Mysql, None) | (SqlFamily::Sqlite, None) => {
+            json_path_filter(column, *condition, "$".to_owned(), target_type, fields, sql_family)
+        }
+        (sql_family, path) => unreachable!("Unsupported JSON filter path {:?} on {:?}.", path, sql_family),
+    }
+}
+
+/// JSON filters on Postgres, extracting the nested value with the `#>` operator
+/// from a list of keys. An empty list extracts the whole document.
+fn postgres_json_filter(
+    column: Column<'static>,
+    condition: ScalarCondition,
+    keys: Vec<String>,
+    target_type: Option<JsonTargetType>,
+    fields: &[ScalarFieldRef],
+) -> ConditionTree<'static> {
+    // `("column"::jsonb #> $1)`, or with `#>>` the nested value as text.
+    let extract = |operator: &'static str| -> Row<'static> {
+        let keys = Value::Array(Some(keys.iter().cloned().map(Value::text).collect()));
+        Row::from(vec![Expression::from(column.clone().compare_raw(operator, keys))])
+    };
+
+    // `($1) = jsonb_typeof("column"::jsonb #> $2)`
+    let is_string = || Row::from(vec![Value::text("string")]).compare_raw("= jsonb_typeof", extract("::jsonb #>"));
+
+    match (condition, target_type) {
+        (ScalarCondition::Contains(value), Some(JsonTargetType::String)) => {
+            extract("::jsonb #>>").like(format!("{}", value)).and(is_string())
+        }
+        (ScalarCondition::NotContains(value), Some(JsonTargetType::String)) => {
+            extract("::jsonb #>>").not_like(format!("{}", value)).and(is_string())
+        }
+        (ScalarCondition::StartsWith(value), Some(JsonTargetType::String)) => extract("::jsonb #>>")
+            .begins_with(format!("{}", value))
+            .and(is_string()),
+        (ScalarCondition::NotStartsWith(value), Some(JsonTargetType::String)) => extract("::jsonb #>>")
+            .not_begins_with(format!("{}", value))
+            .and(is_string()),
+
+        (ScalarCondition::Contains(value), Some(JsonTargetType::Array)) => {
+            ConditionTree::single(extract("::jsonb #>").compare_raw("@>", json_array(fields, value)))
+        }
+        (ScalarCondition::NotContains(value), Some(JsonTargetType::Array)) => {
+            ConditionTree::single(extract("::jsonb #>").compare_raw("@>", json_array(fields, value))).not()
+        }
+        (ScalarCondition::StartsWith(value), Some(JsonTargetType::Array)) => {
+            ConditionTree::single(extract("::jsonb #>").compare_raw("-> 0 =", convert_value(fields, value)))
+        }
+        (ScalarCondition::NotStartsWith(value), Some(JsonTargetType::Array)) => {
+            ConditionTree::single(extract("::jsonb #>").compare_raw("-> 0 =", convert_value(fields, value))).not()
+        }
+
+        (condition, _) => default_scalar_filter(extract("::jsonb #>"), condition, fields),
+    }
+}
+
+/// JSON filters on MySQL and SQLite, extracting the nested value with
+/// `JSON_EXTRACT` from a JSON path. The function call has to be the right
+/// operand, so comparisons are flipped to put the filter value on the left.
+fn json_path_filter(
+    column: Column<'static>,
+    condition: ScalarCondition,
+    path: String,
+    target_type: Option<JsonTargetType>,
+    fields: &[ScalarFieldRef],
+    sql_family: SqlFamily,
+) -> ConditionTree<'static> {
+    let arguments = |path: &str| -> Row<'static> {
+        Row::from(vec![
+            Expression::from(column.clone()),
+            Value::text(path.to_owned()).into(),
+        ])
+    };
+
+    // `(value) <operator> JSON_EXTRACT("column", $path)`
+    let extracted = |operator: &'static str, value: PrismaValue, path: &str| {
+        Row::from(vec![json_scalar_value(fields, value)]).compare_raw(operator, arguments(path))
+    };
+
+    // Strings are matched with `JSON_SEARCH` on MySQL, which returns the path
+    // of a matching string. Comparing it with the filter path ensures the
+    // match is the value at the path and not a string nested below it.
+    let mysql_search = |pattern: String| {
+        let arguments = Row::from(vec![
+            Expression::from(column.clone()),
+            Value::text("one").into(),
+            Value::text(pattern).into(),
+            Value::Text(None).into(),
+            Value::text(path.clone()).into(),
+        ]);
+
+        Row::from(vec![Value::text(path.clone())]).compare_raw("= JSON_SEARCH", arguments)
+    };
+
+    // SQLite returns strings from `json_extract` as text. The concatenation
+    // puts the function call on the right, so the result can be matched with `LIKE`.
+    let sqlite_text = || {
+        let text = Row::from(vec![Value::text("")]).compare_raw("|| JSON_EXTRACT", arguments(&path));
+        Row::from(vec![Expression::from(text)])
+    };
+
+    let sqlite_is_string = || Row::from(vec![Value::text("text")]).compare_raw("= JSON_TYPE", arguments(&path));
+
+    match (condition, target_type, sql_family) {
+        (ScalarCondition::Contains(value), Some(JsonTargetType::String), SqlFamily::Mysql) => {
+            ConditionTree::single(mysql_search(format!("%{}%", value)))
+        }
+        (ScalarCondition::NotContains(value), Some(JsonTargetType::String), SqlFamily::Mysql) => {
+            ConditionTree::single(mysql_search(format!("%{}%", value)))
+                .not()
+                .and(mysql_search("%".to_owned()))
+        }
+        (ScalarCondition::StartsWith(value), Some(JsonTargetType::String), SqlFamily::Mysql) => {
+            ConditionTree::single(mysql_search(format!("{}%", value)))
+        }
+        (ScalarCondition::NotStartsWith(value), Some(JsonTargetType::String), SqlFamily::Mysql) => {
+            ConditionTree::single(mysql_search(format!("{}%", value)))
+                .not()
+                .and(mysql_search("%".to_owned()))
+        }
+
+        (ScalarCondition::Contains(value), Some(JsonTargetType::String), _) => {
+            sqlite_text().like(format!("{}", value)).and(sqlite_is_string())
+        }
+        (ScalarCondition::NotContains(value), Some(JsonTargetType::String), _) => {
+            sqlite_text().not_like(format!("{}", value)).and(sqlite_is_string())
+        }
+        (ScalarCondition::StartsWith(value), Some(JsonTargetType::String), _) => {
+            sqlite_text().begins_with(format!("{}", value)).and(sqlite_is_string())
+        }
+        (ScalarCondition::NotStartsWith(value), Some(JsonTargetType::String), _) => sqlite_text()
+            .not_begins_with(format!("{}", value))
+            .and(sqlite_is_string()),
+
+        (ScalarCondition::Contains(value), Some(JsonTargetType::Array), SqlFamily::Mysql) => {
+            ConditionTree::single(mysql_contains(column.clone(), fields, value, path.clone()))
+        }
+        (ScalarCondition::NotContains(value), Some(JsonTargetType::Array), SqlFamily::Mysql) => {
+            ConditionTree::single(mysql_contains(column.clone(), fields, value, path.clone())).not()
+        }
+        (ScalarCondition::Contains(_), Some(JsonTargetType::Array), _)
+        | (ScalarCondition::NotContains(_), Some(JsonTargetType::Array), _) => {
+            unreachable!("SQLite does not support array_contains filters.")
+        }
+        (ScalarCondition::StartsWith(value), Some(JsonTargetType::Array), _) => {
+            ConditionTree::single(extracted("= JSON_EXTRACT", value, &format!("{}[0]", path)))
+        }
+        (ScalarCondition::NotStartsWith(value), Some(JsonTargetType::Array), _) => {
+            ConditionTree::single(extracted("= JSON_EXTRACT", value, &format!("{}[0]", path))).not()
+        }
+
+        (ScalarCondition::Equals(PrismaValue::Null), _, SqlFamily::Mysql) => {
+            ConditionTree::single(extracted("<=> JSON_EXTRACT", PrismaValue::Null, &path))
+        }
+        (ScalarCondition::NotEquals(PrismaValue::Null), _, SqlFamily::Mysql) => {
+            ConditionTree::single(extracted("<=> JSON_EXTRACT", PrismaValue::Null, &path)).not()
+        }
+        (ScalarCondition::Equals(PrismaValue::Null), _, _) => {
+            ConditionTree::single(extracted("IS JSON_EXTRACT", PrismaValue::Null, &path))
+        }
+        (ScalarCondition::NotEquals(PrismaValue::Null), _, _) => {
+            ConditionTree::single(extracted("IS NOT JSON_EXTRACT", PrismaValue::Null, &path))
+        }
+        (ScalarCondition::Equals(value), _, _) => ConditionTree::single(extracted("= JSON_EXTRACT", value, &path)),
+        (ScalarCondition::NotEquals(value), _, _) => ConditionTree::single(extracted("<> JSON_EXTRACT", value, &path)),
+        (ScalarCondition::LessThan(value), _, _) => ConditionTree::single(extracted("> JSON_EXTRACT", value, &path)),
+        (ScalarCondition::LessThanOrEquals(value), _, _) => {
+            ConditionTree::single(extracted(">= JSON_EXTRACT", value, &path))
+        }
+        (ScalarCondition::GreaterThan(value), _, _) => ConditionTree::single(extracted("< JSON_EXTRACT", value, &path)),
+        (ScalarCondition::GreaterThanOrEquals(value), _, _) => {
+            ConditionTree::single(extracted("<= JSON_EXTRACT", value, &path))
+        }
+
+        (condition, _, _) => unreachable!("Unsupported JSON path filter: {:?}", condition),
+    }
+}
+
+/// `(1) = JSON_CONTAINS("column", $candidate, $path)`
+fn mysql_contains(
+    column: Column<'static>,
+    fields: &[ScalarFieldRef],
+    value: PrismaValue,
+    path: String,
+) -> Compare<'static> {
+    let candidate = match json_array(fields, value) {
+        Value::Json(Some(array)) => Value::text(array.to_string()),
+        value => value,
+    };
+
+    let arguments = Row::from(vec![
+        Expression::from(column),
+        candidate.into(),
+        Value::text(path).into(),
+    ]);
+
+    Row::from(vec![Value::integer(1)]).compare_raw("= JSON_CONTAINS", arguments)
+}
+
+/// The array an `array_contains` filter value is matched against. A single
+/// element is wrapped in an array.
+fn json_array(fields: &[ScalarFieldRef], value: PrismaValue) -> Value<'static> {
+    match convert_value(fields, value) {
+        Value::Json(Some(serde_json::Value::Array(elements))) => Value::Json(Some(serde_json::Value::Array(elements))),
+        Value::Json(Some(element)) => Value::Json(Some(serde_json::Value::Array(vec![element]))),
+        value => value,
+    }
+}
+
+/// MySQL and SQLite compare extracted JSON scalars with plain SQL values, so
+/// the JSON filter value is unwrapped: `"foo"` is compared as the text `foo`.
+fn json_scalar_value(fields: &[ScalarFieldRef], value: PrismaValue) -> Value<'static> {
+    match convert_value(fields, value) {
+        Value::Json(Some(serde_json::Value::String(s))) => Value::text(s),
+        Value::Json(Some(serde_json::Value::Bool(b))) => Value::from(b),
+        Value::Json(Some(serde_json::Value::Number(n))) => match n.as_i64() {
+            Some(i) => Value::integer(i),
+            None => Decimal::from_str(&n.to_string())
+                .map(Value::from)
+                .unwrap_or_else(|_| Value::text(n.to_string())),
+        },
+        Value::Json(Some(other)) => Value::text(other.to_string()),
+        Value::Json(None) => Value::Text(None),
+        value => value,
+    }
+}
+
 fn convert_value<'a>(fields: &[ScalarFieldRef], value: PrismaValue) -> Value<'a> {
     fields.first().unwrap().value(value)
 }
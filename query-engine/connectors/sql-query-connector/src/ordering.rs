@@ -3,18 +3,136 @@ use connector_interface::QueryArguments;
 use prisma_models::*;
 use quaint::ast::*;
 
+static ORDER_JOIN_PREFIX: &'static str = "orderby";
+
 /// Builds all expressions for an `ORDER BY` clause based on the query arguments.
 pub fn build(query_arguments: &QueryArguments) -> Vec<OrderDefinition<'static>> {
     let needs_reversed_order = query_arguments.needs_reversed_order();
 
-    query_arguments.order_by.iter().fold(vec![], |mut acc, next_order_by| {
-        match (next_order_by.sort_order, needs_reversed_order) {
-            (SortOrder::Ascending, true) => acc.push(next_order_by.field.as_column().descend()),
-            (SortOrder::Descending, true) => acc.push(next_order_by.field.as_column().ascend()),
-            (SortOrder::Ascending, false) => acc.push(next_order_by.field.as_column().ascend()),
-            (SortOrder::Descending, false) => acc.push(next_order_by.field.as_column().descend()),
+    query_arguments
+        .order_by
+        .iter()
+        .enumerate()
+        .map(|(index, order_by)| {
+            let expression = order_by_expression(order_by, index);
+
+            match (order_by.sort_order, needs_reversed_order) {
+                (SortOrder::Ascending, true) => (expression, Some(Order::Desc)),
+                (SortOrder::Descending, true) => (expression, Some(Order::Asc)),
+                (SortOrder::Ascending, false) => (expression, Some(Order::Asc)),
+                (SortOrder::Descending, false) => (expression, Some(Order::Desc)),
+            }
+        })
+        .collect()
+}
+
+/// Builds the expression the records of the ordered model are ordered by. Orderings on
+/// related records are correlated subqueries on the ordered model table:
+///
+/// ```sql
+/// -- orderBy: { author: { name: asc } }
+/// (SELECT `orderby_0_0`.`name` FROM `User` AS `orderby_0_0` WHERE `orderby_0_0`.`id` = `Post`.`authorId`)
+///
+/// -- orderBy: { posts: { count: desc } }
+/// (SELECT COUNT(*) FROM `Post` AS `orderby_0_0` WHERE `orderby_0_0`.`authorId` = `User`.`id`)
+/// ```
+///
+/// Longer paths of to-one relations are joined inside of the subquery. `index` is the position of
+/// the ordering in the `ORDER BY` clause, keeping the table aliases of the subqueries unique.
+pub fn order_by_expression(order_by: &OrderBy, index: usize) -> Expression<'static> {
+    match (&order_by.field, order_by.sort_aggregation) {
+        (Some(field), _) if order_by.path.is_empty() => field.as_column().into(),
+
+        (Some(field), _) => {
+            let (select, alias) = join_to_one_path(&order_by.path, index);
+            select.column(field.as_column().table(alias)).into()
         }
 
-        acc
-    })
+        (None, Some(SortAggregation::Count)) => {
+            let (to_one_path, to_many) = order_by.path.split_at(order_by.path.len() - 1);
+            let count_alias = join_alias(index, to_one_path.len());
+
+            if to_one_path.is_empty() {
+                count_related(&to_many[0], None, count_alias).into()
+            } else {
+                let (select, alias) = join_to_one_path(to_one_path, index);
+                select
+                    .value(count_related(&to_many[0], Some(alias), count_alias))
+                    .into()
+            }
+        }
+
+        (None, None) => unreachable!("Orderings without a field must aggregate related records."),
+    }
+}
+
+/// Builds a select over the related records reached through the given to-one relations, correlated
+/// to the ordered model. Returns the select and the alias of the last joined table.
+fn join_to_one_path(path: &[RelationFieldRef], index: usize) -> (Select<'static>, String) {
+    let (first, rest) = path.split_first().expect("A relation ordering must have a path.");
+    let first_alias = join_alias(index, 0);
+
+    let select = Select::from_table(first.related_model().as_table().alias(first_alias.clone())).so_that(
+        related_columns(first, &first_alias).equals(Row::from(first.linking_fields().as_columns().collect::<Vec<_>>())),
+    );
+
+    rest.iter()
+        .enumerate()
+        .fold((select, first_alias), |(select, previous_alias), (i, rf)| {
+            let alias = join_alias(index, i + 1);
+            let join = rf
+                .related_model()
+                .as_table()
+                .alias(alias.clone())
+                .on(related_columns(rf, &alias).equals(linking_columns(rf, &previous_alias)));
+
+            (select.left_join(join), alias)
+        })
+}
+
+/// Builds a select counting the related records of a to-many relation, correlated to the
+/// model of the relation field. The model table is either referenced by the given alias or by its name.
+fn count_related(rf: &RelationFieldRef, source_alias: Option<String>, alias: String) -> Select<'static> {
+    let source_columns = match source_alias {
+        Some(ref source_alias) => linking_columns(rf, source_alias),
+        None => Row::from(rf.linking_fields().as_columns().collect::<Vec<_>>()),
+    };
+
+    let (table, columns) = if rf.relation().is_many_to_many() {
+        let columns: Vec<_> = rf.identifier_columns().map(|c| c.table(alias.clone())).collect();
+        (rf.as_table(), Row::from(columns))
+    } else {
+        (rf.related_model().as_table(), related_columns(rf, &alias))
+    };
+
+    Select::from_table(table.alias(alias))
+        .value(count(asterisk()))
+        .so_that(columns.equals(source_columns))
+}
+
+/// The columns of the relation field's model linking to the related model, on the aliased table.
+fn linking_columns(rf: &RelationFieldRef, alias: &str) -> Row<'static> {
+    let columns: Vec<_> = rf
+        .linking_fields()
+        .as_columns()
+        .map(|c| c.table(alias.to_owned()))
+        .collect();
+
+    Row::from(columns)
+}
+
+/// The columns of the related model linking back to the relation field's model, on the aliased table.
+fn related_columns(rf: &RelationFieldRef, alias: &str) -> Row<'static> {
+    let columns: Vec<_> = rf
+        .related_field()
+        .linking_fields()
+        .as_columns()
+        .map(|c| c.table(alias.to_owned()))
+        .collect();
+
+    Row::from(columns)
+}
+
+fn join_alias(index: usize, depth: usize) -> String {
+    format!("{}_{}_{}", ORDER_JOIN_PREFIX, index, depth)
 }
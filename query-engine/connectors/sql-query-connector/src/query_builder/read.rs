@@ -2,33 +2,33 @@ use crate::{cursor_condition, filter_conversion::AliasedCondition, ordering};
 use connector_interface::{filter::Filter, Aggregator, QueryArguments};
 use itertools::Itertools;
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, connector::SqlFamily};
 
 pub trait SelectDefinition {
-    fn into_select(self, _: &ModelRef) -> Select<'static>;
+    fn into_select(self, _: &ModelRef, sql_family: SqlFamily) -> Select<'static>;
 }
 
 impl SelectDefinition for Filter {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
+    fn into_select(self, model: &ModelRef, sql_family: SqlFamily) -> Select<'static> {
         let args = QueryArguments::from((model.clone(), self));
-        args.into_select(model)
+        args.into_select(model, sql_family)
     }
 }
 
 impl SelectDefinition for &Filter {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
-        self.clone().into_select(model)
+    fn into_select(self, model: &ModelRef, sql_family: SqlFamily) -> Select<'static> {
+        self.clone().into_select(model, sql_family)
     }
 }
 
 impl SelectDefinition for Select<'static> {
-    fn into_select(self, _: &ModelRef) -> Select<'static> {
+    fn into_select(self, _: &ModelRef, _: SqlFamily) -> Select<'static> {
         self
     }
 }
 
 impl SelectDefinition for QueryArguments {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
+    fn into_select(self, model: &ModelRef, sql_family: SqlFamily) -> Select<'static> {
         let (table_opt, cursor_condition) = cursor_condition::build(&self, &model);
         let orderings = ordering::build(&self);
 
@@ -37,7 +37,7 @@ impl SelectDefinition for QueryArguments {
 
         let filter: ConditionTree = self
             .filter
-            .map(|f| f.aliased_cond(None))
+            .map(|f| f.aliased_cond(None, sql_family))
             .unwrap_or(ConditionTree::NoCondition);
 
         let conditions = match (filter, cursor_condition) {
@@ -65,11 +65,16 @@ impl SelectDefinition for QueryArguments {
     }
 }
 
-pub fn get_records<T>(model: &ModelRef, columns: impl Iterator<Item = Column<'static>>, query: T) -> Select<'static>
+pub fn get_records<T>(
+    model: &ModelRef,
+    columns: impl Iterator<Item = Column<'static>>,
+    query: T,
+    sql_family: SqlFamily,
+) -> Select<'static>
 where
     T: SelectDefinition,
 {
-    columns.fold(query.into_select(model), |acc, col| acc.column(col))
+    columns.fold(query.into_select(model, sql_family), |acc, col| acc.column(col))
 }
 
 /// Generates a query of the form:
@@ -96,9 +101,14 @@ where
 ///             1 = 1
 ///     ) AS `sub`;
 /// ```
-pub fn aggregate(model: &ModelRef, aggregators: &[Aggregator], args: QueryArguments) -> Select<'static> {
+pub fn aggregate(
+    model: &ModelRef,
+    aggregators: &[Aggregator],
+    args: QueryArguments,
+    sql_family: SqlFamily,
+) -> Select<'static> {
     let columns = extract_columns(model, &aggregators);
-    let sub_query = get_records(model, columns.into_iter(), args);
+    let sub_query = get_records(model, columns.into_iter(), args, sql_family);
     let sub_table = Table::from(sub_query).alias("sub");
 
     aggregators
@@ -124,6 +134,148 @@ pub fn aggregate(model: &ModelRef, aggregators: &[Aggregator], args: QueryArgume
         })
 }
 
+/// Generates a query of the form:
+/// ```sql
+/// SELECT
+///     `Table`.`name`,
+///     COUNT(*),
+///     AVG(`Table`.`int`)
+/// FROM
+///     `Table`
+/// WHERE
+///     1 = 1
+/// GROUP BY
+///     `Table`.`name`
+/// HAVING
+///     AVG(`Table`.`int`) > 10
+/// ORDER BY
+///     `Table`.`name` ASC
+/// LIMIT 10 OFFSET 0;
+/// ```
+/// The grouped columns are always selected first, followed by the aggregations in order.
+pub fn group_by_aggregate(
+    model: &ModelRef,
+    args: QueryArguments,
+    group_by: &[ScalarFieldRef],
+    aggregators: &[Aggregator],
+    having: Option<Filter>,
+    sql_family: SqlFamily,
+) -> Select<'static> {
+    let orderings = ordering::build(&args);
+    let limit = args.take_abs();
+    let skip = args.skip.unwrap_or(0);
+
+    let filter: ConditionTree = args
+        .filter
+        .map(|f| f.aliased_cond(None, sql_family))
+        .unwrap_or(ConditionTree::NoCondition);
+
+    let select_query = group_by.iter().fold(
+        Select::from_table(model.as_table())
+            .so_that(filter)
+            .offset(skip as usize),
+        |select, next_field| select.column(next_field.as_column()),
+    );
+
+    let select_query = aggregators.iter().fold(select_query, |select, next_op| match next_op {
+        Aggregator::Count => select.value(count(asterisk())),
+
+        Aggregator::Average(fields) => fields
+            .iter()
+            .fold(select, |select, next_field| select.value(avg(next_field.as_column()))),
+
+        Aggregator::Sum(fields) => fields
+            .iter()
+            .fold(select, |select, next_field| select.value(sum(next_field.as_column()))),
+
+        Aggregator::Min(fields) => fields
+            .iter()
+            .fold(select, |select, next_field| select.value(min(next_field.as_column()))),
+
+        Aggregator::Max(fields) => fields
+            .iter()
+            .fold(select, |select, next_field| select.value(max(next_field.as_column()))),
+    });
+
+    let grouped = group_by
+        .iter()
+        .fold(select_query, |query, field| query.group_by(field.as_column()));
+
+    let grouped = match having {
+        Some(filter) => grouped.having(filter.aliased_cond(None, sql_family)),
+        None => grouped,
+    };
+
+    let grouped = orderings.into_iter().fold(grouped, |acc, ord| acc.order_by(ord));
+
+    match limit {
+        Some(limit) => grouped.limit(limit as usize),
+        None => grouped,
+    }
+}
+
+/// Generates a query counting the related records of a to-many relation for a set of parent
+/// records, grouped by the parent they belong to:
+/// ```sql
+/// SELECT
+///     `Post`.`authorId`,
+///     COUNT(*)
+/// FROM
+///     `Post`
+/// WHERE
+///     `Post`.`authorId` IN (1, 2) AND `Post`.`published` = true
+/// GROUP BY
+///     `Post`.`authorId`;
+/// ```
+/// Many-to-many relations are counted on the relation table. A filter on the related records
+/// is then applied by selecting the ids of the matching related records in a subquery.
+pub fn count_related(
+    from_field: &RelationFieldRef,
+    from_record_links: &[RecordProjection],
+    filter: Option<Filter>,
+    sql_family: SqlFamily,
+) -> Select<'static> {
+    let related_model = from_field.related_model();
+
+    let (table, parent_columns, filter) = if from_field.relation().is_many_to_many() {
+        let filter: Option<ConditionTree> = filter.map(|filter| {
+            let matching_ids = Select::from_table(related_model.as_table())
+                .columns(related_model.primary_identifier().as_columns())
+                .so_that(filter.aliased_cond(None, sql_family));
+
+            Row::from(from_field.m2m_columns()).in_selection(matching_ids).into()
+        });
+
+        (
+            from_field.relation().as_table(),
+            from_field.related_field().m2m_columns(),
+            filter,
+        )
+    } else {
+        let columns: Vec<_> = from_field.related_field().linking_fields().as_columns().collect();
+        let filter = filter.map(|filter| filter.aliased_cond(None, sql_family));
+
+        (related_model.as_table(), columns, filter)
+    };
+
+    let conditions = super::conditions(&parent_columns, from_record_links);
+    let conditions = match filter {
+        Some(filter) => ConditionTree::and(conditions, filter),
+        None => conditions,
+    };
+
+    let select = parent_columns
+        .iter()
+        .fold(Select::from_table(table).so_that(conditions), |select, column| {
+            select.column(column.clone())
+        })
+        .value(count(asterisk()));
+
+    parent_columns
+        .into_iter()
+        .fold(select, |select, column| select.group_by(column))
+}
+
 fn extract_columns(model: &ModelRef, aggregators: &[Aggregator]) -> Vec<Column<'static>> {
     let fields: Vec<_> = aggregators
         .iter()
@@ -33,6 +33,85 @@ pub fn create_record(model: &ModelRef, mut args: WriteArgs) -> (Insert<'static>,
     )
 }
 
+/// `INSERT` many records to the database in as few statements as possible.
+///
+/// Records are grouped by the set of fields they provide values for, as every row of a
+/// multi-row `INSERT` must have the same columns. Each group is then split into chunks
+/// that stay below `max_bind_values` parameters and `max_rows` rows per statement.
+/// Records without any values are inserted one by one, relying on the database defaults.
+///
+/// If `skip_duplicates` is set, rows violating a unique constraint are ignored.
+pub fn create_records(
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    skip_duplicates: bool,
+    max_bind_values: usize,
+    max_rows: usize,
+) -> Vec<Insert<'static>> {
+    let scalar_fields = model.fields().scalar();
+    let mut groups: Vec<(Vec<String>, Vec<Vec<Value<'static>>>)> = Vec::new();
+
+    for mut args in args {
+        let fields: Vec<_> = scalar_fields
+            .iter()
+            .filter(|field| args.has_arg_for(&field.db_name()))
+            .cloned()
+            .collect();
+
+        let row: Vec<_> = fields
+            .iter()
+            .map(|field| {
+                let value = args.take_field_value(field.db_name()).unwrap();
+                let value: PrismaValue = value
+                    .try_into()
+                    .expect("Create calls can only use PrismaValue write expressions (right now).");
+
+                field.value(value)
+            })
+            .collect();
+
+        let columns: Vec<_> = fields.iter().map(|field| field.db_name().to_owned()).collect();
+
+        match groups.iter_mut().find(|(group_columns, _)| group_columns == &columns) {
+            Some((_, rows)) => rows.push(row),
+            None => groups.push((columns, vec![row])),
+        }
+    }
+
+    let mut inserts = Vec::new();
+
+    for (columns, rows) in groups {
+        if columns.is_empty() {
+            inserts.extend(
+                rows.into_iter()
+                    .map(|_| Insert::from(Insert::single_into(model.as_table()))),
+            );
+            continue;
+        }
+
+        let chunk_size = std::cmp::max(1, std::cmp::min(max_bind_values / columns.len(), max_rows));
+
+        for chunk in rows.chunks(chunk_size) {
+            let insert = chunk
+                .iter()
+                .fold(Insert::multi_into(model.as_table(), columns.clone()), |insert, row| {
+                    insert.values(row.clone())
+                });
+
+            inserts.push(insert.build());
+        }
+    }
+
+    if skip_duplicates {
+        inserts
+            .into_iter()
+            .map(|insert| insert.on_conflict(OnConflict::DoNothing))
+            .collect()
+    } else {
+        inserts
+    }
+}
+
 pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs) -> crate::Result<Vec<Query<'static>>> {
     if args.args.is_empty() || ids.is_empty() {
         return Ok(Vec::new());
@@ -1,13 +1,13 @@
 use crate::error::SqlError;
 use chrono::{DateTime, NaiveDate, Utc};
-use connector_interface::{AggregationResult, Aggregator};
+use connector_interface::{AggregationResult, AggregationRow, Aggregator};
 use datamodel::FieldArity;
-use prisma_models::{PrismaValue, Record, TypeIdentifier};
+use prisma_models::{PrismaValue, Record, ScalarFieldRef, TypeIdentifier};
 use quaint::{
     ast::{Expression, Value},
     connector::ResultRow,
 };
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use std::{borrow::Borrow, io, str::FromStr};
 use uuid::Uuid;
 
@@ -59,6 +59,21 @@ impl SqlRow {
             })
             .collect()
     }
+
+    /// Splits a grouped row into the values of the grouped fields, which are expected
+    /// to come first, and the aggregation results following them.
+    pub fn into_group_by_results(mut self, group_by: &[ScalarFieldRef], aggregators: &[Aggregator]) -> AggregationRow {
+        let aggregations = SqlRow {
+            values: self.values.split_off(group_by.len()),
+        };
+
+        group_by
+            .iter()
+            .zip(self.values)
+            .map(|(field, value)| AggregationResult::Field(field.clone(), value))
+            .chain(aggregations.into_aggregation_results(aggregators))
+            .collect()
+    }
 }
 
 fn coerce_null_to_zero_value(value: PrismaValue) -> PrismaValue {
@@ -143,6 +158,9 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
             value if value.is_null() => PrismaValue::Null,
             Value::Text(Some(json)) => PrismaValue::Json(json.into()),
             Value::Json(Some(json)) => PrismaValue::Json(json.to_string()),
+            // SQLite stores documents that are a single number with numeric affinity.
+            Value::Integer(Some(i)) => PrismaValue::Json(i.to_string()),
+            Value::Real(Some(f)) => PrismaValue::Json(f.to_string()),
             _ => {
                 let error = io::Error::new(io::ErrorKind::InvalidData, "Json value not stored as text or json");
                 return Err(SqlError::ConversionError(error.into()));
@@ -227,6 +245,38 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
             ),
             other => PrismaValue::from(other),
         },
+        TypeIdentifier::BigInt => match p_value {
+            value if value.is_null() => PrismaValue::Null,
+            Value::Integer(Some(i)) => PrismaValue::BigInt(i),
+            // Aggregations like `SUM` over BIGINT columns return a numeric value on Postgres and MySQL.
+            Value::Real(Some(d)) => match d.to_i64() {
+                Some(i) => PrismaValue::BigInt(i),
+                None => {
+                    let error = io::Error::new(io::ErrorKind::InvalidData, "BigInt value out of range");
+                    return Err(SqlError::ConversionError(error.into()));
+                }
+            },
+            Value::Bytes(Some(bytes)) => PrismaValue::BigInt(interpret_bytes_as_i64(&bytes)),
+            Value::Text(Some(txt)) => PrismaValue::BigInt(
+                i64::from_str(txt.trim_start_matches('\0')).map_err(|err| SqlError::ConversionError(err.into()))?,
+            ),
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "BigInt value not stored as int, numeric or text",
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Bytes => match p_value {
+            value if value.is_null() => PrismaValue::Null,
+            Value::Bytes(Some(bytes)) => PrismaValue::Bytes(bytes.into_owned()),
+            Value::Text(Some(txt)) => PrismaValue::Bytes(txt.into_owned().into_bytes()),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Bytes value not stored as bytes or text");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
         TypeIdentifier::String => match p_value {
             value if value.is_null() => PrismaValue::Null,
             Value::Uuid(Some(uuid)) => PrismaValue::String(uuid.to_string()),
@@ -1,6 +1,6 @@
 use crate::{
     InterpreterError, QueryGraphBuilderError, QueryGraphError, QueryParserError, QueryParserErrorKind,
-    RelationViolation,
+    RelationViolation, TransactionError,
 };
 use connector::error::ConnectorError;
 use prisma_models::DomainError;
@@ -35,6 +35,9 @@ pub enum CoreError {
 
     #[error("{}", _0)]
     InterpreterError(InterpreterError),
+
+    #[error("{}", _0)]
+    TransactionError(TransactionError),
 }
 
 impl From<QueryGraphBuilderError> for CoreError {
@@ -73,6 +76,12 @@ impl From<InterpreterError> for CoreError {
     }
 }
 
+impl From<TransactionError> for CoreError {
+    fn from(e: TransactionError) -> CoreError {
+        CoreError::TransactionError(e)
+    }
+}
+
 impl From<CoreError> for user_facing_errors::Error {
     fn from(err: CoreError) -> user_facing_errors::Error {
         match err {
@@ -167,6 +176,19 @@ impl From<CoreError> for user_facing_errors::Error {
                     .into(),
                 }
             }
+            CoreError::TransactionError(TransactionError::Expired { id, timeout_ms }) => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::TransactionExpired {
+                    id,
+                    timeout_ms,
+                })
+                .into()
+            }
+            CoreError::TransactionError(err) => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::TransactionApiError {
+                    details: err.to_string(),
+                })
+                .into()
+            }
             _ => user_facing_errors::Error::from_dyn_error(&err),
         }
     }
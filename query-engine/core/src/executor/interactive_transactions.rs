@@ -0,0 +1,263 @@
+//! Interactive transactions are long-lived transactions that span multiple requests.
+//!
+//! Every open transaction is owned by a dedicated task that holds the connection and the
+//! `connector::Transaction` on it. Requests are routed to that task through a channel, keyed
+//! by the id of the transaction. The task rolls back the transaction once it was either
+//! committed or rolled back explicitly, or if the configured timeout elapsed.
+use super::pipeline::QueryPipeline;
+use crate::{CoreError, Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData};
+use connector::{Connection, ConnectionLike};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use thiserror::Error;
+use tokio::{
+    sync::{mpsc, oneshot, Mutex},
+    time::{self, Instant},
+};
+use uuid::Uuid;
+
+/// Identifier of an open interactive transaction.
+pub type TxId = String;
+
+/// How long an expired transaction is remembered, so that requests to it report the expiry
+/// instead of an unknown transaction, if no request reported it before.
+const EXPIRED_TRANSACTION_TTL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Error)]
+pub enum TransactionError {
+    #[error("Transaction {} not found.", _0)]
+    NotFound(TxId),
+
+    #[error("Transaction {} expired after {}ms and was rolled back.", id, timeout_ms)]
+    Expired { id: TxId, timeout_ms: u64 },
+
+    #[error("Transaction {} is already closed.", _0)]
+    Closed(TxId),
+}
+
+enum TxMessage {
+    Execute {
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        respond: oneshot::Sender<crate::Result<ResponseData>>,
+    },
+    Commit(oneshot::Sender<crate::Result<()>>),
+    Rollback(oneshot::Sender<crate::Result<()>>),
+}
+
+enum TxState {
+    Open(mpsc::Sender<TxMessage>),
+    Expired { timeout_ms: u64 },
+}
+
+/// Keeps track of all open interactive transactions of an executor.
+#[derive(Default)]
+pub struct TransactionManager {
+    transactions: Arc<Mutex<HashMap<TxId, TxState>>>,
+}
+
+impl TransactionManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new transaction on the given connection, which is rolled back
+    /// automatically if it is not closed before `timeout` elapses.
+    pub async fn start(&self, conn: Box<dyn Connection>, timeout: Duration) -> crate::Result<TxId> {
+        let id = Uuid::new_v4().to_string();
+        let (sender, receiver) = mpsc::channel(1);
+        let (started_sender, started_receiver) = oneshot::channel();
+
+        self.transactions.lock().await.insert(id.clone(), TxState::Open(sender));
+
+        tokio::spawn(run_transaction(
+            id.clone(),
+            conn,
+            timeout,
+            receiver,
+            started_sender,
+            Arc::clone(&self.transactions),
+        ));
+
+        let started = match started_receiver.await {
+            Ok(result) => result,
+            Err(_) => Err(TransactionError::Closed(id.clone()).into()),
+        };
+
+        if let Err(err) = started {
+            self.transactions.lock().await.remove(&id);
+            return Err(err);
+        }
+
+        Ok(id)
+    }
+
+    /// Executes the operation inside of the transaction with the given id.
+    pub async fn execute(
+        &self,
+        id: &TxId,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<ResponseData> {
+        let (respond, response) = oneshot::channel();
+
+        self.send(
+            id,
+            TxMessage::Execute {
+                operation,
+                query_schema,
+                respond,
+            },
+        )
+        .await?;
+
+        match response.await {
+            Ok(result) => result,
+            Err(_) => Err(self.closed_error(id).await),
+        }
+    }
+
+    /// Commits the transaction with the given id.
+    pub async fn commit(&self, id: &TxId) -> crate::Result<()> {
+        let (respond, response) = oneshot::channel();
+
+        self.send(id, TxMessage::Commit(respond)).await?;
+        match response.await {
+            Ok(result) => result,
+            Err(_) => Err(self.closed_error(id).await),
+        }
+    }
+
+    /// Rolls back the transaction with the given id.
+    pub async fn rollback(&self, id: &TxId) -> crate::Result<()> {
+        let (respond, response) = oneshot::channel();
+
+        self.send(id, TxMessage::Rollback(respond)).await?;
+        match response.await {
+            Ok(result) => result,
+            Err(_) => Err(self.closed_error(id).await),
+        }
+    }
+
+    async fn send(&self, id: &TxId, message: TxMessage) -> crate::Result<()> {
+        let sender = match self.transactions.lock().await.get(id) {
+            Some(TxState::Open(sender)) => Some(sender.clone()),
+            _ => None,
+        };
+
+        match sender {
+            Some(mut sender) => match sender.send(message).await {
+                Ok(_) => Ok(()),
+                Err(_) => Err(self.closed_error(id).await),
+            },
+            None => Err(self.closed_error(id).await),
+        }
+    }
+
+    /// Error for a transaction that can't be reached (anymore). The transaction task may also
+    /// finish between looking up the transaction and sending to it, e.g. because it expired.
+    /// Expired transactions are forgotten once their expiry was reported.
+    async fn closed_error(&self, id: &TxId) -> CoreError {
+        let mut transactions = self.transactions.lock().await;
+
+        let error = match transactions.remove(id) {
+            Some(TxState::Expired { timeout_ms }) => TransactionError::Expired {
+                id: id.clone(),
+                timeout_ms,
+            },
+            Some(state @ TxState::Open(_)) => {
+                transactions.insert(id.clone(), state);
+                TransactionError::Closed(id.clone())
+            }
+            None => TransactionError::NotFound(id.clone()),
+        };
+
+        error.into()
+    }
+}
+
+/// Owns the connection and the transaction on it, processing messages until the
+/// transaction is closed or expired.
+async fn run_transaction(
+    id: TxId,
+    conn: Box<dyn Connection>,
+    timeout: Duration,
+    mut receiver: mpsc::Receiver<TxMessage>,
+    started: oneshot::Sender<crate::Result<()>>,
+    transactions: Arc<Mutex<HashMap<TxId, TxState>>>,
+) {
+    let tx = match conn.start_transaction().await {
+        Ok(tx) => {
+            let _ = started.send(Ok(()));
+            tx
+        }
+        Err(err) => {
+            let _ = started.send(Err(err.into()));
+            return;
+        }
+    };
+
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        match time::timeout_at(deadline, receiver.recv()).await {
+            Ok(Some(TxMessage::Execute {
+                operation,
+                query_schema,
+                respond,
+            })) => {
+                let result = match QueryGraphBuilder::new(query_schema).build(operation) {
+                    Ok((query, serializer)) => {
+                        let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
+                        QueryPipeline::new(query, interpreter, serializer).execute().await
+                    }
+                    Err(err) => Err(err.into()),
+                };
+
+                let _ = respond.send(result);
+            }
+
+            Ok(Some(TxMessage::Commit(respond))) => {
+                transactions.lock().await.remove(&id);
+                let _ = respond.send(tx.commit().await.map_err(CoreError::from));
+                break;
+            }
+
+            Ok(Some(TxMessage::Rollback(respond))) => {
+                transactions.lock().await.remove(&id);
+                let _ = respond.send(tx.rollback().await.map_err(CoreError::from));
+                break;
+            }
+
+            // All senders are gone, the transaction can't be closed explicitly anymore.
+            Ok(None) => {
+                let _ = tx.rollback().await;
+                break;
+            }
+
+            Err(_) => {
+                let timeout_ms = timeout.as_millis() as u64;
+                warn!("Transaction {} expired after {}ms, rolling back.", id, timeout_ms);
+
+                transactions
+                    .lock()
+                    .await
+                    .insert(id.clone(), TxState::Expired { timeout_ms });
+
+                let _ = tx.rollback().await;
+
+                // Forget the transaction after a while, even if its expiry is never reported.
+                tokio::spawn(async move {
+                    time::delay_for(EXPIRED_TRANSACTION_TTL).await;
+
+                    let mut transactions = transactions.lock().await;
+
+                    if let Some(TxState::Expired { .. }) = transactions.get(&id) {
+                        transactions.remove(&id);
+                    }
+                });
+
+                break;
+            }
+        }
+    }
+}
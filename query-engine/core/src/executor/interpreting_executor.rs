@@ -1,8 +1,9 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
+use super::{pipeline::QueryPipeline, QueryExecutor, TransactionManager, TxId};
 use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData};
 use async_trait::async_trait;
 use connector::{Connection, ConnectionLike, Connector};
 use futures::future;
+use std::time::Duration;
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
@@ -12,6 +13,9 @@ pub struct InterpretingExecutor<C> {
     /// Flag that forces individual operations to run in a transaction.
     /// Does _not_ force batches to use transactions.
     force_transactions: bool,
+
+    /// Open interactive transactions, spanning multiple requests.
+    transactions: TransactionManager,
 }
 
 impl<C> InterpretingExecutor<C>
@@ -22,6 +26,7 @@ where
         InterpretingExecutor {
             connector,
             force_transactions,
+            transactions: TransactionManager::new(),
         }
     }
 
@@ -136,6 +141,34 @@ where
         Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone()).await
     }
 
+    async fn start_tx(&self, timeout: Duration) -> crate::Result<TxId> {
+        if !feature_flags::get().interactiveTransactions {
+            return Err(crate::CoreError::UnsupportedFeatureError(
+                "Interactive transactions (experimental feature, needs to be enabled).".to_owned(),
+            ));
+        }
+
+        let conn = self.connector.get_connection().await?;
+        self.transactions.start(conn, timeout).await
+    }
+
+    async fn execute_in_tx(
+        &self,
+        tx_id: &TxId,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<ResponseData> {
+        self.transactions.execute(tx_id, operation, query_schema).await
+    }
+
+    async fn commit_tx(&self, tx_id: &TxId) -> crate::Result<()> {
+        self.transactions.commit(tx_id).await
+    }
+
+    async fn rollback_tx(&self, tx_id: &TxId) -> crate::Result<()> {
+        self.transactions.rollback(tx_id).await
+    }
+
     fn primary_connector(&self) -> &dyn Connector {
         &self.connector
     }
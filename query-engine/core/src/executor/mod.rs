@@ -5,14 +5,17 @@
 //!
 //! What the executor module DOES NOT DO:
 //! - Define low level execution of queries. This is considered an implementation detail of the modules used by the executors.
+mod interactive_transactions;
 mod interpreting_executor;
 mod pipeline;
 
+pub use interactive_transactions::*;
 pub use interpreting_executor::*;
 
 use crate::{query_document::Operation, response_ir::ResponseData, schema::QuerySchemaRef};
 use async_trait::async_trait;
 use connector::Connector;
+use std::time::Duration;
 
 #[async_trait]
 pub trait QueryExecutor {
@@ -27,5 +30,23 @@ pub trait QueryExecutor {
         query_schema: QuerySchemaRef,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>>;
 
+    /// Starts an interactive transaction that stays open across requests until it is committed,
+    /// rolled back, or the `timeout` elapsed. Returns the id to refer to the transaction.
+    async fn start_tx(&self, timeout: Duration) -> crate::Result<TxId>;
+
+    /// Executes a single operation inside of the open interactive transaction with the given id.
+    async fn execute_in_tx(
+        &self,
+        tx_id: &TxId,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<ResponseData>;
+
+    /// Commits the interactive transaction with the given id.
+    async fn commit_tx(&self, tx_id: &TxId) -> crate::Result<()>;
+
+    /// Rolls back the interactive transaction with the given id.
+    async fn rollback_tx(&self, tx_id: &TxId) -> crate::Result<()>;
+
     fn primary_connector(&self) -> &dyn Connector;
 }
@@ -3,7 +3,7 @@ use crate::{interpreter::InterpretationResult, query_ast::*};
 use connector::{self, filter::Filter, ConnectionLike, QueryArguments, ReadOperations, ScalarCompare};
 use prisma_models::{ManyRecords, ModelProjection, Record, RecordProjection, RelationFieldRef};
 use prisma_value::PrismaValue;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub async fn m2m<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
@@ -227,3 +227,48 @@ pub async fn one2m<'a, 'b>(
 
     Ok(processor.apply(scalars))
 }
+
+/// Counts the related records of every relation of the query for all parent records.
+/// Returns the primary IDs of the parents with their counts in the order of the query,
+/// parents without related records have a count of zero.
+pub async fn count_related<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: &CountRelatedRecordsQuery,
+    parent_result: &'a ManyRecords,
+) -> InterpretationResult<Vec<(RecordProjection, Vec<usize>)>> {
+    let parent_model_id = query.model.primary_identifier();
+    let mut counts: Vec<(RecordProjection, Vec<usize>)> = parent_result
+        .projections(&parent_model_id)?
+        .into_iter()
+        .map(|id| (id, Vec::with_capacity(query.counts.len())))
+        .collect();
+
+    for count in query.counts.iter() {
+        let parent_links = parent_result.projections(&count.field.linking_fields())?;
+
+        // Only unique, non-null links need to be counted.
+        let mut seen_links = HashSet::new();
+        let uniq_links: Vec<RecordProjection> = parent_links
+            .iter()
+            .filter(|link| !link.values().any(|v| v.is_null()) && seen_links.insert(*link))
+            .cloned()
+            .collect();
+
+        let count_mapping: HashMap<Vec<PrismaValue>, usize> = if uniq_links.is_empty() {
+            HashMap::new()
+        } else {
+            tx.count_related_records(&count.field, &uniq_links, count.filter.clone())
+                .await?
+                .into_iter()
+                .map(|(link, count)| (link.values().collect(), count))
+                .collect()
+        };
+
+        for ((_, record_counts), link) in counts.iter_mut().zip(parent_links) {
+            let link_values: Vec<PrismaValue> = link.values().collect();
+            record_counts.push(count_mapping.get(&link_values).copied().unwrap_or(0));
+        }
+    }
+
+    Ok(counts)
+}
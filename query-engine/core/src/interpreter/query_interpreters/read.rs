@@ -16,6 +16,8 @@ pub fn execute<'a, 'b>(
             ReadQuery::ManyRecordsQuery(q) => read_many(tx, q).await,
             ReadQuery::RelatedRecordsQuery(q) => read_related(tx, q, parent_result).await,
             ReadQuery::AggregateRecordsQuery(q) => aggregate(tx, q).await,
+            ReadQuery::GroupByRecordsQuery(q) => group_by(tx, q).await,
+            ReadQuery::CountRelatedRecordsQuery(q) => count_related(tx, q, parent_result).await,
         }
     };
 
@@ -162,6 +164,45 @@ async fn aggregate<'a, 'b>(
     }))
 }
 
+async fn group_by<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: GroupByRecordsQuery,
+) -> InterpretationResult<QueryResult> {
+    let selection_order = query.selection_order;
+    let results = tx
+        .group_by_records(
+            &query.model,
+            query.group_by,
+            query.aggregators,
+            query.args,
+            query.having,
+        )
+        .await?;
+
+    Ok(QueryResult::RecordAggregations(RecordAggregations {
+        selection_order,
+        results,
+    }))
+}
+
+/// Counts the related records of the parent records, with one grouped query per relation.
+async fn count_related<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: CountRelatedRecordsQuery,
+    parent_result: Option<&'a ManyRecords>,
+) -> InterpretationResult<QueryResult> {
+    let parent_result = parent_result
+        .expect("[ID retrieval] No parent results present in the query graph for counting related records.");
+
+    let counts = nested_read::count_related(tx, &query, parent_result).await?;
+
+    Ok(QueryResult::RelationCounts(RelationCounts {
+        name: query.name,
+        fields: query.counts.into_iter().map(|count| count.name).collect(),
+        counts,
+    }))
+}
+
 fn process_nested<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     nested: Vec<ReadQuery>,
@@ -12,6 +12,7 @@ pub async fn execute<'a, 'b>(
 ) -> InterpretationResult<QueryResult> {
     match write_query {
         WriteQuery::CreateRecord(q) => create_one(tx, q).await,
+        WriteQuery::CreateManyRecords(q) => create_many(tx, q).await,
         WriteQuery::UpdateRecord(q) => update_one(tx, q).await,
         WriteQuery::DeleteRecord(q) => delete_one(tx, q).await,
         WriteQuery::UpdateManyRecords(q) => update_many(tx, q).await,
@@ -55,6 +56,15 @@ async fn create_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: CreateRecord) ->
     Ok(QueryResult::Id(Some(res)))
 }
 
+async fn create_many<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: CreateManyRecords,
+) -> InterpretationResult<QueryResult> {
+    let res = tx.create_records(&q.model, q.args, q.skip_duplicates).await?;
+
+    Ok(QueryResult::Count(res))
+}
+
 async fn update_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: UpdateRecord) -> InterpretationResult<QueryResult> {
     let mut res = tx.update_records(&q.model, q.record_filter, q.args).await?;
 
@@ -10,6 +10,8 @@ pub enum ReadQuery {
     ManyRecordsQuery(ManyRecordsQuery),
     RelatedRecordsQuery(RelatedRecordsQuery),
     AggregateRecordsQuery(AggregateRecordsQuery),
+    GroupByRecordsQuery(GroupByRecordsQuery),
+    CountRelatedRecordsQuery(CountRelatedRecordsQuery),
 }
 
 impl ReadQuery {
@@ -19,6 +21,8 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => &x.name,
             ReadQuery::RelatedRecordsQuery(x) => &x.name,
             ReadQuery::AggregateRecordsQuery(x) => &x.name,
+            ReadQuery::GroupByRecordsQuery(x) => &x.name,
+            ReadQuery::CountRelatedRecordsQuery(x) => &x.name,
         }
     }
 
@@ -30,6 +34,8 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::RelatedRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::AggregateRecordsQuery(_x) => false,
+            ReadQuery::GroupByRecordsQuery(_x) => false,
+            ReadQuery::CountRelatedRecordsQuery(_x) => false,
         }
     }
 
@@ -39,6 +45,8 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => x.model.clone(),
             ReadQuery::RelatedRecordsQuery(x) => x.parent_field.related_field().model().clone(),
             ReadQuery::AggregateRecordsQuery(x) => x.model.clone(),
+            ReadQuery::GroupByRecordsQuery(x) => x.model.clone(),
+            ReadQuery::CountRelatedRecordsQuery(x) => x.model.clone(),
         }
     }
 }
@@ -88,6 +96,20 @@ impl Display for ReadQuery {
                 q.selected_fields.names().collect::<Vec<_>>()
             ),
             Self::AggregateRecordsQuery(q) => write!(f, "AggregateRecordsQuery: {}", q.name),
+            Self::GroupByRecordsQuery(q) => write!(
+                f,
+                "GroupByRecordsQuery(name: '{}', model: {}, by: {:?})",
+                q.name,
+                q.model.name,
+                q.group_by.iter().map(|f| f.name.as_str()).collect::<Vec<_>>()
+            ),
+            Self::CountRelatedRecordsQuery(q) => write!(
+                f,
+                "CountRelatedRecordsQuery(name: '{}', model: {}, relations: {:?})",
+                q.name,
+                q.model.name,
+                q.counts.iter().map(|c| c.field.name.as_str()).collect::<Vec<_>>()
+            ),
         }
     }
 }
@@ -139,6 +161,45 @@ pub struct AggregateRecordsQuery {
     pub aggregators: Vec<Aggregator>,
 }
 
+#[derive(Debug, Clone)]
+pub struct GroupByRecordsQuery {
+    pub name: String,
+    pub alias: Option<String>,
+    pub model: ModelRef,
+    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+    pub args: QueryArguments,
+    pub group_by: Vec<ScalarFieldRef>,
+    pub aggregators: Vec<Aggregator>,
+
+    /// Filter applied to the groups, may contain aggregation filters.
+    pub having: Option<Filter>,
+}
+
+/// Counts the related records of to-many relations for all parent records (`_count` selection).
+#[derive(Debug, Clone)]
+pub struct CountRelatedRecordsQuery {
+    pub name: String,
+    pub alias: Option<String>,
+
+    /// The model of the parent records.
+    pub model: ModelRef,
+
+    /// The relations to count, in the order of the query selection.
+    pub counts: Vec<RelationCount>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RelationCount {
+    /// Name of the count in the response, the alias or name of the relation field.
+    pub name: String,
+
+    /// The to-many relation field on the parent model.
+    pub field: RelationFieldRef,
+
+    /// Only related records matching the filter are counted.
+    pub filter: Option<Filter>,
+}
+
 impl FilteredQuery for RecordQuery {
     fn get_filter(&mut self) -> Option<&mut Filter> {
         self.filter.as_mut()
@@ -233,6 +233,13 @@ impl QueryDocumentParser {
             (QueryValue::String(s), ScalarType::UUID) => {
                 Self::parse_uuid(parent_path, s.as_str()).map(PrismaValue::Uuid)
             }
+            (QueryValue::String(s), ScalarType::BigInt) => {
+                Self::parse_bigint(parent_path, s.as_str()).map(PrismaValue::BigInt)
+            }
+            (QueryValue::String(s), ScalarType::Bytes) => {
+                Self::parse_bytes(parent_path, s.as_str()).map(PrismaValue::Bytes)
+            }
+            (QueryValue::Int(i), ScalarType::BigInt) => Ok(PrismaValue::BigInt(i)),
             (QueryValue::Int(i), ScalarType::Float) => Ok(PrismaValue::Float(Decimal::from(i))),
             (QueryValue::Int(i), ScalarType::Int) => Ok(PrismaValue::Int(i)),
             (QueryValue::Float(f), ScalarType::Float) => Ok(PrismaValue::Float(f)),
@@ -300,6 +307,20 @@ impl QueryDocumentParser {
         })
     }
 
+    pub fn parse_bigint(path: &QueryPath, s: &str) -> QueryParserResult<i64> {
+        s.parse::<i64>().map_err(|err| QueryParserError {
+            path: path.clone(),
+            error_kind: QueryParserErrorKind::ValueParseError(format!("Invalid BigInt: {}", err)),
+        })
+    }
+
+    pub fn parse_bytes(path: &QueryPath, s: &str) -> QueryParserResult<Vec<u8>> {
+        prisma_value::decode_bytes(s).map_err(|err| QueryParserError {
+            path: path.clone(),
+            error_kind: QueryParserErrorKind::ValueParseError(format!("Invalid Bytes: {}", err)),
+        })
+    }
+
     pub fn parse_list(
         path: &QueryPath,
         values: Vec<QueryValue>,
@@ -55,6 +55,8 @@ impl From<PrismaValue> for QueryValue {
             PrismaValue::Null => Self::Null,
             PrismaValue::Uuid(u) => Self::String(u.to_hyphenated().to_string()),
             PrismaValue::Json(s) => Self::String(s),
+            PrismaValue::BigInt(i) => Self::String(i.to_string()),
+            PrismaValue::Bytes(b) => Self::String(prisma_value::encode_bytes(&b)),
         }
     }
 }
@@ -33,6 +33,42 @@ impl TryInto<PrismaValue> for ParsedInputValue {
     }
 }
 
+/// Composite type values are stored inline as Json. This converts a parsed composite object,
+/// or a list of them, into the Json document that gets stored.
+impl TryInto<serde_json::Value> for ParsedInputValue {
+    type Error = QueryParserError;
+
+    fn try_into(self) -> QueryParserResult<serde_json::Value> {
+        match self {
+            ParsedInputValue::Single(val) => serde_json::to_value(val).map_err(|err| QueryParserError {
+                path: QueryPath::default(),
+                error_kind: QueryParserErrorKind::AssertionError(format!(
+                    "Attempted conversion of a value into Json failed: {}",
+                    err
+                )),
+            }),
+            ParsedInputValue::List(values) => values
+                .into_iter()
+                .map(|val| val.try_into())
+                .collect::<QueryParserResult<Vec<serde_json::Value>>>()
+                .map(serde_json::Value::Array),
+            ParsedInputValue::Map(map) => map
+                .into_iter()
+                .map(|(key, val)| val.try_into().map(|val| (key, val)))
+                .collect::<QueryParserResult<serde_json::Map<String, serde_json::Value>>>()
+                .map(serde_json::Value::Object),
+
+            v => Err(QueryParserError {
+                path: QueryPath::default(),
+                error_kind: QueryParserErrorKind::AssertionError(format!(
+                    "Attempted conversion of ParsedInputValue ({:?}) into Json failed.",
+                    v
+                )),
+            }),
+        }
+    }
+}
+
 impl TryInto<ParsedInputMap> for ParsedInputValue {
     type Error = QueryParserError;
 
@@ -9,7 +9,7 @@ use crate::{
 };
 use connector::{filter::Filter, QueryMode, RelationCompare, ScalarCompare};
 use filter_grouping::*;
-use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
+use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef, TypeIdentifier};
 use std::{convert::TryInto, str::FromStr};
 
 /// Extracts a filter for a unique selector, i.e. a filter that selects exactly one record.
@@ -95,6 +95,38 @@ pub fn extract_filter(value_map: ParsedInputMap, model: &ModelRef) -> QueryGraph
 fn extract_scalar_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<Filter>> {
     match value {
         ParsedInputValue::Single(pv) => Ok(vec![field.equals(pv)]),
+        ParsedInputValue::Map(filter_map) if field.is_composite() => filter_map
+            .into_iter()
+            .map(|(k, v)| {
+                let value = match v {
+                    ParsedInputValue::Single(PrismaValue::Null) => PrismaValue::Null,
+                    _ => {
+                        let json: serde_json::Value = v.try_into()?;
+                        PrismaValue::Json(json.to_string())
+                    }
+                };
+
+                match k.as_str() {
+                    "equals" => Ok(field.equals(value)),
+                    "not" => Ok(field.not_equals(value)),
+                    _ => Err(QueryGraphBuilderError::InputError(format!(
+                        "{} is not a valid composite type filter operation",
+                        k
+                    ))),
+                }
+            })
+            .collect::<QueryGraphBuilderResult<Vec<_>>>(),
+        ParsedInputValue::Map(mut filter_map) if field.type_identifier == TypeIdentifier::Json => {
+            let path = match filter_map.remove("path") {
+                Some(path) => Some(scalar::parse_json_path(path)?),
+                None => None,
+            };
+
+            filter_map
+                .into_iter()
+                .map(|(k, v)| scalar::parse_json(&k, field, v, path.clone()))
+                .collect::<QueryGraphBuilderResult<Vec<_>>>()
+        }
         ParsedInputValue::Map(mut filter_map) => {
             let mode = match filter_map.remove("mode") {
                 Some(i) => parse_query_mode(i)?,
@@ -1,5 +1,7 @@
 use crate::{ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult};
-use connector::{Filter, ScalarCompare};
+use connector::{
+    AggregationFilter, Filter, JsonCompare, JsonFilterPath, JsonTargetType, ScalarCompare, ScalarCondition,
+};
 use prisma_models::{PrismaValue, ScalarFieldRef};
 use std::convert::TryInto;
 
@@ -74,6 +76,13 @@ pub fn parse(
         "lte" => field.less_than_or_equals(as_prisma_value(input)?),
         "gte" => field.greater_than_or_equals(as_prisma_value(input)?),
 
+        // Aggregation filters are only available on `having` filters, validation guarantees this.
+        "count" => aggregation_filter(field, input, reverse, AggregationFilter::Count)?,
+        "avg" => aggregation_filter(field, input, reverse, AggregationFilter::Average)?,
+        "sum" => aggregation_filter(field, input, reverse, AggregationFilter::Sum)?,
+        "min" => aggregation_filter(field, input, reverse, AggregationFilter::Min)?,
+        "max" => aggregation_filter(field, input, reverse, AggregationFilter::Max)?,
+
         _ => Err(QueryGraphBuilderError::InputError(format!(
             "{} is not a valid scalar filter operation",
             filter_key
@@ -83,6 +92,98 @@ pub fn parse(
     Ok(filter)
 }
 
+/// Parses a filter operation on a JSON field. If a path is given, the operation
+/// applies to the nested value at that path instead of the whole document.
+pub fn parse_json(
+    filter_key: &str,
+    field: &ScalarFieldRef,
+    input: ParsedInputValue,
+    path: Option<JsonFilterPath>,
+) -> QueryGraphBuilderResult<Filter> {
+    let (condition, target_type) = match filter_key {
+        "string_contains" => (
+            ScalarCondition::Contains(as_prisma_value(input)?),
+            Some(JsonTargetType::String),
+        ),
+        "string_starts_with" => (
+            ScalarCondition::StartsWith(as_prisma_value(input)?),
+            Some(JsonTargetType::String),
+        ),
+        "array_contains" => (
+            ScalarCondition::Contains(as_prisma_value(input)?),
+            Some(JsonTargetType::Array),
+        ),
+        "array_starts_with" => (
+            ScalarCondition::StartsWith(as_prisma_value(input)?),
+            Some(JsonTargetType::Array),
+        ),
+
+        // Without a path, regular operations compare the whole document.
+        _ if path.is_none() => return parse(filter_key, field, input, false),
+
+        "equals" => (ScalarCondition::Equals(as_prisma_value(input)?), None),
+        "not" => (ScalarCondition::NotEquals(as_prisma_value(input)?), None),
+        "lt" => (ScalarCondition::LessThan(as_prisma_value(input)?), None),
+        "lte" => (ScalarCondition::LessThanOrEquals(as_prisma_value(input)?), None),
+        "gt" => (ScalarCondition::GreaterThan(as_prisma_value(input)?), None),
+        "gte" => (ScalarCondition::GreaterThanOrEquals(as_prisma_value(input)?), None),
+
+        _ => return parse(filter_key, field, input, false),
+    };
+
+    if target_type == Some(JsonTargetType::String) && path.is_none() {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "The JSON filter operation {} requires a path to a string value.",
+            filter_key
+        )));
+    }
+
+    Ok(field.json_compare(condition, path, target_type))
+}
+
+/// Parses the path of a JSON filter, either a JSON path string (`$.a.b`) or a list of keys (`["a", "b"]`),
+/// depending on what the connector supports.
+pub fn parse_json_path(input: ParsedInputValue) -> QueryGraphBuilderResult<JsonFilterPath> {
+    match as_prisma_value(input)? {
+        PrismaValue::String(path) => Ok(JsonFilterPath::String(path)),
+        PrismaValue::List(keys) => {
+            let keys = keys
+                .into_iter()
+                .map(|key| {
+                    key.into_string().ok_or_else(|| {
+                        QueryGraphBuilderError::InputError("JSON filter path keys must be strings.".to_owned())
+                    })
+                })
+                .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+            Ok(JsonFilterPath::Array(keys))
+        }
+        x => Err(QueryGraphBuilderError::InputError(format!(
+            "Invalid JSON filter path: {:?}",
+            x
+        ))),
+    }
+}
+
+fn aggregation_filter<F>(
+    field: &ScalarFieldRef,
+    input: ParsedInputValue,
+    reverse: bool,
+    func: F,
+) -> QueryGraphBuilderResult<Filter>
+where
+    F: Fn(Box<Filter>) -> AggregationFilter,
+{
+    let inner_object: ParsedInputMap = input.try_into()?;
+
+    let filters = inner_object
+        .into_iter()
+        .map(|(k, v)| parse(&k, field, v, reverse))
+        .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+    Ok(Filter::from(func(Box::new(Filter::and(filters)))))
+}
+
 fn as_prisma_value(input: ParsedInputValue) -> QueryGraphBuilderResult<PrismaValue> {
     Ok(input.try_into()?)
 }
@@ -5,7 +5,8 @@ use crate::{
 };
 use connector::QueryArguments;
 use prisma_models::{
-    Field, ModelProjection, ModelRef, OrderBy, PrismaValue, RecordProjection, ScalarFieldRef, SortOrder,
+    Field, ModelProjection, ModelRef, OrderBy, PrismaValue, RecordProjection, RelationFieldRef, ScalarFieldRef,
+    SortOrder,
 };
 use std::convert::{identity, TryInto};
 
@@ -72,26 +73,12 @@ fn extract_order_by(model: &ModelRef, value: ParsedInputValue) -> QueryGraphBuil
             .into_iter()
             .map(|list_value| {
                 let object: ParsedInputMap = list_value.try_into()?;
-
-                match object.into_iter().next() {
-                    None => Ok(None),
-                    Some((field_name, sort_order)) => {
-                        let field = model.fields().find_from_scalar(&field_name)?;
-                        let value: PrismaValue = sort_order.try_into()?;
-                        let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
-                            "asc" => SortOrder::Ascending,
-                            "desc" => SortOrder::Descending,
-                            _ => unreachable!(),
-                        };
-
-                        Ok(Some(OrderBy::new(field, sort_order)))
-                    }
-                }
+                process_order_object(model, object, vec![])
             })
             .collect::<QueryGraphBuilderResult<Vec<_>>>()
             .map(|results| results.into_iter().filter_map(identity).collect()),
 
-        ParsedInputValue::Map(map) => Ok(match process_order_object(model, map)? {
+        ParsedInputValue::Map(map) => Ok(match process_order_object(model, map, vec![])? {
             Some(order) => vec![order],
             None => vec![],
         }),
@@ -100,22 +87,47 @@ fn extract_order_by(model: &ModelRef, value: ParsedInputValue) -> QueryGraphBuil
     }
 }
 
-fn process_order_object(model: &ModelRef, object: ParsedInputMap) -> QueryGraphBuilderResult<Option<OrderBy>> {
-    // let object: ParsedInputMap = list_value.try_into()?;
-
+/// Processes a single order object, e.g. `{ name: asc }`. Relation fields nest further order objects:
+/// To-one relations order by the fields of the related model (`{ author: { name: asc } }`),
+/// to-many relations by the aggregated related records (`{ posts: { count: desc } }`).
+/// `path` holds the relation fields traversed so far.
+fn process_order_object(
+    model: &ModelRef,
+    object: ParsedInputMap,
+    mut path: Vec<RelationFieldRef>,
+) -> QueryGraphBuilderResult<Option<OrderBy>> {
     match object.into_iter().next() {
         None => Ok(None),
-        Some((field_name, sort_order)) => {
-            let field = model.fields().find_from_scalar(&field_name)?;
-            let value: PrismaValue = sort_order.try_into()?;
-            let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
-                "asc" => SortOrder::Ascending,
-                "desc" => SortOrder::Descending,
-                _ => unreachable!(),
-            };
-
-            Ok(Some(OrderBy::new(field, sort_order)))
-        }
+        Some((field_name, value)) => match model.fields().find_from_all(&field_name)? {
+            Field::Scalar(sf) => Ok(Some(OrderBy::with_path(sf.clone(), path, extract_sort_order(value)?))),
+
+            Field::Relation(rf) if rf.is_list => {
+                let object: ParsedInputMap = value.try_into()?;
+                path.push(rf.clone());
+
+                match object.into_iter().next() {
+                    None => Ok(None),
+                    Some((_, sort_order)) => Ok(Some(OrderBy::to_many_count(path, extract_sort_order(sort_order)?))),
+                }
+            }
+
+            Field::Relation(rf) => {
+                let object: ParsedInputMap = value.try_into()?;
+                path.push(rf.clone());
+
+                process_order_object(&rf.related_model(), object, path)
+            }
+        },
+    }
+}
+
+fn extract_sort_order(value: ParsedInputValue) -> QueryGraphBuilderResult<SortOrder> {
+    let value: PrismaValue = value.try_into()?;
+
+    match value.into_string().unwrap().to_lowercase().as_str() {
+        "asc" => Ok(SortOrder::Ascending),
+        "desc" => Ok(SortOrder::Descending),
+        _ => unreachable!(),
     }
 }
 
@@ -27,7 +27,7 @@ impl AggregateRecordsBuilder {
         Ok(query)
     }
 
-    fn resolve_fields(model: &ModelRef, field: ParsedField) -> Vec<ScalarFieldRef> {
+    pub(super) fn resolve_fields(model: &ModelRef, field: ParsedField) -> Vec<ScalarFieldRef> {
         let fields = field.nested_fields.unwrap().fields;
         let scalars = model.fields().scalar();
 
@@ -42,7 +42,7 @@ impl AggregateRecordsBuilder {
             .collect()
     }
 
-    fn collect_selection_tree(fields: &[ParsedField]) -> Vec<(String, Option<Vec<String>>)> {
+    pub(super) fn collect_selection_tree(fields: &[ParsedField]) -> Vec<(String, Option<Vec<String>>)> {
         fields
             .into_iter()
             .map(|field| {
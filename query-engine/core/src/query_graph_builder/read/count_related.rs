@@ -0,0 +1,59 @@
+use super::*;
+use crate::{query_document::*, CountRelatedRecordsQuery, ReadQuery, RelationCount};
+use prisma_models::ModelRef;
+use std::convert::TryInto;
+
+pub struct CountRelatedRecordsBuilder {
+    /// The model of the parent records.
+    model: ModelRef,
+
+    /// The `_count` field as parsed field in the query document.
+    field: ParsedField,
+}
+
+impl CountRelatedRecordsBuilder {
+    pub fn new(model: ModelRef, field: ParsedField) -> Self {
+        Self { model, field }
+    }
+}
+
+impl Builder<ReadQuery> for CountRelatedRecordsBuilder {
+    /// Unwraps are safe because of query validation that ensures conformity to the query schema.
+    fn build(self) -> QueryGraphBuilderResult<ReadQuery> {
+        let name = self.field.name;
+        let alias = self.field.alias;
+        let model = self.model;
+
+        let counts = self
+            .field
+            .nested_fields
+            .unwrap()
+            .fields
+            .into_iter()
+            .map(|mut count_field| {
+                let field = model.fields().find_from_relation_fields(&count_field.name).unwrap();
+
+                let filter = match count_field.arguments.lookup("where") {
+                    Some(where_arg) => {
+                        let arg: ParsedInputMap = where_arg.value.try_into()?;
+                        Some(extractors::extract_filter(arg, &field.related_model())?)
+                    }
+                    None => None,
+                };
+
+                Ok(RelationCount {
+                    name: count_field.alias.unwrap_or(count_field.name),
+                    field,
+                    filter,
+                })
+            })
+            .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+        Ok(ReadQuery::CountRelatedRecordsQuery(CountRelatedRecordsQuery {
+            name,
+            alias,
+            model,
+            counts,
+        }))
+    }
+}
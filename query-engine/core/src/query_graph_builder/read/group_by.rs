@@ -0,0 +1,147 @@
+use super::*;
+use crate::{
+    query_document::{ParsedArgument, ParsedField, ParsedInputMap, ParsedInputValue},
+    GroupByRecordsQuery, ReadQuery,
+};
+use connector::{Aggregator, Filter, QueryArguments};
+use prisma_models::{ModelRef, ScalarFieldRef};
+use std::convert::TryInto;
+
+pub struct GroupByRecordsBuilder {
+    field: ParsedField,
+    model: ModelRef,
+}
+
+impl GroupByRecordsBuilder {
+    pub fn new(field: ParsedField, model: ModelRef) -> Self {
+        Self { field, model }
+    }
+
+    /// Resolves the given field either as a grouped scalar field or as an aggregation.
+    /// Returns `None` for grouped scalar fields, as they are returned with every group anyways.
+    fn resolve_query(field: ParsedField, model: &ModelRef) -> Option<Aggregator> {
+        match field.name.as_str() {
+            "count" => Some(Aggregator::Count),
+            "avg" => Some(Aggregator::Average(AggregateRecordsBuilder::resolve_fields(
+                model, field,
+            ))),
+            "sum" => Some(Aggregator::Sum(AggregateRecordsBuilder::resolve_fields(model, field))),
+            "min" => Some(Aggregator::Min(AggregateRecordsBuilder::resolve_fields(model, field))),
+            "max" => Some(Aggregator::Max(AggregateRecordsBuilder::resolve_fields(model, field))),
+            _ => None,
+        }
+    }
+
+    fn extract_group_by(arguments: &mut Vec<ParsedArgument>) -> QueryGraphBuilderResult<Vec<ScalarFieldRef>> {
+        let position = arguments.iter().position(|arg| arg.name == "by");
+        let by_arg = position.map(|pos| arguments.remove(pos)).ok_or_else(|| {
+            QueryGraphBuilderError::InputError("Missing required argument 'by' for group by query.".to_owned())
+        })?;
+
+        match by_arg.value {
+            ParsedInputValue::List(list) => list
+                .into_iter()
+                .map(|element| Ok(element.try_into()?))
+                .collect::<QueryGraphBuilderResult<Vec<ScalarFieldRef>>>(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn extract_having(
+        arguments: &mut Vec<ParsedArgument>,
+        model: &ModelRef,
+    ) -> QueryGraphBuilderResult<Option<Filter>> {
+        match arguments.iter().position(|arg| arg.name == "having") {
+            Some(pos) => {
+                let having_arg = arguments.remove(pos);
+                let val: Option<ParsedInputMap> = having_arg.value.try_into()?;
+
+                match val {
+                    Some(m) => Ok(Some(extractors::extract_filter(m, model)?)),
+                    None => Ok(None),
+                }
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Every selected scalar field and every field used for ordering needs to be part of the `by` argument,
+    /// as there is no single value per group for the other fields.
+    fn verify_selections(
+        group_by: &[ScalarFieldRef],
+        selection_order: &[(String, Option<Vec<String>>)],
+        args: &QueryArguments,
+    ) -> QueryGraphBuilderResult<()> {
+        let grouped_names: Vec<&str> = group_by.iter().map(|f| f.name.as_str()).collect();
+
+        let missing_selections: Vec<&str> = selection_order
+            .iter()
+            .filter(|(name, nested)| nested.is_none() && name != "count" && !grouped_names.contains(&name.as_str()))
+            .map(|(name, _)| name.as_str())
+            .collect();
+
+        if !missing_selections.is_empty() {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Every selected scalar field that is not part of an aggregation must be included in the by-arguments of the query. Missing fields: {}",
+                missing_selections.join(", ")
+            )));
+        }
+
+        let missing_orderings: Vec<&str> = args
+            .order_by
+            .iter()
+            .filter_map(|o| o.field.as_ref())
+            .filter(|field| !grouped_names.contains(&field.name.as_str()))
+            .map(|field| field.name.as_str())
+            .collect();
+
+        if !missing_orderings.is_empty() {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Every field used for orderBy must be included in the by-arguments of the query. Missing fields: {}",
+                missing_orderings.join(", ")
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Builder<ReadQuery> for GroupByRecordsBuilder {
+    fn build(self) -> QueryGraphBuilderResult<ReadQuery> {
+        let name = self.field.name;
+        let alias = self.field.alias;
+        let model = self.model;
+        let mut arguments = self.field.arguments;
+
+        let group_by = Self::extract_group_by(&mut arguments)?;
+        let having = Self::extract_having(&mut arguments, &model)?;
+        let has_explicit_ordering = arguments.iter().any(|arg| arg.name == "orderBy");
+        let mut args = extractors::extract_query_args(arguments, &model)?;
+
+        // Implicit orderings for pagination have to be based on the groups, not on the individual records.
+        if !has_explicit_ordering && !args.order_by.is_empty() {
+            args.order_by = group_by.iter().map(|field| field.clone().into()).collect();
+        }
+
+        let nested_fields = self.field.nested_fields.unwrap().fields;
+        let selection_order = AggregateRecordsBuilder::collect_selection_tree(&nested_fields);
+
+        Self::verify_selections(&group_by, &selection_order, &args)?;
+
+        let aggregators: Vec<_> = nested_fields
+            .into_iter()
+            .filter_map(|field| Self::resolve_query(field, &model))
+            .collect();
+
+        Ok(ReadQuery::GroupByRecordsQuery(GroupByRecordsQuery {
+            name,
+            alias,
+            model,
+            selection_order,
+            args,
+            group_by,
+            aggregators,
+            having,
+        }))
+    }
+}
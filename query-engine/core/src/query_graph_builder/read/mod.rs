@@ -1,11 +1,15 @@
 mod aggregate;
+mod count_related;
 mod first;
+mod group_by;
 mod many;
 mod one;
 mod related;
 
 pub use aggregate::*;
+pub use count_related::*;
 pub use first::*;
+pub use group_by::*;
 pub use many::*;
 pub use one::*;
 pub use related::*;
@@ -21,6 +25,8 @@ pub enum ReadQueryBuilder {
     ReadManyRecordsBuilder(ReadManyRecordsBuilder),
     ReadRelatedRecordsBuilder(ReadRelatedRecordsBuilder),
     AggregateRecordsBuilder(AggregateRecordsBuilder),
+    GroupByRecordsBuilder(GroupByRecordsBuilder),
+    CountRelatedRecordsBuilder(CountRelatedRecordsBuilder),
 }
 
 impl Builder<ReadQuery> for ReadQueryBuilder {
@@ -31,6 +37,8 @@ impl Builder<ReadQuery> for ReadQueryBuilder {
             ReadQueryBuilder::ReadManyRecordsBuilder(b) => b.build(),
             ReadQueryBuilder::ReadRelatedRecordsBuilder(b) => b.build(),
             ReadQueryBuilder::AggregateRecordsBuilder(b) => b.build(),
+            ReadQueryBuilder::GroupByRecordsBuilder(b) => b.build(),
+            ReadQueryBuilder::CountRelatedRecordsBuilder(b) => b.build(),
         }
     }
 }
@@ -70,6 +78,13 @@ pub fn collect_selected_fields(from: &[ParsedField], model: &ModelRef) -> ModelP
 pub fn collect_nested_queries(from: Vec<ParsedField>, model: &ModelRef) -> QueryGraphBuilderResult<Vec<ReadQuery>> {
     from.into_iter()
         .filter_map(|selected_field| {
+            // The virtual `_count` field isn't a field of the model.
+            if selected_field.name == "_count" {
+                return Some(ReadQueryBuilder::CountRelatedRecordsBuilder(
+                    CountRelatedRecordsBuilder::new(Arc::clone(model), selected_field),
+                ));
+            }
+
             let model_field = model.fields().find_from_all(&selected_field.name).unwrap();
             match model_field {
                 Field::Scalar(_) => None,
@@ -108,12 +123,10 @@ pub fn merge_relation_selections(
 
     let nested: Vec<_> = nested_queries
         .into_iter()
-        .map(|nested_query| {
-            if let ReadQuery::RelatedRecordsQuery(ref rq) = nested_query {
-                rq.parent_field.linking_fields()
-            } else {
-                unreachable!()
-            }
+        .flat_map(|nested_query| match nested_query {
+            ReadQuery::RelatedRecordsQuery(ref rq) => vec![rq.parent_field.linking_fields()],
+            ReadQuery::CountRelatedRecordsQuery(ref cq) => cq.counts.iter().map(|c| c.field.linking_fields()).collect(),
+            _ => unreachable!(),
         })
         .collect();
 
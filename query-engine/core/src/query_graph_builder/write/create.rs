@@ -2,7 +2,7 @@ use super::*;
 use crate::{
     query_ast::*,
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
-    ArgumentListLookup, ParsedField, ParsedInputMap, ReadOneRecordBuilder,
+    ArgumentListLookup, ParsedField, ParsedInputMap, ParsedInputValue, ReadOneRecordBuilder,
 };
 use connector::IdFilter;
 use prisma_models::ModelRef;
@@ -48,6 +48,44 @@ pub fn create_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     Ok(())
 }
 
+/// Creates a create many records query and adds it to the query graph.
+/// Only scalar values are accepted, nested writes are not supported for create many.
+pub fn create_many_records(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    mut field: ParsedField,
+) -> QueryGraphBuilderResult<()> {
+    let data_list: Vec<ParsedInputValue> = match field.arguments.lookup("data") {
+        Some(data) => data.value.try_into()?,
+        None => vec![],
+    };
+
+    let skip_duplicates: Option<bool> = match field.arguments.lookup("skipDuplicates") {
+        Some(arg) => arg.value.try_into()?,
+        None => None,
+    };
+
+    let args = data_list
+        .into_iter()
+        .map(|data_value| {
+            let data_map: ParsedInputMap = data_value.try_into()?;
+            let mut args = WriteArgsParser::from(&model, data_map)?.args;
+
+            args.add_datetimes(Arc::clone(&model));
+            Ok(args)
+        })
+        .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+    let query = CreateManyRecords {
+        model,
+        args,
+        skip_duplicates: skip_duplicates.unwrap_or(false),
+    };
+
+    graph.create_node(Query::Write(WriteQuery::CreateManyRecords(query)));
+    Ok(())
+}
+
 pub fn create_record_node(
     graph: &mut QueryGraph,
     model: ModelRef,
@@ -307,13 +307,18 @@ pub fn insert_deletion_checks(
     child_node: &NodeRef,
 ) -> QueryGraphBuilderResult<()> {
     let internal_model = model.internal_data_model();
-    let relation_fields = internal_model.fields_requiring_model(model);
+    let relation_fields: Vec<_> = internal_model
+        .fields_requiring_model(model)
+        .into_iter()
+        .filter(|rf| !rf.resolves_deletions_in_database())
+        .collect();
     let mut check_nodes = vec![];
 
     if relation_fields.len() > 0 {
         let noop_node = graph.create_node(Node::Empty);
 
         // We know that the relation can't be a list and must be required on the related model for `model` (see fields_requiring_model).
+        // Relations with an explicit database-side `onDelete` action are skipped, the database resolves those itself.
         // For all requiring models (RM), we use the field on `model` to query for existing RM records and error out if at least one exists.
         for rf in relation_fields {
             let relation_field = rf.related_field();
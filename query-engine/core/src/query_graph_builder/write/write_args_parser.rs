@@ -20,6 +20,18 @@ impl WriteArgsParser {
                 let field = model.fields().find_from_all(&k).unwrap();
 
                 match field {
+                    Field::Scalar(sf) if sf.is_composite() => {
+                        let value = match v {
+                            ParsedInputValue::Single(PrismaValue::Null) => PrismaValue::Null,
+                            _ => {
+                                let json: serde_json::Value = v.try_into()?;
+                                PrismaValue::Json(json.to_string())
+                            }
+                        };
+
+                        args.args.insert(sf, value)
+                    }
+
                     Field::Scalar(sf) if sf.is_list => {
                         let set_value: PrismaValue = match v {
                             ParsedInputValue::List(_) => v.try_into()?,
@@ -1,9 +1,10 @@
 use super::*;
 use crate::{
     schema::{IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType},
-    CoreError, EnumType, OutputFieldRef, QueryResult, RecordAggregation, RecordSelection,
+    CoreError, EnumType, OutputFieldRef, QueryResult, RecordAggregation, RecordAggregations, RecordSelection,
+    RelationCounts,
 };
-use connector::AggregationResult;
+use connector::{AggregationResult, AggregationRow};
 use indexmap::IndexMap;
 use prisma_models::{InternalEnum, PrismaValue, RecordProjection};
 use rust_decimal::prelude::ToPrimitive;
@@ -41,6 +42,8 @@ pub fn serialize_internal(
     match result {
         QueryResult::RecordSelection(rs) => serialize_record_selection(rs, field, &field.field_type, is_list),
         QueryResult::RecordAggregation(ra) => serialize_aggregation(ra),
+        QueryResult::RecordAggregations(ras) => serialize_aggregations(ras, field),
+        QueryResult::RelationCounts(rc) => Ok(serialize_relation_counts(rc)),
 
         QueryResult::Count(c) => {
             // Todo needs a real implementation or needs to move to RecordAggregation
@@ -61,12 +64,56 @@ pub fn serialize_internal(
 
 fn serialize_aggregation(record_aggregation: RecordAggregation) -> crate::Result<CheckedItemsWithParents> {
     let ordering = record_aggregation.selection_order;
-    let results = record_aggregation.results;
+    let inner_map = serialize_aggregation_row(&ordering, record_aggregation.results, None)?;
 
+    let mut envelope = CheckedItemsWithParents::new();
+    envelope.insert(None, Item::Map(inner_map));
+
+    Ok(envelope)
+}
+
+fn serialize_aggregations(
+    record_aggregations: RecordAggregations,
+    field: &OutputFieldRef,
+) -> crate::Result<CheckedItemsWithParents> {
+    let ordering = record_aggregations.selection_order;
+    let typ = field
+        .field_type
+        .as_object_type()
+        .expect("Invariant violation: Aggregations are always serialized into objects.");
+
+    let rows: Vec<Item> = record_aggregations
+        .results
+        .into_iter()
+        .map(|row| Ok(Item::Map(serialize_aggregation_row(&ordering, row, Some(&typ))?)))
+        .collect::<crate::Result<_>>()?;
+
+    let mut envelope = CheckedItemsWithParents::new();
+    envelope.insert(None, Item::list(rows));
+
+    Ok(envelope)
+}
+
+/// Serializes the results of a single aggregation (row) into a map, ordered by the original query selection.
+/// Values of grouped fields are serialized with the type of the corresponding field of `typ`.
+fn serialize_aggregation_row(
+    ordering: &[(String, Option<Vec<String>>)],
+    results: AggregationRow,
+    typ: Option<&ObjectTypeStrongRef>,
+) -> crate::Result<Map> {
     let mut flattened = HashMap::with_capacity(ordering.len());
 
     for result in results {
         match result {
+            AggregationResult::Field(field, value) => {
+                let item = match typ.and_then(|typ| typ.find_field(&field.name)) {
+                    Some(output_field) => serialize_scalar(&output_field, value)?,
+                    None => Item::Value(value),
+                };
+
+                flattened.insert(field.name.clone(), item);
+            }
+
             AggregationResult::Count(count) => {
                 flattened.insert("count".to_owned(), Item::Value(count));
             }
@@ -97,20 +144,35 @@ fn serialize_aggregation(record_aggregation: RecordAggregation) -> crate::Result
 
             for field in order {
                 let item = flattened.remove(&format!("{}_{}", query, field)).unwrap();
-                nested_map.insert(field, item);
+                nested_map.insert(field.clone(), item);
             }
 
-            inner_map.insert(query, Item::Map(nested_map));
+            inner_map.insert(query.clone(), Item::Map(nested_map));
         } else {
-            let item = flattened.remove(&query).unwrap();
-            inner_map.insert(query, item);
+            let item = flattened.remove(query).unwrap();
+            inner_map.insert(query.clone(), item);
         }
     }
 
-    let mut envelope = CheckedItemsWithParents::new();
-    envelope.insert(None, Item::Map(inner_map));
+    Ok(inner_map)
+}
 
-    Ok(envelope)
+/// Serializes the relation counts into a count object per parent record.
+fn serialize_relation_counts(relation_counts: RelationCounts) -> CheckedItemsWithParents {
+    let fields = relation_counts.fields;
+    let mut result = CheckedItemsWithParents::with_capacity(relation_counts.counts.len());
+
+    for (parent_id, counts) in relation_counts.counts {
+        let map: Map = fields
+            .iter()
+            .zip(counts)
+            .map(|(name, count)| (name.clone(), Item::Value(PrismaValue::Int(count as i64))))
+            .collect();
+
+        result.insert(Some(parent_id), Item::Ref(ItemRef::new(Item::Map(map))));
+    }
+
+    result
 }
 
 fn serialize_record_selection(
@@ -206,9 +268,9 @@ fn serialize_objects(
     let scalar_db_field_names = result.scalars.field_names;
 
     let model = result.model_id.model();
-    let field_names: Vec<_> = scalar_db_field_names
+    let scalar_fields: Vec<_> = scalar_db_field_names
         .iter()
-        .filter_map(|f| model.map_scalar_db_field_name(f).map(|x| x.name.clone()))
+        .filter_map(|f| model.map_scalar_db_field_name(f))
         .collect();
 
     // Write all fields, nested and list fields unordered into a map, afterwards order all into the final order.
@@ -224,11 +286,13 @@ fn serialize_objects(
         let values = record.values;
         let mut object = HashMap::with_capacity(values.len());
 
-        for (val, scalar_field_name) in values.into_iter().zip(field_names.iter()) {
-            let field = typ.find_field(scalar_field_name).unwrap();
+        for (val, scalar_field) in values.into_iter().zip(scalar_fields.iter()) {
+            let field = typ.find_field(&scalar_field.name).unwrap();
 
-            if !field.field_type.is_object() {
-                object.insert(scalar_field_name.to_owned(), serialize_scalar(&field, val)?);
+            if scalar_field.is_composite() {
+                object.insert(scalar_field.name.clone(), serialize_composite(&field, val)?);
+            } else if !field.field_type.is_object() {
+                object.insert(scalar_field.name.clone(), serialize_scalar(&field, val)?);
             }
         }
 
@@ -304,13 +368,16 @@ fn process_nested_results(
     // Unwraps are safe due to query validation.
     for nested_result in nested {
         // todo Workaround, tb changed with flat reads.
-        if let QueryResult::RecordSelection(ref rs) = nested_result {
-            let name = rs.name.clone();
-            let field = enclosing_type.find_field(&name).unwrap();
-            let result = serialize_internal(nested_result, &field, false)?;
+        let name = match nested_result {
+            QueryResult::RecordSelection(ref rs) => rs.name.clone(),
+            QueryResult::RelationCounts(ref rc) => rc.name.clone(),
+            _ => continue,
+        };
 
-            nested_mapping.insert(name, result);
-        }
+        let field = enclosing_type.find_field(&name).unwrap();
+        let result = serialize_internal(nested_result, &field, false)?;
+
+        nested_mapping.insert(name, result);
     }
 
     Ok(nested_mapping)
@@ -355,6 +422,93 @@ fn serialize_scalar(field: &OutputFieldRef, value: PrismaValue) -> crate::Result
     }
 }
 
+/// Composite values are stored inline as JSON on the parent record.
+/// The JSON is unpacked into maps and lists according to the composite output type.
+fn serialize_composite(field: &OutputFieldRef, value: PrismaValue) -> crate::Result<Item> {
+    let json = match value {
+        PrismaValue::Null => serde_json::Value::Null,
+        PrismaValue::Json(s) | PrismaValue::String(s) => serde_json::from_str(&s).map_err(|err| {
+            CoreError::SerializationError(format!("Unable to parse stored composite value as JSON: {}", err))
+        })?,
+        pv => {
+            return Err(CoreError::SerializationError(format!(
+                "Attempted to serialize composite field '{}' with non-JSON value '{}'",
+                field.name, pv
+            )))
+        }
+    };
+
+    match (json, field.field_type.as_ref()) {
+        (serde_json::Value::Null, _) if !field.is_required => Ok(Item::Value(PrismaValue::Null)),
+        (json, typ) => convert_composite_value(json, typ),
+    }
+}
+
+fn convert_composite_value(value: serde_json::Value, typ: &OutputType) -> crate::Result<Item> {
+    match (value, typ) {
+        (serde_json::Value::Null, OutputType::List(_)) => Ok(Item::list(Vec::new())),
+        (serde_json::Value::Null, _) => Ok(Item::Value(PrismaValue::Null)),
+        (serde_json::Value::Array(values), OutputType::List(inner)) => {
+            let items = values
+                .into_iter()
+                .map(|v| convert_composite_value(v, inner))
+                .collect::<crate::Result<Vec<Item>>>()?;
+
+            Ok(Item::list(items))
+        }
+        (serde_json::Value::Object(mut values), OutputType::Object(obj)) => {
+            let obj = obj.into_arc();
+            let mut map = Map::with_capacity(obj.get_fields().len());
+
+            for field in obj.get_fields() {
+                let value = values.remove(&field.name).unwrap_or(serde_json::Value::Null);
+                map.insert(field.name.clone(), convert_composite_value(value, &field.field_type)?);
+            }
+
+            Ok(Item::Map(map))
+        }
+        (serde_json::Value::String(s), OutputType::Enum(et)) => match et.borrow() {
+            EnumType::Internal(ref i) => convert_enum(PrismaValue::Enum(s), i),
+            _ => unreachable!(),
+        },
+        (value, OutputType::Scalar(st)) => Ok(Item::Value(convert_composite_scalar(value, st)?)),
+        (value, typ) => Err(CoreError::SerializationError(format!(
+            "Attempted to serialize composite value '{}' with incompatible type '{:?}'",
+            value, typ
+        ))),
+    }
+}
+
+/// Converts a scalar stored inside a composite value back into a `PrismaValue`.
+/// The stored representation is the one produced by serializing `PrismaValue`s to JSON.
+fn convert_composite_scalar(value: serde_json::Value, st: &ScalarType) -> Result<PrismaValue, CoreError> {
+    let converted = match (st, value) {
+        (ScalarType::String, serde_json::Value::String(s)) => Some(PrismaValue::String(s)),
+        (ScalarType::Boolean, serde_json::Value::Bool(b)) => Some(PrismaValue::Boolean(b)),
+        (ScalarType::Int, serde_json::Value::Number(n)) => n.as_i64().map(PrismaValue::Int),
+        (ScalarType::Float, serde_json::Value::Number(n)) => n.as_f64().map(PrismaValue::new_float),
+        (ScalarType::BigInt, serde_json::Value::Number(n)) => n.as_i64().map(PrismaValue::BigInt),
+        (ScalarType::BigInt, serde_json::Value::String(s)) => s.parse().ok().map(PrismaValue::BigInt),
+        (ScalarType::DateTime, serde_json::Value::String(s)) => chrono::DateTime::parse_from_rfc3339(&s)
+            .ok()
+            .map(|dt| PrismaValue::DateTime(dt.with_timezone(&chrono::Utc))),
+        (ScalarType::UUID, serde_json::Value::String(s)) => uuid::Uuid::parse_str(&s).ok().map(PrismaValue::Uuid),
+        (ScalarType::Bytes, serde_json::Value::String(s)) => {
+            prisma_value::decode_bytes(&s).ok().map(PrismaValue::Bytes)
+        }
+        (ScalarType::Json, serde_json::Value::String(s)) => Some(PrismaValue::Json(s)),
+        (ScalarType::Json, value) => Some(PrismaValue::Json(value.to_string())),
+        _ => None,
+    };
+
+    converted.ok_or_else(|| {
+        CoreError::SerializationError(format!(
+            "Attempted to serialize composite value with incompatible type '{:?}'",
+            st
+        ))
+    })
+}
+
 fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaValue, CoreError> {
     let item_value = match (st, value) {
         (ScalarType::String, PrismaValue::String(s)) => PrismaValue::String(s),
@@ -374,6 +528,11 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
         (ScalarType::DateTime, PrismaValue::DateTime(dt)) => PrismaValue::DateTime(dt),
         (ScalarType::UUID, PrismaValue::Uuid(u)) => PrismaValue::Uuid(u),
 
+        (ScalarType::BigInt, PrismaValue::BigInt(i)) => PrismaValue::BigInt(i),
+        (ScalarType::BigInt, PrismaValue::Int(i)) => PrismaValue::BigInt(i),
+
+        (ScalarType::Bytes, PrismaValue::Bytes(b)) => PrismaValue::Bytes(b),
+
         (st, pv) => {
             return Err(CoreError::SerializationError(format!(
                 "Attempted to serialize scalar '{}' with incompatible type '{:?}'",
@@ -1,4 +1,4 @@
-use connector::{AggregationResult, QueryArguments};
+use connector::{AggregationResult, AggregationRow, QueryArguments};
 use prisma_models::{ManyRecords, ModelProjection, RecordProjection};
 
 #[derive(Debug, Clone)]
@@ -8,6 +8,8 @@ pub enum QueryResult {
     RecordSelection(RecordSelection),
     Json(serde_json::Value),
     RecordAggregation(RecordAggregation),
+    RecordAggregations(RecordAggregations),
+    RelationCounts(RelationCounts),
     Unit,
 }
 
@@ -42,3 +44,24 @@ pub struct RecordAggregation {
     /// Actual aggregation results.
     pub results: Vec<AggregationResult>,
 }
+
+#[derive(Debug, Clone)]
+pub struct RecordAggregations {
+    /// Ordered list of selected fields as defined by the original incoming query.
+    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+
+    /// Aggregation results, one row per group.
+    pub results: Vec<AggregationRow>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RelationCounts {
+    /// Name of the query.
+    pub name: String,
+
+    /// Ordered list of the selected counts.
+    pub fields: Vec<String>,
+
+    /// The counts of every parent record, in the order of `fields`.
+    pub counts: Vec<(RecordProjection, Vec<usize>)>,
+}
@@ -252,12 +252,14 @@ pub enum QueryTag {
     FindFirst,
     FindMany,
     CreateOne,
+    CreateMany,
     UpdateOne,
     UpdateMany,
     DeleteOne,
     DeleteMany,
     UpsertOne,
     Aggregate,
+    GroupBy,
 }
 
 impl fmt::Display for QueryTag {
@@ -267,12 +269,14 @@ impl fmt::Display for QueryTag {
             QueryTag::FindFirst => "findFirst",
             QueryTag::FindMany => "findMany",
             QueryTag::CreateOne => "createOne",
+            QueryTag::CreateMany => "createMany",
             QueryTag::UpdateOne => "updateOne",
             QueryTag::UpdateMany => "updateMany",
             QueryTag::DeleteOne => "deleteOne",
             QueryTag::DeleteMany => "deleteMany",
             QueryTag::UpsertOne => "upsertOne",
             QueryTag::Aggregate => "aggregate",
+            QueryTag::GroupBy => "groupBy",
         };
 
         write!(f, "{}", s)
@@ -470,6 +474,14 @@ impl InputType {
         InputType::Scalar(ScalarType::UUID)
     }
 
+    pub fn bigint() -> InputType {
+        InputType::Scalar(ScalarType::BigInt)
+    }
+
+    pub fn bytes() -> InputType {
+        InputType::Scalar(ScalarType::Bytes)
+    }
+
     pub fn null() -> InputType {
         InputType::Scalar(ScalarType::Null)
     }
@@ -524,6 +536,14 @@ impl OutputType {
         OutputType::Scalar(ScalarType::UUID)
     }
 
+    pub fn bigint() -> OutputType {
+        OutputType::Scalar(ScalarType::BigInt)
+    }
+
+    pub fn bytes() -> OutputType {
+        OutputType::Scalar(ScalarType::Bytes)
+    }
+
     /// Attempts to recurse through the type until an object type is found.
     /// Returns Some(ObjectTypeStrongRef) if ab object type is found, None otherwise.
     pub fn as_object_type(&self) -> Option<ObjectTypeStrongRef> {
@@ -562,6 +582,8 @@ pub enum ScalarType {
     Json,
     JsonList,
     UUID,
+    BigInt,
+    Bytes,
 }
 
 impl From<EnumType> for OutputType {
@@ -29,6 +29,17 @@ pub(crate) fn create_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Op
     }
 }
 
+/// Builds "data" and "skipDuplicates" arguments intended for the create many field.
+pub(crate) fn create_many_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
+    let create_many_type = input_types::create_input_objects::create_many_input_type(ctx, model);
+    let data_arg = input_field("data", InputType::list(InputType::object(create_many_type)), None);
+
+    vec![
+        data_arg,
+        input_field("skipDuplicates", InputType::boolean(), None).optional(),
+    ]
+}
+
 /// Builds "where" (unique) argument intended for the delete field.
 pub(crate) fn delete_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Vec<InputField>> {
     where_unique_argument(ctx, model).map(|arg| vec![arg])
@@ -96,29 +107,51 @@ pub(crate) fn many_records_arguments(ctx: &mut BuilderContext, model: &ModelRef)
 
     let mut args = vec![
         where_argument(ctx, &model),
-        order_by_argument(ctx, &model),
+        order_by_argument(ctx, &model, true),
         input_field("cursor", unique_input_type.clone(), None).optional(),
         input_field("take", InputType::int(), None).optional(),
         input_field("skip", InputType::int(), None).optional(),
     ];
 
-    let enum_type = Arc::new(EnumType::FieldRef(FieldRefEnumType {
+    let enum_type = scalar_field_enum_type(model);
+
+    args.push(input_field("distinct", InputType::list(InputType::Enum(enum_type)), None).optional());
+    args
+}
+
+/// Builds "group by" arguments based on the given model.
+pub(crate) fn group_by_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
+    let enum_type = scalar_field_enum_type(model);
+    let having_input_type =
+        InputType::object(input_types::filter_input_objects::scalar_where_with_aggregates_object_type(ctx, model));
+
+    vec![
+        where_argument(ctx, &model),
+        order_by_argument(ctx, &model, false),
+        input_field("by", InputType::list(InputType::Enum(enum_type)), None),
+        input_field("having", having_input_type, None).optional(),
+        input_field("take", InputType::int(), None).optional(),
+        input_field("skip", InputType::int(), None).optional(),
+    ]
+}
+
+/// Builds an enum type referencing all scalar fields of the model (e.g. for "distinct" and "by").
+fn scalar_field_enum_type(model: &ModelRef) -> EnumTypeRef {
+    Arc::new(EnumType::FieldRef(FieldRefEnumType {
         name: format!("{}DistinctFieldEnum", capitalize(&model.name)),
         values: model
             .fields()
             .scalar()
             .into_iter()
+            .filter(|field| !field.is_composite())
             .map(|field| (field.name.clone(), field))
             .collect(),
-    }));
-
-    args.push(input_field("distinct", InputType::list(InputType::Enum(enum_type)), None).optional());
-    args
+    }))
 }
 
-// Builds "orderBy" argument.
-pub(crate) fn order_by_argument(ctx: &mut BuilderContext, model: &ModelRef) -> InputField {
-    let order_object_type = InputType::object(input_types::order_by_object_type(ctx, model));
+// Builds "orderBy" argument. Ordering by relations is only possible if `include_relations` is set.
+pub(crate) fn order_by_argument(ctx: &mut BuilderContext, model: &ModelRef, include_relations: bool) -> InputField {
+    let order_object_type = InputType::object(input_types::order_by_object_type(ctx, model, include_relations));
 
     input_field(
         "orderBy",
@@ -0,0 +1,61 @@
+use super::*;
+use prisma_models::{InternalCompositeType, InternalCompositeTypeField};
+
+/// Maps a composite type field to its input type: the composite input object, or a list of them.
+/// Composite values are stored inline, hence they are always written as a whole.
+pub(crate) fn map_composite_input_type(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> InputType {
+    let composite_type = field
+        .composite_type
+        .as_ref()
+        .expect("A composite field must always have an associated composite type.");
+
+    let typ = InputType::object(composite_create_input_type(ctx, composite_type));
+
+    if field.is_list {
+        InputType::list(typ)
+    } else {
+        typ
+    }
+}
+
+/// Builds "<CompositeType>CreateInput" input object types.
+pub(crate) fn composite_create_input_type(
+    ctx: &mut BuilderContext,
+    composite_type: &InternalCompositeType,
+) -> InputObjectTypeWeakRef {
+    let name = format!("{}CreateInput", composite_type.name);
+    return_cached_input!(ctx, &name);
+
+    let input_object = Arc::new(init_input_object_type(name.clone()));
+    ctx.cache_input_type(name, input_object.clone());
+
+    let fields = composite_type
+        .fields
+        .iter()
+        .map(|f| {
+            let field = input_field(f.name.clone(), map_composite_type_field_input_type(ctx, f), None);
+
+            if f.is_required() {
+                field
+            } else {
+                field.optional().nullable_if(!f.is_list())
+            }
+        })
+        .collect();
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+fn map_composite_type_field_input_type(ctx: &mut BuilderContext, field: &InternalCompositeTypeField) -> InputType {
+    let typ = match &field.composite_type {
+        Some(composite_type) => InputType::object(composite_create_input_type(ctx, composite_type)),
+        None => map_type_identifier_input_type(&field.type_identifier, field.internal_enum.as_ref()),
+    };
+
+    if field.is_list() {
+        InputType::list(typ)
+    } else {
+        typ
+    }
+}
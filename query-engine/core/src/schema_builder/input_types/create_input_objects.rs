@@ -67,20 +67,7 @@ pub(crate) fn create_input_type(
         model.name.clone(),
         "Create",
         scalar_fields,
-        |_, f: ScalarFieldRef, default: Option<DefaultValue>| {
-            let typ = map_scalar_input_type(&f);
-            if f.is_required && f.default_value.is_none() && (f.is_created_at() || f.is_updated_at()) {
-                input_field(f.name.clone(), typ, default)
-                    .optional()
-                    .nullable_if(!f.is_required)
-            } else if f.is_required && f.default_value.is_none() {
-                input_field(f.name.clone(), typ, default)
-            } else {
-                input_field(f.name.clone(), typ, default)
-                    .optional()
-                    .nullable_if(!f.is_required)
-            }
-        },
+        |ctx, f: ScalarFieldRef, default: Option<DefaultValue>| create_scalar_input_field(ctx, f, default),
         true,
     );
 
@@ -93,6 +80,55 @@ pub(crate) fn create_input_type(
     Arc::downgrade(&input_object)
 }
 
+/// Builds the create many input type (<x>CreateManyInput).
+/// Only contains scalar fields, including the ones backing relations, as nested writes are not supported.
+pub(crate) fn create_many_input_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
+    let name = format!("{}CreateManyInput", model.name);
+    return_cached_input!(ctx, &name);
+
+    let input_object = Arc::new(init_input_object_type(name.clone()));
+    ctx.cache_input_type(name, input_object.clone());
+
+    let scalar_fields: Vec<ScalarFieldRef> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|f| field_should_be_kept_for_create_input_type(&f))
+        .collect();
+
+    let fields = input_fields::scalar_input_fields(
+        ctx,
+        model.name.clone(),
+        "CreateMany",
+        scalar_fields,
+        |ctx, f: ScalarFieldRef, default: Option<DefaultValue>| create_scalar_input_field(ctx, f, default),
+        true,
+    );
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+fn create_scalar_input_field(ctx: &mut BuilderContext, f: ScalarFieldRef, default: Option<DefaultValue>) -> InputField {
+    let typ = if f.is_composite() {
+        composite_input_objects::map_composite_input_type(ctx, &f)
+    } else {
+        map_scalar_input_type(&f)
+    };
+
+    if f.is_required && f.default_value.is_none() && (f.is_created_at() || f.is_updated_at()) {
+        input_field(f.name.clone(), typ, default)
+            .optional()
+            .nullable_if(!f.is_required)
+    } else if f.is_required && f.default_value.is_none() {
+        input_field(f.name.clone(), typ, default)
+    } else {
+        input_field(f.name.clone(), typ, default)
+            .optional()
+            .nullable_if(!f.is_required)
+    }
+}
+
 /// For create input types only. Compute input fields for relational fields.
 fn relation_input_fields_for_create(
     ctx: &mut BuilderContext,
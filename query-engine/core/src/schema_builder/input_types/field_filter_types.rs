@@ -3,16 +3,27 @@ use datamodel_connector::ConnectorCapability;
 use prisma_models::{dml::DefaultValue, PrismaValue};
 
 /// Builds filter types for the given model field.
-pub(crate) fn get_field_filter_types(ctx: &mut BuilderContext, field: &ModelField) -> Vec<InputType> {
+/// If `include_aggregates` is set, the scalar filters additionally allow filtering on aggregated values (`having`).
+pub(crate) fn get_field_filter_types(
+    ctx: &mut BuilderContext,
+    field: &ModelField,
+    include_aggregates: bool,
+) -> Vec<InputType> {
     match field {
         ModelField::Relation(rf) => {
             let mut types = vec![InputType::object(full_relation_filter(ctx, rf))];
             types.extend(mto1_relation_filter_shorthand_types(ctx, rf));
             types
         }
+        ModelField::Scalar(sf) if sf.is_composite() => vec![InputType::object(composite_filter_type(ctx, sf))],
         ModelField::Scalar(sf) if field.is_list() => vec![InputType::object(scalar_list_filter_type(ctx, sf))],
         ModelField::Scalar(sf) => {
-            let mut types = vec![InputType::object(full_scalar_filter_type(ctx, sf, false))];
+            let mut types = vec![InputType::object(full_scalar_filter_type(
+                ctx,
+                sf,
+                false,
+                include_aggregates,
+            ))];
 
             if sf.type_identifier != TypeIdentifier::Json {
                 types.push(map_scalar_input_type(sf)); // Scalar equality shorthand
@@ -77,84 +88,167 @@ fn full_relation_filter(ctx: &mut BuilderContext, rf: &RelationFieldRef) -> Inpu
 }
 
 fn scalar_list_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> InputObjectTypeWeakRef {
-    let name = scalar_filter_name(sf, false);
+    let name = scalar_filter_name(sf, false, false);
     return_cached_input!(ctx, &name);
 
     let object = Arc::new(init_input_object_type(name.clone()));
     ctx.cache_input_type(name, object.clone());
 
-    let fields = equality_filters(sf).collect();
+    let fields = equality_filters(map_scalar_input_type(sf), !sf.is_required).collect();
     object.set_fields(fields);
 
     Arc::downgrade(&object)
 }
 
-fn full_scalar_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef, nested: bool) -> InputObjectTypeWeakRef {
-    let name = scalar_filter_name(sf, nested);
+/// Composite values can only be compared as a whole.
+fn composite_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> InputObjectTypeWeakRef {
+    let composite_type = sf
+        .composite_type
+        .as_ref()
+        .expect("A composite field must always have an associated composite type.");
+
+    let list = if sf.is_list { "List" } else { "" };
+    let nullable = if sf.is_required { "" } else { "Nullable" };
+    let name = format!("{}{}{}CompositeFilter", composite_type.name, nullable, list);
+    return_cached_input!(ctx, &name);
+
+    let object = Arc::new(init_input_object_type(name.clone()));
+    ctx.cache_input_type(name, object.clone());
+
+    let mapped_type = composite_input_objects::map_composite_input_type(ctx, sf);
+    let fields = equality_filters(mapped_type.clone(), !sf.is_required)
+        .chain(std::iter::once(
+            input_field("not", mapped_type, None)
+                .optional()
+                .nullable_if(!sf.is_required),
+        ))
+        .collect();
+
+    object.set_fields(fields);
+    Arc::downgrade(&object)
+}
+
+fn full_scalar_filter_type(
+    ctx: &mut BuilderContext,
+    sf: &ScalarFieldRef,
+    nested: bool,
+    include_aggregates: bool,
+) -> InputObjectTypeWeakRef {
+    let name = scalar_filter_name(sf, nested, include_aggregates);
     return_cached_input!(ctx, &name);
 
     let object = Arc::new(init_input_object_type(name.clone()));
     ctx.cache_input_type(name, object.clone());
 
+    let mapped_type = map_scalar_input_type(sf);
+    let nullable = !sf.is_required;
+
     let mut fields: Vec<_> = match sf.type_identifier {
-        TypeIdentifier::String | TypeIdentifier::UUID => equality_filters(sf)
-            .chain(inclusion_filters(sf))
-            .chain(alphanumeric_filters(sf))
-            .chain(string_filters(sf))
+        TypeIdentifier::String | TypeIdentifier::UUID => equality_filters(mapped_type.clone(), nullable)
+            .chain(inclusion_filters(mapped_type.clone(), nullable))
+            .chain(alphanumeric_filters(mapped_type.clone()))
+            .chain(string_filters(mapped_type.clone()))
             .chain(query_mode_field(ctx, nested))
             .collect(),
 
-        TypeIdentifier::Int | TypeIdentifier::Float | TypeIdentifier::DateTime => equality_filters(sf)
-            .chain(inclusion_filters(sf))
-            .chain(alphanumeric_filters(sf))
+        TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float | TypeIdentifier::DateTime => {
+            equality_filters(mapped_type.clone(), nullable)
+                .chain(inclusion_filters(mapped_type.clone(), nullable))
+                .chain(alphanumeric_filters(mapped_type.clone()))
+                .collect()
+        }
+
+        TypeIdentifier::Json => equality_filters(mapped_type.clone(), nullable)
+            .chain(json_filters(ctx, mapped_type.clone()))
             .collect(),
 
-        TypeIdentifier::Boolean | TypeIdentifier::Json => equality_filters(sf).collect(),
-        TypeIdentifier::Enum(_) => equality_filters(sf).chain(inclusion_filters(sf)).collect(),
+        TypeIdentifier::Boolean => equality_filters(mapped_type.clone(), nullable).collect(),
+        TypeIdentifier::Bytes | TypeIdentifier::Enum(_) => equality_filters(mapped_type.clone(), nullable)
+            .chain(inclusion_filters(mapped_type.clone(), nullable))
+            .collect(),
     };
 
     // Shorthand `not equals` filter, skips the nested object filter.
-    let mut not_types = vec![map_scalar_input_type(sf)];
+    let mut not_types = vec![mapped_type];
 
     if sf.type_identifier != TypeIdentifier::Json {
         // Full nested filter. Only available on non-JSON fields.
-        not_types.push(InputType::object(full_scalar_filter_type(ctx, sf, true)));
+        not_types.push(InputType::object(full_scalar_filter_type(ctx, sf, true, false)));
     }
 
-    let not_field = input_field("not", not_types, None)
-        .optional()
-        .nullable_if(!sf.is_required);
-
+    let not_field = input_field("not", not_types, None).optional().nullable_if(nullable);
     fields.push(not_field);
+
+    if include_aggregates {
+        fields.extend(aggregate_filters(ctx, sf));
+    }
+
     object.set_fields(fields);
 
     Arc::downgrade(&object)
 }
 
-fn equality_filters(sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
-    vec![input_field("equals", map_scalar_input_type(sf), None)
+/// Filters on the aggregated values of a field, only available in `having` filters of group by queries.
+fn aggregate_filters(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> Vec<InputField> {
+    let count_filter = aggregate_value_filter_type(ctx, InputType::int(), "Int");
+    let mut fields = vec![input_field("count", InputType::object(count_filter), None).optional()];
+
+    if matches!(
+        sf.type_identifier,
+        TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float
+    ) {
+        let avg_filter = aggregate_value_filter_type(ctx, InputType::float(), "Float");
+        let nested_filter = InputType::object(full_scalar_filter_type(ctx, sf, true, false));
+
+        fields.push(input_field("avg", InputType::object(avg_filter), None).optional());
+        fields.push(input_field("sum", nested_filter.clone(), None).optional());
+        fields.push(input_field("min", nested_filter.clone(), None).optional());
+        fields.push(input_field("max", nested_filter, None).optional());
+    }
+
+    fields
+}
+
+/// Builds a nested numeric filter for aggregated values that are computed instead of read from a field (`count`, `avg`).
+/// The shape is identical to the nested filter of a required field of the same type.
+fn aggregate_value_filter_type(ctx: &mut BuilderContext, typ: InputType, type_name: &str) -> InputObjectTypeWeakRef {
+    let name = format!("Nested{}Filter", type_name);
+    return_cached_input!(ctx, &name);
+
+    let object = Arc::new(init_input_object_type(name.clone()));
+    ctx.cache_input_type(name, object.clone());
+
+    let not_types = vec![typ.clone(), InputType::object(Arc::downgrade(&object))];
+    let fields = equality_filters(typ.clone(), false)
+        .chain(inclusion_filters(typ.clone(), false))
+        .chain(alphanumeric_filters(typ))
+        .chain(std::iter::once(input_field("not", not_types, None).optional()))
+        .collect();
+
+    object.set_fields(fields);
+    Arc::downgrade(&object)
+}
+
+fn equality_filters(mapped_type: InputType, nullable: bool) -> impl Iterator<Item = InputField> {
+    vec![input_field("equals", mapped_type, None)
         .optional()
-        .nullable_if(!sf.is_required)]
+        .nullable_if(nullable)]
     .into_iter()
 }
 
-fn inclusion_filters(sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
-    let typ = InputType::list(map_scalar_input_type(sf));
+fn inclusion_filters(mapped_type: InputType, nullable: bool) -> impl Iterator<Item = InputField> {
+    let typ = InputType::list(mapped_type);
 
     vec![
-        input_field("in", typ.clone(), None)
-            .optional()
-            .nullable_if(!sf.is_required),
+        input_field("in", typ.clone(), None).optional().nullable_if(nullable),
         input_field("notIn", typ, None) // Kept for legacy reasons!
             .optional()
-            .nullable_if(!sf.is_required),
+            .nullable_if(nullable),
     ]
     .into_iter()
 }
 
-fn alphanumeric_filters(sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
-    let mapped_type = map_scalar_input_type(sf);
-
+fn alphanumeric_filters(mapped_type: InputType) -> impl Iterator<Item = InputField> {
     vec![
         input_field("lt", mapped_type.clone(), None).optional(),
         input_field("lte", mapped_type.clone(), None).optional(),
@@ -164,9 +258,7 @@ fn alphanumeric_filters(sf: &ScalarFieldRef) -> impl Iterator<Item = InputField>
     .into_iter()
 }
 
-fn string_filters(sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
-    let mapped_type = map_scalar_input_type(sf);
-
+fn string_filters(mapped_type: InputType) -> impl Iterator<Item = InputField> {
     vec![
         input_field("contains", mapped_type.clone(), None).optional(),
         input_field("startsWith", mapped_type.clone(), None).optional(),
@@ -175,6 +267,42 @@ fn string_filters(sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
     .into_iter()
 }
 
+/// Filters on the nested values of JSON documents, only built for connectors supporting them.
+/// Depending on the connector, the `path` is either a list of keys or a JSON path string.
+fn json_filters(ctx: &BuilderContext, mapped_type: InputType) -> impl Iterator<Item = InputField> {
+    let path_type = if ctx.capabilities.contains(ConnectorCapability::JsonFilteringArrayPath) {
+        Some(InputType::list(InputType::string()))
+    } else if ctx.capabilities.contains(ConnectorCapability::JsonFilteringJsonPath) {
+        Some(InputType::string())
+    } else {
+        None
+    };
+
+    let fields = match path_type {
+        Some(path_type) => {
+            let mut fields = vec![
+                input_field("path", path_type, None).optional(),
+                input_field("string_contains", InputType::string(), None).optional(),
+                input_field("string_starts_with", InputType::string(), None).optional(),
+            ];
+
+            // Matching any array element needs more than extracting a value at a path, not available on SQLite.
+            if ctx
+                .capabilities
+                .contains(ConnectorCapability::JsonFilteringArrayContains)
+            {
+                fields.push(input_field("array_contains", mapped_type.clone(), None).optional());
+            }
+
+            fields.push(input_field("array_starts_with", mapped_type.clone(), None).optional());
+            fields.into_iter().chain(alphanumeric_filters(mapped_type)).collect()
+        }
+        None => vec![],
+    };
+
+    fields.into_iter()
+}
+
 fn query_mode_field(ctx: &BuilderContext, nested: bool) -> impl Iterator<Item = InputField> {
     // Limit query mode field to the topmost filter level.
     // Only build mode field for connectors with insensitive filter support.
@@ -199,19 +327,22 @@ fn query_mode_field(ctx: &BuilderContext, nested: bool) -> impl Iterator<Item =
     fields.into_iter()
 }
 
-fn scalar_filter_name(sf: &ScalarFieldRef, nested: bool) -> String {
+fn scalar_filter_name(sf: &ScalarFieldRef, nested: bool, include_aggregates: bool) -> String {
     let list = if sf.is_list { "List" } else { "" };
     let nullable = if sf.is_required { "" } else { "Nullable" };
     let nested = if nested { "Nested" } else { "" };
+    let aggregates = if include_aggregates { "WithAggregates" } else { "" };
 
     match sf.type_identifier {
-        TypeIdentifier::UUID => format!("{}Uuid{}{}Filter", nested, nullable, list),
-        TypeIdentifier::String => format!("{}String{}{}Filter", nested, nullable, list),
-        TypeIdentifier::Int => format!("{}Int{}{}Filter", nested, nullable, list),
-        TypeIdentifier::Float => format!("{}Float{}{}Filter", nested, nullable, list),
-        TypeIdentifier::Boolean => format!("{}Bool{}{}Filter", nested, nullable, list),
-        TypeIdentifier::DateTime => format!("{}DateTime{}{}Filter", nested, nullable, list),
-        TypeIdentifier::Json => format!("{}Json{}{}Filter", nested, nullable, list),
-        TypeIdentifier::Enum(ref e) => format!("{}Enum{}{}{}Filter", nested, e, nullable, list),
+        TypeIdentifier::UUID => format!("{}Uuid{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::String => format!("{}String{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Int => format!("{}Int{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Float => format!("{}Float{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Boolean => format!("{}Bool{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::DateTime => format!("{}DateTime{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Json => format!("{}Json{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::BigInt => format!("{}BigInt{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Bytes => format!("{}Bytes{}{}{}Filter", nested, nullable, list, aggregates),
+        TypeIdentifier::Enum(ref e) => format!("{}Enum{}{}{}{}Filter", nested, e, nullable, list, aggregates),
     }
 }
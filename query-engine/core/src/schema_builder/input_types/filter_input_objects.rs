@@ -33,7 +33,52 @@ pub(crate) fn scalar_filter_object_type(ctx: &mut BuilderContext, model: &ModelR
     ];
 
     input_fields.extend(model.fields().all.iter().filter_map(|f| match f {
-        ModelField::Scalar(_) => Some(input_fields::filter_input_field(ctx, f)),
+        ModelField::Scalar(_) => Some(input_fields::filter_input_field(ctx, f, false)),
+        ModelField::Relation(_) => None,
+    }));
+
+    input_object.set_fields(input_fields);
+    weak_ref
+}
+
+/// Builds the filter object for the `having` argument of group by queries (e.g. UserScalarWhereWithAggregatesInput).
+/// Scalar field filters of this object additionally allow filtering on aggregated values of the field.
+pub(crate) fn scalar_where_with_aggregates_object_type(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+) -> InputObjectTypeWeakRef {
+    let object_name = format!("{}ScalarWhereWithAggregatesInput", model.name);
+    return_cached_input!(ctx, &object_name);
+
+    let input_object = Arc::new(init_input_object_type(object_name.clone()));
+    ctx.cache_input_type(object_name, input_object.clone());
+
+    let weak_ref = Arc::downgrade(&input_object);
+    let object_type = InputType::object(weak_ref.clone());
+
+    let mut input_fields = vec![
+        input_field(
+            "AND",
+            vec![object_type.clone(), InputType::list(object_type.clone())],
+            None,
+        )
+        .optional(),
+        input_field(
+            "OR",
+            vec![object_type.clone(), InputType::list(object_type.clone())],
+            None,
+        )
+        .optional(),
+        input_field(
+            "NOT",
+            vec![object_type.clone(), InputType::list(object_type.clone())],
+            None,
+        )
+        .optional(),
+    ];
+
+    input_fields.extend(model.fields().all.iter().filter_map(|f| match f {
+        ModelField::Scalar(_) => Some(input_fields::filter_input_field(ctx, f, true)),
         ModelField::Relation(_) => None,
     }));
 
@@ -77,7 +122,7 @@ pub(crate) fn where_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> I
             .fields()
             .all
             .iter()
-            .map(|f| input_fields::filter_input_field(ctx, f)),
+            .map(|f| input_fields::filter_input_field(ctx, f, false)),
     );
 
     input_object.set_fields(fields);
@@ -1,8 +1,8 @@
 use super::*;
 use prisma_models::dml::DefaultValue;
 
-pub(crate) fn filter_input_field(ctx: &mut BuilderContext, field: &ModelField) -> InputField {
-    let types = field_filter_types::get_field_filter_types(ctx, field);
+pub(crate) fn filter_input_field(ctx: &mut BuilderContext, field: &ModelField, include_aggregates: bool) -> InputField {
+    let types = field_filter_types::get_field_filter_types(ctx, field, include_aggregates);
     input_field(field.name().to_owned(), types, None).optional()
 }
 
@@ -121,7 +121,7 @@ where
     let input_object_name = input_object_name.into();
     let mut non_list_fields: Vec<InputField> = prefiltered_fields
         .iter()
-        .filter(|f| !f.is_list)
+        .filter(|f| !f.is_list || f.is_composite())
         .map(|f| {
             let default = if with_defaults { f.default_value.clone() } else { None };
             non_list_field_mapper(ctx, f.clone(), default)
@@ -130,7 +130,7 @@ where
 
     let mut list_fields: Vec<InputField> = prefiltered_fields
         .into_iter()
-        .filter(|f| f.is_list)
+        .filter(|f| f.is_list && !f.is_composite())
         .map(|f| {
             let name = f.name.clone();
             let list_input_type = map_scalar_input_type(&f);
@@ -1,3 +1,4 @@
+pub(crate) mod composite_input_objects;
 pub(crate) mod create_input_objects;
 pub(crate) mod field_filter_types;
 pub(crate) mod filter_input_objects;
@@ -6,12 +7,21 @@ pub(crate) mod update_input_objects;
 
 use super::*;
 use crate::schema::*;
-use prisma_models::{RelationFieldRef, ScalarFieldRef};
+use prisma_models::{InternalEnum, RelationFieldRef, ScalarFieldRef};
 
-/// Builds "<Model>OrderByInput" object types.
-pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
+/// Builds "<Model>OrderByInput" object types, or "<Model>ScalarOrderByInput" if `include_relations` is false.
+/// Relation fields allow ordering by the fields of to-one related records and by the count of to-many related records.
+pub(crate) fn order_by_object_type(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    include_relations: bool,
+) -> InputObjectTypeWeakRef {
     let enum_type = Arc::new(string_enum_type("SortOrder", vec!["asc".to_owned(), "desc".to_owned()]));
-    let name = format!("{}OrderByInput", model.name);
+    let name = if include_relations {
+        format!("{}OrderByInput", model.name)
+    } else {
+        format!("{}ScalarOrderByInput", model.name)
+    };
 
     return_cached_input!(ctx, &name);
 
@@ -21,19 +31,68 @@ pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -
     let input_object = Arc::new(input_object);
     ctx.cache_input_type(name, input_object.clone());
 
-    let fields = model
+    let mut fields: Vec<_> = model
         .fields()
         .scalar()
         .iter()
+        .filter(|sf| !sf.is_composite())
         .map(|sf| input_field(sf.name.clone(), InputType::Enum(enum_type.clone()), None).optional())
         .collect();
 
+    if include_relations {
+        let relation_fields = model
+            .fields()
+            .relation()
+            .into_iter()
+            .filter(|rf| !rf.related_model().is_embedded);
+
+        for rf in relation_fields {
+            let related_model = rf.related_model();
+            let related_type = if rf.is_list {
+                order_by_relation_aggregate_object_type(ctx, &related_model)
+            } else {
+                order_by_object_type(ctx, &related_model, true)
+            };
+
+            fields.push(input_field(rf.name.clone(), InputType::object(related_type), None).optional());
+        }
+    }
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds "<Model>OrderByRelationAggregateInput" object types, ordering by aggregations of to-many related records.
+fn order_by_relation_aggregate_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
+    let enum_type = Arc::new(string_enum_type("SortOrder", vec!["asc".to_owned(), "desc".to_owned()]));
+    let name = format!("{}OrderByRelationAggregateInput", model.name);
+
+    return_cached_input!(ctx, &name);
+
+    let mut input_object = init_input_object_type(name.clone());
+    input_object.allow_at_most_one_field();
+
+    let input_object = Arc::new(input_object);
+    ctx.cache_input_type(name, input_object.clone());
+
+    let fields = vec![input_field("count", InputType::Enum(enum_type), None).optional()];
+
     input_object.set_fields(fields);
     Arc::downgrade(&input_object)
 }
 
 fn map_scalar_input_type(field: &ScalarFieldRef) -> InputType {
-    let typ = match field.type_identifier {
+    let typ = map_type_identifier_input_type(&field.type_identifier, field.internal_enum.as_ref());
+
+    if field.is_list {
+        InputType::list(typ)
+    } else {
+        typ
+    }
+}
+
+fn map_type_identifier_input_type(type_identifier: &TypeIdentifier, internal_enum: Option<&InternalEnum>) -> InputType {
+    match type_identifier {
         TypeIdentifier::String => InputType::string(),
         TypeIdentifier::Int => InputType::int(),
         TypeIdentifier::Float => InputType::float(),
@@ -41,21 +100,15 @@ fn map_scalar_input_type(field: &ScalarFieldRef) -> InputType {
         TypeIdentifier::UUID => InputType::uuid(),
         TypeIdentifier::DateTime => InputType::date_time(),
         TypeIdentifier::Json => InputType::json(),
-        TypeIdentifier::Enum(_) => map_enum_input_type(&field),
-    };
-
-    if field.is_list {
-        InputType::list(typ)
-    } else {
-        typ
+        TypeIdentifier::BigInt => InputType::bigint(),
+        TypeIdentifier::Bytes => InputType::bytes(),
+        TypeIdentifier::Enum(_) => map_enum_input_type(internal_enum),
     }
 }
 
-fn map_enum_input_type(field: &ScalarFieldRef) -> InputType {
-    let internal_enum = field
-        .internal_enum
-        .as_ref()
-        .expect("A field with TypeIdentifier Enum must always have an associated internal enum.");
+fn map_enum_input_type(internal_enum: Option<&InternalEnum>) -> InputType {
+    let internal_enum =
+        internal_enum.expect("A field with TypeIdentifier Enum must always have an associated internal enum.");
 
     let et: EnumType = internal_enum.clone().into();
     et.into()
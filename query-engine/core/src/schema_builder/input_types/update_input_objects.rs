@@ -52,6 +52,14 @@ fn non_list_scalar_update_field_mapper(
     field: &ScalarFieldRef,
     default: Option<DefaultValue>,
 ) -> InputField {
+    // Composite values are replaced as a whole, there are no update operations for them.
+    if field.is_composite() {
+        let typ = composite_input_objects::map_composite_input_type(ctx, field);
+        return input_field(field.name.clone(), typ, default)
+            .optional()
+            .nullable_if(!field.is_required);
+    }
+
     let base_update_type = match &field.type_identifier {
         TypeIdentifier::Float => InputType::object(operations_object_type(ctx, "Float", field, true)),
         TypeIdentifier::Int => InputType::object(operations_object_type(ctx, "Int", field, true)),
@@ -61,6 +69,8 @@ fn non_list_scalar_update_field_mapper(
         TypeIdentifier::Json => map_scalar_input_type(field),
         TypeIdentifier::DateTime => InputType::object(operations_object_type(ctx, "DateTime", field, false)),
         TypeIdentifier::UUID => InputType::object(operations_object_type(ctx, "Uuid", field, false)),
+        TypeIdentifier::BigInt => InputType::object(operations_object_type(ctx, "BigInt", field, true)),
+        TypeIdentifier::Bytes => InputType::object(operations_object_type(ctx, "Bytes", field, false)),
     };
 
     let input_field = if field.type_identifier != TypeIdentifier::Json {
@@ -358,6 +368,6 @@ fn field_should_be_kept_for_update_input_type(field: &ScalarFieldRef) -> bool {
     !field.is_auto_generated_int_id
         && !matches!(
             (&field.type_identifier, field.unique(), field.is_autoincrement),
-            (TypeIdentifier::Int, true, true)
+            (TypeIdentifier::Int, true, true) | (TypeIdentifier::BigInt, true, true)
         )
 }
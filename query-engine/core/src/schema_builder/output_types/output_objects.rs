@@ -1,5 +1,5 @@
 use super::*;
-use prisma_models::ScalarFieldRef;
+use prisma_models::{InternalCompositeType, InternalCompositeTypeField, ScalarFieldRef};
 
 /// Initializes model output object type cache on the context.
 /// This is a critical first step to ensure that all model output object types are present
@@ -35,12 +35,52 @@ pub(crate) fn initialize_model_object_type_cache(ctx: &mut BuilderContext) {
 /// Computes model output type fields.
 /// Important: This requires that the cache has already been initialized.
 fn compute_model_object_type_fields(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<OutputField> {
-    model
+    let mut fields: Vec<OutputField> = model
         .fields()
         .all
         .iter()
         .map(|f| output_objects::map_field(ctx, f))
-        .collect()
+        .collect();
+
+    append_opt(&mut fields, relation_count_field(ctx, model));
+    fields
+}
+
+/// Returns the virtual `_count` field if the model has any to-many relations.
+fn relation_count_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
+    let relation_fields: Vec<RelationFieldRef> =
+        model.fields().relation().into_iter().filter(|rf| rf.is_list).collect();
+
+    if relation_fields.is_empty() {
+        None
+    } else {
+        let object_type = OutputType::object(relation_count_object_type(ctx, model, &relation_fields));
+        Some(field("_count", vec![], object_type, None))
+    }
+}
+
+/// Builds the relation count object type for given model (e.g. UserCountOutputType).
+/// Contains a field per to-many relation, counting the (filtered) related records.
+pub(crate) fn relation_count_object_type(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    relation_fields: &[RelationFieldRef],
+) -> ObjectTypeWeakRef {
+    let name = format!("{}CountOutputType", capitalize(&model.name));
+    return_cached_output!(ctx, &name);
+
+    let fields: Vec<OutputField> = relation_fields
+        .iter()
+        .map(|rf| {
+            let where_arg = arguments::where_argument(ctx, &rf.related_model());
+            field(rf.name.clone(), vec![where_arg], OutputType::int(), None)
+        })
+        .collect();
+
+    let object = Arc::new(object_type(name.clone(), fields, None));
+    ctx.cache_output_type(name, object.clone());
+
+    Arc::downgrade(&object)
 }
 
 /// Returns an output object type for the given model.
@@ -72,6 +112,15 @@ pub(crate) fn map_output_type(ctx: &mut BuilderContext, model_field: &ModelField
             }
         }
 
+        ModelField::Scalar(sf) if sf.is_composite() => {
+            let composite_type = sf
+                .composite_type
+                .as_ref()
+                .expect("A composite field must always have an associated composite type.");
+
+            OutputType::object(composite_object_type(ctx, composite_type))
+        }
+
         ModelField::Scalar(sf) => match sf.type_identifier {
             TypeIdentifier::String => OutputType::string(),
             TypeIdentifier::Float => OutputType::float(),
@@ -81,6 +130,8 @@ pub(crate) fn map_output_type(ctx: &mut BuilderContext, model_field: &ModelField
             TypeIdentifier::DateTime => OutputType::date_time(),
             TypeIdentifier::UUID => OutputType::uuid(),
             TypeIdentifier::Int => OutputType::int(),
+            TypeIdentifier::BigInt => OutputType::bigint(),
+            TypeIdentifier::Bytes => OutputType::bytes(),
         },
     };
 
@@ -91,6 +142,58 @@ pub(crate) fn map_output_type(ctx: &mut BuilderContext, model_field: &ModelField
     }
 }
 
+/// Builds the output object type for a composite type (e.g. `Address`).
+/// Composite object types are not backed by a model, their values are stored inline on the parent record.
+pub(crate) fn composite_object_type(
+    ctx: &mut BuilderContext,
+    composite_type: &InternalCompositeType,
+) -> ObjectTypeWeakRef {
+    let name = composite_type.name.clone();
+    return_cached_output!(ctx, &name);
+
+    let object = ObjectTypeStrongRef::new(ObjectType::new(&name, None));
+    ctx.cache_output_type(name, ObjectTypeStrongRef::clone(&object));
+
+    let fields = composite_type
+        .fields
+        .iter()
+        .map(|f| {
+            let typ = match &f.composite_type {
+                Some(ct) => OutputType::object(composite_object_type(ctx, ct)),
+                None => map_composite_type_field_scalar_type(f),
+            };
+
+            let typ = if f.is_list() { OutputType::list(typ) } else { typ };
+            field(f.name.clone(), vec![], typ, None).optional_if(!f.is_required())
+        })
+        .collect();
+
+    object.set_fields(fields);
+    ObjectTypeStrongRef::downgrade(&object)
+}
+
+fn map_composite_type_field_scalar_type(field: &InternalCompositeTypeField) -> OutputType {
+    match field.type_identifier {
+        TypeIdentifier::String => OutputType::string(),
+        TypeIdentifier::Float => OutputType::float(),
+        TypeIdentifier::Boolean => OutputType::boolean(),
+        TypeIdentifier::Enum(_) => {
+            let internal_enum = field
+                .internal_enum
+                .clone()
+                .expect("Invariant violation: Enum fields are expected to have an internal_enum associated with them.");
+
+            EnumType::from(internal_enum).into()
+        }
+        TypeIdentifier::Json => OutputType::json(),
+        TypeIdentifier::DateTime => OutputType::date_time(),
+        TypeIdentifier::UUID => OutputType::uuid(),
+        TypeIdentifier::Int => OutputType::int(),
+        TypeIdentifier::BigInt => OutputType::bigint(),
+        TypeIdentifier::Bytes => OutputType::bytes(),
+    }
+}
+
 pub(crate) fn map_enum_field(scalar_field: &ScalarFieldRef) -> EnumType {
     match scalar_field.type_identifier {
         TypeIdentifier::Enum(_) => {
@@ -141,6 +244,47 @@ pub(crate) fn aggregation_object_type(ctx: &mut BuilderContext, model: &ModelRef
     ObjectTypeStrongRef::downgrade(&object)
 }
 
+/// Builds group by output object type for given model (e.g. UserGroupByOutputType).
+/// Contains all scalar fields of the model, as well as the aggregations computed per group.
+pub(crate) fn group_by_output_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> ObjectTypeWeakRef {
+    let name = format!("{}GroupByOutputType", capitalize(&model.name));
+    return_cached_output!(ctx, &name);
+
+    let object = ObjectTypeStrongRef::new(ObjectType::new(&name, Some(ModelRef::clone(model))));
+
+    let mut fields: Vec<OutputField> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|sf| !sf.is_composite())
+        .map(|sf| {
+            field(
+                sf.name.clone(),
+                vec![],
+                map_output_type(ctx, &ModelField::Scalar(sf.clone())),
+                None,
+            )
+            .optional_if(!sf.is_required)
+        })
+        .collect();
+
+    fields.push(count_field());
+
+    append_opt(
+        &mut fields,
+        numeric_aggregation_field(ctx, "avg", &model, Some(OutputType::float())),
+    );
+
+    append_opt(&mut fields, numeric_aggregation_field(ctx, "sum", &model, None));
+    append_opt(&mut fields, numeric_aggregation_field(ctx, "min", &model, None));
+    append_opt(&mut fields, numeric_aggregation_field(ctx, "max", &model, None));
+
+    object.set_fields(fields);
+    ctx.cache_output_type(name, ObjectTypeStrongRef::clone(&object));
+
+    ObjectTypeStrongRef::downgrade(&object)
+}
+
 pub(crate) fn count_field() -> OutputField {
     field("count", vec![], OutputType::int(), None)
 }
@@ -210,6 +354,7 @@ fn collect_numeric_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
         .into_iter()
         .filter(|f| match f.type_identifier {
             TypeIdentifier::Int => true,
+            TypeIdentifier::BigInt => true,
             TypeIdentifier::Float => true,
             _ => false,
         })
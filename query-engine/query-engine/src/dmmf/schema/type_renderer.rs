@@ -53,6 +53,8 @@ pub(super) fn render_output_type(output_type: &OutputType, ctx: &mut RenderConte
                 ScalarType::DateTime => "DateTime",
                 ScalarType::Json => "Json",
                 ScalarType::UUID => "UUID",
+                ScalarType::BigInt => "BigInt",
+                ScalarType::Bytes => "Bytes",
                 ScalarType::JsonList => "Json",
                 ScalarType::Enum(_) => unreachable!(), // Handled separately above.
             };
@@ -127,6 +129,8 @@ pub(super) fn render_input_type(input_type: &InputType, ctx: &mut RenderContext)
                 ScalarType::DateTime => "DateTime",
                 ScalarType::Json => "Json",
                 ScalarType::UUID => "UUID",
+                ScalarType::BigInt => "BigInt",
+                ScalarType::Bytes => "Bytes",
                 ScalarType::JsonList => "Json",
                 ScalarType::Enum(_) => unreachable!(), // Handled separately above.
             };
@@ -1,7 +1,7 @@
 use crate::{error::PrismaError, PrismaResult};
-use datamodel::{Configuration, Datamodel};
+use datamodel::{Configuration, Datamodel, SourceFile};
 use serde::Deserialize;
-use std::{ffi::OsStr, fs::File, io::Read};
+use std::{borrow::Cow, ffi::OsStr};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt, Clone)]
@@ -59,9 +59,9 @@ pub struct PrismaOpt {
     #[structopt(long, short, env)]
     pub unix_path: Option<String>,
 
-    /// Path to the Prisma datamodel file
-    #[structopt(long, env = "PRISMA_DML_PATH", parse(from_os_str = load_datamodel_file))]
-    pub datamodel_path: Option<String>,
+    /// Path to the Prisma datamodel file. The files it imports are loaded with it.
+    #[structopt(long, env = "PRISMA_DML_PATH", parse(from_os_str = load_datamodel_files))]
+    pub datamodel_path: Option<DatamodelFiles>,
 
     /// Base64 encoded Prisma datamodel
     #[structopt(long, env = "PRISMA_DML", parse(try_from_str = parse_base64_string))]
@@ -98,6 +98,10 @@ pub struct PrismaOpt {
     pub raw_feature_flags: Vec<String>,
 }
 
+/// The files of a datamodel loaded from disk, the root file first.
+#[derive(Debug, Clone)]
+pub struct DatamodelFiles(pub Vec<SourceFile>);
+
 #[derive(Debug, Deserialize)]
 struct SourceOverride {
     name: String,
@@ -105,12 +109,15 @@ struct SourceOverride {
 }
 
 impl PrismaOpt {
-    fn datamodel_str(&self) -> PrismaResult<&str> {
+    fn datamodel_files(&self) -> PrismaResult<Cow<'_, [SourceFile]>> {
         let res = self
             .datamodel
             .as_ref()
-            .map(|dm| dm.as_str())
-            .or(self.datamodel_path.as_ref().map(|dm| dm.as_str()))
+            .map(|dm| Cow::Owned(vec![SourceFile::new("schema.prisma", dm.as_str())]))
+            .or(self
+                .datamodel_path
+                .as_ref()
+                .map(|files| Cow::Borrowed(files.0.as_slice())))
             .ok_or_else(|| {
                 PrismaError::ConfigurationError(
                     "Datamodel should be provided either as path or base64-encoded string.".into(),
@@ -121,22 +128,22 @@ impl PrismaOpt {
     }
 
     pub fn datamodel(&self, ignore_env_errors: bool) -> PrismaResult<Datamodel> {
-        let datamodel_str = self.datamodel_str()?;
+        let files = self.datamodel_files()?;
 
         let datamodel = if ignore_env_errors {
-            datamodel::parse_datamodel_and_ignore_datasource_urls(datamodel_str)
+            datamodel::parse_datamodel_from_files_and_ignore_datasource_urls(&files)
         } else {
-            datamodel::parse_datamodel(datamodel_str)
+            datamodel::parse_datamodel_from_files(&files)
         };
 
         match datamodel {
-            Err(errors) => Err(PrismaError::ConversionError(errors, datamodel_str.to_string())),
+            Err(errors) => Err(PrismaError::ConversionError(errors, files.into_owned())),
             _ => Ok(datamodel?),
         }
     }
 
     pub fn configuration(&self, ignore_env_errors: bool) -> PrismaResult<Configuration> {
-        let datamodel_str = self.datamodel_str()?;
+        let files = self.datamodel_files()?;
 
         let datasource_url_overrides: Vec<(String, String)> = if let Some(ref json) = self.overwrite_datasources {
             let datasource_url_overrides: Vec<SourceOverride> = serde_json::from_str(&json)?;
@@ -146,12 +153,12 @@ impl PrismaOpt {
         };
 
         let config_result = if ignore_env_errors {
-            datamodel::parse_configuration_and_ignore_datasource_urls(datamodel_str)
+            datamodel::parse_configuration_from_files_and_ignore_datasource_urls(&files)
         } else {
-            datamodel::parse_configuration_with_url_overrides(datamodel_str, datasource_url_overrides)
+            datamodel::parse_configuration_from_files_with_url_overrides(&files, datasource_url_overrides)
         };
 
-        config_result.map_err(|errors| PrismaError::ConversionError(errors, datamodel_str.to_string()))
+        config_result.map_err(|errors| PrismaError::ConversionError(errors, files.into_owned()))
     }
 
     /// Extract the log format from on the RUST_LOG_FORMAT env var.
@@ -182,12 +189,8 @@ fn parse_base64_string(s: &str) -> PrismaResult<String> {
     }
 }
 
-fn load_datamodel_file(path: &OsStr) -> String {
-    let mut f = File::open(path).expect(&format!("Could not open datamodel file {:?}", path));
-    let mut datamodel = String::new();
-
-    f.read_to_string(&mut datamodel)
-        .expect(&format!("Could not read datamodel file: {:?}", path));
+fn load_datamodel_files(path: &OsStr) -> DatamodelFiles {
+    let files = datamodel::load_schema_files(path).expect(&format!("Could not read datamodel file: {:?}", path));
 
-    datamodel
+    DatamodelFiles(files)
 }
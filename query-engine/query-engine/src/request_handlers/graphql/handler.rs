@@ -3,7 +3,7 @@ use crate::{context::PrismaContext, PrismaResponse, PrismaResult};
 use futures::FutureExt;
 use graphql_parser as gql;
 use indexmap::IndexMap;
-use query_core::{BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue, ResponseData};
+use query_core::{BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue, ResponseData, TxId};
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, panic::AssertUnwindSafe, sync::Arc};
 
@@ -75,23 +75,26 @@ impl GraphQlBody {
 }
 
 /// Handle a Graphql request.
-pub(crate) async fn handle(body: GraphQlBody, cx: Arc<PrismaContext>) -> PrismaResponse {
+/// If a transaction id is given, the request is executed inside of that open interactive transaction.
+pub(crate) async fn handle(body: GraphQlBody, tx_id: Option<TxId>, cx: Arc<PrismaContext>) -> PrismaResponse {
     debug!("Incoming GraphQL query: {:?}", body);
 
     match body.into_doc() {
-        Ok(QueryDocument::Single(query)) => handle_single_query(query, cx.clone()).await,
-        Ok(QueryDocument::Multi(batch)) => match batch.compact() {
-            BatchDocument::Multi(batch, transactional) => handle_batch(batch, transactional, &cx).await,
-            BatchDocument::Compact(compacted) => handle_compacted(compacted, &cx).await,
+        Ok(QueryDocument::Single(query)) => handle_single_query(query, tx_id, cx.clone()).await,
+        Ok(QueryDocument::Multi(batch)) => match (batch.compact(), tx_id) {
+            // Batches in interactive transactions run in sequence inside of that transaction.
+            (BatchDocument::Multi(batch, _), Some(tx_id)) => handle_batch_in_tx(batch, tx_id, &cx).await,
+            (BatchDocument::Multi(batch, transactional), None) => handle_batch(batch, transactional, &cx).await,
+            (BatchDocument::Compact(compacted), tx_id) => handle_compacted(compacted, tx_id, &cx).await,
         },
         Err(err) => PrismaResponse::Single(err.into()),
     }
 }
 
-async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_single_query(query: Operation, tx_id: Option<TxId>, ctx: Arc<PrismaContext>) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, &*ctx))
+    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, tx_id.as_ref(), &*ctx))
         .catch_unwind()
         .await
     {
@@ -139,7 +142,21 @@ async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<Pr
     }
 }
 
-async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_batch_in_tx(queries: Vec<Operation>, tx_id: TxId, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+    let mut responses = Vec::with_capacity(queries.len());
+
+    for query in queries {
+        responses.push(handle_single_query(query, Some(tx_id.clone()), Arc::clone(ctx)).await);
+    }
+
+    PrismaResponse::Multi(responses)
+}
+
+async fn handle_compacted(
+    document: CompactedDocument,
+    tx_id: Option<TxId>,
+    ctx: &Arc<PrismaContext>,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
     let plural_name = document.plural_name();
@@ -148,7 +165,7 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     let arguments = document.arguments;
     let nested_selection = document.nested_selection;
 
-    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx))
+    match AssertUnwindSafe(handle_graphql_query(document.operation, tx_id.as_ref(), ctx))
         .catch_unwind()
         .await
     {
@@ -206,6 +223,15 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     }
 }
 
-async fn handle_graphql_query(query_doc: Operation, ctx: &PrismaContext) -> PrismaResult<ResponseData> {
-    Ok(ctx.executor.execute(query_doc, Arc::clone(ctx.query_schema())).await?)
+async fn handle_graphql_query(
+    query_doc: Operation,
+    tx_id: Option<&TxId>,
+    ctx: &PrismaContext,
+) -> PrismaResult<ResponseData> {
+    let query_schema = Arc::clone(ctx.query_schema());
+
+    match tx_id {
+        Some(tx_id) => Ok(ctx.executor.execute_in_tx(tx_id, query_doc, query_schema).await?),
+        None => Ok(ctx.executor.execute(query_doc, query_schema).await?),
+    }
 }
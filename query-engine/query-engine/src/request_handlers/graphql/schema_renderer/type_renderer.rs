@@ -48,6 +48,8 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "DateTime",
                     ScalarType::UUID => "UUID",
+                    ScalarType::BigInt => "BigInt",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::JsonList => "Json",
                     ScalarType::Enum(_) => unreachable!("Encountered enum type during GQL scalar rendering."), // Handled separately above.
                     ScalarType::Null => unreachable!("Null types should not be picked for GQL rendering."),
@@ -90,6 +92,8 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
+                    ScalarType::BigInt => "BigInt",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::JsonList => "Json",
                     ScalarType::Enum(_) => unreachable!("Encountered enum type during GQL scalar rendering."), // Handled separately above.
                     ScalarType::Null => unreachable!("Null types should not be picked for GQL rendering."),
@@ -3,17 +3,18 @@
 use crate::context::PrismaContext;
 use crate::dmmf;
 use crate::opt::PrismaOpt;
-use crate::request_handlers::graphql::{self, GraphQLSchemaRenderer, GraphQlBody};
+use crate::request_handlers::graphql::{self, GQLResponse, GraphQLSchemaRenderer, GraphQlBody};
 use crate::PrismaResult;
 use elapsed_middleware::ElapsedMiddleware;
 
-use query_core::schema::QuerySchemaRenderer;
+use query_core::{schema::QuerySchemaRenderer, TxId};
+use serde::Deserialize;
 use serde_json::json;
 use tide::http::{mime, StatusCode};
 use tide::{Body, Request, Response};
 use tide_server_timing::TimingMiddleware;
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 mod elapsed_middleware;
 
@@ -63,6 +64,9 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
     }
 
     app.at("/").post(graphql_handler);
+    app.at("/transaction/start").post(transaction_start_handler);
+    app.at("/transaction/:id/commit").post(transaction_commit_handler);
+    app.at("/transaction/:id/rollback").post(transaction_rollback_handler);
     app.at("/").get(playground_handler);
     app.at("/sdl").get(sdl_handler);
     app.at("/dmmf").get(dmmf_handler);
@@ -92,14 +96,80 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
         }
     }
 
+    let tx_id: Option<TxId> = req
+        .header(TRANSACTION_ID_HEADER)
+        .map(|values| values.last().as_str().to_owned());
+
     let body: GraphQlBody = req.body_json().await?;
     let cx = req.state().cx.clone();
-    let result = graphql::handle(body, cx).await;
+    let result = graphql::handle(body, tx_id, cx).await;
+    let mut res = Response::new(StatusCode::Ok);
+    res.set_body(Body::from_json(&result)?);
+    Ok(res)
+}
+
+/// Header routing a GraphQL request onto an open interactive transaction.
+static TRANSACTION_ID_HEADER: &str = "x-transaction-id";
+
+/// Timeout of interactive transactions if not given when starting the transaction.
+const DEFAULT_TRANSACTION_TIMEOUT_MS: u64 = 5000;
+
+/// Request body to start an interactive transaction.
+#[derive(Debug, Default, Deserialize)]
+struct StartTransactionBody {
+    /// Milliseconds until the transaction expires and is rolled back.
+    timeout: Option<u64>,
+}
+
+/// Starts an interactive transaction and returns its id. Subsequent GraphQL requests
+/// carrying the id in the transaction id header are executed inside of the transaction.
+async fn transaction_start_handler(mut req: Request<State>) -> tide::Result {
+    let body = req.body_string().await?;
+    let body: StartTransactionBody = if body.trim().is_empty() {
+        StartTransactionBody::default()
+    } else {
+        serde_json::from_str(&body)?
+    };
+
+    let timeout = Duration::from_millis(body.timeout.unwrap_or(DEFAULT_TRANSACTION_TIMEOUT_MS));
+    let result = match req.state().cx.executor.start_tx(timeout).await {
+        Ok(tx_id) => json!({ "id": tx_id }),
+        Err(err) => serde_json::to_value(GQLResponse::from(err))?,
+    };
+
     let mut res = Response::new(StatusCode::Ok);
     res.set_body(Body::from_json(&result)?);
     Ok(res)
 }
 
+/// Commits the interactive transaction with the given id.
+async fn transaction_commit_handler(req: Request<State>) -> tide::Result {
+    let tx_id: TxId = req.param("id")?;
+    let result = req.state().cx.executor.commit_tx(&tx_id).await;
+
+    transaction_response(result)
+}
+
+/// Rolls back the interactive transaction with the given id.
+async fn transaction_rollback_handler(req: Request<State>) -> tide::Result {
+    let tx_id: TxId = req.param("id")?;
+    let result = req.state().cx.executor.rollback_tx(&tx_id).await;
+
+    transaction_response(result)
+}
+
+/// Empty response on success, errors are rendered like GraphQL errors.
+fn transaction_response(result: query_core::Result<()>) -> tide::Result {
+    let body = match result {
+        Ok(()) => json!({}),
+        Err(err) => serde_json::to_value(GQLResponse::from(err))?,
+    };
+
+    let mut res = Response::new(StatusCode::Ok);
+    res.set_body(Body::from_json(&body)?);
+    Ok(res)
+}
+
 /// Expose the GraphQL playground if enabled.
 ///
 /// # Security
@@ -1,5 +1,6 @@
 mod decimal;
 mod dmmf;
 mod execute_raw;
+mod interactive_transactions;
 mod test_api;
 mod type_mappings;
@@ -141,6 +141,44 @@ fn must_fail_if_the_schema_is_invalid() {
     assert!(test_dmmf_cli_command(schema).is_err());
 }
 
+#[test]
+#[serial]
+fn views_only_get_read_operations() {
+    let dm = r#"
+        model Blog {
+            blogId String @id
+        }
+
+        view BlogInfo {
+            blogId String @id
+            title  String
+        }
+    "#;
+
+    let (query_schema, datamodel) = get_query_schema(dm);
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    let field_names = |type_name: &str| -> Vec<String> {
+        dmmf.schema
+            .output_types
+            .iter()
+            .find(|output_type| output_type.name == type_name)
+            .unwrap_or_else(|| panic!("finding {}", type_name))
+            .fields
+            .iter()
+            .map(|field| field.name.clone())
+            .collect()
+    };
+
+    let query_fields = field_names("Query");
+    let mutation_fields = field_names("Mutation");
+
+    assert!(query_fields.iter().any(|name| name == "findManyBlogInfo"));
+    assert!(query_fields.iter().any(|name| name == "findFirstBlogInfo"));
+    assert!(mutation_fields.iter().any(|name| name == "createOneBlog"));
+    assert!(!mutation_fields.iter().any(|name| name.contains("BlogInfo")));
+}
+
 fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
     feature_flags::initialize(&[]).unwrap();
 
@@ -0,0 +1,126 @@
+use super::test_api::*;
+use indoc::indoc;
+use query_core::{CoreError, TransactionError};
+use serde_json::json;
+use std::time::Duration;
+use test_macros::test_each_connector;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int    @id
+        title String
+    }
+"};
+
+static FIND_TODOS: &str = "query { findManyTodo(orderBy: { id: asc }) { id } }";
+
+#[test_each_connector]
+async fn committed_transactions_persist_writes(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let tx_id = query_engine.start_tx(Duration::from_secs(5)).await?;
+
+    query_engine
+        .request_in_tx(
+            &tx_id,
+            r#"mutation { createOneTodo(data: { id: 1, title: "a" }) { id } }"#,
+        )
+        .await;
+
+    // Reads inside of the transaction see its own writes.
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 1 }] } }),
+        query_engine.request_in_tx(&tx_id, FIND_TODOS).await
+    );
+
+    query_engine.commit_tx(&tx_id).await?;
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "id": 1 }] } }),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rolled_back_transactions_discard_writes(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let tx_id = query_engine.start_tx(Duration::from_secs(5)).await?;
+
+    query_engine
+        .request_in_tx(
+            &tx_id,
+            r#"mutation { createOneTodo(data: { id: 1, title: "a" }) { id } }"#,
+        )
+        .await;
+
+    query_engine.rollback_tx(&tx_id).await?;
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [] } }),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    match query_engine.commit_tx(&tx_id).await {
+        Err(CoreError::TransactionError(TransactionError::NotFound(_))) => (),
+        other => panic!("Expected a transaction not found error, got {:?}", other),
+    }
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn expired_transactions_are_rolled_back(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let tx_id = query_engine.start_tx(Duration::from_millis(50)).await?;
+
+    query_engine
+        .request_in_tx(
+            &tx_id,
+            r#"mutation { createOneTodo(data: { id: 1, title: "a" }) { id } }"#,
+        )
+        .await;
+
+    async_std::task::sleep(Duration::from_millis(200)).await;
+
+    match query_engine.commit_tx(&tx_id).await {
+        Err(CoreError::TransactionError(TransactionError::Expired { timeout_ms: 50, .. })) => (),
+        other => panic!("Expected a transaction expired error, got {:?}", other),
+    }
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [] } }),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn expired_transactions_are_forgotten_once_reported(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&vec![String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let tx_id = query_engine.start_tx(Duration::from_millis(50)).await?;
+
+    async_std::task::sleep(Duration::from_millis(200)).await;
+
+    match query_engine.commit_tx(&tx_id).await {
+        Err(CoreError::TransactionError(TransactionError::Expired { timeout_ms: 50, .. })) => (),
+        other => panic!("Expected a transaction expired error, got {:?}", other),
+    }
+
+    // The expired transaction was removed from the open transactions.
+    match query_engine.rollback_tx(&tx_id).await {
+        Err(CoreError::TransactionError(TransactionError::NotFound(_))) => (),
+        other => panic!("Expected a transaction not found error, got {:?}", other),
+    }
+
+    Ok(())
+}
@@ -12,8 +12,9 @@ use quaint::{
     connector::ConnectionInfo,
     visitor::{self, Visitor},
 };
+use query_core::TxId;
 use sql_migration_connector::{sql_migration::SqlMigration, SqlMigrationConnector};
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use test_setup::*;
 
 pub struct QueryEngine {
@@ -28,11 +29,32 @@ impl QueryEngine {
     pub async fn request(&self, body: impl Into<SingleQuery>) -> serde_json::Value {
         let body = GraphQlBody::Single(body.into());
         let cx = self.context.clone();
-        match graphql::handle(body, cx).await {
+        match graphql::handle(body, None, cx).await {
             PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
             _ => unreachable!(),
         }
     }
+
+    pub async fn request_in_tx(&self, tx_id: &TxId, body: impl Into<SingleQuery>) -> serde_json::Value {
+        let body = GraphQlBody::Single(body.into());
+        let cx = self.context.clone();
+        match graphql::handle(body, Some(tx_id.clone()), cx).await {
+            PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
+            _ => unreachable!(),
+        }
+    }
+
+    pub async fn start_tx(&self, timeout: Duration) -> query_core::Result<TxId> {
+        self.context.executor.start_tx(timeout).await
+    }
+
+    pub async fn commit_tx(&self, tx_id: &TxId) -> query_core::Result<()> {
+        self.context.executor.commit_tx(tx_id).await
+    }
+
+    pub async fn rollback_tx(&self, tx_id: &TxId) -> query_core::Result<()> {
+        self.context.executor.rollback_tx(tx_id).await
+    }
 }
 
 pub struct TestApi {
@@ -49,7 +71,7 @@ impl TestApi {
 
         self.migration_api
             .schema_push(&SchemaPushInput {
-                schema: datamodel_string,
+                schema: datamodel_string.into(),
                 force: true,
                 assume_empty: true,
             })
@@ -79,7 +79,7 @@ fn expected_create_one_types_result() -> serde_json::Value {
         "numeric_integer_tinyint": 12,
         "numeric_integer_smallint": 350,
         "numeric_integer_int": 9002,
-        "numeric_integer_bigint": 30000,
+        "numeric_integer_bigint": "30000",
         "numeric_floating_decimal": 3.14,
         "numeric_floating_numeric": 3.14,
         "numeric_floating_float": -32.0,
@@ -150,7 +150,7 @@ async fn types_roundtrip(api: &TestApi) -> TestResult {
             .assert_field_type("numeric_integer_tinyint", ScalarType::Int)?
             .assert_field_type("numeric_integer_smallint", ScalarType::Int)?
             .assert_field_type("numeric_integer_int", ScalarType::Int)?
-            .assert_field_type("numeric_integer_bigint", ScalarType::Int)?
+            .assert_field_type("numeric_integer_bigint", ScalarType::BigInt)?
             .assert_field_type("numeric_floating_decimal", ScalarType::Float)?
             .assert_field_type("numeric_floating_float", ScalarType::Float)?
             .assert_field_type("numeric_floating_double", ScalarType::Float)?
@@ -104,7 +104,7 @@ fn expected_create_one_types_result() -> serde_json::Value {
         "numeric_integer_tinyint": 12,
         "numeric_integer_smallint": 350,
         "numeric_integer_int": 9002,
-        "numeric_integer_bigint": 30000,
+        "numeric_integer_bigint": "30000",
         "numeric_floating_decimal": 3.14,
         "numeric_floating_float": -32.0,
         "numeric_fixed_double": 0.14,
@@ -183,7 +183,7 @@ async fn mysql_types_roundtrip(api: &TestApi) -> TestResult {
             .assert_field_type("numeric_integer_tinyint", ScalarType::Int)?
             .assert_field_type("numeric_integer_smallint", ScalarType::Int)?
             .assert_field_type("numeric_integer_int", ScalarType::Int)?
-            .assert_field_type("numeric_integer_bigint", ScalarType::Int)?
+            .assert_field_type("numeric_integer_bigint", ScalarType::BigInt)?
             .assert_field_type("numeric_floating_decimal", ScalarType::Float)?
             .assert_field_type("numeric_floating_float", ScalarType::Float)?
             .assert_field_type("numeric_fixed_double", ScalarType::Float)?
@@ -647,7 +647,7 @@ async fn mysql_db_level_defaults_work(api: &TestApi) -> TestResult {
                     "numeric_integer_tinyint": 7,
                     "numeric_integer_smallint": 42,
                     "numeric_integer_int": 9001,
-                    "numeric_integer_bigint": 1000000,
+                    "numeric_integer_bigint": "1000000",
                     "numeric_floating_decimal": 3.14,
                     "numeric_floating_float": 6.0,
                     "numeric_fixed_double": 60.3,
@@ -156,7 +156,7 @@ fn create_one_types_response() -> serde_json::Value {
             "createOnetypes": {
                 "numeric_int2": 12,
                 "numeric_int4": 9002,
-                "numeric_int8": 100000000,
+                "numeric_int8": "100000000",
                 "numeric_serial2": 8,
                 "numeric_serial4": 80,
                 "numeric_serial8": 80000,
@@ -195,7 +195,7 @@ async fn postgres_types_roundtrip(api: &TestApi) -> TestResult {
         model
             .assert_field_type("numeric_int2", ScalarType::Int)?
             .assert_field_type("numeric_int4", ScalarType::Int)?
-            .assert_field_type("numeric_int8", ScalarType::Int)?
+            .assert_field_type("numeric_int8", ScalarType::BigInt)?
             .assert_field_type("numeric_decimal", ScalarType::Float)?
             .assert_field_type("numeric_float4", ScalarType::Float)?
             .assert_field_type("numeric_float8", ScalarType::Float)?
@@ -408,7 +408,7 @@ fn create_one_array_types_response() -> serde_json::Value {
             "createOnearraytypes": {
                 "numeric_int2": [12],
                 "numeric_int4": [9002],
-                "numeric_int8": [100000000],
+                "numeric_int8": ["100000000"],
                 "numeric_decimal": [49.3444],
                 "numeric_float4": [12.12],
                 "numeric_float8": [3.139428],
@@ -444,7 +444,7 @@ async fn postgres_array_types_roundtrip(api: &TestApi) -> TestResult {
         model
             .assert_field_type("numeric_int2", ScalarType::Int)?
             .assert_field_type("numeric_int4", ScalarType::Int)?
-            .assert_field_type("numeric_int8", ScalarType::Int)?
+            .assert_field_type("numeric_int8", ScalarType::BigInt)?
             .assert_field_type("numeric_decimal", ScalarType::Float)?
             .assert_field_type("numeric_float4", ScalarType::Float)?
             .assert_field_type("numeric_float8", ScalarType::Float)?
@@ -482,7 +482,7 @@ async fn all_postgres_id_types_work(api: &TestApi) -> TestResult {
     let identifier_types = &[
         ("int2", "12"),
         ("int4", "78"),
-        ("int8", "1293"),
+        ("int8", "\"1293\""),
         ("decimal(8, 4)", "2.5"),
         ("float4", "2.8"),
         ("float8", "2.000039"),
@@ -706,7 +706,7 @@ async fn postgres_db_level_defaults_work(api: &TestApi) -> TestResult {
             "createOnetypes": {
                 "numeric_int2": 7,
                 "numeric_int4": 777,
-                "numeric_int8": 777777,
+                "numeric_int8": "777777",
                 "numeric_decimal": 3.14,
                 "numeric_float4": 3.14,
                 "numeric_float8": 3.14,
@@ -829,7 +829,7 @@ async fn postgres_db_level_array_defaults_work(api: &TestApi) -> TestResult {
             "createOnearraytypes": {
                 "numeric_int2": [1, 2, 3],
                 "numeric_int4": [3, 2, 3],
-                "numeric_int8": [3, 2, 3],
+                "numeric_int8": ["3", "2", "3"],
                 "numeric_decimal": [6.1, 6.2, 6.3],
                 "numeric_float4": [6.1, 6.2, 6.3],
                 "numeric_float8": [6.1, 6.2, 6.3],